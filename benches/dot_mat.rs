@@ -0,0 +1,42 @@
+//! compares the naive matrix product with the blocked one
+//!
+//! run it with `cargo bench --bench dot_mat`
+
+use math::linear_algebra::Matrix;
+use std::time::{Duration, Instant};
+
+// keeps the result alive so the product isn't optimized away, `std::hint::black_box` needs Rust 1.66
+#[inline(never)]
+fn black_box<T>(val: T) -> T {
+    val
+}
+
+fn time<F: FnMut() -> Matrix>(runs: u32, mut func: F) -> Duration {
+    let start = Instant::now();
+    for _ in 0..runs {
+        black_box(func());
+    }
+    start.elapsed() / runs
+}
+
+fn main() {
+    for &n in [128, 256, 512, 1000].iter() {
+        let matrix1 = Matrix::new_rand(n, n);
+        let matrix2 = Matrix::new_rand(n, n);
+        let runs = if n < 512 { 10 } else { 2 };
+
+        // a block size bigger then the matrix is the naive product
+        let naive = time(runs, || matrix1.dot_mat_blocked(&matrix2, n));
+        println!("{0}x{0} naive      {1:?}", n, naive);
+        for &block_size in [16, 32, 64, 128].iter() {
+            let blocked = time(runs, || matrix1.dot_mat_blocked(&matrix2, block_size));
+            println!(
+                "{0}x{0} block {1:<4} {2:?} ({3:.2}x)",
+                n,
+                block_size,
+                blocked,
+                naive.as_secs_f64() / blocked.as_secs_f64()
+            );
+        }
+    }
+}
@@ -0,0 +1,51 @@
+/* C interface of the math crate, build the shared library with
+ * `cargo rustc --release --lib --features ffi --crate-type cdylib` */
+
+#ifndef MATH_H
+#define MATH_H
+
+#include <stdbool.h>
+#include <stddef.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* opaque handles, release them with math_matrix_free / math_vector_free */
+typedef struct Matrix Matrix;
+typedef struct Vector Vector;
+
+/* data has cols * rows values in the layout of Matrix::new_flatt, NULL if cols * rows overflows */
+Matrix *math_matrix_new(const float *data, size_t cols, size_t rows);
+Matrix *math_matrix_zero(size_t cols, size_t rows);
+void math_matrix_free(Matrix *matrix);
+
+size_t math_matrix_cols(const Matrix *matrix);
+size_t math_matrix_rows(const Matrix *matrix);
+
+/* returns NaN if the index is out of bounds */
+float math_matrix_get(const Matrix *matrix, size_t row, size_t col);
+/* returns false if the index is out of bounds */
+bool math_matrix_set(Matrix *matrix, size_t row, size_t col, float val);
+/* returns false if len is not cols * rows */
+bool math_matrix_copy_data(const Matrix *matrix, float *out, size_t len);
+
+/* returns NULL if the shapes don't match */
+Matrix *math_matrix_matmul(const Matrix *a, const Matrix *b);
+/* solves a * x = b, returns NULL if a is singular or the shapes don't match */
+Vector *math_matrix_solve(const Matrix *a, const Vector *b);
+
+Vector *math_vector_new(const float *data, size_t len);
+void math_vector_free(Vector *vector);
+
+size_t math_vector_len(const Vector *vector);
+/* returns NaN if the index is out of bounds */
+float math_vector_get(const Vector *vector, size_t index);
+/* returns false if len is not the len of the vector */
+bool math_vector_copy_data(const Vector *vector, float *out, size_t len);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* MATH_H */
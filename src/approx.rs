@@ -0,0 +1,191 @@
+//! approximations of functions by polynomials and rational functions
+
+use crate::linear_algebra::{Matrix, Vector};
+use std::f64::consts::PI;
+
+/// a [Chebyshev] series `sum c_k T_k(t)` on the interval `[a, b]`, where `t` is `x` mapped to `[-1, 1]`
+///
+/// [Chebyshev]: https://en.wikipedia.org/wiki/Chebyshev_polynomials
+#[derive(PartialEq, Clone, Debug)]
+pub struct Chebyshev {
+    a: f32,
+    b: f32,
+    coeffs: Vec<f32>,
+}
+
+/// fits the function on `[a, b]` with a Chebyshev series of the degree by interpolating it at the
+/// `degree + 1` Chebyshev nodes, which is close to the best polynomial approximation for smooth functions
+///
+/// ## Example
+///
+/// ```rust
+/// use math::approx::chebyshev_fit;
+/// let exp = chebyshev_fit(|x| x.exp(), 0., 2., 10);
+/// assert!((exp.eval(1.3) - 1.3f32.exp()).abs() < 1e-5);
+/// assert_eq!(exp.coeffs().len(), 11);
+/// ```
+/// note it panics if `a >= b`
+pub fn chebyshev_fit<F: Fn(f32) -> f32>(func: F, a: f32, b: f32, degree: usize) -> Chebyshev {
+    if a >= b {
+        panic!("the interval [{}, {}] is empty", a, b);
+    }
+    let n = degree + 1;
+    let (half, mid) = ((b as f64 - a as f64) / 2., (b as f64 + a as f64) / 2.);
+    let values: Vec<f64> = (0..n)
+        .map(|k| {
+            let t = (PI * (k as f64 + 0.5) / n as f64).cos();
+            func((mid + half * t) as f32) as f64
+        })
+        .collect();
+
+    let coeffs = (0..n)
+        .map(|j| {
+            let sum: f64 = values
+                .iter()
+                .enumerate()
+                .map(|(k, v)| v * (PI * j as f64 * (k as f64 + 0.5) / n as f64).cos())
+                .sum();
+            let scale = if j == 0 { 1. } else { 2. };
+            (scale * sum / n as f64) as f32
+        })
+        .collect();
+    Chebyshev { a, b, coeffs }
+}
+
+impl Chebyshev {
+    /// creates the series from the coefficients `c_0, c_1, ...` on the interval `[a, b]`
+    ///
+    /// note it panics if `a >= b`
+    pub fn new(coeffs: Vec<f32>, a: f32, b: f32) -> Self {
+        if a >= b {
+            panic!("the interval [{}, {}] is empty", a, b);
+        }
+        Chebyshev { a, b, coeffs }
+    }
+
+    /// returns the coefficients `c_0, c_1, ...`
+    pub fn coeffs(&self) -> &[f32] {
+        &self.coeffs
+    }
+
+    /// returns the interval `(a, b)`
+    pub fn interval(&self) -> (f32, f32) {
+        (self.a, self.b)
+    }
+
+    /// evaluates the series at `x` with the [Clenshaw algorithm],
+    /// values outside of the interval are extrapolated
+    ///
+    /// [Clenshaw algorithm]: https://en.wikipedia.org/wiki/Clenshaw_algorithm
+    pub fn eval(&self, x: f32) -> f32 {
+        let t = (2. * x as f64 - self.a as f64 - self.b as f64) / (self.b as f64 - self.a as f64);
+        let (mut b1, mut b2) = (0f64, 0f64);
+        for &c in self.coeffs.iter().skip(1).rev() {
+            let b0 = 2. * t * b1 - b2 + c as f64;
+            b2 = b1;
+            b1 = b0;
+        }
+        let c0 = self.coeffs.first().copied().unwrap_or(0.) as f64;
+        (t * b1 - b2 + c0) as f32
+    }
+
+    /// returns the series of the derivative
+    pub fn derivative(&self) -> Chebyshev {
+        let n = self.coeffs.len();
+        if n <= 1 {
+            return Chebyshev::new(vec![0.], self.a, self.b);
+        }
+        // c'_{k-1} = c'_{k+1} + 2 k c_k
+        let mut deriv = vec![0f64; n + 1];
+        for k in (1..n).rev() {
+            deriv[k - 1] = deriv[k + 1] + 2. * k as f64 * self.coeffs[k] as f64;
+        }
+        deriv[0] /= 2.;
+        let scale = 2. / (self.b as f64 - self.a as f64);
+        let coeffs = deriv[..n - 1].iter().map(|c| (c * scale) as f32).collect();
+        Chebyshev::new(coeffs, self.a, self.b)
+    }
+}
+
+/// a rational function `p(x) / q(x)` with the polynomial coefficients in increasing order
+#[derive(PartialEq, Clone, Debug)]
+pub struct Pade {
+    numer: Vec<f32>,
+    denom: Vec<f32>,
+}
+
+/// computes the [Padé approximant] of the power series `sum coeffs[k] x^k`
+/// with a numerator of degree `m` and a denominator of degree `n`
+///
+/// the coefficients of the denominator are found by solving the linear system that makes the first
+/// `m + n + 1` coefficients of the series of `p(x) / q(x)` match, the constant of the denominator is 1
+///
+/// [Padé approximant]: https://en.wikipedia.org/wiki/Pad%C3%A9_approximant
+///
+/// ## Example
+///
+/// ```rust
+/// use math::approx::pade;
+/// // the series of exp(x)
+/// let coeffs = [1., 1., 1. / 2., 1. / 6., 1. / 24.];
+/// let exp = pade(&coeffs, 2, 2);
+/// // (1 + x/2 + x^2/12) / (1 - x/2 + x^2/12)
+/// let expected = [1., 0.5, 1. / 12.];
+/// assert!(exp.numer().iter().zip(expected.iter()).all(|(a, b)| (a - b).abs() < 1e-6));
+/// assert!((exp.eval(1.) - 1f32.exp()).abs() < 5e-3);
+/// ```
+/// note it panics if there are less then `m + n + 1` coefficients or the system is singular
+pub fn pade(coeffs: &[f32], m: usize, n: usize) -> Pade {
+    if coeffs.len() < m + n + 1 {
+        panic!(
+            "expected at least {} coefficients, got {}",
+            m + n + 1,
+            coeffs.len()
+        );
+    }
+    // c_k for negative k is 0
+    let c = |k: isize| if k < 0 { 0. } else { coeffs[k as usize] };
+
+    let mut denom = vec![1.];
+    if n > 0 {
+        // sum_{j=1..n} q_j c_{k-j} = -c_k for k = m+1..m+n
+        let system = Matrix::new(
+            (m + 1..=m + n)
+                .map(|k| (1..=n).map(|j| c(k as isize - j as isize)).collect())
+                .collect(),
+        );
+        let rhs = Vector::new((m + 1..=m + n).map(|k| -coeffs[k]).collect());
+        denom.extend(system.solve(&rhs).vec());
+    }
+
+    let numer = (0..=m)
+        .map(|k| {
+            (0..=n.min(k))
+                .map(|j| denom[j] * c(k as isize - j as isize))
+                .sum()
+        })
+        .collect();
+    Pade { numer, denom }
+}
+
+// evaluates the polynomial with Horner's method
+fn horner(coeffs: &[f32], x: f32) -> f32 {
+    coeffs.iter().rev().fold(0., |acc, &c| acc * x + c)
+}
+
+impl Pade {
+    /// returns the coefficients of the numerator `p`
+    pub fn numer(&self) -> &[f32] {
+        &self.numer
+    }
+
+    /// returns the coefficients of the denominator `q`, the first one is always 1
+    pub fn denom(&self) -> &[f32] {
+        &self.denom
+    }
+
+    /// evaluates `p(x) / q(x)`
+    pub fn eval(&self, x: f32) -> f32 {
+        horner(&self.numer, x) / horner(&self.denom, x)
+    }
+}
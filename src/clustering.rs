@@ -0,0 +1,183 @@
+use crate::linear_algebra::Matrix;
+use crate::random::Random;
+
+/// the squared euclidean distance between two samples
+fn distance(a: &[f32], b: &[f32]) -> f32 {
+    a.iter().zip(b.iter()).map(|(x, y)| (x - y) * (x - y)).sum()
+}
+
+/// groups the samples into `k` clusters with [k-means clustering] (Lloyd's algorithm with the k-means++ initialization)
+///
+/// [k-means clustering]: https://en.wikipedia.org/wiki/K-means_clustering
+///
+/// ## Example
+///
+/// ```rust
+/// use math::clustering::KMeans;
+/// use math::linear_algebra::Matrix;
+/// let data = Matrix::new(vec![
+///     vec![0., 0.],
+///     vec![0.1, 0.2],
+///     vec![5., 5.],
+///     vec![5.2, 4.9],
+/// ]);
+/// let mut kmeans = KMeans::new(2);
+/// let labels = kmeans.fit_predict(&data);
+/// assert_eq!(labels[0], labels[1]);
+/// assert_eq!(labels[2], labels[3]);
+/// assert_ne!(labels[0], labels[2]);
+/// ```
+/// note each `col` of the data is one sample and each `row` is one feature
+#[derive(PartialEq, Clone, Debug)]
+pub struct KMeans {
+    k: usize,
+    max_iterations: usize,
+    centroids: Option<Matrix>,
+}
+
+impl KMeans {
+    /// creates the clustering with `k` clusters which still has to be fitted
+    ///
+    /// note it panics if `k` is 0
+    pub fn new(k: usize) -> Self {
+        if k == 0 {
+            panic!("k has to be at least 1");
+        }
+        KMeans {
+            k,
+            max_iterations: 100,
+            centroids: None,
+        }
+    }
+
+    /// sets the maximal number of iterations (the default is 100)
+    pub fn max_iterations(mut self, max_iterations: usize) -> Self {
+        self.max_iterations = max_iterations;
+        self
+    }
+
+    /// returns the centroids, `col(c)` is the center of the cluster `c`
+    pub fn centroids(&self) -> Option<&Matrix> {
+        self.centroids.as_ref()
+    }
+
+    /// computes the centroids of the data, the initialization uses `Random::new` so the result is deterministic
+    ///
+    /// note it panics if there are less samples then clusters
+    pub fn fit(&mut self, data: &Matrix) {
+        let (samples, features) = (data.cols(), data.rows());
+        if samples < self.k {
+            panic!(
+                "expected at least {} samples for {} clusters, got {}",
+                self.k, self.k, samples
+            );
+        }
+        let flatt = data.matrix_flatt();
+        let sample = |i: usize| &flatt[i * features..(i + 1) * features];
+
+        // k-means++ picks each new center with a probability proportional to the squared distance to the nearest center
+        let mut rng = Random::new();
+        let mut centroids: Vec<f32> = sample(rng.usize(samples)).to_vec();
+        let mut nearest: Vec<f32> = (0..samples)
+            .map(|i| distance(sample(i), &centroids))
+            .collect();
+        for c in 1..self.k {
+            let total: f32 = nearest.iter().sum();
+            let next = if total > 0. {
+                let mut target = rng.f32() * total;
+                let mut next = samples - 1;
+                for (i, &d) in nearest.iter().enumerate() {
+                    if target < d {
+                        next = i;
+                        break;
+                    }
+                    target -= d;
+                }
+                next
+            } else {
+                rng.usize(samples)
+            };
+            centroids.extend_from_slice(sample(next));
+            let center = &centroids[c * features..];
+            for (i, d) in nearest.iter_mut().enumerate() {
+                *d = d.min(distance(sample(i), center));
+            }
+        }
+
+        let mut labels = vec![usize::MAX; samples];
+        for _ in 0..self.max_iterations {
+            let new_labels: Vec<usize> = (0..samples)
+                .map(|i| closest(sample(i), &centroids, features))
+                .collect();
+            if new_labels == labels {
+                break;
+            }
+            labels = new_labels;
+
+            let mut sums = vec![0.; self.k * features];
+            let mut counts = vec![0; self.k];
+            for (i, &label) in labels.iter().enumerate() {
+                counts[label] += 1;
+                for (sum, x) in sums[label * features..].iter_mut().zip(sample(i)) {
+                    *sum += x;
+                }
+            }
+            // empty clusters keep their old center
+            for c in 0..self.k {
+                if counts[c] > 0 {
+                    for f in 0..features {
+                        centroids[c * features + f] = sums[c * features + f] / counts[c] as f32;
+                    }
+                }
+            }
+        }
+
+        self.centroids = Some(Matrix::new_flatt(centroids, self.k, features));
+    }
+
+    /// returns the index of the closest centroid for each sample
+    ///
+    /// note it panics if `fit` wasn't called yet or the data has the wrong number of features
+    pub fn predict(&self, data: &Matrix) -> Vec<usize> {
+        let centroids = match &self.centroids {
+            Some(centroids) => centroids,
+            None => panic!("the clustering has to be fitted before it can predict"),
+        };
+        let features = centroids.rows();
+        if data.rows() != features {
+            panic!(
+                "wrong number of features expected {}, got {}",
+                features,
+                data.rows()
+            );
+        }
+        let centroids = centroids.matrix_flatt();
+        let flatt = data.matrix_flatt();
+        (0..data.cols())
+            .map(|i| {
+                closest(
+                    &flatt[i * features..(i + 1) * features],
+                    &centroids,
+                    features,
+                )
+            })
+            .collect()
+    }
+
+    /// same as `fit` followed by `predict`
+    pub fn fit_predict(&mut self, data: &Matrix) -> Vec<usize> {
+        self.fit(data);
+        self.predict(data)
+    }
+}
+
+fn closest(sample: &[f32], centroids: &[f32], features: usize) -> usize {
+    let mut best = (0, f32::INFINITY);
+    for (c, center) in centroids.chunks(features.max(1)).enumerate() {
+        let d = distance(sample, center);
+        if d < best.1 {
+            best = (c, d);
+        }
+    }
+    best.0
+}
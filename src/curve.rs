@@ -0,0 +1,168 @@
+//! Bézier and B-spline curves through control points of any dimension
+//!
+//! all curves are parameterized by `t` in `[0, 1]`, the derivatives are with respect to `t`
+//!
+//! ## Example
+//!
+//! ```rust
+//! use math::curve::{bezier, bezier_derivative};
+//! use math::linear_algebra::Vector;
+//! let points = [
+//!     Vector::new(vec![0., 0.]),
+//!     Vector::new(vec![1., 2.]),
+//!     Vector::new(vec![2., 0.]),
+//! ];
+//! assert_eq!(bezier(&points, 0.5), Vector::new(vec![1., 1.]));
+//! assert_eq!(bezier_derivative(&points, 0.), Vector::new(vec![2., 4.]));
+//! ```
+
+use crate::linear_algebra::Vector;
+
+// returns the control points as buffers and panics if there are none or they have different lens
+fn control_points(points: &[Vector]) -> Vec<Vec<f32>> {
+    let dim = match points.first() {
+        Some(first) => first.len(),
+        None => panic!("a curve needs at least one control point"),
+    };
+    points
+        .iter()
+        .map(|point| {
+            if point.len() != dim {
+                panic!(
+                    "all control points need the len {}, got {}",
+                    dim,
+                    point.len()
+                );
+            }
+            point.vec()
+        })
+        .collect()
+}
+
+// a = (1 - t) * a + t * b
+fn lerp_into(a: &mut [f32], b: &[f32], t: f32) {
+    a.iter_mut()
+        .zip(b.iter())
+        .for_each(|(x, y)| *x += t * (y - *x));
+}
+
+// the differences of consecutive points scaled by `scale`
+fn differences(points: &[Vec<f32>], scale: f32) -> Vec<Vec<f32>> {
+    points
+        .windows(2)
+        .map(|pair| {
+            pair[1]
+                .iter()
+                .zip(pair[0].iter())
+                .map(|(b, a)| scale * (b - a))
+                .collect()
+        })
+        .collect()
+}
+
+// evaluates the Bézier curve with De Casteljau's algorithm
+fn de_casteljau(mut points: Vec<Vec<f32>>, t: f32) -> Vector {
+    for n in (1..points.len()).rev() {
+        for i in 0..n {
+            let (lhs, rhs) = points.split_at_mut(i + 1);
+            lerp_into(&mut lhs[i], &rhs[0], t);
+        }
+    }
+    Vector::new(points.swap_remove(0))
+}
+
+/// evaluates the [Bézier curve] of the control points at `t` with De Casteljau's algorithm,
+/// the curve starts at the first point and ends at the last one
+///
+/// [Bézier curve]: https://en.wikipedia.org/wiki/B%C3%A9zier_curve
+///
+/// note it panics if there are no control points or they have different lens
+pub fn bezier(points: &[Vector], t: f32) -> Vector {
+    de_casteljau(control_points(points), t)
+}
+
+/// evaluates the derivative of the Bézier curve at `t`, which is the Bézier curve of the
+/// differences of the control points times the degree
+///
+/// note it panics if there are no control points or they have different lens
+pub fn bezier_derivative(points: &[Vector], t: f32) -> Vector {
+    let points = control_points(points);
+    if points.len() == 1 {
+        return Vector::new_zero(points[0].len());
+    }
+    let degree = (points.len() - 1) as f32;
+    de_casteljau(differences(&points, degree), t)
+}
+
+// evaluates the B-spline with the knots `i + offset` at u with de Boor's algorithm
+fn de_boor(points: &[Vec<f32>], degree: usize, offset: f32, u: f32) -> Vector {
+    let n = points.len();
+    // the segment k with u in [k, k + 1), the end of the domain belongs to the last segment
+    let k = ((u - offset).floor() as usize).clamp(degree, n - 1);
+    let mut d: Vec<Vec<f32>> = points[k - degree..=k].to_vec();
+    for r in 1..=degree {
+        for j in (r..=degree).rev() {
+            let knot = (j + k - degree) as f32 + offset;
+            let alpha = (u - knot) / (degree + 1 - r) as f32;
+            // d[j] = (1 - alpha) * d[j - 1] + alpha * d[j]
+            let (lhs, rhs) = d.split_at_mut(j);
+            rhs[0]
+                .iter_mut()
+                .zip(lhs[j - 1].iter())
+                .for_each(|(x, y)| *x = y + alpha * (*x - y));
+        }
+    }
+    Vector::new(d.swap_remove(degree))
+}
+
+// checks the degree and maps t to the domain [degree, n] of the uniform knots 0, 1, ..., n + degree
+fn bspline_param(points: &[Vec<f32>], degree: usize, t: f32) -> (f32, f32) {
+    let n = points.len();
+    if n <= degree {
+        panic!(
+            "a B-spline of degree {} needs at least {} control points, got {}",
+            degree,
+            degree + 1,
+            n
+        );
+    }
+    let scale = (n - degree) as f32;
+    (degree as f32 + t * scale, scale)
+}
+
+/// evaluates the uniform [B-spline] of the degree at `t` with de Boor's algorithm
+///
+/// the knots are uniform and not clamped, so the curve doesn't go through the first and last
+/// control point (except for degree 0 and 1) but each segment only depends on `degree + 1` points
+///
+/// [B-spline]: https://en.wikipedia.org/wiki/B-spline
+///
+/// ## Example
+///
+/// ```rust
+/// use math::curve::bspline;
+/// use math::linear_algebra::Vector;
+/// let points: Vec<Vector> = [0., 6., 0., 6.].iter().map(|&y| Vector::new(vec![y])).collect();
+/// // the cubic B-spline starts at (p0 + 4 p1 + p2) / 6
+/// assert_eq!(bspline(&points, 3, 0.), Vector::new(vec![4.]));
+/// ```
+/// note it panics if there are not more control points then the degree or they have different lens
+pub fn bspline(points: &[Vector], degree: usize, t: f32) -> Vector {
+    let points = control_points(points);
+    let (u, _) = bspline_param(&points, degree, t);
+    de_boor(&points, degree, 0., u)
+}
+
+/// evaluates the derivative of the uniform B-spline at `t`
+///
+/// note it panics if there are not more control points then the degree or they have different lens
+pub fn bspline_derivative(points: &[Vector], degree: usize, t: f32) -> Vector {
+    let points = control_points(points);
+    let (u, scale) = bspline_param(&points, degree, t);
+    if degree == 0 {
+        return Vector::new_zero(points[0].len());
+    }
+    // the derivative is a B-spline of degree - 1 of the differences with the knots shifted by 1,
+    // du / dt is the scale of the parameter
+    de_boor(&differences(&points, scale), degree - 1, 1., u)
+}
@@ -0,0 +1,189 @@
+use crate::linear_algebra::{Matrix, Vector};
+use crate::random::Random;
+
+// returns a matrix with the given `col`s of the data in this order
+fn select_cols(data: &Matrix, indices: &[usize]) -> Matrix {
+    let rows = data.rows();
+    let flatt = data.matrix_flatt();
+    let mut matrix_flatt = Vec::with_capacity(indices.len() * rows);
+    for &i in indices {
+        matrix_flatt.extend_from_slice(&flatt[i * rows..(i + 1) * rows]);
+    }
+    Matrix::new_flatt(matrix_flatt, indices.len(), rows)
+}
+
+fn select_vals(vector: &Vector, indices: &[usize]) -> Vector {
+    let vec = vector.vec();
+    Vector::new(indices.iter().map(|&i| vec[i]).collect())
+}
+
+fn check_samples(x: &Matrix, y: &Vector) {
+    if x.cols() != y.len() {
+        panic!(
+            "the number of samples is not the same x.cols() = {}, y.len() = {}",
+            x.cols(),
+            y.len()
+        );
+    }
+}
+
+/// shuffles the samples and splits them in to a training and test set,
+/// `train_ratio` is the fraction of samples which are used for the training set
+///
+/// ## Example
+///
+/// ```rust
+/// use math::data::train_test_split;
+/// use math::linear_algebra::{Matrix, Vector};
+/// use math::random::Random;
+/// let x = Matrix::new(vec![vec![0., 0.], vec![1., 1.], vec![2., 2.], vec![3., 3.]]);
+/// let y = Vector::new(vec![0., 1., 2., 3.]);
+/// let (x_train, y_train, x_test, y_test) = train_test_split(&x, &y, 0.75, &mut Random::new());
+/// assert_eq!(x_train.cols(), 3);
+/// assert_eq!(y_test.len(), 1);
+/// assert_eq!(x_test.col(0).index(0), y_test.index(0));
+/// ```
+/// note each `col` of `x` is one sample and `y` has one target per sample
+pub fn train_test_split(
+    x: &Matrix,
+    y: &Vector,
+    train_ratio: f32,
+    rng: &mut Random,
+) -> (Matrix, Vector, Matrix, Vector) {
+    check_samples(x, y);
+    if !(0. ..=1.).contains(&train_ratio) {
+        panic!("the ratio has to be between 0 and 1, got {}", train_ratio);
+    }
+
+    let mut indices: Vec<usize> = (0..x.cols()).collect();
+    rng.shuffle(&mut indices);
+    let split = (x.cols() as f32 * train_ratio).round() as usize;
+    let (train, test) = indices.split_at(split);
+
+    (
+        select_cols(x, train),
+        select_vals(y, train),
+        select_cols(x, test),
+        select_vals(y, test),
+    )
+}
+
+/// iterator over batches of the samples of a matrix
+///
+/// ## Example
+///
+/// ```rust
+/// use math::data::Batches;
+/// use math::linear_algebra::Matrix;
+/// let x = Matrix::new(vec![vec![0., 0.], vec![1., 1.], vec![2., 2.]]);
+/// let batches: Vec<Matrix> = Batches::new(&x, 2).collect();
+/// assert_eq!(batches[0], Matrix::new(vec![vec![0., 0.], vec![1., 1.]]));
+/// assert_eq!(batches[1], Matrix::new(vec![vec![2., 2.]]));
+/// ```
+/// note each `col` of the matrix is one sample and the last batch can be smaller
+pub struct Batches<'a> {
+    x: &'a Matrix,
+    order: Vec<usize>,
+    batch_size: usize,
+    position: usize,
+}
+
+impl<'a> Batches<'a> {
+    /// creates batches of `batch_size` samples in the original order
+    pub fn new(x: &'a Matrix, batch_size: usize) -> Self {
+        if batch_size == 0 {
+            panic!("the batch size has to be larger then 0");
+        }
+        Batches {
+            x,
+            order: (0..x.cols()).collect(),
+            batch_size,
+            position: 0,
+        }
+    }
+
+    /// shuffles the order of the samples
+    pub fn shuffle(mut self, rng: &mut Random) -> Self {
+        rng.shuffle(&mut self.order);
+        self
+    }
+
+    /// returns the number of batches
+    pub fn num_batches(&self) -> usize {
+        (self.order.len() + self.batch_size - 1) / self.batch_size
+    }
+
+    fn next_indices(&mut self) -> Option<&[usize]> {
+        if self.position >= self.order.len() {
+            return None;
+        }
+        let end = (self.position + self.batch_size).min(self.order.len());
+        let indices = &self.order[self.position..end];
+        self.position = end;
+        Some(indices)
+    }
+}
+
+impl<'a> Iterator for Batches<'a> {
+    type Item = Matrix;
+
+    fn next(&mut self) -> Option<Matrix> {
+        let x = self.x;
+        self.next_indices().map(|indices| select_cols(x, indices))
+    }
+}
+
+/// iterator over batches of samples and their targets
+///
+/// ## Example
+///
+/// ```rust
+/// use math::data::LabeledBatches;
+/// use math::linear_algebra::{Matrix, Vector};
+/// use math::random::Random;
+/// let x = Matrix::new(vec![vec![0., 0.], vec![1., 1.], vec![2., 2.]]);
+/// let y = Vector::new(vec![0., 1., 2.]);
+/// for (batch_x, batch_y) in LabeledBatches::new(&x, &y, 2).shuffle(&mut Random::new()) {
+///     for i in 0..batch_x.cols() {
+///         assert_eq!(batch_x.col(i).index(0), batch_y.index(i));
+///     }
+/// }
+/// ```
+/// note each `col` of the matrix is one sample and the last batch can be smaller
+pub struct LabeledBatches<'a> {
+    batches: Batches<'a>,
+    y: &'a Vector,
+}
+
+impl<'a> LabeledBatches<'a> {
+    /// creates batches of `batch_size` samples in the original order
+    pub fn new(x: &'a Matrix, y: &'a Vector, batch_size: usize) -> Self {
+        check_samples(x, y);
+        LabeledBatches {
+            batches: Batches::new(x, batch_size),
+            y,
+        }
+    }
+
+    /// shuffles the order of the samples
+    pub fn shuffle(mut self, rng: &mut Random) -> Self {
+        self.batches = self.batches.shuffle(rng);
+        self
+    }
+
+    /// returns the number of batches
+    pub fn num_batches(&self) -> usize {
+        self.batches.num_batches()
+    }
+}
+
+impl<'a> Iterator for LabeledBatches<'a> {
+    type Item = (Matrix, Vector);
+
+    fn next(&mut self) -> Option<(Matrix, Vector)> {
+        let (x, y) = (self.batches.x, self.y);
+        self.batches
+            .next_indices()
+            .map(|indices| (select_cols(x, indices), select_vals(y, indices)))
+    }
+}
@@ -0,0 +1,69 @@
+//! the error of the fallible `try_` functions
+//!
+//! ## Example
+//!
+//! ```rust
+//! use math::linear_algebra::{Matrix, Vector};
+//! use math::MathError;
+//! let matrix = Matrix::new(vec![vec![1., 2.], vec![3., 4.]]);
+//! let err = matrix.try_dot_vec(&Vector::new(vec![1., 2., 3.])).unwrap_err();
+//! assert_eq!(err, MathError::VectorShape { expected: 2, got: 3 });
+//! assert_eq!(err.to_string(), "wrong vector shape expected 2, got 3");
+//! ```
+
+use std::error::Error;
+use std::fmt;
+
+/// the reason a `try_` function failed, the message is the same as the panic message of the function without `try_`
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum MathError {
+    /// the matrices don't have the same rows
+    RowShape { expected: usize, got: usize },
+    /// the matrices don't have the same cols
+    ColShape { expected: usize, got: usize },
+    /// the len of the vector doesn't match the matrix
+    VectorShape { expected: usize, got: usize },
+    /// the vectors don't have the same len
+    VectorLen { expected: usize, got: usize },
+    /// the cols of the other matrix don't match the rows of this matrix in a matrix product
+    MatrixShape { expected: usize, got: usize },
+    /// the matrix has to be a square matrix
+    NotSquare,
+    /// the matrix has only one row
+    TooSmall,
+    /// the matrix is singular
+    Singular,
+}
+
+impl fmt::Display for MathError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MathError::RowShape { expected, got } => {
+                write!(f, "wrong row shape expected {}, got {}", expected, got)
+            }
+            MathError::ColShape { expected, got } => {
+                write!(f, "wrong col shape expected {}, got {}", expected, got)
+            }
+            MathError::VectorShape { expected, got } => {
+                write!(f, "wrong vector shape expected {}, got {}", expected, got)
+            }
+            MathError::VectorLen { expected, got } => write!(
+                f,
+                "the other vector has not the same len self.len() = {}, other.len() = {}",
+                expected, got
+            ),
+            MathError::MatrixShape { expected, got } => {
+                write!(
+                    f,
+                    "wrong matrix shape expected {} cols, got {}",
+                    expected, got
+                )
+            }
+            MathError::NotSquare => write!(f, "the matrix has to be a square matrix"),
+            MathError::TooSmall => write!(f, "the matrix has to have more then one row"),
+            MathError::Singular => write!(f, "the matrix is singular"),
+        }
+    }
+}
+
+impl Error for MathError {}
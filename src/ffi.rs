@@ -0,0 +1,223 @@
+//! C interface for the crate, enabled with the `ffi` feature
+//!
+//! matrices and vectors are passed as opaque pointers which are created by the `*_new` functions
+//! and have to be released with the matching `*_free` function,
+//! the declarations for C are in `include/math.h`, the shared library is built with
+//! `cargo rustc --release --lib --features ffi --crate-type cdylib`
+//!
+//! functions which can fail return a null pointer (or `false`) instead of panicking across the FFI boundary
+
+use crate::linear_algebra::{Matrix, Vector};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::ptr;
+use std::slice;
+
+fn into_raw<T>(result: std::thread::Result<T>) -> *mut T {
+    match result {
+        Ok(val) => Box::into_raw(Box::new(val)),
+        Err(_) => ptr::null_mut(),
+    }
+}
+
+/// creates a matrix from `cols * rows` values in the layout of `Matrix::new_flatt`,
+/// it returns a null pointer if `cols * rows` overflows
+///
+/// # Safety
+///
+/// `data` has to point to `cols * rows` valid floats
+#[no_mangle]
+pub unsafe extern "C" fn math_matrix_new(
+    data: *const f32,
+    cols: usize,
+    rows: usize,
+) -> *mut Matrix {
+    let len = match cols.checked_mul(rows) {
+        Some(len) if !data.is_null() => len,
+        _ => return ptr::null_mut(),
+    };
+    let matrix_flatt = slice::from_raw_parts(data, len).to_vec();
+    into_raw(catch_unwind(|| Matrix::new_flatt(matrix_flatt, cols, rows)))
+}
+
+/// creates a matrix with all values being 0
+#[no_mangle]
+pub extern "C" fn math_matrix_zero(cols: usize, rows: usize) -> *mut Matrix {
+    into_raw(catch_unwind(|| Matrix::new_zero(cols, rows)))
+}
+
+/// releases a matrix, passing a null pointer does nothing
+///
+/// # Safety
+///
+/// `matrix` has to be created by this library and can't be used afterwards
+#[no_mangle]
+pub unsafe extern "C" fn math_matrix_free(matrix: *mut Matrix) {
+    if !matrix.is_null() {
+        drop(Box::from_raw(matrix));
+    }
+}
+
+/// returns the cols of the matrix
+///
+/// # Safety
+///
+/// `matrix` has to be a valid matrix created by this library
+#[no_mangle]
+pub unsafe extern "C" fn math_matrix_cols(matrix: *const Matrix) -> usize {
+    (*matrix).cols()
+}
+
+/// returns the rows of the matrix
+///
+/// # Safety
+///
+/// `matrix` has to be a valid matrix created by this library
+#[no_mangle]
+pub unsafe extern "C" fn math_matrix_rows(matrix: *const Matrix) -> usize {
+    (*matrix).rows()
+}
+
+/// returns the value at `Matrix::index(row, col)` or NaN if the index is out of bounds
+///
+/// # Safety
+///
+/// `matrix` has to be a valid matrix created by this library
+#[no_mangle]
+pub unsafe extern "C" fn math_matrix_get(matrix: *const Matrix, row: usize, col: usize) -> f32 {
+    let matrix = &*matrix;
+    if row >= matrix.cols() || col >= matrix.rows() {
+        return f32::NAN;
+    }
+    matrix.index(row, col)
+}
+
+/// sets the value at `Matrix::index(row, col)`, returns false if the index is out of bounds
+///
+/// # Safety
+///
+/// `matrix` has to be a valid matrix created by this library
+#[no_mangle]
+pub unsafe extern "C" fn math_matrix_set(
+    matrix: *mut Matrix,
+    row: usize,
+    col: usize,
+    val: f32,
+) -> bool {
+    let matrix = &mut *matrix;
+    if row >= matrix.cols() || col >= matrix.rows() {
+        return false;
+    }
+    matrix.set_index(row, col, val);
+    true
+}
+
+/// copies the values of the matrix in the layout of `Matrix::matrix_flatt` in to `out`,
+/// returns false if `len` is not `cols * rows`
+///
+/// # Safety
+///
+/// `matrix` has to be a valid matrix created by this library and `out` has to point to `len` writable floats
+#[no_mangle]
+pub unsafe extern "C" fn math_matrix_copy_data(
+    matrix: *const Matrix,
+    out: *mut f32,
+    len: usize,
+) -> bool {
+    let matrix_flatt = (*matrix).matrix_flatt();
+    if out.is_null() || matrix_flatt.len() != len {
+        return false;
+    }
+    ptr::copy_nonoverlapping(matrix_flatt.as_ptr(), out, len);
+    true
+}
+
+/// returns the matrix product `a.dot_mat(b)` or a null pointer if the shapes don't match
+///
+/// # Safety
+///
+/// `a` and `b` have to be valid matrices created by this library
+#[no_mangle]
+pub unsafe extern "C" fn math_matrix_matmul(a: *const Matrix, b: *const Matrix) -> *mut Matrix {
+    let (a, b) = (&*a, &*b);
+    into_raw(catch_unwind(|| a.dot_mat(b)))
+}
+
+/// solves `a.dot_vec(x) == b` and returns `x` or a null pointer if the matrix is singular or the shapes don't match
+///
+/// # Safety
+///
+/// `a` has to be a valid matrix and `b` a valid vector created by this library
+#[no_mangle]
+pub unsafe extern "C" fn math_matrix_solve(a: *const Matrix, b: *const Vector) -> *mut Vector {
+    let (a, b) = (&*a, &*b);
+    into_raw(catch_unwind(AssertUnwindSafe(|| a.solve(b))))
+}
+
+/// creates a vector from `len` values
+///
+/// # Safety
+///
+/// `data` has to point to `len` valid floats
+#[no_mangle]
+pub unsafe extern "C" fn math_vector_new(data: *const f32, len: usize) -> *mut Vector {
+    if data.is_null() {
+        return ptr::null_mut();
+    }
+    let vec = slice::from_raw_parts(data, len).to_vec();
+    Box::into_raw(Box::new(Vector::new(vec)))
+}
+
+/// releases a vector, passing a null pointer does nothing
+///
+/// # Safety
+///
+/// `vector` has to be created by this library and can't be used afterwards
+#[no_mangle]
+pub unsafe extern "C" fn math_vector_free(vector: *mut Vector) {
+    if !vector.is_null() {
+        drop(Box::from_raw(vector));
+    }
+}
+
+/// returns the len of the vector
+///
+/// # Safety
+///
+/// `vector` has to be a valid vector created by this library
+#[no_mangle]
+pub unsafe extern "C" fn math_vector_len(vector: *const Vector) -> usize {
+    (*vector).len()
+}
+
+/// returns the value at `index` or NaN if the index is out of bounds
+///
+/// # Safety
+///
+/// `vector` has to be a valid vector created by this library
+#[no_mangle]
+pub unsafe extern "C" fn math_vector_get(vector: *const Vector, index: usize) -> f32 {
+    let vector = &*vector;
+    if index >= vector.len() {
+        return f32::NAN;
+    }
+    vector.index(index)
+}
+
+/// copies the values of the vector in to `out`, returns false if `len` is not the len of the vector
+///
+/// # Safety
+///
+/// `vector` has to be a valid vector created by this library and `out` has to point to `len` writable floats
+#[no_mangle]
+pub unsafe extern "C" fn math_vector_copy_data(
+    vector: *const Vector,
+    out: *mut f32,
+    len: usize,
+) -> bool {
+    let vec = (*vector).vec();
+    if out.is_null() || vec.len() != len {
+        return false;
+    }
+    ptr::copy_nonoverlapping(vec.as_ptr(), out, len);
+    true
+}
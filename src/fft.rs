@@ -0,0 +1,267 @@
+//! the [discrete Fourier transform] with the fast Fourier transform
+//!
+//! complex signals are a pair of vectors `(re, im)` like the eigenvalues of `Matrix::eigen_val`.
+//! the forward transform is `X_k = sum x_j e^(-2 pi i jk / n)` without scaling and the inverse
+//! transforms scale by `1 / n`, so `ifft(fft(x)) == x`.
+//! lengths which are a power of two use the radix-2 algorithm, other lengths use Bluestein's algorithm,
+//! so every length is `O(n log n)`, internally everything is computed in `f64`
+//!
+//! the spectrum of a real signal is symmetric (`X_n-k` is the conjugate of `X_k`), so [`rfft`] only
+//! returns the `n / 2 + 1` non redundant values and computes them with a complex transform of half the size
+//!
+//! [discrete Fourier transform]: https://en.wikipedia.org/wiki/Discrete_Fourier_transform
+//!
+//! ## Example
+//!
+//! ```rust
+//! use math::fft::{irfft, rfft};
+//! use math::linear_algebra::Vector;
+//! let signal = Vector::new(vec![1., 2., 0., -1.]);
+//! let (re, im) = rfft(&signal);
+//! let close = |a: &Vector, b: &[f32]| a.vec().iter().zip(b).all(|(x, y)| (x - y).abs() < 1e-6);
+//! assert!(close(&re, &[2., 1., 0.]) && close(&im, &[0., -3., 0.]));
+//! assert!(close(&irfft(&re, &im, 4), &[1., 2., 0., -1.]));
+//! ```
+
+use crate::linear_algebra::Vector;
+use std::f64::consts::PI;
+
+// the unscaled transform in place, inverse uses e^(+2 pi i jk / n)
+fn transform(re: &mut [f64], im: &mut [f64], inverse: bool) {
+    let n = re.len();
+    if n <= 1 {
+        return;
+    }
+    if n.is_power_of_two() {
+        radix2(re, im, inverse);
+    } else {
+        bluestein(re, im, inverse);
+    }
+}
+
+// the iterative Cooley-Tukey algorithm, n has to be a power of two
+fn radix2(re: &mut [f64], im: &mut [f64], inverse: bool) {
+    let n = re.len();
+    let bits = n.trailing_zeros();
+    for i in 0..n {
+        let j = i.reverse_bits() >> (usize::BITS - bits);
+        if i < j {
+            re.swap(i, j);
+            im.swap(i, j);
+        }
+    }
+
+    let sign = if inverse { 1. } else { -1. };
+    let mut len = 2;
+    while len <= n {
+        let half = len / 2;
+        for k in 0..half {
+            let (sin, cos) = (sign * 2. * PI * k as f64 / len as f64).sin_cos();
+            for start in (0..n).step_by(len) {
+                let (a, b) = (start + k, start + k + half);
+                let t_re = re[b] * cos - im[b] * sin;
+                let t_im = re[b] * sin + im[b] * cos;
+                re[b] = re[a] - t_re;
+                im[b] = im[a] - t_im;
+                re[a] += t_re;
+                im[a] += t_im;
+            }
+        }
+        len *= 2;
+    }
+}
+
+// Bluestein's algorithm writes the transform as a convolution with a chirp,
+// the convolution is computed with power of two transforms
+fn bluestein(re: &mut [f64], im: &mut [f64], inverse: bool) {
+    let n = re.len();
+    let m = (2 * n - 1).next_power_of_two();
+    let sign = if inverse { 1. } else { -1. };
+    // the chirp e^(sign pi i k^2 / n), k^2 is taken mod 2n to keep the angle small
+    let chirp: Vec<(f64, f64)> = (0..n)
+        .map(|k| {
+            let k2 = (k as u128 * k as u128 % (2 * n as u128)) as f64;
+            let (sin, cos) = (sign * PI * k2 / n as f64).sin_cos();
+            (cos, sin)
+        })
+        .collect();
+
+    let (mut a_re, mut a_im) = (vec![0.; m], vec![0.; m]);
+    for k in 0..n {
+        let (c, s) = chirp[k];
+        a_re[k] = re[k] * c - im[k] * s;
+        a_im[k] = re[k] * s + im[k] * c;
+    }
+    let (mut b_re, mut b_im) = (vec![0.; m], vec![0.; m]);
+    for k in 0..n {
+        let (c, s) = chirp[k];
+        b_re[k] = c;
+        b_im[k] = -s;
+        if k > 0 {
+            b_re[m - k] = c;
+            b_im[m - k] = -s;
+        }
+    }
+
+    radix2(&mut a_re, &mut a_im, false);
+    radix2(&mut b_re, &mut b_im, false);
+    for k in 0..m {
+        let (x_re, x_im) = (a_re[k], a_im[k]);
+        a_re[k] = x_re * b_re[k] - x_im * b_im[k];
+        a_im[k] = x_re * b_im[k] + x_im * b_re[k];
+    }
+    radix2(&mut a_re, &mut a_im, true);
+
+    for k in 0..n {
+        let (c, s) = chirp[k];
+        let (x_re, x_im) = (a_re[k] / m as f64, a_im[k] / m as f64);
+        re[k] = x_re * c - x_im * s;
+        im[k] = x_re * s + x_im * c;
+    }
+}
+
+fn to_f64(vector: &Vector) -> Vec<f64> {
+    vector.vec().into_iter().map(|x| x as f64).collect()
+}
+
+fn to_vector(values: &[f64]) -> Vector {
+    values.iter().map(|&x| x as f32).collect()
+}
+
+// panics if the parts of the complex signal have different lens
+fn check_complex(re: &Vector, im: &Vector) {
+    if re.len() != im.len() {
+        panic!(
+            "the real and imaginary part need the same len, got {} and {}",
+            re.len(),
+            im.len()
+        );
+    }
+}
+
+/// the discrete Fourier transform of the complex signal `(re, im)`
+///
+/// ## Example
+///
+/// ```rust
+/// use math::fft::{fft, ifft};
+/// use math::linear_algebra::Vector;
+/// let (re, im) = fft(&Vector::new(vec![1., 1., 1.]), &Vector::new(vec![0., 0., 0.]));
+/// assert!((re.index(0) - 3.).abs() < 1e-6 && re.index(1).abs() < 1e-6 && im.index(2).abs() < 1e-6);
+/// ```
+/// note it panics if `re` and `im` have different lens
+pub fn fft(re: &Vector, im: &Vector) -> (Vector, Vector) {
+    check_complex(re, im);
+    let (mut re, mut im) = (to_f64(re), to_f64(im));
+    transform(&mut re, &mut im, false);
+    (to_vector(&re), to_vector(&im))
+}
+
+/// the inverse discrete Fourier transform scaled by `1 / n`, so it undoes `fft`
+///
+/// note it panics if `re` and `im` have different lens
+pub fn ifft(re: &Vector, im: &Vector) -> (Vector, Vector) {
+    check_complex(re, im);
+    let (mut re, mut im) = (to_f64(re), to_f64(im));
+    transform(&mut re, &mut im, true);
+    let n = re.len() as f64;
+    re.iter_mut().chain(im.iter_mut()).for_each(|x| *x /= n);
+    (to_vector(&re), to_vector(&im))
+}
+
+/// the discrete Fourier transform of a real signal, it returns the `n / 2 + 1` values `X_0, ..., X_n/2`
+///
+/// for an even len the signal is packed into a complex signal of half the len, so it is
+/// about twice as fast as `fft` with an imaginary part of 0
+///
+/// note it panics if the signal is empty
+pub fn rfft(signal: &Vector) -> (Vector, Vector) {
+    let n = signal.len();
+    if n == 0 {
+        panic!("can't transform an empty signal");
+    }
+    let x = to_f64(signal);
+    let bins = n / 2 + 1;
+    if n % 2 == 1 {
+        let mut im = vec![0.; n];
+        let mut re = x;
+        transform(&mut re, &mut im, false);
+        return (to_vector(&re[..bins]), to_vector(&im[..bins]));
+    }
+
+    // z_j = x_2j + i x_2j+1, so Z_k = E_k + i O_k with the transforms of the even and odd values
+    let h = n / 2;
+    let mut z_re: Vec<f64> = x.iter().step_by(2).copied().collect();
+    let mut z_im: Vec<f64> = x.iter().skip(1).step_by(2).copied().collect();
+    transform(&mut z_re, &mut z_im, false);
+
+    let (mut re, mut im) = (vec![0.; bins], vec![0.; bins]);
+    for k in 0..bins {
+        let (a_re, a_im) = (z_re[k % h], z_im[k % h]);
+        // the conjugate of Z_h-k
+        let (b_re, b_im) = (z_re[(h - k) % h], -z_im[(h - k) % h]);
+        let (e_re, e_im) = ((a_re + b_re) / 2., (a_im + b_im) / 2.);
+        // O_k = (Z_k - conj(Z_h-k)) / 2i
+        let (o_re, o_im) = ((a_im - b_im) / 2., -(a_re - b_re) / 2.);
+        let (sin, cos) = (-2. * PI * k as f64 / n as f64).sin_cos();
+        re[k] = e_re + o_re * cos - o_im * sin;
+        im[k] = e_im + o_re * sin + o_im * cos;
+    }
+    (to_vector(&re), to_vector(&im))
+}
+
+/// the inverse of `rfft`, it returns the real signal of the len `n` from the values `X_0, ..., X_n/2`
+///
+/// the len has to be given because `rfft` of the len `2m` and `2m + 1` both return `m + 1` values
+///
+/// note it panics if `re` and `im` don't have the len `n / 2 + 1` or `n` is 0
+pub fn irfft(re: &Vector, im: &Vector, n: usize) -> Vector {
+    check_complex(re, im);
+    if n == 0 {
+        panic!("can't transform an empty signal");
+    }
+    let bins = n / 2 + 1;
+    if re.len() != bins {
+        panic!(
+            "a signal of len {} has {} values in the spectrum, got {}",
+            n,
+            bins,
+            re.len()
+        );
+    }
+    let (x_re, x_im) = (to_f64(re), to_f64(im));
+
+    if n % 2 == 1 {
+        // the full spectrum with X_n-k = conj(X_k)
+        let mut full_re = x_re.clone();
+        let mut full_im = x_im.clone();
+        full_re.extend((1..n - bins + 1).rev().map(|k| x_re[k]));
+        full_im.extend((1..n - bins + 1).rev().map(|k| -x_im[k]));
+        transform(&mut full_re, &mut full_im, true);
+        return full_re.iter().map(|&x| (x / n as f64) as f32).collect();
+    }
+
+    // undo the packing of rfft: Z_k = E_k + i O_k
+    let h = n / 2;
+    let (mut z_re, mut z_im) = (vec![0.; h], vec![0.; h]);
+    for k in 0..h {
+        let (a_re, a_im) = (x_re[k], x_im[k]);
+        // the conjugate of X_h-k
+        let (b_re, b_im) = (x_re[h - k], -x_im[h - k]);
+        let (e_re, e_im) = ((a_re + b_re) / 2., (a_im + b_im) / 2.);
+        // O_k = (X_k - conj(X_h-k)) e^(2 pi i k / n) / 2
+        let (d_re, d_im) = ((a_re - b_re) / 2., (a_im - b_im) / 2.);
+        let (sin, cos) = (2. * PI * k as f64 / n as f64).sin_cos();
+        let (o_re, o_im) = (d_re * cos - d_im * sin, d_re * sin + d_im * cos);
+        z_re[k] = e_re - o_im;
+        z_im[k] = e_im + o_re;
+    }
+    transform(&mut z_re, &mut z_im, true);
+
+    let mut signal = Vec::with_capacity(n);
+    for j in 0..h {
+        signal.push((z_re[j] / h as f64) as f32);
+        signal.push((z_im[j] / h as f64) as f32);
+    }
+    Vector::new(signal)
+}
@@ -0,0 +1,831 @@
+//! 3D rotations and transforms and 2D affine transforms
+//!
+//! points and directions are `[f32; 3]` and rotations are either a [`Quaternion`],
+//! a rotation `Matrix3` (which rotates with `dot_vec`) or Euler angles in a given [`EulerOrder`].
+//! transforms are homogeneous `Matrix4` where the translation is in the last col of each line.
+//! the camera transforms [`look_at`], [`perspective`] and [`orthographic`] use the OpenGL conventions:
+//! the camera looks along `-z` in a right handed view space and the depth is mapped to `-1..1`
+
+use crate::linear_algebra::fixed::{Matrix3, Matrix4};
+use crate::linear_algebra::{Matrix, Vector};
+use std::ops::{Mul, MulAssign};
+
+// below this value a ray is treated as parallel to a triangle
+const PARALLEL: f32 = 1e-7;
+
+// above this value of the middle angle's sine the first and last axis are (almost) the same
+const GIMBAL_LOCK: f32 = 0.999_999;
+
+/// the order of the axes for Euler angles
+///
+/// the angles `[a, b, c]` in the order `XYZ` are the rotation `Rx(a) * Ry(b) * Rz(c)`,
+/// so the rotation around x is applied last (intrinsic x, y, z or extrinsic z, y, x)
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum EulerOrder {
+    XYZ,
+    XZY,
+    YXZ,
+    YZX,
+    ZXY,
+    ZYX,
+}
+
+impl EulerOrder {
+    // the indices of the axes and 1 for even permutations of xyz or -1 for odd ones
+    fn axes(&self) -> (usize, usize, usize, f32) {
+        match self {
+            EulerOrder::XYZ => (0, 1, 2, 1.),
+            EulerOrder::YZX => (1, 2, 0, 1.),
+            EulerOrder::ZXY => (2, 0, 1, 1.),
+            EulerOrder::XZY => (0, 2, 1, -1.),
+            EulerOrder::ZYX => (2, 1, 0, -1.),
+            EulerOrder::YXZ => (1, 0, 2, -1.),
+        }
+    }
+}
+
+/// returns the rotation matrix for the Euler angles in radians
+///
+/// ## Example
+///
+/// ```rust
+/// use math::geometry::{euler_to_matrix, EulerOrder};
+/// let rot = euler_to_matrix([0., 0., std::f32::consts::FRAC_PI_2], EulerOrder::XYZ);
+/// let p = rot.dot_vec(&[1., 0., 0.]);
+/// assert!((p[0] - 0.).abs() < 1e-6 && (p[1] - 1.).abs() < 1e-6);
+/// ```
+pub fn euler_to_matrix(angles: [f32; 3], order: EulerOrder) -> Matrix3 {
+    Quaternion::from_euler(angles, order).to_matrix()
+}
+
+/// returns the Euler angles in radians for the rotation matrix
+///
+/// the middle angle is between `-pi/2` and `pi/2`, the others between `-pi` and `pi`.
+/// in a [gimbal lock] (the middle angle is `±pi/2`) the first and last axis rotate around the same axis,
+/// so only their sum (or difference) is defined, in this case the last angle is set to 0
+///
+/// [gimbal lock]: https://en.wikipedia.org/wiki/Gimbal_lock
+///
+/// ## Example
+///
+/// ```rust
+/// use math::geometry::{euler_to_matrix, matrix_to_euler, EulerOrder};
+/// let rot = euler_to_matrix([0.1, 0.2, 0.3], EulerOrder::ZYX);
+/// let angles = matrix_to_euler(&rot, EulerOrder::ZYX);
+/// assert!((angles[0] - 0.1).abs() < 1e-6);
+/// assert!((angles[1] - 0.2).abs() < 1e-6);
+/// assert!((angles[2] - 0.3).abs() < 1e-6);
+/// ```
+pub fn matrix_to_euler(matrix: &Matrix3, order: EulerOrder) -> [f32; 3] {
+    let (i, j, k, s) = order.axes();
+    let r = matrix.data();
+    let sin_b = (s * r[i][k]).clamp(-1., 1.);
+    let b = sin_b.asin();
+    if sin_b.abs() < GIMBAL_LOCK {
+        let a = (-s * r[j][k]).atan2(r[k][k]);
+        let c = (-s * r[i][j]).atan2(r[i][i]);
+        [a, b, c]
+    } else {
+        let a = (s * r[k][j]).atan2(r[j][j]);
+        [a, b, 0.]
+    }
+}
+
+/// a [quaternion] `w + xi + yj + zk`, unit quaternions represent rotations
+///
+/// [quaternion]: https://en.wikipedia.org/wiki/Quaternions_and_spatial_rotation
+///
+/// ## Example
+///
+/// ```rust
+/// use math::geometry::Quaternion;
+/// let q = Quaternion::from_axis_angle([0., 0., 1.], std::f32::consts::PI);
+/// let p = q.rotate(&[1., 2., 3.]);
+/// assert!((p[0] + 1.).abs() < 1e-6 && (p[1] + 2.).abs() < 1e-6 && (p[2] - 3.).abs() < 1e-6);
+/// ```
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct Quaternion {
+    pub w: f32,
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+}
+
+impl Default for Quaternion {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl Quaternion {
+    /// creates the quaternion `w + xi + yj + zk`
+    pub fn new(w: f32, x: f32, y: f32, z: f32) -> Self {
+        Quaternion { w, x, y, z }
+    }
+
+    /// creates the quaternion which doesn't rotate
+    pub fn identity() -> Self {
+        Quaternion::new(1., 0., 0., 0.)
+    }
+
+    /// creates the rotation by `angle` radians around the `axis`, the axis doesn't have to be normalized
+    ///
+    /// note it panics if the axis has a length of 0
+    pub fn from_axis_angle(axis: [f32; 3], angle: f32) -> Self {
+        let len = axis.iter().map(|a| a * a).sum::<f32>().sqrt();
+        if len == 0. {
+            panic!("the rotation axis has a length of 0");
+        }
+        let (sin, cos) = (angle / 2.).sin_cos();
+        let s = sin / len;
+        Quaternion::new(cos, axis[0] * s, axis[1] * s, axis[2] * s)
+    }
+
+    /// creates the rotation for the Euler angles in radians, see [`EulerOrder`]
+    pub fn from_euler(angles: [f32; 3], order: EulerOrder) -> Self {
+        let (i, j, k, _) = order.axes();
+        let axis = |index: usize| {
+            let mut axis = [0.; 3];
+            axis[index] = 1.;
+            axis
+        };
+        Quaternion::from_axis_angle(axis(i), angles[0])
+            * Quaternion::from_axis_angle(axis(j), angles[1])
+            * Quaternion::from_axis_angle(axis(k), angles[2])
+    }
+
+    /// returns the Euler angles in radians, see [`matrix_to_euler`] for the range and gimbal lock
+    pub fn to_euler(&self, order: EulerOrder) -> [f32; 3] {
+        matrix_to_euler(&self.to_matrix(), order)
+    }
+
+    /// returns the normalized rotation axis and the angle in radians between 0 and pi,
+    /// the inverse of `from_axis_angle`
+    ///
+    /// the identity has no unique axis, then the axis is `[1, 0, 0]` with an angle of 0
+    pub fn to_axis_angle(&self) -> ([f32; 3], f32) {
+        let q = self.normalize();
+        // q and -q are the same rotation, the one with w >= 0 has an angle <= pi
+        let sign = if q.w < 0. { -1. } else { 1. };
+        let len = (q.x * q.x + q.y * q.y + q.z * q.z).sqrt();
+        if len == 0. {
+            return ([1., 0., 0.], 0.);
+        }
+        let angle = 2. * len.atan2(sign * q.w);
+        let s = sign / len;
+        ([q.x * s, q.y * s, q.z * s], angle)
+    }
+
+    /// creates the rotation from a rotation matrix with [Shepperd's method]
+    ///
+    /// [Shepperd's method]: https://doi.org/10.2514/3.55767b
+    ///
+    /// note the matrix has to be orthogonal with a determinant of 1
+    pub fn from_matrix(matrix: &Matrix3) -> Self {
+        let r = matrix.data();
+        let trace = r[0][0] + r[1][1] + r[2][2];
+        let q = if trace > 0. {
+            let s = (trace + 1.).sqrt() * 2.;
+            Quaternion::new(
+                s / 4.,
+                (r[2][1] - r[1][2]) / s,
+                (r[0][2] - r[2][0]) / s,
+                (r[1][0] - r[0][1]) / s,
+            )
+        } else if r[0][0] > r[1][1] && r[0][0] > r[2][2] {
+            let s = (1. + r[0][0] - r[1][1] - r[2][2]).sqrt() * 2.;
+            Quaternion::new(
+                (r[2][1] - r[1][2]) / s,
+                s / 4.,
+                (r[0][1] + r[1][0]) / s,
+                (r[0][2] + r[2][0]) / s,
+            )
+        } else if r[1][1] > r[2][2] {
+            let s = (1. + r[1][1] - r[0][0] - r[2][2]).sqrt() * 2.;
+            Quaternion::new(
+                (r[0][2] - r[2][0]) / s,
+                (r[0][1] + r[1][0]) / s,
+                s / 4.,
+                (r[1][2] + r[2][1]) / s,
+            )
+        } else {
+            let s = (1. + r[2][2] - r[0][0] - r[1][1]).sqrt() * 2.;
+            Quaternion::new(
+                (r[1][0] - r[0][1]) / s,
+                (r[0][2] + r[2][0]) / s,
+                (r[1][2] + r[2][1]) / s,
+                s / 4.,
+            )
+        };
+        q.normalize()
+    }
+
+    /// returns the rotation matrix, the quaternion is normalized first
+    pub fn to_matrix(&self) -> Matrix3 {
+        let Quaternion { w, x, y, z } = self.normalize();
+        Matrix3::new([
+            [
+                1. - 2. * (y * y + z * z),
+                2. * (x * y - w * z),
+                2. * (x * z + w * y),
+            ],
+            [
+                2. * (x * y + w * z),
+                1. - 2. * (x * x + z * z),
+                2. * (y * z - w * x),
+            ],
+            [
+                2. * (x * z - w * y),
+                2. * (y * z + w * x),
+                1. - 2. * (x * x + y * y),
+            ],
+        ])
+    }
+
+    /// returns the length of the quaternion
+    pub fn norm(&self) -> f32 {
+        (self.w * self.w + self.x * self.x + self.y * self.y + self.z * self.z).sqrt()
+    }
+
+    /// returns the quaternion scaled to a length of 1
+    pub fn normalize(&self) -> Self {
+        let norm = self.norm();
+        Quaternion::new(self.w / norm, self.x / norm, self.y / norm, self.z / norm)
+    }
+
+    /// returns the conjugate `w - xi - yj - zk`, which is the inverse rotation for unit quaternions
+    pub fn conjugate(&self) -> Self {
+        Quaternion::new(self.w, -self.x, -self.y, -self.z)
+    }
+
+    /// rotates the point, the quaternion is normalized first
+    pub fn rotate(&self, point: &[f32; 3]) -> [f32; 3] {
+        self.to_matrix().dot_vec(point)
+    }
+}
+
+impl Mul for Quaternion {
+    type Output = Self;
+
+    /// the [Hamilton product], the rotation of `a * b` applies `b` first and then `a`
+    ///
+    /// [Hamilton product]: https://en.wikipedia.org/wiki/Quaternion#Hamilton_product
+    fn mul(self, other: Self) -> Self {
+        let (a, b) = (self, other);
+        Quaternion::new(
+            a.w * b.w - a.x * b.x - a.y * b.y - a.z * b.z,
+            a.w * b.x + a.x * b.w + a.y * b.z - a.z * b.y,
+            a.w * b.y - a.x * b.z + a.y * b.w + a.z * b.x,
+            a.w * b.z + a.x * b.y - a.y * b.x + a.z * b.w,
+        )
+    }
+}
+
+/// returns the homogeneous transform which moves points by `offset`
+pub fn translation(offset: [f32; 3]) -> Matrix4 {
+    let mut matrix = Matrix4::identity();
+    for (i, &val) in offset.iter().enumerate() {
+        matrix.set_index(i, 3, val);
+    }
+    matrix
+}
+
+/// returns the homogeneous transform which scales each axis by `factor`
+pub fn scaling(factor: [f32; 3]) -> Matrix4 {
+    let mut matrix = Matrix4::identity();
+    for (i, &val) in factor.iter().enumerate() {
+        matrix.set_index(i, i, val);
+    }
+    matrix
+}
+
+/// returns the homogeneous transform of the rotation
+pub fn rotation(rotation: &Quaternion) -> Matrix4 {
+    let rot = rotation.to_matrix();
+    let mut matrix = Matrix4::identity();
+    for i in 0..3 {
+        for j in 0..3 {
+            matrix.set_index(i, j, rot.index(i, j));
+        }
+    }
+    matrix
+}
+
+/// applies the homogeneous transform to a point, the result is divided by `w`
+pub fn transform_point(transform: &Matrix4, point: &[f32; 3]) -> [f32; 3] {
+    let p = transform.dot_vec(&[point[0], point[1], point[2], 1.]);
+    [p[0] / p[3], p[1] / p[3], p[2] / p[3]]
+}
+
+/// applies the homogeneous transform to a direction, so the translation is ignored
+pub fn transform_direction(transform: &Matrix4, direction: &[f32; 3]) -> [f32; 3] {
+    let d = transform.dot_vec(&[direction[0], direction[1], direction[2], 0.]);
+    [d[0], d[1], d[2]]
+}
+
+/// returns the perspective projection with the vertical field of view `fov` in radians,
+/// the `aspect` ratio (width / height) and the distances of the `near` and `far` clipping planes
+///
+/// ## Example
+///
+/// ```rust
+/// use math::geometry::{perspective, transform_point};
+/// let proj = perspective(std::f32::consts::FRAC_PI_2, 2., 1., 10.);
+/// let p = transform_point(&proj, &[2., 1., -1.]);
+/// assert!((p[0] - 1.).abs() < 1e-6 && (p[1] - 1.).abs() < 1e-6 && (p[2] + 1.).abs() < 1e-6);
+/// ```
+/// note it panics if `near` and `far` are not `0 < near < far`
+pub fn perspective(fov: f32, aspect: f32, near: f32, far: f32) -> Matrix4 {
+    check_depth(near, far);
+    if near <= 0. {
+        panic!("near has to be bigger then 0, got {}", near);
+    }
+    let f = 1. / (fov / 2.).tan();
+    Matrix4::new([
+        [f / aspect, 0., 0., 0.],
+        [0., f, 0., 0.],
+        [
+            0.,
+            0.,
+            (far + near) / (near - far),
+            2. * far * near / (near - far),
+        ],
+        [0., 0., -1., 0.],
+    ])
+}
+
+/// returns the orthographic projection which maps the box between the planes to the cube `-1..1`
+///
+/// ## Example
+///
+/// ```rust
+/// use math::geometry::{orthographic, transform_point};
+/// let proj = orthographic(0., 4., 0., 2., 1., 3.);
+/// assert_eq!(transform_point(&proj, &[4., 1., -1.]), [1., 0., -1.]);
+/// ```
+/// note it panics if `near` is not smaller then `far`
+pub fn orthographic(left: f32, right: f32, bottom: f32, top: f32, near: f32, far: f32) -> Matrix4 {
+    check_depth(near, far);
+    Matrix4::new([
+        [
+            2. / (right - left),
+            0.,
+            0.,
+            -(right + left) / (right - left),
+        ],
+        [
+            0.,
+            2. / (top - bottom),
+            0.,
+            -(top + bottom) / (top - bottom),
+        ],
+        [0., 0., -2. / (far - near), -(far + near) / (far - near)],
+        [0., 0., 0., 1.],
+    ])
+}
+
+/// returns the view transform of a camera at `eye` which looks at `target`,
+/// `up` is the direction which becomes `y` in view space
+///
+/// ## Example
+///
+/// ```rust
+/// use math::geometry::{look_at, transform_point};
+/// let view = look_at([0., 0., 5.], [0., 0., 0.], [0., 1., 0.]);
+/// assert_eq!(transform_point(&view, &[1., 0., 0.]), [1., 0., -5.]);
+/// ```
+/// note it panics if `eye` and `target` are the same point or `up` is parallel to the view direction
+pub fn look_at(eye: [f32; 3], target: [f32; 3], up: [f32; 3]) -> Matrix4 {
+    let forward = normalize(&sub(&target, &eye), "eye and target are the same point");
+    let side = normalize(
+        &cross(&forward, &up),
+        "up is parallel to the view direction",
+    );
+    let up = cross(&side, &forward);
+    Matrix4::new([
+        [side[0], side[1], side[2], -dot(&side, &eye)],
+        [up[0], up[1], up[2], -dot(&up, &eye)],
+        [-forward[0], -forward[1], -forward[2], dot(&forward, &eye)],
+        [0., 0., 0., 1.],
+    ])
+}
+
+impl Matrix {
+    /// returns the 3x3 rotation matrix which rotates by `angle` radians around the `axis`
+    /// with [Rodrigues' rotation formula], the axis doesn't have to be normalized
+    ///
+    /// [Rodrigues' rotation formula]: https://en.wikipedia.org/wiki/Rodrigues%27_rotation_formula
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{Matrix, Vector};
+    /// let axis = Vector::new(vec![0., 0., 2.]);
+    /// let rot = Matrix::rotation_axis_angle(&axis, std::f32::consts::FRAC_PI_2);
+    /// let p = rot.dot_vec(&Vector::new(vec![1., 0., 0.]));
+    /// assert!(p.index(0).abs() < 1e-6 && (p.index(1) - 1.).abs() < 1e-6);
+    /// ```
+    /// note it panics if the axis has not the len 3 or a length of 0
+    pub fn rotation_axis_angle(axis: &Vector, angle: f32) -> Matrix {
+        if axis.len() != 3 {
+            panic!(
+                "the rotation axis has to have the len 3, got {}",
+                axis.len()
+            );
+        }
+        let k = normalize(
+            &[axis.index(0), axis.index(1), axis.index(2)],
+            "the rotation axis has a length of 0",
+        );
+        let (sin, cos) = angle.sin_cos();
+        // R = cos * I + sin * [k]x + (1 - cos) * k k^T
+        let cross = [[0., -k[2], k[1]], [k[2], 0., -k[0]], [-k[1], k[0], 0.]];
+        let mut matrix_flatt = Vec::with_capacity(9);
+        for i in 0..3 {
+            for j in 0..3 {
+                let identity = if i == j { cos } else { 0. };
+                matrix_flatt.push(identity + sin * cross[i][j] + (1. - cos) * k[i] * k[j]);
+            }
+        }
+        Matrix::new_flatt(matrix_flatt, 3, 3)
+    }
+
+    /// returns the normalized rotation axis and the angle in radians between 0 and pi of a 3x3 rotation matrix,
+    /// the inverse of [`rotation_axis_angle`]
+    ///
+    /// the identity has no unique axis, then the axis is `[1, 0, 0]` with an angle of 0
+    ///
+    /// [`rotation_axis_angle`]: Matrix::rotation_axis_angle
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{Matrix, Vector};
+    /// let rot = Matrix::rotation_axis_angle(&Vector::new(vec![0., 3., 0.]), 2.);
+    /// let (axis, angle) = rot.to_axis_angle();
+    /// assert!((axis.index(1) - 1.).abs() < 1e-6 && (angle - 2.).abs() < 1e-6);
+    /// ```
+    /// note the matrix has to be orthogonal with a determinant of 1 and it panics if it is not 3x3
+    pub fn to_axis_angle(&self) -> (Vector, f32) {
+        let (axis, angle) = Quaternion::from_matrix(&Matrix3::from_matrix(self)).to_axis_angle();
+        (Vector::new(axis.to_vec()), angle)
+    }
+}
+
+fn check_depth(near: f32, far: f32) {
+    if near >= far {
+        panic!("near has to be smaller then far, got {} and {}", near, far);
+    }
+}
+
+/// a stack of transforms for hierarchical scenes like the matrix stack in classic OpenGL
+///
+/// new transforms are multiplied from the right onto the top, so the transform applied last
+/// is the first one which acts on the points (the local transform of the innermost object)
+///
+/// ## Example
+///
+/// ```rust
+/// use math::geometry::{Quaternion, TransformStack};
+/// let mut stack = TransformStack::new();
+/// stack.translate([10., 0., 0.]);
+///
+/// stack.push();
+/// stack.rotate(&Quaternion::from_axis_angle([0., 0., 1.], std::f32::consts::FRAC_PI_2));
+/// stack.scale([2., 2., 2.]);
+/// let p = stack.transform_point(&[1., 0., 0.]);
+/// assert!((p[0] - 10.).abs() < 1e-6 && (p[1] - 2.).abs() < 1e-6);
+/// stack.pop();
+///
+/// assert_eq!(stack.transform_point(&[1., 0., 0.]), [11., 0., 0.]);
+/// ```
+#[derive(PartialEq, Clone, Debug)]
+pub struct TransformStack {
+    stack: Vec<Matrix4>,
+}
+
+impl Default for TransformStack {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TransformStack {
+    /// creates a stack with the identity as its only transform
+    pub fn new() -> Self {
+        TransformStack {
+            stack: vec![Matrix4::identity()],
+        }
+    }
+
+    /// saves the current transform, it is restored by the next `pop`
+    pub fn push(&mut self) {
+        let top = *self.top();
+        self.stack.push(top);
+    }
+
+    /// restores the transform of the matching `push` and returns the removed one
+    ///
+    /// note it panics if there is no matching `push`
+    pub fn pop(&mut self) -> Matrix4 {
+        if self.stack.len() == 1 {
+            panic!("pop without a matching push");
+        }
+        self.stack.pop().unwrap()
+    }
+
+    /// returns the number of saved transforms (the number of `push` without `pop`)
+    pub fn depth(&self) -> usize {
+        self.stack.len() - 1
+    }
+
+    /// returns the current transform
+    pub fn top(&self) -> &Matrix4 {
+        self.stack.last().unwrap()
+    }
+
+    /// replaces the current transform with the identity
+    pub fn load_identity(&mut self) {
+        *self.stack.last_mut().unwrap() = Matrix4::identity();
+    }
+
+    /// multiplies the current transform with `transform` from the right
+    pub fn apply(&mut self, transform: &Matrix4) {
+        let top = self.stack.last_mut().unwrap();
+        *top = top.dot_mat(transform);
+    }
+
+    /// applies a [`translation`]
+    pub fn translate(&mut self, offset: [f32; 3]) {
+        self.apply(&translation(offset));
+    }
+
+    /// applies a [`rotation`]
+    pub fn rotate(&mut self, rot: &Quaternion) {
+        self.apply(&rotation(rot));
+    }
+
+    /// applies a [`scaling`]
+    pub fn scale(&mut self, factor: [f32; 3]) {
+        self.apply(&scaling(factor));
+    }
+
+    /// transforms a point with the current transform, see [`transform_point`]
+    pub fn transform_point(&self, point: &[f32; 3]) -> [f32; 3] {
+        transform_point(self.top(), point)
+    }
+
+    /// transforms a direction with the current transform, see [`transform_direction`]
+    pub fn transform_direction(&self, direction: &[f32; 3]) -> [f32; 3] {
+        transform_direction(self.top(), direction)
+    }
+}
+
+/// a 2D affine transform stored as a homogeneous `Matrix3`, the translation is in the last col of each line
+///
+/// the builder methods apply their transform after the current one,
+/// so `Transform2::rotation(a).translate(t)` first rotates and then translates.
+/// `a * b` is the matrix product, so it applies `b` first like `Mul` for `Quaternion`
+///
+/// ## Example
+///
+/// ```rust
+/// use math::geometry::Transform2;
+/// let transform = Transform2::scaling([2., 2.])
+///     .rotate(std::f32::consts::FRAC_PI_2)
+///     .translate([10., 0.]);
+/// let p = transform.transform_point(&[1., 0.]);
+/// assert!((p[0] - 10.).abs() < 1e-6 && (p[1] - 2.).abs() < 1e-6);
+/// let v = transform.transform_vector(&[1., 0.]);
+/// assert!(v[0].abs() < 1e-6 && (v[1] - 2.).abs() < 1e-6);
+/// ```
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct Transform2 {
+    matrix: Matrix3,
+}
+
+impl Default for Transform2 {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl Transform2 {
+    /// creates the transform which doesn't change any point
+    pub fn identity() -> Self {
+        Transform2 {
+            matrix: Matrix3::identity(),
+        }
+    }
+
+    /// creates the transform of the homogeneous matrix, the last line should be `[0, 0, 1]`
+    pub fn from_matrix(matrix: Matrix3) -> Self {
+        Transform2 { matrix }
+    }
+
+    /// creates the transform which moves points by `offset`
+    pub fn translation(offset: [f32; 2]) -> Self {
+        Transform2::from_matrix(Matrix3::new([
+            [1., 0., offset[0]],
+            [0., 1., offset[1]],
+            [0., 0., 1.],
+        ]))
+    }
+
+    /// creates the counterclockwise rotation by `angle` radians around the origin
+    pub fn rotation(angle: f32) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        Transform2::from_matrix(Matrix3::new([
+            [cos, -sin, 0.],
+            [sin, cos, 0.],
+            [0., 0., 1.],
+        ]))
+    }
+
+    /// creates the transform which scales each axis by `factor`
+    pub fn scaling(factor: [f32; 2]) -> Self {
+        Transform2::from_matrix(Matrix3::new([
+            [factor[0], 0., 0.],
+            [0., factor[1], 0.],
+            [0., 0., 1.],
+        ]))
+    }
+
+    /// creates the shear which adds `x_by_y * y` to `x` and `y_by_x * x` to `y`
+    pub fn shearing(x_by_y: f32, y_by_x: f32) -> Self {
+        Transform2::from_matrix(Matrix3::new([
+            [1., x_by_y, 0.],
+            [y_by_x, 1., 0.],
+            [0., 0., 1.],
+        ]))
+    }
+
+    /// applies `other` after this transform
+    pub fn then(&self, other: &Transform2) -> Self {
+        *other * *self
+    }
+
+    /// moves the points by `offset` after this transform
+    pub fn translate(&self, offset: [f32; 2]) -> Self {
+        self.then(&Transform2::translation(offset))
+    }
+
+    /// rotates the points by `angle` radians around the origin after this transform
+    pub fn rotate(&self, angle: f32) -> Self {
+        self.then(&Transform2::rotation(angle))
+    }
+
+    /// scales the points by `factor` after this transform
+    pub fn scale(&self, factor: [f32; 2]) -> Self {
+        self.then(&Transform2::scaling(factor))
+    }
+
+    /// shears the points after this transform, see [`shearing`]
+    ///
+    /// [`shearing`]: Transform2::shearing
+    pub fn shear(&self, x_by_y: f32, y_by_x: f32) -> Self {
+        self.then(&Transform2::shearing(x_by_y, y_by_x))
+    }
+
+    /// returns the transform which undoes this one
+    ///
+    /// note it panics if the transform is singular (like a scaling by 0)
+    pub fn inverse(&self) -> Self {
+        Transform2::from_matrix(self.matrix.inverse())
+    }
+
+    /// getter for the homogeneous matrix
+    pub fn matrix(&self) -> &Matrix3 {
+        &self.matrix
+    }
+
+    /// applies the transform to a point
+    pub fn transform_point(&self, point: &[f32; 2]) -> [f32; 2] {
+        let p = self.matrix.dot_vec(&[point[0], point[1], 1.]);
+        [p[0] / p[2], p[1] / p[2]]
+    }
+
+    /// applies the transform to a vector, so the translation is ignored
+    pub fn transform_vector(&self, vector: &[f32; 2]) -> [f32; 2] {
+        let v = self.matrix.dot_vec(&[vector[0], vector[1], 0.]);
+        [v[0], v[1]]
+    }
+}
+
+impl Mul for Transform2 {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Transform2::from_matrix(self.matrix.dot_mat(&other.matrix))
+    }
+}
+
+impl MulAssign for Transform2 {
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+
+fn sub(a: &[f32; 3], b: &[f32; 3]) -> [f32; 3] {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+fn dot(a: &[f32; 3], b: &[f32; 3]) -> f32 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+// panics with msg if the vector has a length of 0
+fn normalize(a: &[f32; 3], msg: &str) -> [f32; 3] {
+    let len = dot(a, a).sqrt();
+    if len == 0. {
+        panic!("{}", msg);
+    }
+    [a[0] / len, a[1] / len, a[2] / len]
+}
+
+fn cross(a: &[f32; 3], b: &[f32; 3]) -> [f32; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+/// a half line starting at `origin` which points in `direction`, the direction doesn't have to be normalized
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct Ray {
+    pub origin: [f32; 3],
+    pub direction: [f32; 3],
+}
+
+/// the intersection of a [`Ray`] with a triangle
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct RayHit {
+    /// the hit point is `origin + t * direction`
+    pub t: f32,
+    /// the barycentric coordinate of the second corner
+    pub u: f32,
+    /// the barycentric coordinate of the third corner, the first one is `1 - u - v`
+    pub v: f32,
+}
+
+impl Ray {
+    /// creates a ray
+    pub fn new(origin: [f32; 3], direction: [f32; 3]) -> Self {
+        Ray { origin, direction }
+    }
+
+    /// returns the point `origin + t * direction`
+    pub fn at(&self, t: f32) -> [f32; 3] {
+        [
+            self.origin[0] + t * self.direction[0],
+            self.origin[1] + t * self.direction[1],
+            self.origin[2] + t * self.direction[2],
+        ]
+    }
+
+    /// intersects the ray with the triangle `a`, `b`, `c` with the [Möller–Trumbore algorithm]
+    ///
+    /// both sides of the triangle are hit, rays which are parallel to the triangle or hit behind the origin return `None`
+    ///
+    /// [Möller–Trumbore algorithm]: https://en.wikipedia.org/wiki/M%C3%B6ller%E2%80%93Trumbore_intersection_algorithm
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::geometry::Ray;
+    /// let ray = Ray::new([0.25, 0.25, -1.], [0., 0., 2.]);
+    /// let hit = ray.intersect_triangle(&[0., 0., 0.], &[1., 0., 0.], &[0., 1., 0.]).unwrap();
+    /// assert_eq!((hit.t, hit.u, hit.v), (0.5, 0.25, 0.25));
+    /// assert_eq!(ray.at(hit.t), [0.25, 0.25, 0.]);
+    /// ```
+    pub fn intersect_triangle(&self, a: &[f32; 3], b: &[f32; 3], c: &[f32; 3]) -> Option<RayHit> {
+        let edge1 = sub(b, a);
+        let edge2 = sub(c, a);
+        let p = cross(&self.direction, &edge2);
+        let det = dot(&edge1, &p);
+        if det.abs() < PARALLEL {
+            return None;
+        }
+        let inv_det = 1. / det;
+
+        let s = sub(&self.origin, a);
+        let u = dot(&s, &p) * inv_det;
+        if !(0. ..=1.).contains(&u) {
+            return None;
+        }
+
+        let q = cross(&s, &edge1);
+        let v = dot(&self.direction, &q) * inv_det;
+        if v < 0. || u + v > 1. {
+            return None;
+        }
+
+        let t = dot(&edge2, &q) * inv_det;
+        if t > 0. {
+            Some(RayHit { t, u, v })
+        } else {
+            None
+        }
+    }
+}
@@ -0,0 +1,178 @@
+//! graph algorithms on adjacency matrices
+//!
+//! the value at `index(i, j)` is the weight of the edge from node `i` to node `j`
+
+use crate::clustering::KMeans;
+use crate::linear_algebra::Matrix;
+
+/// the matrix product in the [tropical semiring] (min, +)
+///
+/// the value at `index(i, j)` is the minimum of `a.index(i, k) + b.index(k, j)` over all `k`,
+/// so with the edge weights `a` and `b` it is the shortest path which takes an edge of `a` and then an edge of `b`.
+/// missing edges are `f32::INFINITY`
+///
+/// [tropical semiring]: https://en.wikipedia.org/wiki/Tropical_semiring
+///
+/// ## Example
+///
+/// ```rust
+/// use math::graph::min_plus;
+/// use math::linear_algebra::Matrix;
+/// let inf = f32::INFINITY;
+/// let a = Matrix::new(vec![vec![0., 1.], vec![inf, 0.]]);
+/// let b = Matrix::new(vec![vec![0., inf], vec![2., 0.]]);
+/// assert_eq!(min_plus(&a, &b), Matrix::new(vec![vec![0., 1.], vec![2., 0.]]));
+/// ```
+/// note it panics if the shapes don't match like `Matrix::dot_mat`
+pub fn min_plus(a: &Matrix, b: &Matrix) -> Matrix {
+    if a.rows() != b.cols() {
+        panic!(
+            "wrong matrix shape expected {} cols, got {}",
+            a.rows(),
+            b.cols()
+        );
+    }
+    let (n, m, inner) = (a.cols(), b.rows(), a.rows());
+    let lhs = a.matrix_flatt();
+    let rhs = b.matrix_flatt();
+    let mut matrix_flatt = vec![f32::INFINITY; n * m];
+    for i in 0..n {
+        for k in 0..inner {
+            let a_ik = lhs[i * inner + k];
+            if a_ik == f32::INFINITY {
+                continue;
+            }
+            for j in 0..m {
+                let val = a_ik + rhs[k * m + j];
+                if val < matrix_flatt[i * m + j] {
+                    matrix_flatt[i * m + j] = val;
+                }
+            }
+        }
+    }
+    Matrix::new_flatt(matrix_flatt, n, m)
+}
+
+/// computes the shortest distances between all pairs of nodes with the [Floyd–Warshall algorithm]
+///
+/// the weights can be negative, missing edges are `f32::INFINITY` and the distance from a node to itself is at most 0.
+/// unreachable nodes have the distance `f32::INFINITY`
+///
+/// [Floyd–Warshall algorithm]: https://en.wikipedia.org/wiki/Floyd%E2%80%93Warshall_algorithm
+///
+/// ## Example
+///
+/// ```rust
+/// use math::graph::floyd_warshall;
+/// use math::linear_algebra::Matrix;
+/// let inf = f32::INFINITY;
+/// let weights = Matrix::new(vec![
+///     vec![0., 4., inf],
+///     vec![inf, 0., 1.],
+///     vec![2., inf, 0.],
+/// ]);
+/// let dist = floyd_warshall(&weights);
+/// assert_eq!(dist.index(0, 2), 5.);
+/// assert_eq!(dist.index(2, 1), 6.);
+/// ```
+/// note it panics if the matrix is not square or the graph has a negative cycle
+pub fn floyd_warshall(weights: &Matrix) -> Matrix {
+    if !weights.is_square() {
+        panic!("the matrix has to be a square matrix");
+    }
+    let n = weights.cols();
+    let mut dist = weights.matrix_flatt();
+    for i in 0..n {
+        dist[i * n + i] = dist[i * n + i].min(0.);
+    }
+
+    for k in 0..n {
+        for i in 0..n {
+            let d_ik = dist[i * n + k];
+            if d_ik == f32::INFINITY {
+                continue;
+            }
+            for j in 0..n {
+                let val = d_ik + dist[k * n + j];
+                if val < dist[i * n + j] {
+                    dist[i * n + j] = val;
+                }
+            }
+        }
+    }
+
+    if (0..n).any(|i| dist[i * n + i] < 0.) {
+        panic!("the graph has a negative cycle");
+    }
+    Matrix::new_flatt(dist, n, n)
+}
+
+/// groups the nodes of an undirected graph into `k` clusters with [spectral clustering]
+///
+/// it computes the normalized laplacian `I - D^-1/2 A D^-1/2`, embeds each node with the eigenvectors of the `k` smallest
+/// eigenvalues, normalizes the embeddings to a length of 1 and clusters them with [`KMeans`].
+/// returns the cluster of each node
+///
+/// [spectral clustering]: https://en.wikipedia.org/wiki/Spectral_clustering
+///
+/// ## Example
+///
+/// ```rust
+/// use math::graph::spectral_clustering;
+/// use math::linear_algebra::Matrix;
+/// // two triangles connected by a single edge
+/// let adjacency = Matrix::new(vec![
+///     vec![0., 1., 1., 0., 0., 0.],
+///     vec![1., 0., 1., 0., 0., 0.],
+///     vec![1., 1., 0., 1., 0., 0.],
+///     vec![0., 0., 1., 0., 1., 1.],
+///     vec![0., 0., 0., 1., 0., 1.],
+///     vec![0., 0., 0., 1., 1., 0.],
+/// ]);
+/// let labels = spectral_clustering(&adjacency, 2);
+/// assert!(labels[..3].iter().all(|&l| l == labels[0]));
+/// assert!(labels[3..].iter().all(|&l| l == labels[3]));
+/// assert_ne!(labels[0], labels[3]);
+/// ```
+/// note it panics if the matrix is not square or `k` is 0 or larger then the number of nodes,
+/// the adjacency matrix has to be symmetric with non negative weights
+pub fn spectral_clustering(adjacency: &Matrix, k: usize) -> Vec<usize> {
+    if !adjacency.is_square() {
+        panic!("the matrix has to be a square matrix");
+    }
+    let n = adjacency.cols();
+    if k == 0 || k > n {
+        panic!("k has to be between 1 and {}, got {}", n, k);
+    }
+
+    let weights = adjacency.matrix_flatt();
+    // isolated nodes have a degree of 0 and are only connected to themselves in the laplacian
+    let inv_sqrt: Vec<f32> = (0..n)
+        .map(|i| {
+            let degree: f32 = weights[i * n..(i + 1) * n].iter().sum();
+            if degree > 0. {
+                1. / degree.sqrt()
+            } else {
+                0.
+            }
+        })
+        .collect();
+    let mut laplacian = Vec::with_capacity(n * n);
+    for i in 0..n {
+        for j in 0..n {
+            let identity = if i == j { 1. } else { 0. };
+            laplacian.push(identity - inv_sqrt[i] * weights[i * n + j] * inv_sqrt[j]);
+        }
+    }
+    let (_, vectors) = Matrix::new_flatt(laplacian, n, n).symmetric_eigen();
+
+    // each node is one sample with the k features of its row in the eigenvectors
+    let vectors = vectors.matrix_flatt();
+    let mut embedding = Vec::with_capacity(n * k);
+    for node in 0..n {
+        let point: Vec<f32> = (0..k).map(|c| vectors[c * n + node]).collect();
+        let norm = point.iter().map(|x| x * x).sum::<f32>().sqrt();
+        embedding.extend(point.iter().map(|x| if norm > 0. { x / norm } else { 0. }));
+    }
+    KMeans::new(k).fit_predict(&Matrix::new_flatt(embedding, n, k))
+}
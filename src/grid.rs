@@ -0,0 +1,99 @@
+//! discrete differential operators on a `Matrix` field
+//!
+//! the value at `index(i, j)` is the value at the grid point `(i, j) * dx`,
+//! all operators use central differences and the [`Boundary`] decides the values outside of the grid
+//!
+//! ## Example
+//!
+//! ```rust
+//! use math::grid::{divergence, gradient, laplacian, Boundary};
+//! use math::linear_algebra::Matrix;
+//! let field = Matrix::new(vec![vec![0., 1., 0.], vec![1., 4., 1.], vec![0., 1., 0.]]);
+//! let (di, dj) = gradient(&field, 1., Boundary::Zero);
+//! assert_eq!(di.index(0, 1), 2.);
+//! assert_eq!(dj.index(1, 0), 2.);
+//! assert_eq!(laplacian(&field, 1., Boundary::Zero).index(1, 1), -12.);
+//! // the divergence of the gradient is the laplacian with a step of 2 * dx
+//! assert_eq!(divergence(&di, &dj, 1., Boundary::Zero).index(1, 1), -4.);
+//! ```
+
+use crate::linear_algebra::Matrix;
+
+/// the values outside of the grid
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum Boundary {
+    /// the values outside are 0 (Dirichlet)
+    Zero,
+    /// the values outside are the same as on the nearest edge, so the derivative across the edge is 0 (Neumann)
+    Clamp,
+    /// the grid repeats, so the values outside wrap around to the other side
+    Periodic,
+}
+
+// the value at (i, j) where the indices can be one outside of the grid
+fn at(field: &Matrix, i: isize, j: isize, boundary: Boundary) -> f32 {
+    let (n, m) = (field.cols() as isize, field.rows() as isize);
+    let inside = (0..n).contains(&i) && (0..m).contains(&j);
+    if inside {
+        return field.index(i as usize, j as usize);
+    }
+    match boundary {
+        Boundary::Zero => 0.,
+        Boundary::Clamp => field.index(i.clamp(0, n - 1) as usize, j.clamp(0, m - 1) as usize),
+        Boundary::Periodic => field.index(i.rem_euclid(n) as usize, j.rem_euclid(m) as usize),
+    }
+}
+
+fn map_grid<F: Fn(isize, isize) -> f32>(cols: usize, rows: usize, func: F) -> Matrix {
+    let mut matrix_flatt = Vec::with_capacity(cols * rows);
+    for i in 0..cols as isize {
+        for j in 0..rows as isize {
+            matrix_flatt.push(func(i, j));
+        }
+    }
+    Matrix::new_flatt(matrix_flatt, cols, rows)
+}
+
+/// returns the partial derivatives `(du/di, du/dj)` of the field with central differences
+pub fn gradient(field: &Matrix, dx: f32, boundary: Boundary) -> (Matrix, Matrix) {
+    let (n, m) = (field.cols(), field.rows());
+    let di = map_grid(n, m, |i, j| {
+        (at(field, i + 1, j, boundary) - at(field, i - 1, j, boundary)) / (2. * dx)
+    });
+    let dj = map_grid(n, m, |i, j| {
+        (at(field, i, j + 1, boundary) - at(field, i, j - 1, boundary)) / (2. * dx)
+    });
+    (di, dj)
+}
+
+/// returns the divergence `dfi/di + dfj/dj` of the vector field with the components `fi` and `fj`
+///
+/// note it panics if the components don't have the same shape
+pub fn divergence(fi: &Matrix, fj: &Matrix, dx: f32, boundary: Boundary) -> Matrix {
+    if fi.cols() != fj.cols() || fi.rows() != fj.rows() {
+        panic!(
+            "wrong matrix shape expected {}x{}, got {}x{}",
+            fi.cols(),
+            fi.rows(),
+            fj.cols(),
+            fj.rows()
+        );
+    }
+    map_grid(fi.cols(), fi.rows(), |i, j| {
+        (at(fi, i + 1, j, boundary) - at(fi, i - 1, j, boundary) + at(fj, i, j + 1, boundary)
+            - at(fj, i, j - 1, boundary))
+            / (2. * dx)
+    })
+}
+
+/// returns the laplacian `d^2u/di^2 + d^2u/dj^2` of the field with the 5-point stencil
+pub fn laplacian(field: &Matrix, dx: f32, boundary: Boundary) -> Matrix {
+    map_grid(field.cols(), field.rows(), |i, j| {
+        (at(field, i + 1, j, boundary)
+            + at(field, i - 1, j, boundary)
+            + at(field, i, j + 1, boundary)
+            + at(field, i, j - 1, boundary)
+            - 4. * at(field, i, j, boundary))
+            / (dx * dx)
+    })
+}
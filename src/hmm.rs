@@ -0,0 +1,394 @@
+use crate::linear_algebra::{Matrix, Vector};
+
+/// a [hidden Markov model] with discrete observations
+///
+/// [hidden Markov model]: https://en.wikipedia.org/wiki/Hidden_Markov_model
+///
+/// - `initial.index(i)` is the probability to start in the state `i`
+/// - `transition.index(i, j)` is the probability to go from the state `i` to the state `j`
+/// - `emission.index(i, k)` is the probability to observe `k` in the state `i`
+///
+/// ## Example
+///
+/// ```rust
+/// use math::hmm::HMM;
+/// use math::linear_algebra::{Matrix, Vector};
+/// let hmm = HMM::new(
+///     Vector::new(vec![0.6, 0.4]),
+///     Matrix::new(vec![vec![0.7, 0.3], vec![0.4, 0.6]]),
+///     Matrix::new(vec![vec![0.5, 0.4, 0.1], vec![0.1, 0.3, 0.6]]),
+/// );
+/// let (states, _) = hmm.viterbi(&[0, 1, 2]);
+/// assert_eq!(states, vec![0, 0, 1]);
+/// ```
+#[derive(PartialEq, Clone, Debug)]
+pub struct HMM {
+    initial: Vector,
+    transition: Matrix,
+    emission: Matrix,
+}
+
+impl HMM {
+    /// creates a hidden Markov model
+    ///
+    /// note it panics if the shapes don't match up
+    pub fn new(initial: Vector, transition: Matrix, emission: Matrix) -> Self {
+        let states = initial.len();
+        if transition.cols() != states || transition.rows() != states {
+            panic!(
+                "wrong transition shape expected {}x{}, got {}x{}",
+                states,
+                states,
+                transition.cols(),
+                transition.rows()
+            );
+        }
+        if emission.cols() != states {
+            panic!(
+                "wrong emission shape expected {} cols, got {}",
+                states,
+                emission.cols()
+            );
+        }
+        HMM {
+            initial,
+            transition,
+            emission,
+        }
+    }
+
+    /// returns the number of hidden states
+    pub fn states(&self) -> usize {
+        self.initial.len()
+    }
+
+    /// returns the number of possible observations
+    pub fn symbols(&self) -> usize {
+        self.emission.rows()
+    }
+
+    /// getter for the initial state probabilities
+    pub fn initial(&self) -> &Vector {
+        &self.initial
+    }
+
+    /// getter for the transition matrix
+    pub fn transition(&self) -> &Matrix {
+        &self.transition
+    }
+
+    /// getter for the emission matrix
+    pub fn emission(&self) -> &Matrix {
+        &self.emission
+    }
+
+    /// computes the forward probabilities with the [forward algorithm],
+    /// `index(t, i)` is the probability of the first `t + 1` observations and being in state `i` at time `t`
+    ///
+    /// [forward algorithm]: https://en.wikipedia.org/wiki/Forward_algorithm
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::hmm::HMM;
+    /// use math::linear_algebra::{Matrix, Vector};
+    /// let hmm = HMM::new(
+    ///     Vector::new(vec![0.5, 0.5]),
+    ///     Matrix::new(vec![vec![1., 0.], vec![0., 1.]]),
+    ///     Matrix::new(vec![vec![0.5, 0.5], vec![1., 0.]]),
+    /// );
+    /// let alpha = hmm.forward(&[0, 0]);
+    /// assert_eq!(alpha, Matrix::new(vec![vec![0.25, 0.5], vec![0.125, 0.5]]));
+    /// ```
+    /// note the probabilities are not scaled so they underflow for long sequences, use `log_likelihood` for those
+    pub fn forward(&self, observations: &[usize]) -> Matrix {
+        let (alpha, scale) = self.forward_scaled(observations);
+        let n = self.states();
+        let mut factor = 1.;
+        let mut matrix_flatt = alpha;
+        for (t, c) in scale.iter().enumerate() {
+            factor /= c;
+            for x in matrix_flatt[t * n..(t + 1) * n].iter_mut() {
+                *x *= factor;
+            }
+        }
+        Matrix::new_flatt(matrix_flatt, observations.len(), n)
+    }
+
+    /// computes the backward probabilities,
+    /// `index(t, i)` is the probability of the observations after `t` given the state `i` at time `t`
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::hmm::HMM;
+    /// use math::linear_algebra::{Matrix, Vector};
+    /// let hmm = HMM::new(
+    ///     Vector::new(vec![0.5, 0.5]),
+    ///     Matrix::new(vec![vec![1., 0.], vec![0., 1.]]),
+    ///     Matrix::new(vec![vec![0.5, 0.5], vec![1., 0.]]),
+    /// );
+    /// let beta = hmm.backward(&[0, 0]);
+    /// assert_eq!(beta, Matrix::new(vec![vec![0.5, 1.], vec![1., 1.]]));
+    /// ```
+    /// note the probabilities are not scaled so they underflow for long sequences
+    pub fn backward(&self, observations: &[usize]) -> Matrix {
+        let (_, scale) = self.forward_scaled(observations);
+        let mut beta = self.backward_scaled(observations, &scale);
+        let n = self.states();
+        let mut factor = 1.;
+        for t in (0..observations.len()).rev() {
+            if t + 1 < observations.len() {
+                factor /= scale[t + 1];
+            }
+            for x in beta[t * n..(t + 1) * n].iter_mut() {
+                *x *= factor;
+            }
+        }
+        Matrix::new_flatt(beta, observations.len(), n)
+    }
+
+    /// returns the natural log of the probability of the observations
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::hmm::HMM;
+    /// use math::linear_algebra::{Matrix, Vector};
+    /// let hmm = HMM::new(
+    ///     Vector::new(vec![0.5, 0.5]),
+    ///     Matrix::new(vec![vec![1., 0.], vec![0., 1.]]),
+    ///     Matrix::new(vec![vec![0.5, 0.5], vec![1., 0.]]),
+    /// );
+    /// assert!((hmm.log_likelihood(&[0, 0]) - (0.625 as f32).ln()).abs() < 1e-6);
+    /// ```
+    pub fn log_likelihood(&self, observations: &[usize]) -> f32 {
+        let (_, scale) = self.forward_scaled(observations);
+        -scale.iter().map(|c| c.ln()).sum::<f32>()
+    }
+
+    /// finds the most likely sequence of hidden states with the [Viterbi algorithm],
+    /// returns the states and the natural log of the probability of this path
+    ///
+    /// [Viterbi algorithm]: https://en.wikipedia.org/wiki/Viterbi_algorithm
+    pub fn viterbi(&self, observations: &[usize]) -> (Vec<usize>, f32) {
+        self.check_observations(observations);
+        if observations.is_empty() {
+            return (Vec::new(), 0.);
+        }
+
+        let n = self.states();
+        let len = observations.len();
+        let a = self.transition.matrix_flatt();
+        let b = self.emission.matrix_flatt();
+        let m = self.symbols();
+        let pi = self.initial.vec();
+
+        let mut delta: Vec<f32> = (0..n)
+            .map(|i| pi[i].ln() + b[i * m + observations[0]].ln())
+            .collect();
+        let mut psi = vec![0; len * n];
+
+        for t in 1..len {
+            let mut next = vec![f32::NEG_INFINITY; n];
+            for j in 0..n {
+                for i in 0..n {
+                    let val = delta[i] + a[i * n + j].ln();
+                    if val > next[j] {
+                        next[j] = val;
+                        psi[t * n + j] = i;
+                    }
+                }
+                next[j] += b[j * m + observations[t]].ln();
+            }
+            delta = next;
+        }
+
+        let mut last = 0;
+        for i in 1..n {
+            if delta[i] > delta[last] {
+                last = i;
+            }
+        }
+        let mut states = vec![last; len];
+        for t in (1..len).rev() {
+            states[t - 1] = psi[t * n + states[t]];
+        }
+        (states, delta[last])
+    }
+
+    /// trains the model on the observations with the [Baum-Welch algorithm]
+    /// and returns the log likelihood of the observations after training
+    ///
+    /// [Baum-Welch algorithm]: https://en.wikipedia.org/wiki/Baum%E2%80%93Welch_algorithm
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::hmm::HMM;
+    /// use math::linear_algebra::{Matrix, Vector};
+    /// let mut hmm = HMM::new(
+    ///     Vector::new(vec![0.5, 0.5]),
+    ///     Matrix::new(vec![vec![0.6, 0.4], vec![0.4, 0.6]]),
+    ///     Matrix::new(vec![vec![0.6, 0.4], vec![0.3, 0.7]]),
+    /// );
+    /// let observations = [0, 0, 0, 1, 1, 1, 0, 0, 0, 1, 1, 1];
+    /// let before = hmm.log_likelihood(&observations);
+    /// let after = hmm.baum_welch(&observations, 20);
+    /// assert!(after > before);
+    /// ```
+    /// note the training stops early when the log likelihood doesn't improve anymore
+    pub fn baum_welch(&mut self, observations: &[usize], iterations: usize) -> f32 {
+        self.check_observations(observations);
+        let n = self.states();
+        let m = self.symbols();
+        let len = observations.len();
+        let mut log_likelihood = self.log_likelihood(observations);
+        if len < 2 {
+            return log_likelihood;
+        }
+
+        for _ in 0..iterations {
+            let (alpha, scale) = self.forward_scaled(observations);
+            let beta = self.backward_scaled(observations, &scale);
+            let a = self.transition.matrix_flatt();
+            let b = self.emission.matrix_flatt();
+
+            // gamma[t * n + i] probability of state i at time t
+            let mut gamma = vec![0.; len * n];
+            for t in 0..len {
+                let norm: f32 = (0..n).map(|i| alpha[t * n + i] * beta[t * n + i]).sum();
+                for i in 0..n {
+                    gamma[t * n + i] = alpha[t * n + i] * beta[t * n + i] / norm;
+                }
+            }
+
+            // expected number of transitions from i to j
+            let mut xi_sum = vec![0.; n * n];
+            for t in 0..len - 1 {
+                let mut xi = vec![0.; n * n];
+                let mut norm = 0.;
+                for i in 0..n {
+                    for j in 0..n {
+                        let val = alpha[t * n + i]
+                            * a[i * n + j]
+                            * b[j * m + observations[t + 1]]
+                            * beta[(t + 1) * n + j];
+                        xi[i * n + j] = val;
+                        norm += val;
+                    }
+                }
+                for (sum, val) in xi_sum.iter_mut().zip(xi.iter()) {
+                    *sum += val / norm;
+                }
+            }
+
+            let initial = gamma[..n].to_vec();
+
+            let mut transition = vec![0.; n * n];
+            for i in 0..n {
+                let total: f32 = (0..len - 1).map(|t| gamma[t * n + i]).sum();
+                for j in 0..n {
+                    transition[i * n + j] = if total > 0. {
+                        xi_sum[i * n + j] / total
+                    } else {
+                        a[i * n + j]
+                    };
+                }
+            }
+
+            let mut emission = vec![0.; n * m];
+            for i in 0..n {
+                let total: f32 = (0..len).map(|t| gamma[t * n + i]).sum();
+                for t in 0..len {
+                    emission[i * m + observations[t]] += gamma[t * n + i];
+                }
+                for k in 0..m {
+                    emission[i * m + k] = if total > 0. {
+                        emission[i * m + k] / total
+                    } else {
+                        b[i * m + k]
+                    };
+                }
+            }
+
+            self.initial = Vector::new(initial);
+            self.transition = Matrix::new_flatt(transition, n, n);
+            self.emission = Matrix::new_flatt(emission, n, m);
+
+            let next = self.log_likelihood(observations);
+            let improvement = next - log_likelihood;
+            log_likelihood = next;
+            if improvement.abs() < 1e-6 {
+                break;
+            }
+        }
+        log_likelihood
+    }
+
+    fn check_observations(&self, observations: &[usize]) {
+        if let Some(&o) = observations.iter().find(|&&o| o >= self.symbols()) {
+            panic!(
+                "observation out of bounds max observation {}, got {}",
+                self.symbols() - 1,
+                o
+            );
+        }
+    }
+
+    // forward algorithm where each time step is normalized, returns alpha and the scaling factors
+    fn forward_scaled(&self, observations: &[usize]) -> (Vec<f32>, Vec<f32>) {
+        self.check_observations(observations);
+        let n = self.states();
+        let m = self.symbols();
+        let a = self.transition.matrix_flatt();
+        let b = self.emission.matrix_flatt();
+        let pi = self.initial.vec();
+
+        let mut alpha = vec![0.; observations.len() * n];
+        let mut scale = Vec::with_capacity(observations.len());
+        for (t, &o) in observations.iter().enumerate() {
+            for j in 0..n {
+                let prior = if t == 0 {
+                    pi[j]
+                } else {
+                    (0..n).map(|i| alpha[(t - 1) * n + i] * a[i * n + j]).sum()
+                };
+                alpha[t * n + j] = prior * b[j * m + o];
+            }
+            let total: f32 = alpha[t * n..(t + 1) * n].iter().sum();
+            let c = if total > 0. { 1. / total } else { 1. };
+            for x in alpha[t * n..(t + 1) * n].iter_mut() {
+                *x *= c;
+            }
+            scale.push(c);
+        }
+        (alpha, scale)
+    }
+
+    // backward algorithm scaled with the factors of the forward algorithm
+    fn backward_scaled(&self, observations: &[usize], scale: &[f32]) -> Vec<f32> {
+        let n = self.states();
+        let m = self.symbols();
+        let a = self.transition.matrix_flatt();
+        let b = self.emission.matrix_flatt();
+        let len = observations.len();
+
+        let mut beta = vec![0.; len * n];
+        for t in (0..len).rev() {
+            for i in 0..n {
+                beta[t * n + i] = if t + 1 == len {
+                    1.
+                } else {
+                    (0..n)
+                        .map(|j| {
+                            a[i * n + j] * b[j * m + observations[t + 1]] * beta[(t + 1) * n + j]
+                        })
+                        .sum::<f32>()
+                        * scale[t + 1]
+                };
+            }
+        }
+        beta
+    }
+}
@@ -0,0 +1,222 @@
+//! polynomial interpolation of samples `(x, y)`
+//!
+//! the interpolating polynomial of `n` samples with distinct `x` has the degree `n - 1` and goes through every sample.
+//! [`Newton`] stores it in the Newton form with [divided differences], which is cheap to evaluate and to extend
+//! by another sample, [`lagrange`] evaluates it directly with the barycentric Lagrange formula.
+//! both compute in `f64` and give the same polynomial
+//!
+//! [divided differences]: https://en.wikipedia.org/wiki/Divided_differences
+//!
+//! ## Example
+//!
+//! ```rust
+//! use math::interpolate::{lagrange, Newton};
+//! use math::linear_algebra::Vector;
+//! // samples of y = x^2 - 1
+//! let x = Vector::new(vec![0., 1., 3.]);
+//! let y = Vector::new(vec![-1., 0., 8.]);
+//! let newton = Newton::new(&x, &y);
+//! assert_eq!(newton.eval(2.), 3.);
+//! assert_eq!(lagrange(&x, &y, 2.), 3.);
+//! assert_eq!(newton.to_polynomial().coeffs(), &[-1., 0., 1.]);
+//! ```
+
+use crate::linear_algebra::Vector;
+use crate::polynomial::Polynomial;
+
+// panics if the samples have a different len, there are none or an x is repeated
+fn check_samples(x: &[f32], y: &[f32]) {
+    if x.len() != y.len() {
+        panic!("x and y need the same len, got {} and {}", x.len(), y.len());
+    }
+    if x.is_empty() {
+        panic!("there are no samples to interpolate");
+    }
+    for (i, a) in x.iter().enumerate() {
+        if x[..i].contains(a) {
+            panic!("the x value {} is repeated", a);
+        }
+    }
+}
+
+/// the interpolating polynomial in the Newton form
+/// `c_0 + c_1 (t - x_0) + c_2 (t - x_0)(t - x_1) + ...`
+/// where the coefficients are the divided differences `c_k = [y_0, ..., y_k]`
+#[derive(PartialEq, Clone, Debug)]
+pub struct Newton {
+    x: Vec<f64>,
+    coeffs: Vec<f64>,
+    // the last diagonal of the divided difference table, needed to add a sample
+    diagonal: Vec<f64>,
+}
+
+impl Newton {
+    /// creates the interpolating polynomial of the samples
+    ///
+    /// note it panics if `x` and `y` have a different len, are empty or an `x` value is repeated
+    pub fn new(x: &Vector, y: &Vector) -> Self {
+        let (x, y) = (x.vec(), y.vec());
+        check_samples(&x, &y);
+        let mut newton = Newton {
+            x: Vec::with_capacity(x.len()),
+            coeffs: Vec::with_capacity(x.len()),
+            diagonal: Vec::with_capacity(x.len()),
+        };
+        for (&a, &b) in x.iter().zip(y.iter()) {
+            newton.push(a, b);
+        }
+        newton
+    }
+
+    /// adds the sample `(x, y)` in `O(n)` without recomputing the other coefficients
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::interpolate::Newton;
+    /// use math::linear_algebra::Vector;
+    /// let mut newton = Newton::new(&Vector::new(vec![0., 1.]), &Vector::new(vec![0., 1.]));
+    /// assert_eq!(newton.eval(2.), 2.);
+    /// newton.add_point(2., 4.);
+    /// assert_eq!(newton.eval(3.), 9.);
+    /// ```
+    /// note it panics if `x` is already a sample
+    pub fn add_point(&mut self, x: f32, y: f32) {
+        if self.x.contains(&(x as f64)) {
+            panic!("the x value {} is repeated", x);
+        }
+        self.push(x, y);
+    }
+
+    // extends the diagonal of the table, diagonal[i] is [y_i, ..., y_n-1] of the last sample
+    fn push(&mut self, x: f32, y: f32) {
+        let (x, n) = (x as f64, self.x.len());
+        let mut diagonal = vec![0.; n + 1];
+        diagonal[n] = y as f64;
+        for i in (0..n).rev() {
+            diagonal[i] = (diagonal[i + 1] - self.diagonal[i]) / (x - self.x[i]);
+        }
+        self.coeffs.push(diagonal[0]);
+        self.diagonal = diagonal;
+        self.x.push(x);
+    }
+
+    /// returns the coefficients of the Newton form
+    pub fn coeffs(&self) -> Vec<f32> {
+        self.coeffs.iter().map(|&c| c as f32).collect()
+    }
+
+    /// returns the number of samples
+    pub fn len(&self) -> usize {
+        self.x.len()
+    }
+
+    /// returns true if there are no samples, this is never the case for a `Newton` created by `new`
+    pub fn is_empty(&self) -> bool {
+        self.x.is_empty()
+    }
+
+    /// evaluates the polynomial at `t` with the nested form of Horner's method
+    pub fn eval(&self, t: f32) -> f32 {
+        let t = t as f64;
+        self.coeffs
+            .iter()
+            .zip(self.x.iter())
+            .rev()
+            .fold(0., |acc, (&c, &x)| acc * (t - x) + c) as f32
+    }
+
+    /// evaluates the polynomial at every value of `t`
+    pub fn eval_vec(&self, t: &Vector) -> Vector {
+        t.vec().into_iter().map(|t| self.eval(t)).collect()
+    }
+
+    /// expands the Newton form into the coefficients of a [`Polynomial`]
+    pub fn to_polynomial(&self) -> Polynomial {
+        // Horner's method on the coefficients: p = p * (t - x_k) + c_k
+        let mut coeffs: Vec<f64> = Vec::with_capacity(self.len());
+        for (&c, &x) in self.coeffs.iter().zip(self.x.iter()).rev() {
+            coeffs.insert(0, 0.);
+            for k in 0..coeffs.len() - 1 {
+                coeffs[k] -= x * coeffs[k + 1];
+            }
+            coeffs[0] += c;
+        }
+        Polynomial::new(coeffs.into_iter().map(|c| c as f32).collect())
+    }
+}
+
+/// returns the divided differences `[y_0], [y_0, y_1], ..., [y_0, ..., y_n-1]`,
+/// the coefficients of the Newton form
+///
+/// ## Example
+///
+/// ```rust
+/// use math::interpolate::divided_differences;
+/// use math::linear_algebra::Vector;
+/// let x = Vector::new(vec![1., 2., 4.]);
+/// let y = Vector::new(vec![1., 4., 16.]);
+/// assert_eq!(divided_differences(&x, &y), Vector::new(vec![1., 3., 1.]));
+/// ```
+/// note it panics if `x` and `y` have a different len, are empty or an `x` value is repeated
+pub fn divided_differences(x: &Vector, y: &Vector) -> Vector {
+    Vector::new(Newton::new(x, y).coeffs())
+}
+
+/// evaluates the interpolating polynomial of the samples at `t` with the [barycentric Lagrange formula]
+/// in `O(n^2)`, without building the polynomial
+///
+/// [barycentric Lagrange formula]: https://en.wikipedia.org/wiki/Lagrange_polynomial#Barycentric_form
+///
+/// note it panics if `x` and `y` have a different len, are empty or an `x` value is repeated
+pub fn lagrange(x: &Vector, y: &Vector, t: f32) -> f32 {
+    let (x, y) = (x.vec(), y.vec());
+    check_samples(&x, &y);
+    let t = t as f64;
+    let mut numer = 0.;
+    let mut denom = 0.;
+    for (j, (&xj, &yj)) in x.iter().zip(y.iter()).enumerate() {
+        let xj = xj as f64;
+        if t == xj {
+            return yj;
+        }
+        let weight = 1.
+            / x.iter()
+                .enumerate()
+                .filter(|&(k, _)| k != j)
+                .map(|(_, &xk)| xj - xk as f64)
+                .product::<f64>();
+        let term = weight / (t - xj);
+        numer += term * yj as f64;
+        denom += term;
+    }
+    (numer / denom) as f32
+}
+
+/// returns the interpolating polynomial of the samples as the sum of the Lagrange basis polynomials
+///
+/// note it panics if `x` and `y` have a different len, are empty or an `x` value is repeated
+pub fn lagrange_polynomial(x: &Vector, y: &Vector) -> Polynomial {
+    let (xs, ys) = (x.vec(), y.vec());
+    check_samples(&xs, &ys);
+    let n = xs.len();
+    let mut coeffs = vec![0f64; n];
+    for j in 0..n {
+        // the basis polynomial prod_{k != j} (t - x_k) / (x_j - x_k)
+        let mut basis = vec![1f64];
+        let mut denom = 1f64;
+        for (_, &xk) in xs.iter().enumerate().filter(|&(k, _)| k != j) {
+            let xk = xk as f64;
+            basis.insert(0, 0.);
+            for i in 0..basis.len() - 1 {
+                basis[i] -= xk * basis[i + 1];
+            }
+            denom *= xs[j] as f64 - xk;
+        }
+        let scale = ys[j] as f64 / denom;
+        for (c, b) in coeffs.iter_mut().zip(basis.iter()) {
+            *c += scale * b;
+        }
+    }
+    Polynomial::new(coeffs.into_iter().map(|c| c as f32).collect())
+}
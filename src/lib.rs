@@ -1,6 +1,32 @@
+pub mod approx;
+pub mod clustering;
+pub mod curve;
+pub mod data;
+pub mod error;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+pub mod fft;
+pub mod geometry;
+pub mod graph;
+pub mod grid;
+pub mod hmm;
+pub mod interpolate;
 pub mod linear_algebra;
+pub mod losses;
+pub mod metrics;
 pub mod misc;
+pub mod nn;
+pub mod noise;
+pub mod number;
+pub mod pde;
+pub mod polynomial;
+pub mod preprocessing;
 pub mod random;
+pub mod statistics;
+pub mod testing;
+pub mod timeseries;
+
+pub use error::MathError;
 
 #[cfg(test)]
 mod tests {
@@ -10,6 +36,4 @@ mod tests {
     fn it_works() {
         assert_eq!(4, 2 + 2);
     }
-
-    
 }
@@ -1,5 +1,36 @@
+pub use builder::MatrixBuilder;
 pub use matrix::Matrix;
+pub use solve::solve_tridiagonal;
 pub use vector::Vector;
 
+pub mod batch;
+pub mod bit;
+pub mod builder;
+pub mod csv;
+pub mod double;
+mod eigen;
+pub mod encoding;
+pub mod expr;
+pub mod fixed;
+pub mod fixed_vec;
+pub mod format;
+pub mod half;
+pub mod image;
+#[cfg(feature = "mat")]
+pub mod mat;
 pub mod matrix;
+pub mod modular;
+pub mod mtx;
+pub mod norm;
+pub mod npy;
+pub mod parse;
+pub mod rational;
+mod reduce;
+pub mod shape;
+mod small;
+pub(crate) mod solve;
+pub mod sparse;
+mod structure;
 pub mod vector;
+pub mod view;
+pub mod workspace;
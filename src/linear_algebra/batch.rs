@@ -0,0 +1,382 @@
+//! a stack of equally shaped matrices in one contiguous buffer
+//!
+//! the element-wise operations broadcast like NumPy: the number of matrices and each
+//! dimension of the shape has to be the same or 1, a dimension of 1 is repeated.
+//! so a `Matrix` can be added to every matrix of a batch and a batch of `1 x rows` matrices
+//! (like a bias) can be added to a batch of `cols x rows` matrices
+//!
+//! ## Example
+//!
+//! ```rust
+//! use math::linear_algebra::batch::MatrixBatch;
+//! use math::linear_algebra::Matrix;
+//! let inputs = MatrixBatch::new(&[
+//!     Matrix::new(vec![vec![1., 2.]]),
+//!     Matrix::new(vec![vec![3., 4.]]),
+//! ]);
+//! let weights = Matrix::new(vec![vec![1., 0., 1.], vec![0., 1., 1.]]);
+//! let bias = Matrix::new(vec![vec![0.5, 0.5, 0.5]]);
+//! let outputs = &inputs.dot_mat(&weights) + &bias;
+//! assert_eq!(outputs.get(1), Matrix::new(vec![vec![3.5, 4.5, 7.5]]));
+//! ```
+
+use crate::linear_algebra::matrix::{dot_flatt, BLOCK_SIZE};
+use crate::linear_algebra::shape::Shape;
+use crate::linear_algebra::Matrix;
+use std::ops::{Add, Mul, Neg, Sub};
+
+/// a stack of `len` matrices with the shape `cols x rows`
+///
+/// the matrix `k` is stored like `matrix_flatt` at `k * cols * rows`
+#[derive(PartialEq, Clone, Debug)]
+pub struct MatrixBatch {
+    len: usize,
+    cols: usize,
+    rows: usize,
+    data: Vec<f32>,
+}
+
+// the number of matrices of two broadcast batches
+fn broadcast_len(len1: usize, len2: usize) -> usize {
+    match (len1, len2) {
+        _ if len1 == len2 => len1,
+        (1, len) | (len, 1) => len,
+        _ => panic!("can't broadcast a batch of {} and {} matrices", len1, len2),
+    }
+}
+
+impl MatrixBatch {
+    /// copies the matrices into one batch
+    ///
+    /// note it panics if the matrices don't have the same shape or if there are no matrices
+    pub fn new(matrices: &[Matrix]) -> Self {
+        let shape = match matrices.first() {
+            Some(first) => first.shape(),
+            None => panic!("a batch needs at least one matrix to know the shape"),
+        };
+        let mut data = Vec::with_capacity(matrices.len() * shape.len());
+        for matrix in matrices {
+            if matrix.shape() != shape {
+                panic!(
+                    "all matrices of a batch need the shape {}, got {}",
+                    shape,
+                    matrix.shape()
+                );
+            }
+            data.extend(matrix.iter_elems());
+        }
+        Self {
+            len: matrices.len(),
+            cols: shape.cols,
+            rows: shape.rows,
+            data,
+        }
+    }
+
+    /// creates a batch from the matrices stored one after the other like `matrix_flatt`
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::batch::MatrixBatch;
+    /// use math::linear_algebra::Matrix;
+    /// let batch = MatrixBatch::new_flatt(vec![1., 2., 3., 4.], 2, 1, 2);
+    /// assert_eq!(batch.get(1), Matrix::new(vec![vec![3., 4.]]));
+    /// ```
+    /// note it panics if `len * cols * rows` is not the len of `data`
+    pub fn new_flatt(data: Vec<f32>, len: usize, cols: usize, rows: usize) -> Self {
+        if len * cols * rows != data.len() {
+            panic!(
+                "len * cols * rows = {} has to be the same len as the data = {}",
+                len * cols * rows,
+                data.len()
+            );
+        }
+        Self {
+            len,
+            cols,
+            rows,
+            data,
+        }
+    }
+
+    /// creates a batch of `len` matrices with the shape `cols x rows` filled with zeros
+    pub fn new_zero(len: usize, cols: usize, rows: usize) -> Self {
+        Self::new_flatt(vec![0.; len * cols * rows], len, cols, rows)
+    }
+
+    /// returns the number of matrices
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// returns true if the batch has no matrices
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// getter for the cols of each matrix
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// getter for the rows of each matrix
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// returns the shape of each matrix
+    pub fn shape(&self) -> Shape {
+        Shape::new(self.cols, self.rows)
+    }
+
+    /// getter for the contiguous buffer of all matrices
+    pub fn data(&self) -> &[f32] {
+        &self.data
+    }
+
+    /// returns the component `index(row, col)` of the matrix `k`
+    ///
+    /// note it panics if an index is out of bounds
+    pub fn index(&self, k: usize, row: usize, col: usize) -> f32 {
+        self.data[self.offset(k, row, col)]
+    }
+
+    /// sets the component `index(row, col)` of the matrix `k`
+    ///
+    /// note it panics if an index is out of bounds
+    pub fn set_index(&mut self, k: usize, row: usize, col: usize, val: f32) {
+        let offset = self.offset(k, row, col);
+        self.data[offset] = val;
+    }
+
+    /// copies the matrix `k` out of the batch
+    ///
+    /// note it panics if `k` is out of bounds
+    pub fn get(&self, k: usize) -> Matrix {
+        Matrix::new_flatt(self.slice(k).to_vec(), self.cols, self.rows)
+    }
+
+    /// overwrites the matrix `k` with `matrix`
+    ///
+    /// note it panics if `k` is out of bounds or the matrix has not the shape of the batch
+    pub fn set(&mut self, k: usize, matrix: &Matrix) {
+        if matrix.shape() != self.shape() {
+            panic!(
+                "the batch has the shape {}, got {}",
+                self.shape(),
+                matrix.shape()
+            );
+        }
+        let size = self.cols * self.rows;
+        let slice = &mut self.data[k * size..(k + 1) * size];
+        slice
+            .iter_mut()
+            .zip(matrix.iter_elems())
+            .for_each(|(x, y)| *x = y);
+    }
+
+    /// returns an iterator over copies of the matrices
+    pub fn iter(&self) -> impl Iterator<Item = Matrix> + '_ {
+        (0..self.len).map(move |k| self.get(k))
+    }
+
+    /// applies `f` to every component
+    pub fn map<F: Fn(f32) -> f32>(&self, f: F) -> MatrixBatch {
+        Self::new_flatt(
+            self.data.iter().map(|&x| f(x)).collect(),
+            self.len,
+            self.cols,
+            self.rows,
+        )
+    }
+
+    /// the element-wise product with broadcasting, see the module docs
+    ///
+    /// note it panics if the batches can't be broadcast
+    pub fn mul_elem(&self, other: &MatrixBatch) -> MatrixBatch {
+        self.zip(other, |x, y| x * y)
+    }
+
+    /// multiplies every matrix with the matrix of `other` at the same position,
+    /// a batch of one matrix is multiplied with every matrix of the other batch
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::batch::MatrixBatch;
+    /// use math::linear_algebra::Matrix;
+    /// let a = Matrix::new(vec![vec![1., 2.], vec![3., 4.]]);
+    /// let b = Matrix::new(vec![vec![0., 1.], vec![1., 0.]]);
+    /// let batch = MatrixBatch::new(&[a.clone(), b.clone()]);
+    /// let product = batch.dot(&batch);
+    /// assert_eq!(product.get(0), a.dot_mat(&a));
+    /// assert_eq!(product.get(1), b.dot_mat(&b));
+    /// ```
+    /// note it panics if the rows of `self` are not the cols of `other` or the batches can't be broadcast
+    pub fn dot(&self, other: &MatrixBatch) -> MatrixBatch {
+        if self.rows != other.cols {
+            panic!(
+                "the matrices have wrong shape expected {}, got {}",
+                self.rows, other.cols
+            );
+        }
+        let len = broadcast_len(self.len, other.len);
+        let (lhs_size, rhs_size) = (self.cols * self.rows, other.cols * other.rows);
+        let out_size = self.cols * other.rows;
+        let mut data = vec![0.; len * out_size];
+        for k in 0..len {
+            let lhs = &self.data[(k % self.len) * lhs_size..][..lhs_size];
+            let rhs = &other.data[(k % other.len) * rhs_size..][..rhs_size];
+            dot_flatt(
+                lhs,
+                rhs,
+                self.cols,
+                self.rows,
+                other.rows,
+                &mut data[k * out_size..(k + 1) * out_size],
+                BLOCK_SIZE,
+            );
+        }
+        Self::new_flatt(data, len, self.cols, other.rows)
+    }
+
+    /// multiplies every matrix with the same matrix `other`
+    ///
+    /// note it panics if the rows of `self` are not the cols of `other`
+    pub fn dot_mat(&self, other: &Matrix) -> MatrixBatch {
+        self.dot(&MatrixBatch::from(other))
+    }
+
+    // the position of the component in data
+    fn offset(&self, k: usize, row: usize, col: usize) -> usize {
+        if k >= self.len || row >= self.cols || col >= self.rows {
+            panic!(
+                "the index ({}, {}, {}) is out of bounds for a batch of {} {} matrices",
+                k,
+                row,
+                col,
+                self.len,
+                self.shape()
+            );
+        }
+        (k * self.cols + row) * self.rows + col
+    }
+
+    // the values of the matrix k
+    fn slice(&self, k: usize) -> &[f32] {
+        if k >= self.len {
+            panic!("the batch has {} matrices, got index {}", self.len, k);
+        }
+        let size = self.cols * self.rows;
+        &self.data[k * size..(k + 1) * size]
+    }
+
+    // combines the components of the broadcast batches with f
+    fn zip<F: Fn(f32, f32) -> f32>(&self, other: &MatrixBatch, f: F) -> MatrixBatch {
+        let len = broadcast_len(self.len, other.len);
+        let shape = self
+            .shape()
+            .broadcast(&other.shape())
+            .unwrap_or_else(|| panic!("can't broadcast {} and {}", self.shape(), other.shape()));
+        // a dimension of 1 is repeated, so the index is taken modulo the dimension
+        let at = |batch: &MatrixBatch, k: usize, i: usize, j: usize| {
+            batch.data
+                [((k % batch.len) * batch.cols + i % batch.cols) * batch.rows + j % batch.rows]
+        };
+        let mut data = Vec::with_capacity(len * shape.len());
+        for k in 0..len {
+            for i in 0..shape.cols {
+                for j in 0..shape.rows {
+                    data.push(f(at(self, k, i, j), at(other, k, i, j)));
+                }
+            }
+        }
+        Self::new_flatt(data, len, shape.cols, shape.rows)
+    }
+}
+
+impl From<&Matrix> for MatrixBatch {
+    fn from(matrix: &Matrix) -> Self {
+        Self::new_flatt(matrix.matrix_flatt(), 1, matrix.cols(), matrix.rows())
+    }
+}
+
+impl From<Matrix> for MatrixBatch {
+    fn from(matrix: Matrix) -> Self {
+        MatrixBatch::from(&matrix)
+    }
+}
+
+impl Add for &MatrixBatch {
+    type Output = MatrixBatch;
+
+    fn add(self, other: &MatrixBatch) -> MatrixBatch {
+        self.zip(other, |x, y| x + y)
+    }
+}
+
+impl Add for MatrixBatch {
+    type Output = MatrixBatch;
+
+    fn add(self, other: MatrixBatch) -> MatrixBatch {
+        &self + &other
+    }
+}
+
+impl Add<&Matrix> for &MatrixBatch {
+    type Output = MatrixBatch;
+
+    fn add(self, other: &Matrix) -> MatrixBatch {
+        self + &MatrixBatch::from(other)
+    }
+}
+
+impl Sub for &MatrixBatch {
+    type Output = MatrixBatch;
+
+    fn sub(self, other: &MatrixBatch) -> MatrixBatch {
+        self.zip(other, |x, y| x - y)
+    }
+}
+
+impl Sub for MatrixBatch {
+    type Output = MatrixBatch;
+
+    fn sub(self, other: MatrixBatch) -> MatrixBatch {
+        &self - &other
+    }
+}
+
+impl Sub<&Matrix> for &MatrixBatch {
+    type Output = MatrixBatch;
+
+    fn sub(self, other: &Matrix) -> MatrixBatch {
+        self - &MatrixBatch::from(other)
+    }
+}
+
+impl Mul<f32> for &MatrixBatch {
+    type Output = MatrixBatch;
+
+    fn mul(self, other: f32) -> MatrixBatch {
+        self.map(|x| x * other)
+    }
+}
+
+impl Mul<f32> for MatrixBatch {
+    type Output = MatrixBatch;
+
+    fn mul(mut self, other: f32) -> MatrixBatch {
+        self.data.iter_mut().for_each(|x| *x *= other);
+        self
+    }
+}
+
+impl Neg for MatrixBatch {
+    type Output = MatrixBatch;
+
+    fn neg(self) -> MatrixBatch {
+        self * -1.
+    }
+}
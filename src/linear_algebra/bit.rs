@@ -0,0 +1,290 @@
+//! linear algebra over [GF(2)], the field with the values 0 and 1 where the addition is xor and the multiplication is and
+//!
+//! [GF(2)]: https://en.wikipedia.org/wiki/GF(2)
+
+/// a matrix over GF(2) where each `i` is packed into 64 bit words
+///
+/// the indices work like `Matrix::index`, `dot_vec` computes `result[i] = xor of index(i, j) & vector[j]`
+///
+/// ## Example
+///
+/// ```rust
+/// use math::linear_algebra::bit::BitMatrix;
+/// let matrix = BitMatrix::new(vec![
+///     vec![true, true, false],
+///     vec![false, true, true],
+///     vec![true, false, true],
+/// ]);
+/// // the sum of all equations is 0, so the rank is 2
+/// assert_eq!(matrix.rank(), 2);
+/// assert_eq!(matrix.dot_vec(&[true, true, true]), vec![false, false, false]);
+/// ```
+#[derive(PartialEq, Eq, Hash, Clone, Debug)]
+pub struct BitMatrix {
+    cols: usize,
+    rows: usize,
+    words: usize,
+    bits: Vec<u64>,
+}
+
+fn words(rows: usize) -> usize {
+    (rows + 63) / 64
+}
+
+impl BitMatrix {
+    /// creates a matrix from the values, the outer vec are the cols like in `Matrix::new`
+    ///
+    /// note it panics if the inner vecs don't have the same len
+    pub fn new(vec: Vec<Vec<bool>>) -> Self {
+        let rows = vec.first().map_or(0, |v| v.len());
+        let mut matrix = BitMatrix::new_zero(vec.len(), rows);
+        for (i, line) in vec.iter().enumerate() {
+            if line.len() != rows {
+                panic!("wrong row shape expected {}, got {}", rows, line.len());
+            }
+            for (j, &val) in line.iter().enumerate() {
+                matrix.set_index(i, j, val);
+            }
+        }
+        matrix
+    }
+
+    /// creates a matrix where all values are 0
+    pub fn new_zero(cols: usize, rows: usize) -> Self {
+        BitMatrix {
+            cols,
+            rows,
+            words: words(rows),
+            bits: vec![0; cols * words(rows)],
+        }
+    }
+
+    /// creates the `n`x`n` identity matrix
+    pub fn identity(n: usize) -> Self {
+        let mut matrix = BitMatrix::new_zero(n, n);
+        for i in 0..n {
+            matrix.set_index(i, i, true);
+        }
+        matrix
+    }
+
+    /// return the length of the columns like `Matrix::cols`
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// return the length of the rows like `Matrix::rows`
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    fn check_index(&self, i: usize, j: usize) {
+        if i >= self.cols || j >= self.rows {
+            panic!(
+                "the index ({}, {}) is out of bounds for the shape {}x{}",
+                i, j, self.cols, self.rows
+            );
+        }
+    }
+
+    /// returns the value at index(i, j)
+    pub fn index(&self, i: usize, j: usize) -> bool {
+        self.check_index(i, j);
+        self.bits[i * self.words + j / 64] >> (j % 64) & 1 == 1
+    }
+
+    /// sets the value at index(i, j)
+    pub fn set_index(&mut self, i: usize, j: usize, val: bool) {
+        self.check_index(i, j);
+        let word = &mut self.bits[i * self.words + j / 64];
+        if val {
+            *word |= 1 << (j % 64);
+        } else {
+            *word &= !(1 << (j % 64));
+        }
+    }
+
+    fn line(&self, i: usize) -> &[u64] {
+        &self.bits[i * self.words..(i + 1) * self.words]
+    }
+
+    // xors the line `src` in to the line `dst`
+    fn xor_line(&mut self, src: usize, dst: usize) {
+        for w in 0..self.words {
+            let val = self.bits[src * self.words + w];
+            self.bits[dst * self.words + w] ^= val;
+        }
+    }
+
+    fn swap_lines(&mut self, a: usize, b: usize) {
+        for w in 0..self.words {
+            self.bits.swap(a * self.words + w, b * self.words + w);
+        }
+    }
+
+    /// returns the transposed matrix
+    pub fn transpose(&self) -> BitMatrix {
+        let mut result = BitMatrix::new_zero(self.rows, self.cols);
+        for i in 0..self.cols {
+            for j in 0..self.rows {
+                if self.index(i, j) {
+                    result.set_index(j, i, true);
+                }
+            }
+        }
+        result
+    }
+
+    /// computes the product with the vector like `Matrix::dot_vec`
+    ///
+    /// note it panics if the vector has the wrong len
+    pub fn dot_vec(&self, vector: &[bool]) -> Vec<bool> {
+        if vector.len() != self.rows {
+            panic!(
+                "wrong vector shape expected {}, got {}",
+                self.rows,
+                vector.len()
+            );
+        }
+        let packed = pack(vector);
+        (0..self.cols)
+            .map(|i| {
+                let ones: u32 = self
+                    .line(i)
+                    .iter()
+                    .zip(packed.iter())
+                    .map(|(a, b)| (a & b).count_ones())
+                    .sum();
+                ones % 2 == 1
+            })
+            .collect()
+    }
+
+    /// computes the matrix product like `Matrix::dot_mat`
+    ///
+    /// note it panics if the shapes don't match
+    pub fn dot_mat(&self, other: &BitMatrix) -> BitMatrix {
+        if self.rows != other.cols {
+            panic!(
+                "wrong matrix shape expected {} cols, got {}",
+                self.rows, other.cols
+            );
+        }
+        let mut result = BitMatrix::new_zero(self.cols, other.rows);
+        for i in 0..self.cols {
+            for k in 0..self.rows {
+                if self.index(i, k) {
+                    for w in 0..result.words {
+                        result.bits[i * result.words + w] ^= other.bits[k * other.words + w];
+                    }
+                }
+            }
+        }
+        result
+    }
+
+    // reduces the matrix to the reduced row echelon form and applies the same operations to `other`,
+    // returns the pivot column `j` of each of the first lines
+    fn eliminate(&mut self, mut other: Option<&mut BitMatrix>) -> Vec<usize> {
+        let mut pivots = Vec::new();
+        for j in 0..self.rows {
+            let line = pivots.len();
+            let pivot = match (line..self.cols).find(|&i| self.index(i, j)) {
+                Some(pivot) => pivot,
+                None => continue,
+            };
+            self.swap_lines(line, pivot);
+            if let Some(other) = other.as_deref_mut() {
+                other.swap_lines(line, pivot);
+            }
+            for i in 0..self.cols {
+                if i != line && self.index(i, j) {
+                    self.xor_line(line, i);
+                    if let Some(other) = other.as_deref_mut() {
+                        other.xor_line(line, i);
+                    }
+                }
+            }
+            pivots.push(j);
+            if pivots.len() == self.cols {
+                break;
+            }
+        }
+        pivots
+    }
+
+    /// returns the [rank] of the matrix over GF(2)
+    ///
+    /// [rank]: https://en.wikipedia.org/wiki/Rank_(linear_algebra)
+    pub fn rank(&self) -> usize {
+        self.clone().eliminate(None).len()
+    }
+
+    /// solves `self.dot_vec(&x) == b` with [Gaussian elimination] and returns one of the solutions,
+    /// the free variables are set to 0. returns `None` if there is no solution
+    ///
+    /// [Gaussian elimination]: https://en.wikipedia.org/wiki/Gaussian_elimination
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::bit::BitMatrix;
+    /// let matrix = BitMatrix::new(vec![vec![true, true], vec![false, true]]);
+    /// assert_eq!(matrix.solve(&[true, true]), Some(vec![false, true]));
+    /// let singular = BitMatrix::new(vec![vec![true, true], vec![true, true]]);
+    /// assert_eq!(singular.solve(&[true, false]), None);
+    /// ```
+    /// note it panics if the vector has the wrong len
+    pub fn solve(&self, b: &[bool]) -> Option<Vec<bool>> {
+        if b.len() != self.cols {
+            panic!("wrong vector shape expected {}, got {}", self.cols, b.len());
+        }
+        let mut reduced = self.clone();
+        let mut rhs = BitMatrix::new_zero(self.cols, 1);
+        for (i, &val) in b.iter().enumerate() {
+            rhs.set_index(i, 0, val);
+        }
+        let pivots = reduced.eliminate(Some(&mut rhs));
+        if (pivots.len()..self.cols).any(|i| rhs.index(i, 0)) {
+            return None;
+        }
+        let mut x = vec![false; self.rows];
+        for (line, &j) in pivots.iter().enumerate() {
+            x[j] = rhs.index(line, 0);
+        }
+        Some(x)
+    }
+
+    /// returns the inverse of the matrix over GF(2) or `None` if it is singular
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::bit::BitMatrix;
+    /// let matrix = BitMatrix::new(vec![vec![true, true], vec![false, true]]);
+    /// assert_eq!(matrix.inverse(), Some(matrix.clone()));
+    /// ```
+    /// note it panics if the matrix is not square
+    pub fn inverse(&self) -> Option<BitMatrix> {
+        if self.cols != self.rows {
+            panic!("the matrix has to be a square matrix");
+        }
+        let mut reduced = self.clone();
+        let mut inverse = BitMatrix::identity(self.cols);
+        if reduced.eliminate(Some(&mut inverse)).len() == self.cols {
+            Some(inverse)
+        } else {
+            None
+        }
+    }
+}
+
+fn pack(vector: &[bool]) -> Vec<u64> {
+    let mut packed = vec![0; words(vector.len())];
+    for (j, &val) in vector.iter().enumerate() {
+        if val {
+            packed[j / 64] |= 1 << (j % 64);
+        }
+    }
+    packed
+}
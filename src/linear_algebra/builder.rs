@@ -0,0 +1,108 @@
+use crate::linear_algebra::Matrix;
+
+/// builds a matrix value by value without initializing the storage first
+///
+/// the values are pushed in the order of `matrix_flatt()`, so col by col like in `Matrix::new`
+///
+/// ## Example
+///
+/// ```rust
+/// use math::linear_algebra::Matrix;
+/// let mut builder = Matrix::with_capacity(2, 3);
+/// builder.push_col(&[3., 2., 4.]);
+/// builder.push(4.);
+/// builder.extend(vec![5., 6.]);
+/// assert_eq!(
+///     builder.build(),
+///     Matrix::new(vec![vec![3., 2., 4.], vec![4., 5., 6.]])
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct MatrixBuilder {
+    cols: usize,
+    rows: usize,
+    matrix_flatt: Vec<f32>,
+}
+
+impl Matrix {
+    /// returns a [`MatrixBuilder`] for a matrix of size `cols` and `rows`
+    ///
+    /// the storage is allocated once but not filled, unlike `new_zero`
+    pub fn with_capacity(cols: usize, rows: usize) -> MatrixBuilder {
+        MatrixBuilder {
+            cols,
+            rows,
+            matrix_flatt: Vec::with_capacity(cols * rows),
+        }
+    }
+}
+
+impl MatrixBuilder {
+    /// returns the number of values which were pushed
+    pub fn len(&self) -> usize {
+        self.matrix_flatt.len()
+    }
+
+    /// returns true if no value was pushed
+    pub fn is_empty(&self) -> bool {
+        self.matrix_flatt.is_empty()
+    }
+
+    /// returns true if all values of the matrix were pushed
+    pub fn is_full(&self) -> bool {
+        self.len() == self.cols * self.rows
+    }
+
+    /// pushes the next value
+    ///
+    /// note it panics if the matrix is already full
+    pub fn push(&mut self, val: f32) {
+        if self.is_full() {
+            panic!(
+                "the matrix is already full with {} values",
+                self.cols * self.rows
+            );
+        }
+        self.matrix_flatt.push(val);
+    }
+
+    /// pushes the next col
+    ///
+    /// note it panics if the col doesn't have the len of the rows
+    /// or if the previous col is not completely filled
+    pub fn push_col(&mut self, col: &[f32]) {
+        if col.len() != self.rows {
+            panic!("wrong row shape expected {}, got {}", self.rows, col.len())
+        }
+        if self.len() % self.rows != 0 {
+            panic!("the previous col is not completely filled");
+        }
+        if self.is_full() {
+            panic!(
+                "the matrix is already full with {} values",
+                self.cols * self.rows
+            );
+        }
+        self.matrix_flatt.extend_from_slice(col);
+    }
+
+    /// turns the builder into the matrix
+    ///
+    /// note it panics if not all values were pushed
+    pub fn build(self) -> Matrix {
+        if !self.is_full() {
+            panic!(
+                "the matrix is not full expected {} values, got {}",
+                self.cols * self.rows,
+                self.len()
+            );
+        }
+        Matrix::new_flatt(self.matrix_flatt, self.cols, self.rows)
+    }
+}
+
+impl Extend<f32> for MatrixBuilder {
+    fn extend<I: IntoIterator<Item = f32>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|val| self.push(val));
+    }
+}
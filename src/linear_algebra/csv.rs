@@ -0,0 +1,144 @@
+//! reading and writing CSV files
+//!
+//! each line of the file is a col like in `Matrix::new`, so the values of a line
+//! are `matrix.col(i)`
+//!
+//! ## Example
+//!
+//! ```rust
+//! use math::linear_algebra::csv::CsvOptions;
+//! use math::linear_algebra::Matrix;
+//! let data = "x;y\n1;2.5\n3;-4\n";
+//! let options = CsvOptions::default().delimiter(';').header(true);
+//! let matrix = Matrix::from_csv_reader(data.as_bytes(), &options).unwrap();
+//! assert_eq!(matrix, Matrix::new(vec![vec![1., 2.5], vec![3., -4.]]));
+//!
+//! let mut out = Vec::new();
+//! matrix.to_csv_writer(&mut out, &CsvOptions::default()).unwrap();
+//! assert_eq!(String::from_utf8(out).unwrap(), "1,2.5\n3,-4\n");
+//! ```
+
+use crate::linear_algebra::Matrix;
+use std::io;
+use std::io::{BufRead, BufReader, Read, Write};
+
+/// the options for [`Matrix::from_csv_reader`] and [`Matrix::to_csv_writer`]
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct CsvOptions {
+    /// the char between two values, `,` by default
+    pub delimiter: char,
+    /// if the first line is a header, `false` by default
+    ///
+    /// when reading the header is skipped, when writing the indices of the rows are written as header
+    pub header: bool,
+}
+
+impl Default for CsvOptions {
+    fn default() -> Self {
+        CsvOptions {
+            delimiter: ',',
+            header: false,
+        }
+    }
+}
+
+impl CsvOptions {
+    /// sets the char between two values
+    pub fn delimiter(mut self, delimiter: char) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// sets if the first line is a header
+    pub fn header(mut self, header: bool) -> Self {
+        self.header = header;
+        self
+    }
+}
+
+fn invalid(msg: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+impl Matrix {
+    /// reads a matrix from CSV data, each line becomes a col like in `Matrix::new`
+    ///
+    /// whitespace and quotes around the values are ignored and so are empty lines
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::csv::CsvOptions;
+    /// use math::linear_algebra::Matrix;
+    /// let matrix = Matrix::from_csv_reader("1, 2\n\"3\", 4\n".as_bytes(), &CsvOptions::default());
+    /// assert_eq!(matrix.unwrap(), Matrix::new(vec![vec![1., 2.], vec![3., 4.]]));
+    /// ```
+    /// note it returns an error of the kind `InvalidData` if a value is not a number,
+    /// the lines have not the same number of values or there are no values
+    pub fn from_csv_reader<R: Read>(reader: R, options: &CsvOptions) -> io::Result<Matrix> {
+        let mut rows = 0;
+        let mut lines = 0;
+        let mut matrix_flatt = Vec::new();
+
+        for (n, line) in BufReader::new(reader).lines().enumerate() {
+            let line = line?;
+            if (options.header && n == 0) || line.trim().is_empty() {
+                continue;
+            }
+
+            let len = matrix_flatt.len();
+            for field in line.split(options.delimiter) {
+                let field = field.trim().trim_matches('"').trim();
+                let val = field.parse::<f32>().map_err(|_| {
+                    invalid(format!("{:?} in line {} is not a number", field, n + 1))
+                })?;
+                matrix_flatt.push(val);
+            }
+
+            if lines == 0 {
+                rows = matrix_flatt.len();
+            } else if matrix_flatt.len() - len != rows {
+                return Err(invalid(format!(
+                    "line {} has {} values, expected {}",
+                    n + 1,
+                    matrix_flatt.len() - len,
+                    rows
+                )));
+            }
+            lines += 1;
+        }
+
+        if lines == 0 {
+            return Err(invalid("the csv data has no values".to_string()));
+        }
+        Ok(Matrix::new_flatt(matrix_flatt, lines, rows))
+    }
+
+    /// writes the matrix as CSV, each col like in `Matrix::new` is one line
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::csv::CsvOptions;
+    /// use math::linear_algebra::Matrix;
+    /// let matrix = Matrix::new(vec![vec![1., 2.], vec![3., -4.5]]);
+    /// let mut out = Vec::new();
+    /// let options = CsvOptions::default().delimiter('\t').header(true);
+    /// matrix.to_csv_writer(&mut out, &options).unwrap();
+    /// assert_eq!(String::from_utf8(out).unwrap(), "0\t1\n1\t2\n3\t-4.5\n");
+    /// ```
+    pub fn to_csv_writer<W: Write>(&self, writer: W, options: &CsvOptions) -> io::Result<()> {
+        let mut writer = io::BufWriter::new(writer);
+        let delimiter = options.delimiter.to_string();
+
+        if options.header {
+            let header: Vec<String> = (0..self.rows()).map(|j| j.to_string()).collect();
+            writeln!(writer, "{}", header.join(&delimiter))?;
+        }
+        for col in self.iter_cols() {
+            let line: Vec<String> = col.vec().iter().map(|x| x.to_string()).collect();
+            writeln!(writer, "{}", line.join(&delimiter))?;
+        }
+        writer.flush()
+    }
+}
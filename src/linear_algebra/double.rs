@@ -0,0 +1,429 @@
+//! double precision counterparts of `Matrix` and `Vector` for iterative algorithms where the error of f32 adds up
+//!
+//! ## Example
+//!
+//! ```rust
+//! use math::linear_algebra::double::{Matrix64, Vector64};
+//! use math::linear_algebra::Matrix;
+//! // sums 0.1 a million times, which is off by almost 1% in f32
+//! let mut sum = Vector64::new(vec![0.]);
+//! let step = Vector64::new(vec![0.1]);
+//! for _ in 0..1_000_000 {
+//!     sum += &step;
+//! }
+//! assert!((sum.index(0) - 1e5).abs() < 1e-4);
+//!
+//! let matrix = Matrix64::from(&Matrix::new(vec![vec![2., 1.], vec![1., 3.]]));
+//! assert_eq!(matrix.solve(&Vector64::new(vec![3., 5.])), Vector64::new(vec![0.8, 1.4]));
+//! ```
+
+use crate::linear_algebra::solve::Lu;
+use crate::linear_algebra::{Matrix, Vector};
+use std::ops::{Add, AddAssign, Mul, Sub, SubAssign};
+
+/// a matrix of f64 values with the same layout as `Matrix`, so `index(i, j)` and `dot_vec` work the same way
+#[derive(PartialEq, Clone, Debug)]
+pub struct Matrix64 {
+    cols: usize,
+    rows: usize,
+    matrix_flatt: Vec<f64>,
+}
+
+impl Matrix64 {
+    /// creates a matrix from the values, the outer vec are the cols like in `Matrix::new`
+    ///
+    /// note it panics if the inner vecs don't have the same len
+    pub fn new(vec: Vec<Vec<f64>>) -> Self {
+        let rows = vec.first().map_or(0, |v| v.len());
+        let mut matrix_flatt = Vec::with_capacity(vec.len() * rows);
+        for col in vec.iter() {
+            if col.len() != rows {
+                panic!("wrong row shape expected {}, got {}", rows, col.len());
+            }
+            matrix_flatt.extend_from_slice(col);
+        }
+        Matrix64 {
+            cols: vec.len(),
+            rows,
+            matrix_flatt,
+        }
+    }
+
+    /// creates a matrix from the values in the order of `matrix_flatt`
+    ///
+    /// note it panics if the len is not `cols * rows`
+    pub fn new_flatt(matrix_flatt: Vec<f64>, cols: usize, rows: usize) -> Self {
+        if matrix_flatt.len() != cols * rows {
+            panic!(
+                "wrong len expected {} * {} = {}, got {}",
+                cols,
+                rows,
+                cols * rows,
+                matrix_flatt.len()
+            );
+        }
+        Matrix64 {
+            cols,
+            rows,
+            matrix_flatt,
+        }
+    }
+
+    /// creates a matrix where all values are 0
+    pub fn new_zero(cols: usize, rows: usize) -> Self {
+        Matrix64::new_flatt(vec![0.; cols * rows], cols, rows)
+    }
+
+    /// creates the `n`x`n` identity matrix
+    pub fn identity(n: usize) -> Self {
+        let mut matrix = Matrix64::new_zero(n, n);
+        for i in 0..n {
+            matrix.set_index(i, i, 1.);
+        }
+        matrix
+    }
+
+    /// converts the values to f32
+    pub fn to_matrix(&self) -> Matrix {
+        Matrix::new_flatt(
+            self.matrix_flatt.iter().map(|&x| x as f32).collect(),
+            self.cols,
+            self.rows,
+        )
+    }
+
+    /// return the length of the columns
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// return the length of the rows
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// returns true if the matrix is a square matrix
+    pub fn is_square(&self) -> bool {
+        self.cols == self.rows
+    }
+
+    /// getter for the values in the same order as `Matrix::matrix_flatt`
+    pub fn matrix_flatt(&self) -> &[f64] {
+        &self.matrix_flatt
+    }
+
+    fn check_index(&self, row: usize, col: usize) {
+        if self.cols < row + 1 {
+            panic!("index out of bounds max row {}", self.cols - 1)
+        }
+        if self.rows < col + 1 {
+            panic!("index out of bounds max col {}", self.rows - 1)
+        }
+    }
+
+    /// return index(row, col) from matrix like `Matrix::index`
+    pub fn index(&self, row: usize, col: usize) -> f64 {
+        self.check_index(row, col);
+        self.matrix_flatt[row * self.rows + col]
+    }
+
+    /// sets the value at index(row, col) like `Matrix::set_index`
+    pub fn set_index(&mut self, row: usize, col: usize, val: f64) {
+        self.check_index(row, col);
+        self.matrix_flatt[row * self.rows + col] = val;
+    }
+
+    /// returns the transposed matrix
+    pub fn transpose(&self) -> Matrix64 {
+        let mut result = Matrix64::new_zero(self.rows, self.cols);
+        for i in 0..self.cols {
+            for j in 0..self.rows {
+                result.matrix_flatt[j * self.cols + i] = self.matrix_flatt[i * self.rows + j];
+            }
+        }
+        result
+    }
+
+    /// multiplies every value with the scalar
+    pub fn mul_scalar(&mut self, scalar: f64) {
+        self.matrix_flatt.iter_mut().for_each(|x| *x *= scalar);
+    }
+
+    /// computes the product with the vector like `Matrix::dot_vec`
+    ///
+    /// note it panics if the len of the vector is not the same as the rows
+    pub fn dot_vec(&self, vector: &Vector64) -> Vector64 {
+        if vector.len() != self.rows {
+            panic!(
+                "wrong vector shape expected {}, got {}",
+                self.rows,
+                vector.len()
+            );
+        }
+        Vector64::new(
+            self.matrix_flatt
+                .chunks(self.rows.max(1))
+                .take(self.cols)
+                .map(|col| col.iter().zip(vector.vec.iter()).map(|(a, b)| a * b).sum())
+                .collect(),
+        )
+    }
+
+    /// computes the matrix product like `Matrix::dot_mat`
+    ///
+    /// note it panics if the rows of this matrix are not the same as the cols of the other matrix
+    pub fn dot_mat(&self, other: &Matrix64) -> Matrix64 {
+        if self.rows != other.cols {
+            panic!(
+                "wrong matrix shape expected {} cols, got {}",
+                self.rows, other.cols
+            );
+        }
+        let (cols, inner, rows) = (self.cols, self.rows, other.rows);
+        let mut matrix_flatt = vec![0.; cols * rows];
+        for i in 0..cols {
+            for j in 0..inner {
+                let val = self.matrix_flatt[i * inner + j];
+                for k in 0..rows {
+                    matrix_flatt[i * rows + k] += val * other.matrix_flatt[j * rows + k];
+                }
+            }
+        }
+        Matrix64::new_flatt(matrix_flatt, cols, rows)
+    }
+
+    fn check_square(&self) {
+        if !self.is_square() {
+            panic!("the matrix has to be a square matrix");
+        }
+    }
+
+    // the LU decomposition shared with `Matrix`, `None` if the matrix is singular
+    fn lu(&self) -> Option<Lu<f64>> {
+        Lu::factor(self.matrix_flatt.clone(), self.cols)
+    }
+
+    // panics if the matrix is singular
+    fn lu_nonsingular(&self) -> Lu<f64> {
+        match self.lu() {
+            Some(lu) => lu,
+            None => panic!("the matrix is singular"),
+        }
+    }
+
+    /// returns the determinant with Gaussian elimination
+    ///
+    /// note it panics if the matrix is not square
+    pub fn det(&self) -> f64 {
+        self.check_square();
+        self.lu().map_or(0., |lu| lu.det())
+    }
+
+    /// solves `self.dot_vec(&x) == b` with Gaussian elimination with partial pivoting like `Matrix::solve`
+    ///
+    /// note it panics if the matrix is not square, the vector has the wrong len or the matrix is singular
+    pub fn solve(&self, b: &Vector64) -> Vector64 {
+        self.check_square();
+        if b.len() != self.cols {
+            panic!("wrong vector shape expected {}, got {}", self.cols, b.len());
+        }
+        Vector64::new(self.lu_nonsingular().solve(&b.vec))
+    }
+
+    /// returns the inverse of the matrix, it is factored once and solves for each col of the identity matrix
+    ///
+    /// note it panics if the matrix is not square or singular
+    pub fn inverse(&self) -> Matrix64 {
+        self.check_square();
+        Matrix64::new_flatt(self.lu_nonsingular().inverse(), self.cols, self.cols)
+    }
+}
+
+impl From<&Matrix> for Matrix64 {
+    fn from(matrix: &Matrix) -> Self {
+        Matrix64::new_flatt(
+            matrix.matrix_flatt().iter().map(|&x| x as f64).collect(),
+            matrix.cols(),
+            matrix.rows(),
+        )
+    }
+}
+
+impl From<&Matrix64> for Matrix {
+    fn from(matrix: &Matrix64) -> Self {
+        matrix.to_matrix()
+    }
+}
+
+fn check_shape(a: &Matrix64, b: &Matrix64) {
+    if a.rows != b.rows {
+        panic!("wrong row shape expected {}, got {}", a.rows, b.rows)
+    }
+    if a.cols != b.cols {
+        panic!("wrong col shape expected {}, got {}", a.cols, b.cols)
+    }
+}
+
+impl AddAssign<&Matrix64> for Matrix64 {
+    fn add_assign(&mut self, other: &Matrix64) {
+        check_shape(self, other);
+        for (a, b) in self.matrix_flatt.iter_mut().zip(other.matrix_flatt.iter()) {
+            *a += b;
+        }
+    }
+}
+
+impl SubAssign<&Matrix64> for Matrix64 {
+    fn sub_assign(&mut self, other: &Matrix64) {
+        check_shape(self, other);
+        for (a, b) in self.matrix_flatt.iter_mut().zip(other.matrix_flatt.iter()) {
+            *a -= b;
+        }
+    }
+}
+
+impl Add for Matrix64 {
+    type Output = Self;
+    fn add(mut self, other: Self) -> Self {
+        self += &other;
+        self
+    }
+}
+
+impl Sub for Matrix64 {
+    type Output = Self;
+    fn sub(mut self, other: Self) -> Self {
+        self -= &other;
+        self
+    }
+}
+
+impl Mul for &Matrix64 {
+    type Output = Matrix64;
+    fn mul(self, other: Self) -> Matrix64 {
+        self.dot_mat(other)
+    }
+}
+
+/// a vector of f64 values
+#[derive(PartialEq, Clone, Debug)]
+pub struct Vector64 {
+    vec: Vec<f64>,
+}
+
+impl Vector64 {
+    /// creates the vector from the values
+    pub fn new(vec: Vec<f64>) -> Self {
+        Vector64 { vec }
+    }
+
+    /// creates a vector where all values are 0
+    pub fn new_zero(len: usize) -> Self {
+        Vector64 { vec: vec![0.; len] }
+    }
+
+    /// converts the values to f32
+    pub fn to_vector(&self) -> Vector {
+        Vector::new(self.vec.iter().map(|&x| x as f32).collect())
+    }
+
+    /// returns the len of the vector
+    pub fn len(&self) -> usize {
+        self.vec.len()
+    }
+
+    /// returns true if the vector has no components
+    pub fn is_empty(&self) -> bool {
+        self.vec.is_empty()
+    }
+
+    /// returns the value at the given index
+    pub fn index(&self, index: usize) -> f64 {
+        self.vec[index]
+    }
+
+    /// getter for the values
+    pub fn vec(&self) -> &[f64] {
+        &self.vec
+    }
+
+    /// returns the [dot product] of the vectors
+    ///
+    /// [dot product]: https://en.wikipedia.org/wiki/Dot_product
+    ///
+    /// note it panics if the vectors have not the same len
+    pub fn dot_vec(&self, other: &Vector64) -> f64 {
+        check_len(self, other);
+        self.vec
+            .iter()
+            .zip(other.vec.iter())
+            .map(|(a, b)| a * b)
+            .sum()
+    }
+
+    /// returns the euclidean length of the vector
+    pub fn mag(&self) -> f64 {
+        self.dot_vec(self).sqrt()
+    }
+
+    /// multiplies every value with the scalar
+    pub fn mul_scalar(&mut self, scalar: f64) {
+        self.vec.iter_mut().for_each(|x| *x *= scalar);
+    }
+}
+
+fn check_len(a: &Vector64, b: &Vector64) {
+    if a.len() != b.len() {
+        panic!(
+            "the other vector has not the same len self.len() = {}, other.len() = {}",
+            a.len(),
+            b.len()
+        );
+    }
+}
+
+impl From<&Vector> for Vector64 {
+    fn from(vector: &Vector) -> Self {
+        Vector64::new(vector.vec().iter().map(|&x| x as f64).collect())
+    }
+}
+
+impl From<&Vector64> for Vector {
+    fn from(vector: &Vector64) -> Self {
+        vector.to_vector()
+    }
+}
+
+impl AddAssign<&Vector64> for Vector64 {
+    fn add_assign(&mut self, other: &Vector64) {
+        check_len(self, other);
+        for (a, b) in self.vec.iter_mut().zip(other.vec.iter()) {
+            *a += b;
+        }
+    }
+}
+
+impl SubAssign<&Vector64> for Vector64 {
+    fn sub_assign(&mut self, other: &Vector64) {
+        check_len(self, other);
+        for (a, b) in self.vec.iter_mut().zip(other.vec.iter()) {
+            *a -= b;
+        }
+    }
+}
+
+impl Add for Vector64 {
+    type Output = Self;
+    fn add(mut self, other: Self) -> Self {
+        self += &other;
+        self
+    }
+}
+
+impl Sub for Vector64 {
+    type Output = Self;
+    fn sub(mut self, other: Self) -> Self {
+        self -= &other;
+        self
+    }
+}
@@ -0,0 +1,498 @@
+use crate::linear_algebra::{Matrix, Vector};
+
+impl Matrix {
+    /// computes the [eigenvalues] and eigenvectors of a symmetric matrix with the [Jacobi eigenvalue algorithm]
+    ///
+    /// returns the eigenvalues in increasing order and a matrix where `col(k)` is the normalized eigenvector of the `k`-th eigenvalue
+    ///
+    /// [eigenvalues]: https://en.wikipedia.org/wiki/Eigenvalues_and_eigenvectors
+    /// [Jacobi eigenvalue algorithm]: https://en.wikipedia.org/wiki/Jacobi_eigenvalue_algorithm
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{Matrix, Vector};
+    /// let matrix = Matrix::new(vec![vec![2., 1.], vec![1., 2.]]);
+    /// let (values, vectors) = matrix.symmetric_eigen();
+    /// assert_eq!(values, Vector::new(vec![1., 3.]));
+    /// let v = vectors.col(1);
+    /// assert!((v.index(0) - v.index(1)).abs() < 1e-6);
+    /// ```
+    /// note it panics if the matrix is not square, only the upper triangle (`j > i`) is read
+    pub fn symmetric_eigen(&self) -> (Vector, Matrix) {
+        if !self.is_square() {
+            panic!("the matrix has to be a square matrix");
+        }
+        let n = self.cols();
+        let mut a = vec![0f64; n * n];
+        for i in 0..n {
+            for j in i..n {
+                let val = self.index(i, j) as f64;
+                a[i * n + j] = val;
+                a[j * n + i] = val;
+            }
+        }
+        let v = jacobi(&mut a, n);
+
+        let mut order: Vec<usize> = (0..n).collect();
+        order.sort_by(|&x, &y| a[x * n + x].partial_cmp(&a[y * n + y]).unwrap());
+        let values = order.iter().map(|&k| a[k * n + k] as f32).collect();
+        let mut vectors = Vec::with_capacity(n * n);
+        for &k in order.iter() {
+            vectors.extend((0..n).map(|i| v[i * n + k] as f32));
+        }
+        (Vector::new(values), Matrix::new_flatt(vectors, n, n))
+    }
+}
+
+// diagonalizes the symmetric `n x n` matrix `a` in place with Jacobi rotations,
+// afterwards the diagonal of `a` holds the eigenvalues and the columns of the returned matrix the eigenvectors
+pub(crate) fn jacobi(a: &mut [f64], n: usize) -> Vec<f64> {
+    // the columns of v are the eigenvectors
+    let mut v = vec![0f64; n * n];
+    for i in 0..n {
+        v[i * n + i] = 1.;
+    }
+
+    for _ in 0..100 {
+        let off: f64 = (0..n)
+            .flat_map(|i| (i + 1..n).map(move |j| (i, j)))
+            .map(|(i, j)| a[i * n + j] * a[i * n + j])
+            .sum();
+        let total: f64 = a.iter().map(|x| x * x).sum();
+        if off <= total * 1e-30 {
+            break;
+        }
+
+        for p in 0..n {
+            for q in p + 1..n {
+                let a_pq = a[p * n + q];
+                if a_pq == 0. {
+                    continue;
+                }
+                // the rotation which sets a[p][q] to 0
+                let theta = (a[q * n + q] - a[p * n + p]) / (2. * a_pq);
+                let t = theta.signum() / (theta.abs() + (theta * theta + 1.).sqrt());
+                let t = if theta == 0. { 1. } else { t };
+                let c = 1. / (t * t + 1.).sqrt();
+                let s = t * c;
+
+                for k in 0..n {
+                    let (a_kp, a_kq) = (a[k * n + p], a[k * n + q]);
+                    a[k * n + p] = c * a_kp - s * a_kq;
+                    a[k * n + q] = s * a_kp + c * a_kq;
+                }
+                for k in 0..n {
+                    let (a_pk, a_qk) = (a[p * n + k], a[q * n + k]);
+                    a[p * n + k] = c * a_pk - s * a_qk;
+                    a[q * n + k] = s * a_pk + c * a_qk;
+                }
+                for k in 0..n {
+                    let (v_kp, v_kq) = (v[k * n + p], v[k * n + q]);
+                    v[k * n + p] = c * v_kp - s * v_kq;
+                    v[k * n + q] = s * v_kp + c * v_kq;
+                }
+            }
+        }
+    }
+    v
+}
+
+// the min(cols, rows) singular values of the matrix in increasing order,
+// they are the square roots of the eigenvalues of the gram matrix
+pub(crate) fn singular_values(matrix: &Matrix) -> Vec<f64> {
+    let (m, n) = (matrix.cols(), matrix.rows());
+    let elems: Vec<f64> = matrix.iter_elems().map(|x| x as f64).collect();
+    let mut gram = vec![0f64; n * n];
+    for j in 0..n {
+        for k in j..n {
+            let val: f64 = (0..m).map(|i| elems[i * n + j] * elems[i * n + k]).sum();
+            gram[j * n + k] = val;
+            gram[k * n + j] = val;
+        }
+    }
+    jacobi(&mut gram, n);
+    let mut values: Vec<f64> = (0..n).map(|k| gram[k * n + k].max(0.).sqrt()).collect();
+    values.sort_by(|x, y| x.partial_cmp(y).unwrap());
+    values.split_off(n - m.min(n))
+}
+
+fn check_square(matrix: &Matrix) {
+    if !matrix.is_square() {
+        panic!("the matrix has to be a square matrix");
+    }
+}
+
+// the matrix as 1 based rows `a[i][j] == index(i - 1, j - 1)`
+fn one_based(matrix: &Matrix) -> Vec<Vec<f64>> {
+    let n = matrix.cols();
+    let mut a = vec![vec![0f64; n + 1]; n + 1];
+    for i in 0..n {
+        for j in 0..n {
+            a[i + 1][j + 1] = matrix.index(i, j) as f64;
+        }
+    }
+    a
+}
+
+// reduces the matrix to the upper Hessenberg form with Gaussian elimination with pivoting (elmhes)
+#[allow(clippy::needless_range_loop)]
+fn hessenberg(a: &mut [Vec<f64>], n: usize) {
+    for m in 2..n {
+        let mut x = 0f64;
+        let mut pivot = m;
+        for j in m..=n {
+            if a[j][m - 1].abs() > x.abs() {
+                x = a[j][m - 1];
+                pivot = j;
+            }
+        }
+        if pivot != m {
+            for j in m - 1..=n {
+                let t = a[pivot][j];
+                a[pivot][j] = a[m][j];
+                a[m][j] = t;
+            }
+            for row in a.iter_mut().skip(1) {
+                row.swap(pivot, m);
+            }
+        }
+        if x != 0. {
+            for i in m + 1..=n {
+                let mut y = a[i][m - 1];
+                if y != 0. {
+                    y /= x;
+                    a[i][m - 1] = 0.;
+                    for j in m..=n {
+                        a[i][j] -= y * a[m][j];
+                    }
+                    for row in a.iter_mut().skip(1) {
+                        row[m] += y * row[i];
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn sign(a: f64, b: f64) -> f64 {
+    if b >= 0. {
+        a.abs()
+    } else {
+        -a.abs()
+    }
+}
+
+// the eigenvalues of the Hessenberg matrix with the Francis double shift QR algorithm (hqr)
+#[allow(clippy::needless_range_loop)]
+fn hqr(
+    a: &mut [Vec<f64>],
+    n: usize,
+    tolerance: f64,
+    max_iterations: usize,
+) -> (Vec<f64>, Vec<f64>) {
+    let mut wr = vec![0f64; n + 1];
+    let mut wi = vec![0f64; n + 1];
+    let mut anorm = 0f64;
+    for i in 1..=n {
+        for j in i.saturating_sub(1).max(1)..=n {
+            anorm += a[i][j].abs();
+        }
+    }
+
+    let mut nn = n;
+    let mut t = 0f64;
+    while nn >= 1 {
+        let mut its = 0;
+        loop {
+            let mut l = 1;
+            for ll in (2..=nn).rev() {
+                let mut s = a[ll - 1][ll - 1].abs() + a[ll][ll].abs();
+                if s == 0. {
+                    s = anorm;
+                }
+                let h = a[ll][ll - 1].abs();
+                if h + s == s || h <= tolerance * s {
+                    a[ll][ll - 1] = 0.;
+                    l = ll;
+                    break;
+                }
+            }
+
+            let mut x = a[nn][nn];
+            if l == nn {
+                // one root found
+                wr[nn] = x + t;
+                wi[nn] = 0.;
+                nn -= 1;
+                break;
+            }
+            let mut y = a[nn - 1][nn - 1];
+            let mut w = a[nn][nn - 1] * a[nn - 1][nn];
+            if l == nn - 1 {
+                // two roots found
+                let p = 0.5 * (y - x);
+                let q = p * p + w;
+                let mut z = q.abs().sqrt();
+                x += t;
+                if q >= 0. {
+                    z = p + sign(z, p);
+                    wr[nn - 1] = x + z;
+                    wr[nn] = x + z;
+                    if z != 0. {
+                        wr[nn] = x - w / z;
+                    }
+                    wi[nn - 1] = 0.;
+                    wi[nn] = 0.;
+                } else {
+                    wr[nn - 1] = x + p;
+                    wr[nn] = x + p;
+                    wi[nn - 1] = -z;
+                    wi[nn] = z;
+                }
+                nn = nn.saturating_sub(2);
+                break;
+            }
+
+            if its == max_iterations {
+                panic!(
+                    "the eigenvalues did not converge after {} iterations",
+                    max_iterations
+                );
+            }
+            if its > 0 && its % 10 == 0 {
+                // exceptional shift
+                t += x;
+                for i in 1..=nn {
+                    a[i][i] -= x;
+                }
+                let s = a[nn][nn - 1].abs() + a[nn - 1][nn - 2].abs();
+                x = 0.75 * s;
+                y = x;
+                w = -0.4375 * s * s;
+            }
+            its += 1;
+
+            let (mut p, mut q, mut r): (f64, f64, f64);
+            let mut z;
+            let mut m = nn - 2;
+            loop {
+                z = a[m][m];
+                let r1 = x - z;
+                let s1 = y - z;
+                p = (r1 * s1 - w) / a[m + 1][m] + a[m][m + 1];
+                q = a[m + 1][m + 1] - z - r1 - s1;
+                r = a[m + 2][m + 1];
+                let s = p.abs() + q.abs() + r.abs();
+                p /= s;
+                q /= s;
+                r /= s;
+                if m == l {
+                    break;
+                }
+                let u = a[m][m - 1].abs() * (q.abs() + r.abs());
+                let v = p.abs() * (a[m - 1][m - 1].abs() + z.abs() + a[m + 1][m + 1].abs());
+                if u + v == v {
+                    break;
+                }
+                m -= 1;
+            }
+
+            for i in m + 2..=nn {
+                a[i][i - 2] = 0.;
+                if i != m + 2 {
+                    a[i][i - 3] = 0.;
+                }
+            }
+
+            for k in m..nn {
+                if k != m {
+                    p = a[k][k - 1];
+                    q = a[k + 1][k - 1];
+                    r = 0.;
+                    if k != nn - 1 {
+                        r = a[k + 2][k - 1];
+                    }
+                    x = p.abs() + q.abs() + r.abs();
+                    if x != 0. {
+                        p /= x;
+                        q /= x;
+                        r /= x;
+                    }
+                }
+                let s = sign((p * p + q * q + r * r).sqrt(), p);
+                if s != 0. {
+                    if k == m {
+                        if l != m {
+                            a[k][k - 1] = -a[k][k - 1];
+                        }
+                    } else {
+                        a[k][k - 1] = -s * x;
+                    }
+                    p += s;
+                    x = p / s;
+                    y = q / s;
+                    z = r / s;
+                    q /= p;
+                    r /= p;
+                    for j in k..=nn {
+                        p = a[k][j] + q * a[k + 1][j];
+                        if k != nn - 1 {
+                            p += r * a[k + 2][j];
+                            a[k + 2][j] -= p * z;
+                        }
+                        a[k + 1][j] -= p * y;
+                        a[k][j] -= p * x;
+                    }
+                    let mmin = if nn < k + 3 { nn } else { k + 3 };
+                    for row in a.iter_mut().take(mmin + 1).skip(l) {
+                        p = x * row[k] + y * row[k + 1];
+                        if k != nn - 1 {
+                            p += z * row[k + 2];
+                            row[k + 2] -= p * r;
+                        }
+                        row[k + 1] -= p * q;
+                        row[k] -= p;
+                    }
+                }
+            }
+        }
+    }
+    (wr[1..].to_vec(), wi[1..].to_vec())
+}
+
+// the eigenvalues sorted by the real and then the imaginary part
+pub(crate) fn eigenvalues(
+    matrix: &Matrix,
+    tolerance: f32,
+    max_iterations: usize,
+) -> (Vec<f64>, Vec<f64>) {
+    check_square(matrix);
+    let n = matrix.cols();
+    let mut a = one_based(matrix);
+    hessenberg(&mut a, n);
+    let (re, im) = hqr(&mut a, n, tolerance as f64, max_iterations);
+
+    let mut order: Vec<usize> = (0..n).collect();
+    order.sort_by(|&x, &y| {
+        re[x]
+            .partial_cmp(&re[y])
+            .unwrap()
+            .then(im[x].partial_cmp(&im[y]).unwrap())
+    });
+    (
+        order.iter().map(|&k| re[k]).collect(),
+        order.iter().map(|&k| im[k]).collect(),
+    )
+}
+
+// solves `a x = b` in place with partial pivoting, pivots which are 0 are replaced by `tiny`
+// so the shifted matrices of the inverse iteration can be solved
+fn solve_shifted(mut a: Vec<f64>, n: usize, b: &mut [f64], tiny: f64) {
+    for k in 0..n {
+        let pivot = (k..n)
+            .max_by(|&x, &y| a[x * n + k].abs().partial_cmp(&a[y * n + k].abs()).unwrap())
+            .unwrap();
+        // rows are only swapped for a larger pivot, swapping a zero column would mix
+        // the rows of the singular part of the matrix
+        if a[pivot * n + k].abs() > a[k * n + k].abs() {
+            for j in 0..n {
+                a.swap(k * n + j, pivot * n + j);
+            }
+            b.swap(k, pivot);
+        }
+        if a[k * n + k].abs() < tiny {
+            a[k * n + k] = tiny;
+        }
+        for i in k + 1..n {
+            let factor = a[i * n + k] / a[k * n + k];
+            if factor != 0. {
+                for j in k..n {
+                    a[i * n + j] -= factor * a[k * n + j];
+                }
+                b[i] -= factor * b[k];
+            }
+        }
+    }
+    for k in (0..n).rev() {
+        let sum: f64 = (k + 1..n).map(|j| a[k * n + j] * b[j]).sum();
+        b[k] = (b[k] - sum) / a[k * n + k];
+    }
+}
+
+// removes the components of the vectors `same` from x and returns the part of the norm which is left
+fn project_out(x: &mut [f64], same: &[usize], vectors: &[f64], n: usize) -> f64 {
+    let before = norm(x);
+    for &p in same.iter() {
+        let prev = &vectors[p * n..(p + 1) * n];
+        let dot: f64 = x.iter().zip(prev).map(|(a, b)| a * b).sum();
+        x.iter_mut().zip(prev).for_each(|(a, b)| *a -= dot * b);
+    }
+    norm(x) / before
+}
+
+fn norm(x: &[f64]) -> f64 {
+    x.iter().map(|v| v * v).sum::<f64>().sqrt()
+}
+
+fn normalize(x: &mut [f64]) {
+    let norm = norm(x);
+    if norm > 0. {
+        x.iter_mut().for_each(|v| *v /= norm);
+    }
+}
+
+// the real eigenvalues and the eigenvectors in the flat layout of a matrix where `col(k)` is the `k`-th eigenvector
+pub(crate) fn eigenvectors(
+    matrix: &Matrix,
+    tolerance: f32,
+    max_iterations: usize,
+) -> (Vec<f64>, Vec<f64>) {
+    let (values, im) = eigenvalues(matrix, tolerance, max_iterations);
+    let n = values.len();
+    let flatt: Vec<f64> = matrix.matrix_flatt().iter().map(|&x| x as f64).collect();
+    let scale = flatt.iter().map(|x| x.abs()).fold(0., f64::max).max(1.);
+    if im.iter().any(|x| x.abs() > tolerance as f64 * scale) {
+        panic!("the matrix has complex eigenvalues");
+    }
+
+    let tiny = scale * 1e-14;
+    let mut vectors: Vec<f64> = Vec::with_capacity(n * n);
+    for k in 0..n {
+        let lambda = values[k];
+        // the vectors of the same eigenvalue which were found before
+        let same: Vec<usize> = (0..k)
+            .filter(|&p| (values[p] - lambda).abs() <= 1e-6 * scale)
+            .collect();
+
+        let mut shifted = flatt.clone();
+        for i in 0..n {
+            shifted[i * n + i] -= lambda;
+        }
+        // every vector starts somewhere else, so the start is independent of the vectors of the same eigenvalue
+        let mut x: Vec<f64> = (0..n)
+            .map(|i| 1. + ((k * n + i) as f64 * 0.618).fract())
+            .collect();
+        project_out(&mut x, &same, &vectors, n);
+        normalize(&mut x);
+        for _ in 0..3 {
+            solve_shifted(shifted.clone(), n, &mut x, tiny);
+            // for a defective eigenvalue the solution lies almost completely
+            // in the span of the vectors which were found before
+            if project_out(&mut x, &same, &vectors, n) < 1e-6 {
+                panic!("the matrix is defective, it has too few eigenvectors");
+            }
+            normalize(&mut x);
+        }
+        // the sign is chosen so the largest value is positive
+        let largest = x
+            .iter()
+            .copied()
+            .fold(0f64, |m, v| if v.abs() > m.abs() { v } else { m });
+        if largest < 0. {
+            x.iter_mut().for_each(|v| *v = -*v);
+        }
+        vectors.extend(x);
+    }
+    (values, vectors)
+}
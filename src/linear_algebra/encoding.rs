@@ -0,0 +1,180 @@
+//! portable byte format for matrices and vectors
+//!
+//! unlike `bytes()` (which is the native layout for the *GPU*) everything is little endian
+//! and the data starts with a header:
+//!
+//! | offset | size | content                                  |
+//! |--------|------|------------------------------------------|
+//! | 0      | 4    | magic `b"MATH"`                          |
+//! | 4      | 2    | version (`u16`, currently 1)             |
+//! | 6      | 1    | dtype (`0` = `f32`, `1` = `f64`)         |
+//! | 7      | 1    | reserved (0)                             |
+//! | 8      | 8    | rows (`u64`)                             |
+//! | 16     | 8    | cols (`u64`)                             |
+//! | 24     |      | values in the order of `matrix_flatt()`  |
+//!
+//! a vector is stored as a matrix with one col
+
+use crate::linear_algebra::{Matrix, Vector};
+
+const MAGIC: &[u8; 4] = b"MATH";
+const VERSION: u16 = 1;
+const HEADER_LEN: usize = 24;
+
+/// the type of the values in the encoded data
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum DType {
+    F32,
+    F64,
+}
+
+impl DType {
+    /// returns the size of one value in bytes
+    pub fn size(&self) -> usize {
+        match self {
+            DType::F32 => 4,
+            DType::F64 => 8,
+        }
+    }
+
+    fn id(&self) -> u8 {
+        match self {
+            DType::F32 => 0,
+            DType::F64 => 1,
+        }
+    }
+
+    fn from_id(id: u8) -> Self {
+        match id {
+            0 => DType::F32,
+            1 => DType::F64,
+            _ => panic!("unknown dtype {}", id),
+        }
+    }
+}
+
+fn encode(vals: &[f32], cols: usize, rows: usize, dtype: DType) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(HEADER_LEN + vals.len() * dtype.size());
+    bytes.extend_from_slice(MAGIC);
+    bytes.extend_from_slice(&VERSION.to_le_bytes());
+    bytes.push(dtype.id());
+    bytes.push(0);
+    bytes.extend_from_slice(&(rows as u64).to_le_bytes());
+    bytes.extend_from_slice(&(cols as u64).to_le_bytes());
+
+    for &val in vals {
+        match dtype {
+            DType::F32 => bytes.extend_from_slice(&val.to_le_bytes()),
+            DType::F64 => bytes.extend_from_slice(&(val as f64).to_le_bytes()),
+        }
+    }
+    bytes
+}
+
+// returns the values, cols, rows and the dtype
+fn decode(bytes: &[u8]) -> (Vec<f32>, usize, usize, DType) {
+    if bytes.len() < HEADER_LEN {
+        panic!(
+            "the data is to short for the header expected at least {} bytes, got {}",
+            HEADER_LEN,
+            bytes.len()
+        );
+    }
+    if &bytes[0..4] != MAGIC {
+        panic!("the data doesn't start with the magic bytes {:?}", MAGIC);
+    }
+    let version = u16::from_le_bytes([bytes[4], bytes[5]]);
+    if version != VERSION {
+        panic!("unsupported version {}, expected {}", version, VERSION);
+    }
+    let dtype = DType::from_id(bytes[6]);
+
+    let mut u64_bytes = [0; 8];
+    u64_bytes.copy_from_slice(&bytes[8..16]);
+    let rows = u64::from_le_bytes(u64_bytes) as usize;
+    u64_bytes.copy_from_slice(&bytes[16..24]);
+    let cols = u64::from_le_bytes(u64_bytes) as usize;
+
+    let payload = &bytes[HEADER_LEN..];
+    let expected = cols
+        .checked_mul(rows)
+        .and_then(|len| len.checked_mul(dtype.size()));
+    if expected != Some(payload.len()) {
+        panic!(
+            "wrong payload len for {} cols and {} rows of {:?}, got {} bytes",
+            cols,
+            rows,
+            dtype,
+            payload.len()
+        );
+    }
+
+    let vals = payload
+        .chunks_exact(dtype.size())
+        .map(|chunk| match dtype {
+            DType::F32 => f32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]),
+            DType::F64 => {
+                let mut f64_bytes = [0; 8];
+                f64_bytes.copy_from_slice(chunk);
+                f64::from_le_bytes(f64_bytes) as f32
+            }
+        })
+        .collect();
+    (vals, cols, rows, dtype)
+}
+
+impl Matrix {
+    /// encodes the matrix in the portable byte format described in [`encoding`](crate::linear_algebra::encoding)
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::encoding::DType;
+    /// use math::linear_algebra::Matrix;
+    /// let matrix = Matrix::new(vec![vec![1., 2., 3.], vec![4., 5., 6.]]);
+    /// let bytes = matrix.encode(DType::F32);
+    /// assert_eq!(&bytes[0..8], &[b'M', b'A', b'T', b'H', 1, 0, 0, 0]);
+    /// assert_eq!(bytes.len(), 24 + 6 * 4);
+    /// assert_eq!(Matrix::decode(&bytes), matrix);
+    /// ```
+    pub fn encode(&self, dtype: DType) -> Vec<u8> {
+        encode(&self.matrix_flatt(), self.cols(), self.rows(), dtype)
+    }
+
+    /// decodes a matrix from the portable byte format, `f64` values are rounded to `f32`
+    ///
+    /// note it panics if the data is not valid
+    pub fn decode(bytes: &[u8]) -> Matrix {
+        let (vals, cols, rows, _) = decode(bytes);
+        Matrix::new_flatt(vals, cols, rows)
+    }
+}
+
+impl Vector {
+    /// encodes the vector in the portable byte format described in [`encoding`](crate::linear_algebra::encoding)
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::encoding::DType;
+    /// use math::linear_algebra::Vector;
+    /// let vector = Vector::new(vec![1., 2.]);
+    /// let bytes = vector.encode(DType::F64);
+    /// assert_eq!(bytes.len(), 24 + 2 * 8);
+    /// assert_eq!(Vector::decode(&bytes), vector);
+    /// ```
+    pub fn encode(&self, dtype: DType) -> Vec<u8> {
+        encode(&self.vec(), 1, self.len(), dtype)
+    }
+
+    /// decodes a vector from the portable byte format, `f64` values are rounded to `f32`
+    ///
+    /// note it panics if the data is not valid or has more then one col
+    pub fn decode(bytes: &[u8]) -> Vector {
+        let (vals, cols, _, _) = decode(bytes);
+        if cols != 1 {
+            panic!("a vector has to have 1 col, got {}", cols);
+        }
+        Vector::new(vals)
+    }
+}
@@ -0,0 +1,305 @@
+//! lazy element-wise expressions
+//!
+//! `&matrix1 + &matrix2` doesn't compute anything, it builds an expression
+//! and the whole chain is computed in a single loop when `eval` or `assign_to` is called,
+//! `&matrix1 * &matrix2` is the matrix product so the element-wise product is `matrix1.lazy() * &matrix2`
+//!
+//! ## Example
+//!
+//! ```rust
+//! use math::linear_algebra::expr::Expr;
+//! use math::linear_algebra::Matrix;
+//! let a = Matrix::new(vec![vec![1., -4.], vec![3., 2.]]);
+//! let b = Matrix::new(vec![vec![1., 1.], vec![-5., 1.]]);
+//! let c = (&a + &b).mul_scalar(2.).relu().eval();
+//! assert_eq!(c, Matrix::new(vec![vec![4., 0.], vec![0., 6.]]));
+//! ```
+
+use crate::linear_algebra::Matrix;
+use crate::nn::Activation;
+use std::ops::{Add, Div, Mul, Sub};
+
+/// a lazy element-wise expression with the shape `cols` x `rows`
+pub trait Expr: Sized {
+    /// return the length of the columns
+    fn cols(&self) -> usize;
+
+    /// return the length of the rows
+    fn rows(&self) -> usize;
+
+    /// returns the value at position `i` in the order of `matrix_flatt()`
+    fn at(&self, i: usize) -> f32;
+
+    /// computes the expression into a new matrix
+    fn eval(&self) -> Matrix {
+        let matrix_flatt = (0..self.cols() * self.rows()).map(|i| self.at(i)).collect();
+        Matrix::new_flatt(matrix_flatt, self.cols(), self.rows())
+    }
+
+    /// computes the expression into `target`
+    ///
+    /// the storage of `target` is reused if it has the right size and isn't shared with a clone
+    fn assign_to(&self, target: &mut Matrix) {
+        target.fill_flatt(self.cols(), self.rows(), |i| self.at(i));
+    }
+
+    /// applies `func` to each component
+    fn map<F: Fn(f32) -> f32>(self, func: F) -> Map<Self, F> {
+        Map { expr: self, func }
+    }
+
+    /// multiplies each component with a scalar value
+    fn mul_scalar(self, scalar: f32) -> Unary<Self> {
+        Unary::new(self, UnaryOp::MulScalar(scalar))
+    }
+
+    /// adds a scalar value to each component
+    fn add_scalar(self, scalar: f32) -> Unary<Self> {
+        Unary::new(self, UnaryOp::AddScalar(scalar))
+    }
+
+    /// subtracts a scalar value from each component
+    fn sub_scalar(self, scalar: f32) -> Unary<Self> {
+        Unary::new(self, UnaryOp::SubScalar(scalar))
+    }
+
+    /// divides each component by a scalar value
+    fn div_scalar(self, scalar: f32) -> Unary<Self> {
+        Unary::new(self, UnaryOp::DivScalar(scalar))
+    }
+
+    /// applies the activation function to each component
+    fn activation(self, activation: Activation) -> Unary<Self> {
+        Unary::new(self, UnaryOp::Activation(activation))
+    }
+
+    /// applies `max(x, 0)` to each component
+    fn relu(self) -> Unary<Self> {
+        self.activation(Activation::Relu)
+    }
+
+    /// applies the sigmoid function to each component
+    fn sigmoid(self) -> Unary<Self> {
+        self.activation(Activation::Sigmoid)
+    }
+
+    /// applies `tanh` to each component
+    fn tanh(self) -> Unary<Self> {
+        self.activation(Activation::Tanh)
+    }
+}
+
+/// converts a value into an expression, it is implemented for `&Matrix` and every [`Expr`]
+pub trait IntoExpr {
+    type Expr: Expr;
+
+    fn into_expr(self) -> Self::Expr;
+}
+
+impl<E: Expr> IntoExpr for E {
+    type Expr = E;
+
+    fn into_expr(self) -> E {
+        self
+    }
+}
+
+impl<'a> IntoExpr for &'a Matrix {
+    type Expr = Leaf<'a>;
+
+    fn into_expr(self) -> Leaf<'a> {
+        Leaf { matrix: self }
+    }
+}
+
+/// an expression which reads the values of a matrix
+#[must_use]
+#[derive(Clone, Copy, Debug)]
+pub struct Leaf<'a> {
+    matrix: &'a Matrix,
+}
+
+impl Expr for Leaf<'_> {
+    fn cols(&self) -> usize {
+        self.matrix.cols()
+    }
+
+    fn rows(&self) -> usize {
+        self.matrix.rows()
+    }
+
+    fn at(&self, i: usize) -> f32 {
+        self.matrix.flatt_at(i)
+    }
+}
+
+impl Matrix {
+    /// starts a lazy expression from this matrix
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::expr::Expr;
+    /// use math::linear_algebra::Matrix;
+    /// let matrix = Matrix::new(vec![vec![1., 4.], vec![9., 16.]]);
+    /// assert_eq!(
+    ///     matrix.lazy().map(f32::sqrt).eval(),
+    ///     Matrix::new(vec![vec![1., 2.], vec![3., 4.]])
+    /// );
+    /// ```
+    pub fn lazy(&self) -> Leaf<'_> {
+        Leaf { matrix: self }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+enum BinaryOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+/// an element-wise operation of two expressions
+#[must_use]
+#[derive(Clone, Copy, Debug)]
+pub struct Binary<L, R> {
+    lhs: L,
+    rhs: R,
+    op: BinaryOp,
+}
+
+impl<L: Expr, R: Expr> Binary<L, R> {
+    fn new(lhs: L, rhs: R, op: BinaryOp) -> Self {
+        if lhs.rows() != rhs.rows() {
+            panic!(
+                "wrong row shape expected {}, got {}",
+                lhs.rows(),
+                rhs.rows()
+            )
+        }
+
+        if lhs.cols() != rhs.cols() {
+            panic!(
+                "wrong col shape expected {}, got {}",
+                lhs.cols(),
+                rhs.cols()
+            )
+        }
+
+        Self { lhs, rhs, op }
+    }
+}
+
+impl<L: Expr, R: Expr> Expr for Binary<L, R> {
+    fn cols(&self) -> usize {
+        self.lhs.cols()
+    }
+
+    fn rows(&self) -> usize {
+        self.lhs.rows()
+    }
+
+    fn at(&self, i: usize) -> f32 {
+        let (lhs, rhs) = (self.lhs.at(i), self.rhs.at(i));
+        match self.op {
+            BinaryOp::Add => lhs + rhs,
+            BinaryOp::Sub => lhs - rhs,
+            BinaryOp::Mul => lhs * rhs,
+            BinaryOp::Div => lhs / rhs,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+enum UnaryOp {
+    MulScalar(f32),
+    AddScalar(f32),
+    SubScalar(f32),
+    DivScalar(f32),
+    Activation(Activation),
+}
+
+/// a scalar operation or activation function applied to each component of an expression
+#[must_use]
+#[derive(Clone, Copy, Debug)]
+pub struct Unary<E> {
+    expr: E,
+    op: UnaryOp,
+}
+
+impl<E: Expr> Unary<E> {
+    fn new(expr: E, op: UnaryOp) -> Self {
+        Self { expr, op }
+    }
+}
+
+impl<E: Expr> Expr for Unary<E> {
+    fn cols(&self) -> usize {
+        self.expr.cols()
+    }
+
+    fn rows(&self) -> usize {
+        self.expr.rows()
+    }
+
+    fn at(&self, i: usize) -> f32 {
+        let x = self.expr.at(i);
+        match self.op {
+            UnaryOp::MulScalar(scalar) => x * scalar,
+            UnaryOp::AddScalar(scalar) => x + scalar,
+            UnaryOp::SubScalar(scalar) => x - scalar,
+            UnaryOp::DivScalar(scalar) => x / scalar,
+            UnaryOp::Activation(activation) => activation.apply(x),
+        }
+    }
+}
+
+/// a function applied to each component of an expression
+#[must_use]
+#[derive(Clone, Copy, Debug)]
+pub struct Map<E, F> {
+    expr: E,
+    func: F,
+}
+
+impl<E: Expr, F: Fn(f32) -> f32> Expr for Map<E, F> {
+    fn cols(&self) -> usize {
+        self.expr.cols()
+    }
+
+    fn rows(&self) -> usize {
+        self.expr.rows()
+    }
+
+    fn at(&self, i: usize) -> f32 {
+        (self.func)(self.expr.at(i))
+    }
+}
+
+macro_rules! impl_ops {
+    ($ty:ty, [$($gen:tt)*], $trait:ident, $fn:ident, $op:ident) => {
+        impl<$($gen)*, Rhs: IntoExpr> $trait<Rhs> for $ty {
+            type Output = Binary<<$ty as IntoExpr>::Expr, Rhs::Expr>;
+
+            fn $fn(self, rhs: Rhs) -> Self::Output {
+                Binary::new(self.into_expr(), rhs.into_expr(), BinaryOp::$op)
+            }
+        }
+    };
+    ($ty:ty, [$($gen:tt)*]) => {
+        impl_ops!($ty, [$($gen)*], Add, add, Add);
+        impl_ops!($ty, [$($gen)*], Sub, sub, Sub);
+        impl_ops!($ty, [$($gen)*], Mul, mul, Mul);
+        impl_ops!($ty, [$($gen)*], Div, div, Div);
+    };
+}
+
+// `&Matrix * &Matrix` is the matrix product, so a lazy element-wise product has to start with `lazy()`
+impl_ops!(&'a Matrix, ['a], Add, add, Add);
+impl_ops!(&'a Matrix, ['a], Sub, sub, Sub);
+impl_ops!(&'a Matrix, ['a], Div, div, Div);
+impl_ops!(Leaf<'a>, ['a]);
+impl_ops!(Binary<L, R>, [L: Expr, R: Expr]);
+impl_ops!(Unary<E>, [E: Expr]);
+impl_ops!(Map<E, F>, [E: Expr, F: Fn(f32) -> f32]);
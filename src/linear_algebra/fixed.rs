@@ -0,0 +1,437 @@
+//! stack allocated matrices and vectors with the size known at compile time
+//!
+//! they don't allocate for any operation, which makes them a good fit for transforms in graphics and physics.
+//! the shapes are part of the type, so adding matrices of different shapes or multiplying
+//! matrices whose rows and cols don't match is a compile error instead of a panic
+//!
+//! ```compile_fail
+//! use math::linear_algebra::fixed::SMatrix;
+//! let a = SMatrix::<2, 3>::new_zero();
+//! let b = SMatrix::<2, 3>::new_zero();
+//! let _ = a * b; // the rows of a (3) are not the cols of b (2)
+//! ```
+
+use crate::linear_algebra::small;
+use crate::linear_algebra::{Matrix, Vector};
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// a `COLS`x`ROWS` matrix stored on the stack
+///
+/// the values are stored like in `Matrix::new`, so `data[i][j] == index(i, j)`
+/// and the size is `SMatrix<cols, rows>` in the order of `Matrix::new_zero(cols, rows)`
+///
+/// ## Example
+///
+/// ```rust
+/// use math::linear_algebra::fixed::Matrix2;
+/// use math::linear_algebra::Matrix;
+/// let rot = Matrix2::new([[0., -1.], [1., 0.]]);
+/// assert_eq!(rot.dot_vec(&[1., 0.]), [0., 1.]);
+/// assert_eq!(rot.dot_mat(&rot.transpose()), Matrix2::identity());
+/// assert_eq!(rot.to_matrix(), Matrix::new(vec![vec![0., -1.], vec![1., 0.]]));
+/// ```
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct SMatrix<const COLS: usize, const ROWS: usize> {
+    data: [[f32; ROWS]; COLS],
+}
+
+/// a `N`x`N` matrix on the stack
+pub type SquareMatrix<const N: usize> = SMatrix<N, N>;
+/// a 2x2 matrix on the stack
+pub type Matrix2 = SquareMatrix<2>;
+/// a 3x3 matrix on the stack
+pub type Matrix3 = SquareMatrix<3>;
+/// a 4x4 matrix on the stack
+pub type Matrix4 = SquareMatrix<4>;
+
+impl<const COLS: usize, const ROWS: usize> SMatrix<COLS, ROWS> {
+    /// creates a matrix from the values, the outer array are the cols like in `Matrix::new`
+    pub fn new(data: [[f32; ROWS]; COLS]) -> Self {
+        Self { data }
+    }
+
+    /// creates a matrix with all values 0
+    pub fn new_zero() -> Self {
+        Self {
+            data: [[0.; ROWS]; COLS],
+        }
+    }
+
+    /// converts a dynamic matrix (the transpose is applied)
+    ///
+    /// note it panics if the matrix is not `COLS`x`ROWS`
+    pub fn from_matrix(matrix: &Matrix) -> Self {
+        if matrix.cols() != COLS || matrix.rows() != ROWS {
+            panic!(
+                "wrong matrix shape expected {}x{}, got {}x{}",
+                COLS,
+                ROWS,
+                matrix.cols(),
+                matrix.rows()
+            );
+        }
+
+        let mut data = [[0.; ROWS]; COLS];
+        for (i, col) in data.iter_mut().enumerate() {
+            for (j, val) in col.iter_mut().enumerate() {
+                *val = matrix.index(i, j);
+            }
+        }
+        Self { data }
+    }
+
+    /// converts the matrix into a dynamic matrix
+    pub fn to_matrix(&self) -> Matrix {
+        Matrix::new_flatt(self.flatt(), COLS, ROWS)
+    }
+
+    // the values col after col like `Matrix::new_flatt`
+    fn flatt(&self) -> Vec<f32> {
+        self.data
+            .iter()
+            .flat_map(|col| col.iter().copied())
+            .collect()
+    }
+
+    /// getter for the values
+    pub fn data(&self) -> &[[f32; ROWS]; COLS] {
+        &self.data
+    }
+
+    /// return index(row, col) from matrix like `Matrix::index`
+    pub fn index(&self, row: usize, col: usize) -> f32 {
+        self.data[row][col]
+    }
+
+    /// sets the value of the matrix at the specifide index row col
+    pub fn set_index(&mut self, row: usize, col: usize, val: f32) {
+        self.data[row][col] = val;
+    }
+
+    /// returns the [transposed] matrix
+    ///
+    /// [transposed]: https://en.wikipedia.org/wiki/Transpose
+    pub fn transpose(&self) -> SMatrix<ROWS, COLS> {
+        let mut data = [[0.; COLS]; ROWS];
+        for (i, col) in self.data.iter().enumerate() {
+            for (j, &val) in col.iter().enumerate() {
+                data[j][i] = val;
+            }
+        }
+        SMatrix { data }
+    }
+
+    /// computes the product with the vector like `Matrix::dot_vec`
+    pub fn dot_vec(&self, vector: &[f32; ROWS]) -> [f32; COLS] {
+        let mut result = [0.; COLS];
+        for (res, col) in result.iter_mut().zip(self.data.iter()) {
+            *res = col.iter().zip(vector.iter()).map(|(a, b)| a * b).sum();
+        }
+        result
+    }
+
+    /// computes the [matrix product] like `Matrix::dot_mat`,
+    /// the rows of this matrix have to be the cols of the other matrix
+    ///
+    /// [matrix product]: https://en.wikipedia.org/wiki/Matrix_multiplication
+    pub fn dot_mat<const OTHER: usize>(
+        &self,
+        other: &SMatrix<ROWS, OTHER>,
+    ) -> SMatrix<COLS, OTHER> {
+        let mut data = [[0.; OTHER]; COLS];
+        for (col, lhs) in data.iter_mut().zip(self.data.iter()) {
+            for (j, val) in col.iter_mut().enumerate() {
+                *val = (0..ROWS).map(|k| lhs[k] * other.data[k][j]).sum();
+            }
+        }
+        SMatrix { data }
+    }
+
+    /// multiplies each component with a scalar value
+    pub fn mul_scalar(&mut self, scalar: f32) {
+        self.data.iter_mut().flatten().for_each(|x| *x *= scalar);
+    }
+}
+
+impl<const N: usize> SMatrix<N, N> {
+    /// creates the identity matrix
+    pub fn identity() -> Self {
+        let mut matrix = Self::new_zero();
+        for i in 0..N {
+            matrix.data[i][i] = 1.;
+        }
+        matrix
+    }
+
+    /// returns the [determinant] of this matrix, up to 4x4 in closed form
+    ///
+    /// [determinant]: https://en.wikipedia.org/wiki/Determinant
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::fixed::Matrix3;
+    /// let matrix = Matrix3::new([[2., -3., 1.], [2., 0., -1.], [1., 4., 5.]]);
+    /// assert_eq!(matrix.det(), 49.);
+    /// ```
+    pub fn det(&self) -> f32 {
+        match small::det(&self.flatt(), N) {
+            Some(det) => det,
+            None => self.to_matrix().det(),
+        }
+    }
+
+    /// returns the [inverse] of this matrix, up to 4x4 in closed form
+    ///
+    /// [inverse]: https://en.wikipedia.org/wiki/Invertible_matrix
+    ///
+    /// note it panics if the matrix is singular
+    pub fn inverse(&self) -> Self {
+        let mut flatt = vec![0.; N * N];
+        if small::inverse(&self.flatt(), N, &mut flatt) {
+            let mut inverse = Self::new_zero();
+            for (i, col) in inverse.data.iter_mut().enumerate() {
+                col.copy_from_slice(&flatt[i * N..(i + 1) * N]);
+            }
+            inverse
+        } else {
+            Self::from_matrix(&self.to_matrix().inverse())
+        }
+    }
+}
+
+impl<const COLS: usize, const ROWS: usize> Default for SMatrix<COLS, ROWS> {
+    fn default() -> Self {
+        Self::new_zero()
+    }
+}
+
+impl<const COLS: usize, const ROWS: usize> From<[[f32; ROWS]; COLS]> for SMatrix<COLS, ROWS> {
+    fn from(data: [[f32; ROWS]; COLS]) -> Self {
+        Self::new(data)
+    }
+}
+
+impl<const COLS: usize, const ROWS: usize> From<&SMatrix<COLS, ROWS>> for Matrix {
+    fn from(matrix: &SMatrix<COLS, ROWS>) -> Self {
+        matrix.to_matrix()
+    }
+}
+
+impl<const COLS: usize, const ROWS: usize> Add for SMatrix<COLS, ROWS> {
+    type Output = Self;
+    fn add(mut self, other: Self) -> Self {
+        self += other;
+        self
+    }
+}
+
+impl<const COLS: usize, const ROWS: usize> AddAssign for SMatrix<COLS, ROWS> {
+    fn add_assign(&mut self, other: Self) {
+        for (a, b) in self
+            .data
+            .iter_mut()
+            .flatten()
+            .zip(other.data.iter().flatten())
+        {
+            *a += b;
+        }
+    }
+}
+
+impl<const COLS: usize, const ROWS: usize> Sub for SMatrix<COLS, ROWS> {
+    type Output = Self;
+    fn sub(mut self, other: Self) -> Self {
+        self -= other;
+        self
+    }
+}
+
+impl<const COLS: usize, const ROWS: usize> SubAssign for SMatrix<COLS, ROWS> {
+    fn sub_assign(&mut self, other: Self) {
+        for (a, b) in self
+            .data
+            .iter_mut()
+            .flatten()
+            .zip(other.data.iter().flatten())
+        {
+            *a -= b;
+        }
+    }
+}
+
+impl<const COLS: usize, const ROWS: usize> Neg for SMatrix<COLS, ROWS> {
+    type Output = Self;
+    fn neg(mut self) -> Self {
+        self.mul_scalar(-1.);
+        self
+    }
+}
+
+impl<const COLS: usize, const ROWS: usize> Mul<f32> for SMatrix<COLS, ROWS> {
+    type Output = Self;
+    fn mul(mut self, other: f32) -> Self {
+        self.mul_scalar(other);
+        self
+    }
+}
+
+impl<const COLS: usize, const ROWS: usize, const OTHER: usize> Mul<SMatrix<ROWS, OTHER>>
+    for SMatrix<COLS, ROWS>
+{
+    type Output = SMatrix<COLS, OTHER>;
+    fn mul(self, other: SMatrix<ROWS, OTHER>) -> SMatrix<COLS, OTHER> {
+        self.dot_mat(&other)
+    }
+}
+
+impl<const COLS: usize, const ROWS: usize> Mul<SVector<ROWS>> for SMatrix<COLS, ROWS> {
+    type Output = SVector<COLS>;
+    fn mul(self, other: SVector<ROWS>) -> SVector<COLS> {
+        SVector::new(self.dot_vec(&other.data))
+    }
+}
+
+/// a vector with `N` components stored on the stack
+///
+/// ## Example
+///
+/// ```rust
+/// use math::linear_algebra::fixed::{SMatrix, SVector};
+/// use math::linear_algebra::Vector;
+/// let matrix = SMatrix::<2, 3>::new([[1., 0., 1.], [0., 2., 0.]]);
+/// let vector = SVector::new([1., 2., 3.]);
+/// assert_eq!(matrix * vector, SVector::new([4., 4.]));
+/// assert_eq!(vector.to_vector(), Vector::new(vec![1., 2., 3.]));
+/// ```
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct SVector<const N: usize> {
+    data: [f32; N],
+}
+
+impl<const N: usize> SVector<N> {
+    /// creates a vector from the values
+    pub fn new(data: [f32; N]) -> Self {
+        Self { data }
+    }
+
+    /// creates a vector with all values 0
+    pub fn new_zero() -> Self {
+        Self { data: [0.; N] }
+    }
+
+    /// converts a dynamic vector
+    ///
+    /// note it panics if the vector has not the len `N`
+    pub fn from_vector(vector: &Vector) -> Self {
+        if vector.len() != N {
+            panic!("wrong vector len expected {}, got {}", N, vector.len());
+        }
+        let mut data = [0.; N];
+        for (i, val) in data.iter_mut().enumerate() {
+            *val = vector.index(i);
+        }
+        Self { data }
+    }
+
+    /// converts the vector into a dynamic vector
+    pub fn to_vector(&self) -> Vector {
+        Vector::new(self.data.to_vec())
+    }
+
+    /// getter for the values
+    pub fn data(&self) -> &[f32; N] {
+        &self.data
+    }
+
+    /// returns the component at the index
+    pub fn index(&self, index: usize) -> f32 {
+        self.data[index]
+    }
+
+    /// sets the component at the index
+    pub fn set_index(&mut self, index: usize, val: f32) {
+        self.data[index] = val;
+    }
+
+    /// returns the [dot product] with the other vector
+    ///
+    /// [dot product]: https://en.wikipedia.org/wiki/Dot_product
+    pub fn dot(&self, other: &Self) -> f32 {
+        self.data
+            .iter()
+            .zip(other.data.iter())
+            .map(|(a, b)| a * b)
+            .sum()
+    }
+
+    /// returns the euclidean length of the vector
+    pub fn norm(&self) -> f32 {
+        self.dot(self).sqrt()
+    }
+}
+
+impl<const N: usize> Default for SVector<N> {
+    fn default() -> Self {
+        Self::new_zero()
+    }
+}
+
+impl<const N: usize> From<[f32; N]> for SVector<N> {
+    fn from(data: [f32; N]) -> Self {
+        Self::new(data)
+    }
+}
+
+impl<const N: usize> From<&SVector<N>> for Vector {
+    fn from(vector: &SVector<N>) -> Self {
+        vector.to_vector()
+    }
+}
+
+impl<const N: usize> Add for SVector<N> {
+    type Output = Self;
+    fn add(mut self, other: Self) -> Self {
+        self += other;
+        self
+    }
+}
+
+impl<const N: usize> AddAssign for SVector<N> {
+    fn add_assign(&mut self, other: Self) {
+        for (a, b) in self.data.iter_mut().zip(other.data.iter()) {
+            *a += b;
+        }
+    }
+}
+
+impl<const N: usize> Sub for SVector<N> {
+    type Output = Self;
+    fn sub(mut self, other: Self) -> Self {
+        self -= other;
+        self
+    }
+}
+
+impl<const N: usize> SubAssign for SVector<N> {
+    fn sub_assign(&mut self, other: Self) {
+        for (a, b) in self.data.iter_mut().zip(other.data.iter()) {
+            *a -= b;
+        }
+    }
+}
+
+impl<const N: usize> Neg for SVector<N> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        self * -1.
+    }
+}
+
+impl<const N: usize> Mul<f32> for SVector<N> {
+    type Output = Self;
+    fn mul(mut self, other: f32) -> Self {
+        self.data.iter_mut().for_each(|x| *x *= other);
+        self
+    }
+}
@@ -0,0 +1,311 @@
+//! small vectors with named components for graphics and physics
+//!
+//! [`Vec2`], [`Vec3`] and [`Vec4`] are `Copy`, never allocate and can be converted
+//! to arrays, to the stack allocated [`SVector`] and to the dynamic [`Vector`].
+//! swizzles like `v.zyx()` or `v.xy()` return the components in a new order
+//!
+//! ## Example
+//!
+//! ```rust
+//! use math::linear_algebra::fixed_vec::{Vec2, Vec3};
+//! let x = Vec3::new(1., 0., 0.);
+//! let y = Vec3::new(0., 1., 0.);
+//! assert_eq!(x.cross(y), Vec3::new(0., 0., 1.));
+//! assert_eq!((x + y * 2.).xy(), Vec2::new(1., 2.));
+//! assert_eq!(Vec3::new(3., 0., 4.).normalize(), Vec3::new(0.6, 0., 0.8));
+//! ```
+
+use crate::linear_algebra::fixed::SVector;
+use crate::linear_algebra::Vector;
+use std::ops::{Add, AddAssign, Div, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// a vector with the components `x` and `y`
+#[derive(PartialEq, Clone, Copy, Debug, Default)]
+pub struct Vec2 {
+    pub x: f32,
+    pub y: f32,
+}
+
+/// a vector with the components `x`, `y` and `z`
+#[derive(PartialEq, Clone, Copy, Debug, Default)]
+pub struct Vec3 {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+}
+
+/// a vector with the components `x`, `y`, `z` and `w`
+#[derive(PartialEq, Clone, Copy, Debug, Default)]
+pub struct Vec4 {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+    pub w: f32,
+}
+
+macro_rules! impl_vec {
+    ($ty:ident, $n:literal, $($field:ident),+) => {
+        impl $ty {
+            /// creates a vector from the components
+            pub const fn new($($field: f32),+) -> Self {
+                Self { $($field),+ }
+            }
+
+            /// creates a vector with all components set to `val`
+            pub const fn splat(val: f32) -> Self {
+                Self { $($field: val),+ }
+            }
+
+            /// creates a vector with all components 0
+            pub const fn zero() -> Self {
+                Self::splat(0.)
+            }
+
+            /// returns the components as an array
+            pub fn to_array(self) -> [f32; $n] {
+                [$(self.$field),+]
+            }
+
+            /// converts the vector into a dynamic vector
+            pub fn to_vector(self) -> Vector {
+                Vector::new(self.to_array().to_vec())
+            }
+
+            /// converts a dynamic vector
+            ///
+            /// note it panics if the vector has not the len of this type
+            pub fn from_vector(vector: &Vector) -> Self {
+                Self::from(SVector::<$n>::from_vector(vector))
+            }
+
+            /// returns the [dot product] with the other vector
+            ///
+            /// [dot product]: https://en.wikipedia.org/wiki/Dot_product
+            pub fn dot(self, other: Self) -> f32 {
+                0. $(+ self.$field * other.$field)+
+            }
+
+            /// returns the squared euclidean length, this is cheaper then `length`
+            pub fn length_squared(self) -> f32 {
+                self.dot(self)
+            }
+
+            /// returns the euclidean length
+            pub fn length(self) -> f32 {
+                self.length_squared().sqrt()
+            }
+
+            /// returns the vector scaled to the length 1
+            ///
+            /// note the components of the zero vector become NaN
+            pub fn normalize(self) -> Self {
+                self / self.length()
+            }
+
+            /// returns the euclidean distance to the other vector
+            pub fn distance(self, other: Self) -> f32 {
+                (self - other).length()
+            }
+
+            /// linear interpolation, `t = 0` is `self` and `t = 1` is `other`
+            pub fn lerp(self, other: Self, t: f32) -> Self {
+                self + (other - self) * t
+            }
+
+            /// applies `f` to every component
+            pub fn map<F: Fn(f32) -> f32>(self, f: F) -> Self {
+                Self { $($field: f(self.$field)),+ }
+            }
+        }
+
+        impl From<[f32; $n]> for $ty {
+            fn from([$($field),+]: [f32; $n]) -> Self {
+                Self { $($field),+ }
+            }
+        }
+
+        impl From<$ty> for [f32; $n] {
+            fn from(vec: $ty) -> Self {
+                vec.to_array()
+            }
+        }
+
+        impl From<SVector<$n>> for $ty {
+            fn from(vector: SVector<$n>) -> Self {
+                Self::from(*vector.data())
+            }
+        }
+
+        impl From<$ty> for SVector<$n> {
+            fn from(vec: $ty) -> Self {
+                SVector::new(vec.to_array())
+            }
+        }
+
+        impl From<$ty> for Vector {
+            fn from(vec: $ty) -> Self {
+                vec.to_vector()
+            }
+        }
+
+        impl Index<usize> for $ty {
+            type Output = f32;
+
+            fn index(&self, index: usize) -> &f32 {
+                let fields = [$(&self.$field),+];
+                match fields.get(index) {
+                    Some(val) => val,
+                    None => panic!("the index {} is out of bounds for a vector of len {}", index, $n),
+                }
+            }
+        }
+
+        impl IndexMut<usize> for $ty {
+            fn index_mut(&mut self, index: usize) -> &mut f32 {
+                let Self { $($field),+ } = self;
+                match IntoIterator::into_iter([$($field),+]).nth(index) {
+                    Some(val) => val,
+                    None => panic!("the index {} is out of bounds for a vector of len {}", index, $n),
+                }
+            }
+        }
+
+        impl Add for $ty {
+            type Output = Self;
+            fn add(self, other: Self) -> Self {
+                Self { $($field: self.$field + other.$field),+ }
+            }
+        }
+
+        impl AddAssign for $ty {
+            fn add_assign(&mut self, other: Self) {
+                *self = *self + other;
+            }
+        }
+
+        impl Sub for $ty {
+            type Output = Self;
+            fn sub(self, other: Self) -> Self {
+                Self { $($field: self.$field - other.$field),+ }
+            }
+        }
+
+        impl SubAssign for $ty {
+            fn sub_assign(&mut self, other: Self) {
+                *self = *self - other;
+            }
+        }
+
+        impl Mul<f32> for $ty {
+            type Output = Self;
+            fn mul(self, other: f32) -> Self {
+                self.map(|x| x * other)
+            }
+        }
+
+        impl Mul<$ty> for f32 {
+            type Output = $ty;
+            fn mul(self, other: $ty) -> $ty {
+                other * self
+            }
+        }
+
+        impl MulAssign<f32> for $ty {
+            fn mul_assign(&mut self, other: f32) {
+                *self = *self * other;
+            }
+        }
+
+        impl Div<f32> for $ty {
+            type Output = Self;
+            fn div(self, other: f32) -> Self {
+                self.map(|x| x / other)
+            }
+        }
+
+        impl Neg for $ty {
+            type Output = Self;
+            fn neg(self) -> Self {
+                self.map(|x| -x)
+            }
+        }
+    };
+}
+
+impl_vec!(Vec2, 2, x, y);
+impl_vec!(Vec3, 3, x, y, z);
+impl_vec!(Vec4, 4, x, y, z, w);
+
+// attaches a generated doc comment, `#[doc = concat!(..)]` directly needs Rust 1.54
+macro_rules! with_doc {
+    ($doc:expr, $item:item) => {
+        #[doc = $doc]
+        $item
+    };
+}
+
+// generates a method for each swizzle which returns the components in the order of its name
+macro_rules! swizzle {
+    ($ty:ident => $out:ident: $($name:ident($($field:ident),+)),+) => {
+        impl $ty {
+            $(
+                with_doc!(
+                    concat!("returns the components `", stringify!($name), "` as a `", stringify!($out), "`"),
+                    pub fn $name(self) -> $out {
+                        $out::new($(self.$field),+)
+                    }
+                );
+            )+
+        }
+    };
+}
+
+swizzle!(Vec2 => Vec2: yx(y, x));
+swizzle!(Vec3 => Vec2: xy(x, y), xz(x, z), yx(y, x), yz(y, z), zx(z, x), zy(z, y));
+swizzle!(Vec3 => Vec3: xzy(x, z, y), yxz(y, x, z), yzx(y, z, x), zxy(z, x, y), zyx(z, y, x));
+swizzle!(Vec4 => Vec2: xy(x, y), xz(x, z), yz(y, z), zw(z, w));
+swizzle!(Vec4 => Vec3: xyz(x, y, z), zyx(z, y, x), yzw(y, z, w));
+swizzle!(Vec4 => Vec4: wzyx(w, z, y, x), wxyz(w, x, y, z), yzwx(y, z, w, x));
+
+impl Vec2 {
+    /// returns the z component of the cross product of the vectors in the plane,
+    /// it is positive if `other` is counterclockwise of `self`
+    pub fn cross(self, other: Vec2) -> f32 {
+        self.x * other.y - self.y * other.x
+    }
+
+    /// returns the vector rotated by 90 degrees counterclockwise
+    pub fn perp(self) -> Vec2 {
+        Vec2::new(-self.y, self.x)
+    }
+
+    /// extends the vector with the component `z`
+    pub fn extend(self, z: f32) -> Vec3 {
+        Vec3::new(self.x, self.y, z)
+    }
+}
+
+impl Vec3 {
+    /// returns the [cross product] with the other vector
+    ///
+    /// [cross product]: https://en.wikipedia.org/wiki/Cross_product
+    pub fn cross(self, other: Vec3) -> Vec3 {
+        Vec3::new(
+            self.y * other.z - self.z * other.y,
+            self.z * other.x - self.x * other.z,
+            self.x * other.y - self.y * other.x,
+        )
+    }
+
+    /// extends the vector with the component `w`, use 1 for points and 0 for directions
+    pub fn extend(self, w: f32) -> Vec4 {
+        Vec4::new(self.x, self.y, self.z, w)
+    }
+}
+
+impl Vec4 {
+    /// divides `x`, `y` and `z` by `w` to get the point of homogeneous coordinates
+    pub fn project(self) -> Vec3 {
+        self.xyz() / self.w
+    }
+}
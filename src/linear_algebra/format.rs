@@ -0,0 +1,295 @@
+//! text output of matrices and vectors
+//!
+//! `Display` prints each col like in `Matrix::new` on its own line, [`PrintOptions`] configures
+//! the precision, the alignment, the brackets and how many values are shown before the rest is cut with `...`
+//!
+//! ## Example
+//!
+//! ```rust
+//! use math::linear_algebra::format::{Brackets, PrintOptions};
+//! use math::linear_algebra::Matrix;
+//! let matrix = Matrix::new(vec![vec![1., -2.5], vec![30., 4.]]);
+//! assert_eq!(matrix.to_string(), "[ 1.0, -2.5]\n[30.0,  4.0]");
+//! assert_eq!(format!("{:.2}", matrix), "[ 1.00, -2.50]\n[30.00,  4.00]");
+//!
+//! let options = PrintOptions::default().brackets(Brackets::None).align(false);
+//! assert_eq!(matrix.to_pretty(&options), "1.0 -2.5\n30.0 4.0");
+//! ```
+
+use crate::linear_algebra::{Matrix, Vector};
+use std::fmt;
+
+/// the brackets around each line of [`Matrix::to_pretty`]
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Brackets {
+    /// `[1.0, 2.0]`
+    Square,
+    /// `(1.0, 2.0)`
+    Round,
+    /// `|1.0 2.0|`
+    Bar,
+    /// `1.0 2.0`
+    None,
+}
+
+/// the options for [`Matrix::to_pretty`] and [`Vector::to_pretty`]
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct PrintOptions {
+    /// the number of digits after the decimal point, `None` prints the shortest exact value
+    pub precision: Option<usize>,
+    /// if the values are padded so the values at the same position line up, `true` by default
+    pub align: bool,
+    /// the brackets around each line, `Brackets::Square` by default
+    pub brackets: Brackets,
+    /// the max number of lines (cols of the matrix), the middle lines are replaced by `...`, 20 by default
+    pub max_cols: Option<usize>,
+    /// the max number of values in a line (rows of the matrix), the middle values are replaced by `...`, 20 by default
+    pub max_rows: Option<usize>,
+}
+
+impl Default for PrintOptions {
+    fn default() -> Self {
+        PrintOptions {
+            precision: None,
+            align: true,
+            brackets: Brackets::Square,
+            max_cols: Some(20),
+            max_rows: Some(20),
+        }
+    }
+}
+
+impl PrintOptions {
+    /// sets the number of digits after the decimal point
+    pub fn precision(mut self, precision: usize) -> Self {
+        self.precision = Some(precision);
+        self
+    }
+
+    /// sets if the values are aligned
+    pub fn align(mut self, align: bool) -> Self {
+        self.align = align;
+        self
+    }
+
+    /// sets the brackets around each line
+    pub fn brackets(mut self, brackets: Brackets) -> Self {
+        self.brackets = brackets;
+        self
+    }
+
+    /// sets the max number of lines, `None` prints all lines
+    pub fn max_cols(mut self, max_cols: Option<usize>) -> Self {
+        self.max_cols = max_cols;
+        self
+    }
+
+    /// sets the max number of values in a line, `None` prints all values
+    pub fn max_rows(mut self, max_rows: Option<usize>) -> Self {
+        self.max_rows = max_rows;
+        self
+    }
+
+    fn value(&self, x: f32) -> String {
+        match self.precision {
+            Some(precision) => format!("{:.*}", precision, x),
+            None => format!("{:?}", x),
+        }
+    }
+}
+
+// the indices to print out of len, None marks the place of the `...`
+fn shown(len: usize, max: Option<usize>) -> Vec<Option<usize>> {
+    match max {
+        Some(max) if len > max => {
+            let head = (max + 1) / 2;
+            let tail = max / 2;
+            (0..head)
+                .map(Some)
+                .chain(std::iter::once(None))
+                .chain((len - tail..len).map(Some))
+                .collect()
+        }
+        _ => (0..len).map(Some).collect(),
+    }
+}
+
+// joins the lines of cells with the brackets, cells at the same position get the same width
+fn layout(lines: Vec<Option<Vec<String>>>, options: &PrintOptions) -> String {
+    let mut widths: Vec<usize> = Vec::new();
+    if options.align {
+        for line in lines.iter().flatten() {
+            widths.resize(widths.len().max(line.len()), 0);
+            for (width, cell) in widths.iter_mut().zip(line) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+    }
+
+    let (open, close, separator) = match options.brackets {
+        Brackets::Square => ("[", "]", ", "),
+        Brackets::Round => ("(", ")", ", "),
+        Brackets::Bar => ("|", "|", " "),
+        Brackets::None => ("", "", " "),
+    };
+    lines
+        .iter()
+        .map(|line| match line {
+            Some(line) => {
+                let cells: Vec<String> = line
+                    .iter()
+                    .enumerate()
+                    .map(|(j, cell)| format!("{:>1$}", cell, widths.get(j).copied().unwrap_or(0)))
+                    .collect();
+                format!("{}{}{}", open, cells.join(separator), close)
+            }
+            None => "...".to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+impl Matrix {
+    // the values of each col (outer vec of `Matrix::new`) as strings, one line each
+    fn lines(&self) -> Vec<Vec<String>> {
+        (0..self.cols())
+            .map(|i| {
+                (0..self.rows())
+                    .map(|j| self.index(i, j).to_string())
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// returns the matrix as a LaTeX `bmatrix`
+    ///
+    /// each line is a col like in `Matrix::new`
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Matrix;
+    /// let matrix = Matrix::new(vec![vec![1., 2.], vec![3., -4.5]]);
+    /// assert_eq!(
+    ///     matrix.to_latex(),
+    ///     "\\begin{bmatrix}\n1 & 2 \\\\\n3 & -4.5\n\\end{bmatrix}"
+    /// );
+    /// ```
+    pub fn to_latex(&self) -> String {
+        let body = self
+            .lines()
+            .iter()
+            .map(|line| line.join(" & "))
+            .collect::<Vec<_>>()
+            .join(" \\\\\n");
+        format!("\\begin{{bmatrix}}\n{}\n\\end{{bmatrix}}", body)
+    }
+
+    /// returns the matrix as a Markdown table
+    ///
+    /// each line is a col like in `Matrix::new` and the header contains the indices of the rows
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Matrix;
+    /// let matrix = Matrix::new(vec![vec![1., 2.], vec![3., -4.5]]);
+    /// assert_eq!(
+    ///     matrix.to_markdown(),
+    ///     "| 0 | 1 |\n| --- | --- |\n| 1 | 2 |\n| 3 | -4.5 |"
+    /// );
+    /// ```
+    pub fn to_markdown(&self) -> String {
+        let header: Vec<String> = (0..self.rows()).map(|j| j.to_string()).collect();
+        let separator = vec!["---".to_string(); self.rows()];
+
+        let mut lines = vec![header, separator];
+        lines.extend(self.lines());
+        lines
+            .iter()
+            .map(|line| format!("| {} |", line.join(" | ")))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl Matrix {
+    /// returns the matrix as text, each line is a col like in `Matrix::new`
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::format::{Brackets, PrintOptions};
+    /// use math::linear_algebra::Matrix;
+    /// let matrix = Matrix::new_flatt((0..25).map(|x| x as f32).collect(), 5, 5);
+    /// let options = PrintOptions::default()
+    ///     .precision(0)
+    ///     .brackets(Brackets::Bar)
+    ///     .max_cols(Some(2))
+    ///     .max_rows(Some(3));
+    /// assert_eq!(matrix.to_pretty(&options), "| 0  1 ...  4|\n...\n|20 21 ... 24|");
+    /// ```
+    pub fn to_pretty(&self, options: &PrintOptions) -> String {
+        let rows = shown(self.rows(), options.max_rows);
+        let lines = shown(self.cols(), options.max_cols)
+            .into_iter()
+            .map(|i| {
+                i.map(|i| {
+                    rows.iter()
+                        .map(|j| match j {
+                            Some(j) => options.value(self.index(i, *j)),
+                            None => "...".to_string(),
+                        })
+                        .collect()
+                })
+            })
+            .collect();
+        layout(lines, options)
+    }
+}
+
+impl Vector {
+    /// returns the vector as text on a single line, `max_rows` limits the number of values
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::format::PrintOptions;
+    /// use math::linear_algebra::Vector;
+    /// let vector = Vector::new(vec![1., 2., 3., 4., 5.]);
+    /// let options = PrintOptions::default().precision(1).max_rows(Some(4));
+    /// assert_eq!(vector.to_pretty(&options), "[1.0, 2.0, ..., 4.0, 5.0]");
+    /// ```
+    pub fn to_pretty(&self, options: &PrintOptions) -> String {
+        let line = shown(self.len(), options.max_rows)
+            .into_iter()
+            .map(|i| match i {
+                Some(i) => options.value(self.index(i)),
+                None => "...".to_string(),
+            })
+            .collect();
+        layout(vec![Some(line)], &options.align(false))
+    }
+}
+
+impl fmt::Display for Matrix {
+    /// prints the matrix with the default [`PrintOptions`], the precision of the formatter is used if it is set
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let options = PrintOptions {
+            precision: f.precision(),
+            ..PrintOptions::default()
+        };
+        f.write_str(&self.to_pretty(&options))
+    }
+}
+
+impl fmt::Display for Vector {
+    /// prints the vector with the default [`PrintOptions`], the precision of the formatter is used if it is set
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let options = PrintOptions {
+            precision: f.precision(),
+            ..PrintOptions::default()
+        };
+        f.write_str(&self.to_pretty(&options))
+    }
+}
@@ -0,0 +1,278 @@
+use crate::linear_algebra::{Matrix, Vector};
+
+/// a [half precision] float which is only used for storage, all the math is done in `f32`
+///
+/// [half precision]: https://en.wikipedia.org/wiki/Half-precision_floating-point_format
+///
+/// ## Example
+///
+/// ```rust
+/// use math::linear_algebra::half::F16;
+/// assert_eq!(F16::from_f32(1.5).to_f32(), 1.5);
+/// assert_eq!(F16::from_f32(1.5).to_bits(), 0x3e00);
+/// assert_eq!(F16::from_f32(0.1).to_f32(), 0.099975586);
+/// ```
+/// note values larger then 65504 become infinity and the conversion rounds to the nearest even value
+#[derive(PartialEq, Clone, Copy, Debug, Default)]
+pub struct F16(u16);
+
+impl F16 {
+    /// converts the f32 to the nearest f16
+    pub fn from_f32(val: f32) -> Self {
+        let bits = val.to_bits();
+        let sign = ((bits >> 16) & 0x8000) as u16;
+        let exp = ((bits >> 23) & 0xff) as i32;
+        let mant = bits & 0x7f_ffff;
+
+        // infinity and NaN, NaN keeps a mantissa bit so it doesn't become infinity
+        if exp == 0xff {
+            let nan = if mant != 0 {
+                0x200 | (mant >> 13) as u16
+            } else {
+                0
+            };
+            return F16(sign | 0x7c00 | nan);
+        }
+
+        let exp = exp - 127 + 15;
+        if exp >= 0x1f {
+            return F16(sign | 0x7c00);
+        }
+
+        if exp <= 0 {
+            // subnormal numbers
+            if exp < -10 {
+                return F16(sign);
+            }
+            let mant = mant | 0x80_0000;
+            let shift = (14 - exp) as u32;
+            let mut result = mant >> shift;
+            let rem = mant & ((1 << shift) - 1);
+            let halfway = 1 << (shift - 1);
+            if rem > halfway || (rem == halfway && result & 1 == 1) {
+                result += 1;
+            }
+            return F16(sign | result as u16);
+        }
+
+        // rounding can carry in to the exponent which is still the correct result
+        let mut result = ((exp as u32) << 10) | (mant >> 13);
+        let rem = mant & 0x1fff;
+        if rem > 0x1000 || (rem == 0x1000 && result & 1 == 1) {
+            result += 1;
+        }
+        F16(sign | result as u16)
+    }
+
+    /// converts the f16 to a f32 (this is exact)
+    pub fn to_f32(self) -> f32 {
+        let sign = ((self.0 & 0x8000) as u32) << 16;
+        let exp = ((self.0 >> 10) & 0x1f) as u32;
+        let mant = (self.0 & 0x3ff) as u32;
+
+        let bits = if exp == 0 {
+            if mant == 0 {
+                sign
+            } else {
+                // normalize the subnormal number
+                let mut exp = 127 - 15 + 1;
+                let mut mant = mant;
+                while mant & 0x400 == 0 {
+                    mant <<= 1;
+                    exp -= 1;
+                }
+                sign | (exp << 23) | ((mant & 0x3ff) << 13)
+            }
+        } else if exp == 0x1f {
+            sign | 0x7f80_0000 | (mant << 13)
+        } else {
+            sign | ((exp + 127 - 15) << 23) | (mant << 13)
+        };
+        f32::from_bits(bits)
+    }
+
+    /// creates the f16 from the raw bits
+    pub fn from_bits(bits: u16) -> Self {
+        F16(bits)
+    }
+
+    /// returns the raw bits
+    pub fn to_bits(self) -> u16 {
+        self.0
+    }
+}
+
+fn to_half(vals: &[f32]) -> Vec<F16> {
+    vals.iter().map(|&x| F16::from_f32(x)).collect()
+}
+
+fn to_f32(vals: &[F16]) -> Vec<f32> {
+    vals.iter().map(|x| x.to_f32()).collect()
+}
+
+fn to_bytes(vals: &[F16]) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(vals.len() * 2);
+    for x in vals {
+        bytes.extend_from_slice(&x.to_bits().to_ne_bytes());
+    }
+    bytes
+}
+
+/// a matrix which stores its values as f16 to halve the memory,
+/// convert it back to a `Matrix` to do any calculations
+///
+/// ## Example
+///
+/// ```rust
+/// use math::linear_algebra::half::HalfMatrix;
+/// use math::linear_algebra::Matrix;
+/// let matrix = Matrix::new(vec![vec![1., 2.5], vec![-3., 0.25]]);
+/// let half = HalfMatrix::from(&matrix);
+/// assert_eq!(half.index(0, 1), 2.5);
+/// assert_eq!(half.to_matrix(), matrix);
+/// ```
+#[derive(PartialEq, Clone, Debug)]
+pub struct HalfMatrix {
+    cols: usize,
+    rows: usize,
+    matrix_flatt: Vec<F16>,
+}
+
+impl HalfMatrix {
+    /// converts the matrix to f16 (the transpose is applied)
+    pub fn new(matrix: &Matrix) -> Self {
+        HalfMatrix {
+            cols: matrix.cols(),
+            rows: matrix.rows(),
+            matrix_flatt: to_half(&matrix.matrix_flatt()),
+        }
+    }
+
+    /// converts the values back to f32
+    pub fn to_matrix(&self) -> Matrix {
+        Matrix::new_flatt(to_f32(&self.matrix_flatt), self.cols, self.rows)
+    }
+
+    /// return the length of the columns
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// return the length of the rows
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// return index(row, col) from matrix like `Matrix::index`
+    pub fn index(&self, row: usize, col: usize) -> f32 {
+        if self.cols < row + 1 {
+            panic!("index out of bounds max row {}", self.cols - 1)
+        }
+        if self.rows < col + 1 {
+            panic!("index out of bounds max col {}", self.rows - 1)
+        }
+        self.matrix_flatt[row * self.rows + col].to_f32()
+    }
+
+    /// getter for the f16 values in the same order as `Matrix::matrix_flatt`
+    pub fn matrix_flatt(&self) -> &[F16] {
+        &self.matrix_flatt
+    }
+
+    /// returns the f16 values as bytes (2 bytes per value in native endian) which is half the size of `Matrix::bytes`
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::half::HalfMatrix;
+    /// use math::linear_algebra::Matrix;
+    /// let half = HalfMatrix::from(&Matrix::new(vec![vec![1., 2.]]));
+    /// assert_eq!(half.bytes(), [0x3c00_u16.to_ne_bytes(), 0x4000_u16.to_ne_bytes()].concat());
+    /// ```
+    /// note unlike `Matrix::bytes` there is no header with the rows and cols
+    pub fn bytes(&self) -> Vec<u8> {
+        to_bytes(&self.matrix_flatt)
+    }
+}
+
+impl From<&Matrix> for HalfMatrix {
+    fn from(matrix: &Matrix) -> Self {
+        HalfMatrix::new(matrix)
+    }
+}
+
+impl From<&HalfMatrix> for Matrix {
+    fn from(half: &HalfMatrix) -> Self {
+        half.to_matrix()
+    }
+}
+
+/// a vector which stores its values as f16 to halve the memory,
+/// convert it back to a `Vector` to do any calculations
+///
+/// ## Example
+///
+/// ```rust
+/// use math::linear_algebra::half::HalfVector;
+/// use math::linear_algebra::Vector;
+/// let vector = Vector::new(vec![1., 1. / 3.]);
+/// let half = HalfVector::from(&vector);
+/// assert_eq!(half.to_vector(), Vector::new(vec![1., 0.33325195]));
+/// ```
+#[derive(PartialEq, Clone, Debug)]
+pub struct HalfVector {
+    vec: Vec<F16>,
+}
+
+impl HalfVector {
+    /// converts the vector to f16
+    pub fn new(vector: &Vector) -> Self {
+        HalfVector {
+            vec: to_half(&vector.vec()),
+        }
+    }
+
+    /// converts the values back to f32
+    pub fn to_vector(&self) -> Vector {
+        Vector::new(to_f32(&self.vec))
+    }
+
+    /// returns the len of the vector
+    pub fn len(&self) -> usize {
+        self.vec.len()
+    }
+
+    /// returns true if the vector has no components
+    pub fn is_empty(&self) -> bool {
+        self.vec.is_empty()
+    }
+
+    /// returns the value at the given index
+    pub fn index(&self, index: usize) -> f32 {
+        self.vec[index].to_f32()
+    }
+
+    /// getter for the f16 values
+    pub fn vec(&self) -> &[F16] {
+        &self.vec
+    }
+
+    /// returns the f16 values as bytes (2 bytes per value in native endian)
+    ///
+    /// note unlike `Vector::bytes` there is no header with the len
+    pub fn bytes(&self) -> Vec<u8> {
+        to_bytes(&self.vec)
+    }
+}
+
+impl From<&Vector> for HalfVector {
+    fn from(vector: &Vector) -> Self {
+        HalfVector::new(vector)
+    }
+}
+
+impl From<&HalfVector> for Vector {
+    fn from(half: &HalfVector) -> Self {
+        half.to_vector()
+    }
+}
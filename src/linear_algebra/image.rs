@@ -0,0 +1,398 @@
+//! grayscale image interop, heatmaps and 2d convolution
+//!
+//! images are stored as [netpbm] files, PGM for grayscale and PPM for color,
+//! each line of pixels is a col like in `Matrix::new`
+//!
+//! [netpbm]: https://netpbm.sourceforge.net/doc/
+
+use crate::linear_algebra::Matrix;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// maps values between 0 and 1 to colors for [`Matrix::heatmap`]
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum Colormap {
+    /// black to white
+    Gray,
+    /// black over red and yellow to white
+    Hot,
+    /// blue over cyan, green and yellow to red
+    Jet,
+    /// the perceptually uniform [viridis] map from dark purple to yellow
+    ///
+    /// [viridis]: https://cran.r-project.org/web/packages/viridis/vignettes/intro-to-viridis.html
+    Viridis,
+}
+
+/// the padding of [`Matrix::convolve2d`]
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum Padding {
+    /// no padding, the kernel only visits positions where it is completely inside of the matrix
+    Valid,
+    /// the matrix is padded with 0, so with a stride of 1 the result has the same shape
+    Zero,
+    /// the matrix is mirrored at the edges without repeating the edge (`c b | a b c | b a`),
+    /// so with a stride of 1 the result has the same shape
+    Reflect,
+}
+
+// the index inside of 0..len of the mirrored index i
+fn reflect(i: isize, len: usize) -> usize {
+    if len == 1 {
+        return 0;
+    }
+    let period = 2 * (len as isize - 1);
+    let i = i.rem_euclid(period);
+    if i < len as isize {
+        i as usize
+    } else {
+        (period - i) as usize
+    }
+}
+
+const VIRIDIS: [[f32; 3]; 9] = [
+    [68., 1., 84.],
+    [71., 44., 122.],
+    [59., 81., 139.],
+    [44., 113., 142.],
+    [33., 144., 141.],
+    [39., 173., 129.],
+    [92., 200., 99.],
+    [170., 220., 50.],
+    [253., 231., 37.],
+];
+
+impl Colormap {
+    /// returns the rgb color for `t`, values outside of 0 and 1 are clamped
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::image::Colormap;
+    /// assert_eq!(Colormap::Gray.color(0.5), [128, 128, 128]);
+    /// assert_eq!(Colormap::Hot.color(1.), [255, 255, 255]);
+    /// assert_eq!(Colormap::Jet.color(0.), [0, 0, 128]);
+    /// assert_eq!(Colormap::Viridis.color(0.), [68, 1, 84]);
+    /// ```
+    pub fn color(&self, t: f32) -> [u8; 3] {
+        let t = if t.is_nan() { 0. } else { t.clamp(0., 1.) };
+        let rgb = match self {
+            Colormap::Gray => [t, t, t],
+            Colormap::Hot => [3. * t, 3. * t - 1., 3. * t - 2.],
+            Colormap::Jet => [
+                1.5 - (4. * t - 3.).abs(),
+                1.5 - (4. * t - 2.).abs(),
+                1.5 - (4. * t - 1.).abs(),
+            ],
+            Colormap::Viridis => {
+                let pos = t * (VIRIDIS.len() - 1) as f32;
+                let i = (pos as usize).min(VIRIDIS.len() - 2);
+                let frac = pos - i as f32;
+                let mut rgb = [0.; 3];
+                for (c, val) in rgb.iter_mut().enumerate() {
+                    *val = (VIRIDIS[i][c] + (VIRIDIS[i + 1][c] - VIRIDIS[i][c]) * frac) / 255.;
+                }
+                rgb
+            }
+        };
+        let mut bytes = [0; 3];
+        for (byte, c) in bytes.iter_mut().zip(rgb.iter()) {
+            *byte = (c.clamp(0., 1.) * 255.).round() as u8;
+        }
+        bytes
+    }
+}
+
+fn invalid(msg: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+// splits the header of a netpbm file into tokens and skips the comments
+struct Header<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl Header<'_> {
+    fn token(&mut self) -> io::Result<&str> {
+        loop {
+            match self.bytes.get(self.pos) {
+                Some(b'#') => {
+                    while !matches!(self.bytes.get(self.pos), None | Some(b'\n')) {
+                        self.pos += 1;
+                    }
+                }
+                Some(c) if c.is_ascii_whitespace() => self.pos += 1,
+                _ => break,
+            }
+        }
+
+        let start = self.pos;
+        while matches!(self.bytes.get(self.pos), Some(c) if !c.is_ascii_whitespace()) {
+            self.pos += 1;
+        }
+        if start == self.pos {
+            return Err(invalid("unexpected end of the image".to_string()));
+        }
+        std::str::from_utf8(&self.bytes[start..self.pos])
+            .map_err(|_| invalid("the image header is not ascii".to_string()))
+    }
+
+    fn number(&mut self) -> io::Result<usize> {
+        let token = self.token()?;
+        token
+            .parse()
+            .map_err(|_| invalid(format!("{:?} is not a number", token)))
+    }
+}
+
+impl Matrix {
+    /// decodes a grayscale [PGM] image (`P2` or `P5`)
+    ///
+    /// each line of pixels becomes a col like in `Matrix::new`, so the matrix has `height` cols and `width` rows.
+    /// the intensities are scaled to values between 0 (black) and 1 (white)
+    ///
+    /// [PGM]: https://netpbm.sourceforge.net/doc/pgm.html
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Matrix;
+    /// let image = b"P2\n# a comment\n3 2\n4\n0 1 2\n4 3 2\n";
+    /// let matrix = Matrix::from_pgm(image).unwrap();
+    /// assert_eq!(matrix, Matrix::new(vec![vec![0., 0.25, 0.5], vec![1., 0.75, 0.5]]));
+    /// ```
+    pub fn from_pgm(bytes: &[u8]) -> io::Result<Matrix> {
+        let mut header = Header { bytes, pos: 0 };
+        let magic = header.token()?;
+        let binary = match magic {
+            "P2" => false,
+            "P5" => true,
+            _ => return Err(invalid(format!("{:?} is not a pgm magic number", magic))),
+        };
+        let width = header.number()?;
+        let height = header.number()?;
+        let max = header.number()?;
+        if max == 0 || max > u16::MAX as usize {
+            return Err(invalid(format!("the max value {} is out of range", max)));
+        }
+        let len = width
+            .checked_mul(height)
+            .ok_or_else(|| invalid(format!("the image size {}x{} is too large", width, height)))?;
+
+        let values: Vec<usize> = if binary {
+            // exactly one whitespace separates the header from the pixels
+            let data = &bytes[(header.pos + 1).min(bytes.len())..];
+            let size = if max < 256 { 1 } else { 2 };
+            // len * size can only overflow if the image is larger then any slice
+            let expected = len.saturating_mul(size);
+            if data.len() < expected {
+                return Err(invalid(format!(
+                    "the image has {} bytes of pixels, expected {}",
+                    data.len(),
+                    expected
+                )));
+            }
+            data.chunks_exact(size)
+                .take(len)
+                .map(|c| c.iter().fold(0, |acc, &b| acc << 8 | b as usize))
+                .collect()
+        } else {
+            (0..len)
+                .map(|_| header.number())
+                .collect::<io::Result<_>>()?
+        };
+
+        if let Some(val) = values.iter().find(|&&val| val > max) {
+            return Err(invalid(format!(
+                "the pixel value {} is bigger then the max value {}",
+                val, max
+            )));
+        }
+        let matrix_flatt = values.iter().map(|&val| val as f32 / max as f32).collect();
+        Ok(Matrix::new_flatt(matrix_flatt, height, width))
+    }
+
+    /// encodes the matrix as a binary 8 bit [PGM] image (`P5`), the inverse of [`from_pgm`]
+    ///
+    /// the values are clamped between 0 and 1 and `NaN` becomes black
+    ///
+    /// [PGM]: https://netpbm.sourceforge.net/doc/pgm.html
+    /// [`from_pgm`]: Matrix::from_pgm
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Matrix;
+    /// let matrix = Matrix::new(vec![vec![0., 0.5, 2.]]);
+    /// assert_eq!(matrix.to_pgm(), b"P5\n3 1\n255\n\x00\x80\xff");
+    /// ```
+    pub fn to_pgm(&self) -> Vec<u8> {
+        let mut bytes = format!("P5\n{} {}\n255\n", self.rows(), self.cols()).into_bytes();
+        for i in 0..self.cols() {
+            for j in 0..self.rows() {
+                let val = self.index(i, j);
+                let val = if val.is_nan() { 0. } else { val.clamp(0., 1.) };
+                bytes.push((val * 255.).round() as u8);
+            }
+        }
+        bytes
+    }
+
+    /// reads a grayscale image, see [`from_pgm`]
+    ///
+    /// only PGM (`.pgm`) images are supported
+    ///
+    /// [`from_pgm`]: Matrix::from_pgm
+    pub fn from_image<P: AsRef<Path>>(path: P) -> io::Result<Matrix> {
+        Matrix::from_pgm(&fs::read(path)?)
+    }
+
+    /// writes the matrix as a grayscale image, see [`to_pgm`]
+    ///
+    /// only PGM (`.pgm`) images are supported, other file extensions return an error
+    ///
+    /// [`to_pgm`]: Matrix::to_pgm
+    pub fn to_image<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let path = path.as_ref();
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("pgm") => fs::write(path, self.to_pgm()),
+            _ => Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                format!("{} is not a .pgm file", path.display()),
+            )),
+        }
+    }
+
+    /// normalizes the values between the min and max and encodes them with the colormap
+    ///
+    /// `Colormap::Gray` is encoded as a binary PGM (`P5`) and the other maps as a binary PPM (`P6`).
+    /// `NaN` values are ignored for the range and drawn like the min, if all values are the same they are drawn like the min
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::image::Colormap;
+    /// use math::linear_algebra::Matrix;
+    /// let matrix = Matrix::new(vec![vec![-1., 1.]]);
+    /// assert_eq!(matrix.heatmap(Colormap::Gray), b"P5\n2 1\n255\n\x00\xff");
+    /// assert_eq!(
+    ///     matrix.heatmap(Colormap::Hot),
+    ///     b"P6\n2 1\n255\n\x00\x00\x00\xff\xff\xff"
+    /// );
+    /// ```
+    pub fn heatmap(&self, colormap: Colormap) -> Vec<u8> {
+        let vals: Vec<f32> = self.matrix_flatt();
+        let (min, max) = vals
+            .iter()
+            .filter(|val| !val.is_nan())
+            .fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), &val| {
+                (min.min(val), max.max(val))
+            });
+        let range = max - min;
+
+        let magic = if colormap == Colormap::Gray {
+            "P5"
+        } else {
+            "P6"
+        };
+        let mut bytes = format!("{}\n{} {}\n255\n", magic, self.rows(), self.cols()).into_bytes();
+        for val in vals {
+            let t = if range > 0. { (val - min) / range } else { 0. };
+            let color = colormap.color(t);
+            if colormap == Colormap::Gray {
+                bytes.push(color[0]);
+            } else {
+                bytes.extend_from_slice(&color);
+            }
+        }
+        bytes
+    }
+
+    /// writes the [`heatmap`] of the matrix to a file
+    ///
+    /// the file should end with `.pgm` for `Colormap::Gray` and with `.ppm` otherwise
+    ///
+    /// [`heatmap`]: Matrix::heatmap
+    pub fn to_heatmap<P: AsRef<Path>>(&self, path: P, colormap: Colormap) -> io::Result<()> {
+        fs::write(path, self.heatmap(colormap))
+    }
+
+    /// slides the kernel over the matrix and returns the sum of the products at every position,
+    /// like the convolutional layers of a CNN the kernel is not flipped (it is a cross-correlation)
+    ///
+    /// `Padding::Zero` and `Padding::Reflect` pad `(k - 1) / 2` values before and `k / 2` values after
+    /// each axis for a kernel of the len `k`, so a stride of 1 keeps the shape.
+    /// the kernel moves `stride` values in both axes, for the padded len `n` the result has the len `(n - k) / stride + 1`
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::image::Padding;
+    /// use math::linear_algebra::Matrix;
+    /// let image = Matrix::new(vec![vec![1., 2., 3.], vec![4., 5., 6.], vec![7., 8., 9.]]);
+    /// let kernel = Matrix::new(vec![vec![1., 0.], vec![0., -1.]]);
+    /// assert_eq!(
+    ///     image.convolve2d(&kernel, Padding::Valid, 1),
+    ///     Matrix::new(vec![vec![-4., -4.], vec![-4., -4.]])
+    /// );
+    /// let blur = Matrix::new_flatt(vec![1. / 9.; 9], 3, 3);
+    /// let same = image.convolve2d(&blur, Padding::Reflect, 2);
+    /// assert_eq!((same.cols(), same.rows()), (2, 2));
+    /// ```
+    /// note it panics if the stride is 0, the kernel is empty or the kernel is bigger then the padded matrix
+    pub fn convolve2d(&self, kernel: &Matrix, padding: Padding, stride: usize) -> Matrix {
+        if stride == 0 {
+            panic!("the stride has to be at least 1");
+        }
+        let (n, m) = (self.cols(), self.rows());
+        let (kn, km) = (kernel.cols(), kernel.rows());
+        if kn == 0 || km == 0 {
+            panic!("the kernel is empty");
+        }
+        let (before_i, before_j) = match padding {
+            Padding::Valid => (0, 0),
+            Padding::Zero | Padding::Reflect => ((kn - 1) / 2, (km - 1) / 2),
+        };
+        let (padded_n, padded_m) = match padding {
+            Padding::Valid => (n, m),
+            Padding::Zero | Padding::Reflect => (n + kn - 1, m + km - 1),
+        };
+        if padded_n < kn || padded_m < km {
+            panic!(
+                "the kernel {}x{} is bigger then the matrix {}x{}",
+                kn, km, n, m
+            );
+        }
+
+        let data = self.matrix_flatt();
+        let kernel = kernel.matrix_flatt();
+        // the value at (i, j) of the padded matrix, the indices are relative to the original one
+        let at = |i: isize, j: isize| -> f32 {
+            let inside = (0..n as isize).contains(&i) && (0..m as isize).contains(&j);
+            match padding {
+                _ if inside => data[i as usize * m + j as usize],
+                Padding::Reflect => data[reflect(i, n) * m + reflect(j, m)],
+                Padding::Valid | Padding::Zero => 0.,
+            }
+        };
+
+        let (out_n, out_m) = ((padded_n - kn) / stride + 1, (padded_m - km) / stride + 1);
+        let mut matrix_flatt = Vec::with_capacity(out_n * out_m);
+        for oi in 0..out_n {
+            for oj in 0..out_m {
+                let i0 = (oi * stride) as isize - before_i as isize;
+                let j0 = (oj * stride) as isize - before_j as isize;
+                let mut sum = 0.;
+                for ki in 0..kn {
+                    for kj in 0..km {
+                        sum += kernel[ki * km + kj] * at(i0 + ki as isize, j0 + kj as isize);
+                    }
+                }
+                matrix_flatt.push(sum);
+            }
+        }
+        Matrix::new_flatt(matrix_flatt, out_n, out_m)
+    }
+}
@@ -0,0 +1,306 @@
+//! reading and writing MATLAB `.mat` files (level 5)
+//!
+//! only 2D real numeric arrays are supported, they map to a matrix where the outer vec of `Matrix::new`
+//! are the MATLAB rows, so `Matrix::new(vec![vec![1., 2., 3.]])` is the 1x3 array `[1 2 3]`
+//!
+//! the arrays are written as `double`, when reading every numeric class is converted to `f32`.
+//! other variables (char, cell, struct, sparse) are skipped.
+//! compressed files (the default since MATLAB 7) can't be read, save them with `save -v6` instead
+//!
+//! ## Example
+//!
+//! ```rust
+//! use math::linear_algebra::{mat, Matrix};
+//! let a = Matrix::new(vec![vec![1., 2., 3.], vec![4., 5., 6.]]);
+//! let bytes = mat::encode(&[("a", &a)]);
+//! assert_eq!(mat::decode(&bytes).unwrap(), vec![("a".to_string(), a)]);
+//! ```
+
+use crate::linear_algebra::Matrix;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+const HEADER_LEN: usize = 128;
+
+// data types
+const MI_INT8: u32 = 1;
+const MI_UINT8: u32 = 2;
+const MI_INT16: u32 = 3;
+const MI_UINT16: u32 = 4;
+const MI_INT32: u32 = 5;
+const MI_UINT32: u32 = 6;
+const MI_SINGLE: u32 = 7;
+const MI_DOUBLE: u32 = 9;
+const MI_INT64: u32 = 12;
+const MI_UINT64: u32 = 13;
+const MI_MATRIX: u32 = 14;
+const MI_COMPRESSED: u32 = 15;
+
+// array classes
+const MX_DOUBLE_CLASS: u32 = 6;
+const MX_UINT64_CLASS: u32 = 15;
+const COMPLEX_FLAG: u32 = 0x800;
+
+/// encodes the matrices as a `.mat` file with the given variable names
+///
+/// note it panics if a name is not a valid MATLAB variable name
+pub fn encode(vars: &[(&str, &Matrix)]) -> Vec<u8> {
+    let mut bytes = header();
+    for (name, matrix) in vars {
+        write_matrix(&mut bytes, name, matrix);
+    }
+    bytes
+}
+
+/// decodes all 2D numeric arrays of a `.mat` file in the order they are stored
+pub fn decode(bytes: &[u8]) -> io::Result<Vec<(String, Matrix)>> {
+    if bytes.len() < HEADER_LEN {
+        return Err(invalid(format!(
+            "the data is to short for the header expected at least {} bytes, got {}",
+            HEADER_LEN,
+            bytes.len()
+        )));
+    }
+    let reader = match &bytes[126..128] {
+        b"IM" => Reader { bytes, le: true },
+        b"MI" => Reader { bytes, le: false },
+        _ => return Err(invalid("the data is not a level 5 mat file".to_string())),
+    };
+
+    let mut vars = Vec::new();
+    let mut pos = HEADER_LEN;
+    while pos < bytes.len() {
+        let (data_type, data, next) = reader.element(pos)?;
+        match data_type {
+            MI_MATRIX => {
+                if let Some(var) = reader.matrix(data)? {
+                    vars.push(var);
+                }
+            }
+            MI_COMPRESSED => {
+                return Err(invalid(
+                    "compressed mat files are not supported, save them with -v6".to_string(),
+                ))
+            }
+            _ => {}
+        }
+        pos = next;
+    }
+    Ok(vars)
+}
+
+/// writes the matrices to a `.mat` file, see [`encode`]
+pub fn save<P: AsRef<Path>>(path: P, vars: &[(&str, &Matrix)]) -> io::Result<()> {
+    fs::write(path, encode(vars))
+}
+
+/// reads all 2D numeric arrays from a `.mat` file, see [`decode`]
+pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Vec<(String, Matrix)>> {
+    decode(&fs::read(path)?)
+}
+
+impl Matrix {
+    /// writes the matrix as the only variable `name` to a `.mat` file
+    pub fn save_mat<P: AsRef<Path>>(&self, path: P, name: &str) -> io::Result<()> {
+        save(path, &[(name, self)])
+    }
+
+    /// reads the variable `name` from a `.mat` file
+    pub fn load_mat<P: AsRef<Path>>(path: P, name: &str) -> io::Result<Matrix> {
+        load(path)?
+            .into_iter()
+            .find(|(var, _)| var == name)
+            .map(|(_, matrix)| matrix)
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::NotFound,
+                    format!("the mat file has no numeric variable {}", name),
+                )
+            })
+    }
+}
+
+fn invalid(msg: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+fn header() -> Vec<u8> {
+    let mut bytes = format!(
+        "MATLAB 5.0 MAT-file, Created by: math {}",
+        env!("CARGO_PKG_VERSION")
+    )
+    .into_bytes();
+    bytes.resize(116, b' ');
+    bytes.extend_from_slice(&[0; 8]);
+    bytes.extend_from_slice(&0x0100u16.to_le_bytes());
+    bytes.extend_from_slice(b"IM");
+    bytes
+}
+
+fn check_name(name: &str) {
+    let valid = name.len() <= 63
+        && name.starts_with(|c: char| c.is_ascii_alphabetic())
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+    if !valid {
+        panic!("{:?} is not a valid variable name", name);
+    }
+}
+
+fn padded(len: usize) -> usize {
+    (len + 7) / 8 * 8
+}
+
+fn write_tag(bytes: &mut Vec<u8>, data_type: u32, len: usize) {
+    bytes.extend_from_slice(&data_type.to_le_bytes());
+    bytes.extend_from_slice(&(len as u32).to_le_bytes());
+}
+
+fn write_matrix(bytes: &mut Vec<u8>, name: &str, matrix: &Matrix) {
+    check_name(name);
+    let (m, n) = (matrix.cols(), matrix.rows());
+    let len = 16 + 16 + 8 + padded(name.len()) + 8 + m * n * 8;
+    write_tag(bytes, MI_MATRIX, len);
+
+    write_tag(bytes, MI_UINT32, 8);
+    bytes.extend_from_slice(&MX_DOUBLE_CLASS.to_le_bytes());
+    bytes.extend_from_slice(&0u32.to_le_bytes());
+
+    write_tag(bytes, MI_INT32, 8);
+    bytes.extend_from_slice(&(m as i32).to_le_bytes());
+    bytes.extend_from_slice(&(n as i32).to_le_bytes());
+
+    write_tag(bytes, MI_INT8, name.len());
+    bytes.extend_from_slice(name.as_bytes());
+    bytes.resize(bytes.len() + padded(name.len()) - name.len(), 0);
+
+    // MATLAB stores the values column major
+    write_tag(bytes, MI_DOUBLE, m * n * 8);
+    for j in 0..n {
+        for i in 0..m {
+            bytes.extend_from_slice(&(matrix.index(i, j) as f64).to_le_bytes());
+        }
+    }
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    le: bool,
+}
+
+impl Reader<'_> {
+    fn get<const N: usize>(&self, data: &[u8], pos: usize) -> io::Result<[u8; N]> {
+        let mut buf = [0; N];
+        buf.copy_from_slice(
+            data.get(pos..pos + N)
+                .ok_or_else(|| invalid("unexpected end of the data".to_string()))?,
+        );
+        if !self.le {
+            buf.reverse();
+        }
+        Ok(buf)
+    }
+
+    fn u32(&self, data: &[u8], pos: usize) -> io::Result<u32> {
+        Ok(u32::from_le_bytes(self.get(data, pos)?))
+    }
+
+    // returns the data type, the data and the position of the next element
+    fn element(&self, pos: usize) -> io::Result<(u32, &[u8], usize)> {
+        self.sub_element(self.bytes, pos)
+    }
+
+    fn sub_element<'b>(&self, data: &'b [u8], pos: usize) -> io::Result<(u32, &'b [u8], usize)> {
+        let first = self.u32(data, pos)?;
+        let (data_type, start, len, next) = if first >> 16 != 0 {
+            // small data element, the data is packed into the tag
+            let len = (first >> 16) as usize;
+            (first & 0xffff, pos + 4, len, pos + 8)
+        } else {
+            let len = self.u32(data, pos + 4)? as usize;
+            (first, pos + 8, len, pos + 8 + padded(len))
+        };
+        let value = data
+            .get(start..start + len)
+            .ok_or_else(|| invalid("unexpected end of the data".to_string()))?;
+        Ok((data_type, value, next.min(data.len())))
+    }
+
+    fn matrix(&self, data: &[u8]) -> io::Result<Option<(String, Matrix)>> {
+        let (_, flags, pos) = self.sub_element(data, 0)?;
+        let flags = self.u32(flags, 0)?;
+        let class = flags & 0xff;
+        if !(MX_DOUBLE_CLASS..=MX_UINT64_CLASS).contains(&class) {
+            return Ok(None);
+        }
+
+        let (dims_type, dims, pos) = self.sub_element(data, pos)?;
+        let dims = self.values(dims_type, dims)?;
+        let (name_type, name, pos) = self.sub_element(data, pos)?;
+        if name_type != MI_INT8 {
+            return Err(invalid(format!("wrong name type {}", name_type)));
+        }
+        let name = String::from_utf8_lossy(name).into_owned();
+
+        if dims.len() != 2 {
+            return Err(invalid(format!(
+                "{} has {} dimensions, only 2D arrays are supported",
+                name,
+                dims.len()
+            )));
+        }
+        if flags & COMPLEX_FLAG != 0 {
+            return Err(invalid(format!(
+                "{} is complex, which is not supported",
+                name
+            )));
+        }
+
+        let (m, n) = (dims[0] as usize, dims[1] as usize);
+        let (real_type, real, _) = self.sub_element(data, pos)?;
+        let real = self.values(real_type, real)?;
+        if real.len() != m * n {
+            return Err(invalid(format!(
+                "{} has {} values, expected {}x{}",
+                name,
+                real.len(),
+                m,
+                n
+            )));
+        }
+
+        let mut matrix_flatt = vec![0.; m * n];
+        for (k, val) in real.into_iter().enumerate() {
+            matrix_flatt[(k % m) * n + k / m] = val as f32;
+        }
+        Ok(Some((name, Matrix::new_flatt(matrix_flatt, m, n))))
+    }
+
+    fn values(&self, data_type: u32, data: &[u8]) -> io::Result<Vec<f64>> {
+        let size = match data_type {
+            MI_INT8 | MI_UINT8 => 1,
+            MI_INT16 | MI_UINT16 => 2,
+            MI_INT32 | MI_UINT32 | MI_SINGLE => 4,
+            MI_DOUBLE | MI_INT64 | MI_UINT64 => 8,
+            _ => return Err(invalid(format!("unsupported data type {}", data_type))),
+        };
+
+        (0..data.len() / size)
+            .map(|k| {
+                let pos = k * size;
+                Ok(match data_type {
+                    MI_INT8 => data[pos] as i8 as f64,
+                    MI_UINT8 => data[pos] as f64,
+                    MI_INT16 => i16::from_le_bytes(self.get(data, pos)?) as f64,
+                    MI_UINT16 => u16::from_le_bytes(self.get(data, pos)?) as f64,
+                    MI_INT32 => i32::from_le_bytes(self.get(data, pos)?) as f64,
+                    MI_UINT32 => u32::from_le_bytes(self.get(data, pos)?) as f64,
+                    MI_SINGLE => f32::from_le_bytes(self.get(data, pos)?) as f64,
+                    MI_DOUBLE => f64::from_le_bytes(self.get(data, pos)?),
+                    MI_INT64 => i64::from_le_bytes(self.get(data, pos)?) as f64,
+                    _ => u64::from_le_bytes(self.get(data, pos)?) as f64,
+                })
+            })
+            .collect()
+    }
+}
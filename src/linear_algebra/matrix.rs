@@ -1,16 +1,59 @@
+use crate::linear_algebra::eigen;
+use crate::linear_algebra::shape::Axis;
+use crate::linear_algebra::small;
+use crate::linear_algebra::solve;
+use crate::linear_algebra::vector;
+use crate::linear_algebra::workspace::Workspace;
 use crate::linear_algebra::Vector;
 use crate::random;
+use crate::MathError;
+use std::fmt;
 use std::mem;
-use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
-
-#[derive(PartialEq, Clone, Debug)]
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use std::sync::Arc;
+
+// the tile size of the matrix product, a 64x64 tile of f32 values is 16 KiB and fits into the L1 cache
+pub(crate) const BLOCK_SIZE: usize = 64;
+
+/// a matrix of `f32`
+///
+/// the values are stored in an `Arc<[f32]>` so cloning a matrix is cheap,
+/// the values are only copied when a shared matrix gets mutated (copy-on-write)
+///
+/// `transpose` only marks the storage as transposed, this is not observable,
+/// two matrices with the same values are equal no matter how they are stored
+#[derive(Clone)]
 pub struct Matrix {
     cols: usize,
     rows: usize,
-    matrix_flatt: Vec<f32>,
+    matrix_flatt: Arc<[f32]>,
     is_transpose: bool,
 }
 
+impl PartialEq for Matrix {
+    fn eq(&self, other: &Self) -> bool {
+        if self.is_transpose == other.is_transpose {
+            self.cols == other.cols
+                && self.rows == other.rows
+                && self.matrix_flatt == other.matrix_flatt
+        } else {
+            self.cols() == other.cols()
+                && self.rows() == other.rows()
+                && self.iter_elems().eq(other.iter_elems())
+        }
+    }
+}
+
+impl fmt::Debug for Matrix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Matrix")
+            .field("cols", &self.cols())
+            .field("rows", &self.rows())
+            .field("matrix_flatt", &self.matrix_flatt())
+            .finish()
+    }
+}
+
 impl Add for Matrix {
     type Output = Self;
     fn add(self, other: Self) -> Self {
@@ -46,15 +89,21 @@ impl Mul for Matrix {
     type Output = Self;
 
     fn mul(self, other: Self) -> Self {
-        let mut result = self.clone();
-        result.mul_mat(&other);
-        result
+        self.dot_mat(&other)
+    }
+}
+
+impl Mul<&Matrix> for &Matrix {
+    type Output = Matrix;
+
+    fn mul(self, other: &Matrix) -> Matrix {
+        self.dot_mat(other)
     }
 }
 
 impl MulAssign for Matrix {
     fn mul_assign(&mut self, other: Self) {
-        self.mul_mat(&other);
+        *self = self.dot_mat(&other);
     }
 }
 
@@ -74,6 +123,83 @@ impl DivAssign for Matrix {
     }
 }
 
+impl Neg for Matrix {
+    type Output = Self;
+
+    fn neg(mut self) -> Self {
+        self.map_flatt(|x| -x);
+        self
+    }
+}
+
+impl Neg for &Matrix {
+    type Output = Matrix;
+
+    fn neg(self) -> Matrix {
+        -self.clone()
+    }
+}
+
+impl Add<f32> for Matrix {
+    type Output = Self;
+
+    fn add(mut self, scalar: f32) -> Self {
+        self.add_scalar(&scalar);
+        self
+    }
+}
+
+impl Sub<f32> for Matrix {
+    type Output = Self;
+
+    fn sub(mut self, scalar: f32) -> Self {
+        self.sub_scalar(&scalar);
+        self
+    }
+}
+
+impl Mul<f32> for Matrix {
+    type Output = Self;
+
+    fn mul(mut self, scalar: f32) -> Self {
+        self.mul_scalar(&scalar);
+        self
+    }
+}
+
+impl Div<f32> for Matrix {
+    type Output = Self;
+
+    fn div(mut self, scalar: f32) -> Self {
+        self.div_scalar(&scalar);
+        self
+    }
+}
+
+impl Mul<Vector> for Matrix {
+    type Output = Vector;
+
+    fn mul(self, vector: Vector) -> Vector {
+        self.dot_vec(&vector)
+    }
+}
+
+impl Mul<&Vector> for &Matrix {
+    type Output = Vector;
+
+    fn mul(self, vector: &Vector) -> Vector {
+        self.dot_vec(vector)
+    }
+}
+
+impl Mul<Matrix> for f32 {
+    type Output = Matrix;
+
+    fn mul(self, other: Matrix) -> Matrix {
+        other * self
+    }
+}
+
 impl Matrix {
     /// converts 2d vec in to matrix
     ///
@@ -102,9 +228,9 @@ impl Matrix {
         });
 
         Self {
-            cols: cols,
-            rows: rows,
-            matrix_flatt: flatt,
+            cols,
+            rows,
+            matrix_flatt: Arc::from(flatt),
             is_transpose: false,
         }
     }
@@ -130,7 +256,7 @@ impl Matrix {
         Self {
             cols,
             rows,
-            matrix_flatt,
+            matrix_flatt: Arc::from(matrix_flatt),
             is_transpose: false,
         }
     }
@@ -165,7 +291,7 @@ impl Matrix {
         Self {
             cols,
             rows,
-            matrix_flatt,
+            matrix_flatt: Arc::from(matrix_flatt),
             is_transpose: false,
         }
     }
@@ -180,16 +306,10 @@ impl Matrix {
     /// assert_eq!(matrix.matrix_flatt(), vec![0., 0., 0., 0., 0., 0.]);
     /// ```
     pub fn new_zero(cols: usize, rows: usize) -> Self {
-        let mut matrix_flatt = Vec::with_capacity(cols * rows);
-        for _ in 0..cols {
-            for _ in 0..rows {
-                matrix_flatt.push(0.);
-            }
-        }
         Self {
             cols,
             rows,
-            matrix_flatt,
+            matrix_flatt: Arc::from(vec![0.; cols * rows]),
             is_transpose: false,
         }
     }
@@ -228,6 +348,37 @@ impl Matrix {
         bytes
     }
 
+    /// creates the matrix from the bytes of `bytes()`, the inverse of `bytes()`
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Matrix;
+    /// let matrix = Matrix::new(vec![vec![2., 3., 5.], vec![7., 1., 4.]]);
+    /// assert_eq!(Matrix::from_bytes(&matrix.bytes()), matrix);
+    /// ```
+    /// note it panics if the rows and cols in the header don't match the number of values
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        let vals = vector::f32s_from_bytes(bytes);
+        if vals.len() < 2 {
+            panic!(
+                "the data is to short for the header expected at least 8 bytes, got {}",
+                bytes.len()
+            );
+        }
+        let rows = vector::len_from_f32(vals[0]);
+        let cols = vector::len_from_f32(vals[1]);
+        if rows.checked_mul(cols) != Some(vals.len() - 2) {
+            panic!(
+                "the header has {} rows and {} cols, got {} values",
+                rows,
+                cols,
+                vals.len() - 2
+            );
+        }
+        Matrix::new_flatt(vals[2..].to_vec(), cols, rows)
+    }
+
     /// getter for the internal matrix_flatt representation
     ///
     /// ## Example
@@ -238,17 +389,34 @@ impl Matrix {
     /// assert_eq!(matrix.matrix_flatt(), vec![2., 3., 5., 7., 1., 4.]);
     /// ```
     pub fn matrix_flatt(&self) -> Vec<f32> {
-        if self.is_transpose {
-            let mut matrix_flatt = Vec::with_capacity(self.cols * self.rows);
-            for i in 0..self.rows {
-                for val in self.col(i).vec() {
-                    matrix_flatt.push(val);
-                }
-            }
-            matrix_flatt
-        } else {
-            self.matrix_flatt.clone()
-        }
+        let mut matrix_flatt = Vec::with_capacity(self.cols * self.rows);
+        self.extend_flatt(&mut matrix_flatt);
+        matrix_flatt
+    }
+
+    /// returns an iterator over the components in the same order as `matrix_flatt` without copying the matrix
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Matrix;
+    /// let mut matrix = Matrix::new(vec![vec![2., 3., 5.], vec![7., 1., 4.]]);
+    /// assert_eq!(matrix.iter_elems().sum::<f32>(), 22.);
+    /// matrix.transpose();
+    /// assert_eq!(matrix.iter_elems().collect::<Vec<f32>>(), matrix.matrix_flatt());
+    /// ```
+    pub fn iter_elems(&self) -> impl DoubleEndedIterator<Item = f32> + ExactSizeIterator + '_ {
+        let rows = self.rows();
+        (0..self.cols() * rows).map(move |k| self.index(k / rows, k % rows))
+    }
+
+    /// same as `matrix_flatt` but the buffer is taken from the [`Workspace`]
+    ///
+    /// [`Workspace`]: crate::linear_algebra::workspace::Workspace
+    pub fn matrix_flatt_with(&self, ws: &mut Workspace) -> Vec<f32> {
+        let mut matrix_flatt = ws.take(self.cols * self.rows);
+        self.extend_flatt(&mut matrix_flatt);
+        matrix_flatt
     }
 
     /// return index(row, col) from matrix
@@ -262,16 +430,14 @@ impl Matrix {
     /// ```
     pub fn index(&self, mut row: usize, mut col: usize) -> f32 {
         if self.is_transpose {
-            let temp = row;
-            row = col;
-            col = temp;
+            std::mem::swap(&mut row, &mut col);
         }
 
-        if self.rows < row {
-            panic!("index out of bounds max row {}", self.rows - 1)
+        if self.cols < row + 1 {
+            panic!("index out of bounds max row {}", self.cols - 1)
         }
-        if self.cols < col {
-            panic!("index out of bounds max col {}", self.cols - 1)
+        if self.rows < col + 1 {
+            panic!("index out of bounds max col {}", self.rows - 1)
         }
 
         self.matrix_flatt[row * self.rows + col]
@@ -288,19 +454,17 @@ impl Matrix {
     /// ```
     pub fn set_index(&mut self, mut row: usize, mut col: usize, val: f32) {
         if self.is_transpose {
-            let temp = row;
-            row = col;
-            col = temp;
+            std::mem::swap(&mut row, &mut col);
         }
 
-        if self.rows < row + 1 {
-            panic!("index out of bounds max row {}", self.rows - 1)
+        if self.cols < row + 1 {
+            panic!("index out of bounds max row {}", self.cols - 1)
         }
-        if self.cols < col + 1 {
-            panic!("index out of bounds max col {}", self.cols - 1)
+        if self.rows < col + 1 {
+            panic!("index out of bounds max col {}", self.rows - 1)
         }
 
-        self.matrix_flatt[row * self.rows + col] = val;
+        make_mut(&mut self.matrix_flatt)[row * self.rows + col] = val;
     }
 
     /// return the length of the columns
@@ -373,6 +537,63 @@ impl Matrix {
         }
     }
 
+    /// returns an iterator over the columns like `col`, it respects the transpose
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{Matrix, Vector};
+    /// let mut matrix = Matrix::new(vec![vec![3., 2., 4.], vec![4., 5., 6.]]);
+    /// let sums: Vec<f32> = matrix.iter_cols().map(|col| col.vec().iter().sum()).collect();
+    /// assert_eq!(sums, vec![9., 15.]);
+    /// matrix.transpose();
+    /// assert_eq!(matrix.iter_cols().next(), Some(Vector::new(vec![3., 4.])));
+    /// ```
+    pub fn iter_cols(&self) -> impl DoubleEndedIterator<Item = Vector> + ExactSizeIterator + '_ {
+        (0..self.cols()).map(move |i| self.col(i))
+    }
+
+    /// returns an iterator over the rows like `row`, it respects the transpose
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{Matrix, Vector};
+    /// let matrix = Matrix::new(vec![vec![3., 2., 4.], vec![4., 5., 6.]]);
+    /// let rows: Vec<Vector> = matrix.iter_rows().collect();
+    /// assert_eq!(rows.len(), 3);
+    /// assert_eq!(rows[1], Vector::new(vec![2., 5.]));
+    /// ```
+    pub fn iter_rows(&self) -> impl DoubleEndedIterator<Item = Vector> + ExactSizeIterator + '_ {
+        (0..self.rows()).map(move |j| self.row(j))
+    }
+
+    /// same as `col` but the buffer is taken from the [`Workspace`]
+    ///
+    /// [`Workspace`]: crate::linear_algebra::workspace::Workspace
+    pub fn col_with(&self, col: usize, ws: &mut Workspace) -> Vector {
+        let mut result = ws.take(self.rows());
+        if self.is_transpose {
+            self.extend_row(col, &mut result);
+        } else {
+            self.extend_col(col, &mut result);
+        }
+        Vector::new(result)
+    }
+
+    /// same as `row` but the buffer is taken from the [`Workspace`]
+    ///
+    /// [`Workspace`]: crate::linear_algebra::workspace::Workspace
+    pub fn row_with(&self, row: usize, ws: &mut Workspace) -> Vector {
+        let mut result = ws.take(self.cols());
+        if self.is_transpose {
+            self.extend_col(row, &mut result);
+        } else {
+            self.extend_row(row, &mut result);
+        }
+        Vector::new(result)
+    }
+
     /// returns true if the matrix is a [square matrix]  
     ///
     /// that means if it has as much rows as cols
@@ -391,17 +612,233 @@ impl Matrix {
     }
 
     /// getter for the transpose
+    #[deprecated(
+        since = "0.1.0",
+        note = "the transpose is an internal optimization, use `transposed` or `transpose_inplace` to control the storage"
+    )]
     pub fn is_transpose(&self) -> bool {
         self.is_transpose
     }
 
     /// [transposes] matrix flips rows and cols
     ///
+    /// this only marks the storage as transposed so it is free,
+    /// but later accesses like `matrix_flatt` or `col` have to gather the values
+    ///
     /// [transposes]: https://en.wikipedia.org/wiki/Transpose
     pub fn transpose(&mut self) {
         self.is_transpose = !self.is_transpose;
     }
 
+    /// transposes the matrix like `transpose` but moves the values in the storage,
+    /// so later accesses read the values in order
+    ///
+    /// square matrices are transposed in place in tiles, other matrices need one new buffer
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Matrix;
+    /// let mut matrix = Matrix::new(vec![vec![1., 2., 3.], vec![4., 5., 6.]]);
+    /// matrix.transpose_inplace();
+    /// assert_eq!(matrix, Matrix::new(vec![vec![1., 4.], vec![2., 5.], vec![3., 6.]]));
+    /// ```
+    pub fn transpose_inplace(&mut self) {
+        if self.is_transpose {
+            // the storage already holds the transposed values
+            self.is_transpose = false;
+            return;
+        }
+
+        let (cols, rows) = (self.cols, self.rows);
+        if cols == rows {
+            let matrix_flatt = make_mut(&mut self.matrix_flatt);
+            for i0 in (0..cols).step_by(BLOCK_SIZE) {
+                for j0 in (i0..cols).step_by(BLOCK_SIZE) {
+                    for i in i0..(i0 + BLOCK_SIZE).min(cols) {
+                        for j in j0.max(i + 1)..(j0 + BLOCK_SIZE).min(cols) {
+                            matrix_flatt.swap(i * cols + j, j * cols + i);
+                        }
+                    }
+                }
+            }
+        } else {
+            let mut matrix_flatt = vec![0.; cols * rows];
+            transpose_flatt(&self.matrix_flatt, cols, rows, &mut matrix_flatt);
+            self.matrix_flatt = Arc::from(matrix_flatt);
+            self.cols = rows;
+            self.rows = cols;
+        }
+    }
+
+    /// returns the transposed matrix with the values moved in the storage, see `transpose_inplace`
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Matrix;
+    /// let matrix = Matrix::new(vec![vec![1., 2., 3.], vec![4., 5., 6.]]);
+    /// let transposed = matrix.transposed();
+    /// assert_eq!(transposed.matrix_flatt(), vec![1., 4., 2., 5., 3., 6.]);
+    /// assert_eq!(transposed.transposed(), matrix);
+    /// ```
+    pub fn transposed(&self) -> Matrix {
+        let mut result = self.clone();
+        result.transpose_inplace();
+        result
+    }
+
+    /// swaps the rows `a` and `b`, so `row(a)` and `row(b)` change places
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Matrix;
+    /// let mut matrix = Matrix::new(vec![vec![1., 2., 3.], vec![4., 5., 6.]]);
+    /// matrix.swap_rows(0, 2);
+    /// assert_eq!(matrix, Matrix::new(vec![vec![3., 2., 1.], vec![6., 5., 4.]]));
+    /// ```
+    /// note it panics if `a` or `b` is out of bounds
+    pub fn swap_rows(&mut self, a: usize, b: usize) {
+        if self.is_transpose {
+            self.swap_flatt_cols(a, b);
+        } else {
+            self.swap_flatt_rows(a, b);
+        }
+    }
+
+    /// swaps the columns `a` and `b`, so `col(a)` and `col(b)` change places
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Matrix;
+    /// let mut matrix = Matrix::new(vec![vec![1., 2., 3.], vec![4., 5., 6.]]);
+    /// matrix.swap_cols(0, 1);
+    /// assert_eq!(matrix, Matrix::new(vec![vec![4., 5., 6.], vec![1., 2., 3.]]));
+    /// ```
+    /// note it panics if `a` or `b` is out of bounds
+    pub fn swap_cols(&mut self, a: usize, b: usize) {
+        if self.is_transpose {
+            self.swap_flatt_rows(a, b);
+        } else {
+            self.swap_flatt_cols(a, b);
+        }
+    }
+
+    /// reorders the rows so that the new `row(k)` is the old `row(perm[k])`
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{Matrix, Vector};
+    /// let mut matrix = Matrix::new(vec![vec![1., 2., 3.], vec![4., 5., 6.]]);
+    /// matrix.permute_rows(&[2, 0, 1]);
+    /// assert_eq!(matrix.row(0), Vector::new(vec![3., 6.]));
+    /// assert_eq!(matrix.row(1), Vector::new(vec![1., 4.]));
+    /// ```
+    /// note it panics if `perm` is not a permutation of `0..rows()`
+    pub fn permute_rows(&mut self, perm: &[usize]) {
+        if perm.len() != self.rows() {
+            panic!(
+                "wrong permutation len expected {}, got {}",
+                self.rows(),
+                perm.len()
+            );
+        }
+        let mut seen = vec![false; perm.len()];
+        for &k in perm {
+            if k >= perm.len() || seen[k] {
+                panic!("{:?} is not a permutation", perm);
+            }
+            seen[k] = true;
+        }
+
+        let (cols, rows) = (self.cols(), self.rows());
+        let mut matrix_flatt = Vec::with_capacity(cols * rows);
+        for i in 0..cols {
+            for &k in perm {
+                matrix_flatt.push(self.index(i, k));
+            }
+        }
+        *self = Matrix::new_flatt(matrix_flatt, cols, rows);
+    }
+
+    /// applies the function to each component of the matrix
+    ///
+    /// the closure is generic so it is inlined into the loop
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Matrix;
+    /// let mut matrix = Matrix::new(vec![vec![-2., 3.], vec![7., -1.]]);
+    /// matrix.apply(|x| x.max(0.));
+    /// assert_eq!(matrix, Matrix::new(vec![vec![0., 3.], vec![7., 0.]]));
+    /// ```
+    pub fn apply<F: Fn(f32) -> f32>(&mut self, func: F) {
+        self.map_flatt(func);
+    }
+
+    /// returns true if any component of the matrix is NaN
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Matrix;
+    /// let mut matrix = Matrix::new(vec![vec![1., f32::NAN], vec![3., 4.]]);
+    /// assert!(matrix.has_nan());
+    /// matrix.replace_nan(0.);
+    /// assert!(!matrix.has_nan());
+    /// assert_eq!(matrix, Matrix::new(vec![vec![1., 0.], vec![3., 4.]]));
+    /// ```
+    pub fn has_nan(&self) -> bool {
+        self.matrix_flatt.iter().any(|x| x.is_nan())
+    }
+
+    /// returns true if no component of the matrix is NaN or infinite
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Matrix;
+    /// let matrix = Matrix::new(vec![vec![1., f32::INFINITY], vec![3., 4.]]);
+    /// assert!(!matrix.is_finite());
+    /// ```
+    pub fn is_finite(&self) -> bool {
+        self.matrix_flatt.iter().all(|x| x.is_finite())
+    }
+
+    /// replaces each NaN component with `value`
+    pub fn replace_nan(&mut self, value: f32) {
+        if self.has_nan() {
+            make_mut(&mut self.matrix_flatt)
+                .iter_mut()
+                .filter(|x| x.is_nan())
+                .for_each(|x| *x = value);
+        }
+    }
+
+    /// same as `apply` but takes a trait object, for functions which are stored as `Box<dyn Fn(f32) -> f32>`
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Matrix;
+    /// let funcs: Vec<Box<dyn Fn(f32) -> f32>> = vec![Box::new(|x| x + 1.), Box::new(|x| x * 2.)];
+    /// let mut matrix = Matrix::new(vec![vec![-2., 3.], vec![7., -1.]]);
+    /// funcs.iter().for_each(|func| matrix.apply_dyn(func.as_ref()));
+    /// assert_eq!(matrix, Matrix::new(vec![vec![-2., 8.], vec![16., 0.]]));
+    /// ```
+    pub fn apply_dyn(&mut self, func: &dyn Fn(f32) -> f32) {
+        self.apply(func);
+    }
+
+    /// same as `apply`
+    pub fn apply_func<F: Fn(f32) -> f32>(&mut self, func: F) {
+        self.apply(func);
+    }
+
     /// multiplies each component from the matrix with a scalar value and stors the result in this matrix   
     ///
     /// ## Example
@@ -419,7 +856,7 @@ impl Matrix {
     /// );
     /// ```
     pub fn mul_scalar(&mut self, scalar: &f32) {
-        self.matrix_flatt = self.matrix_flatt.iter().map(|x| x * scalar).collect();
+        self.map_flatt(|x| x * scalar);
     }
 
     /// multiplies each component from the matrix with a scalar value and stors the result in this matrix   
@@ -439,7 +876,7 @@ impl Matrix {
     /// );
     /// ```
     pub fn add_scalar(&mut self, scalar: &f32) {
-        self.matrix_flatt = self.matrix_flatt.iter().map(|x| x + scalar).collect();
+        self.map_flatt(|x| x + scalar);
     }
 
     /// multiplies each component from the matrix with a scalar value and stors the result in this matrix   
@@ -459,7 +896,7 @@ impl Matrix {
     /// );
     /// ```
     pub fn div_scalar(&mut self, scalar: &f32) {
-        self.matrix_flatt = self.matrix_flatt.iter().map(|x| x / scalar).collect();
+        self.map_flatt(|x| x / scalar);
     }
 
     /// multiplies each component from the matrix with a scalar value and stors the result in this matrix   
@@ -479,7 +916,7 @@ impl Matrix {
     /// );
     /// ```
     pub fn sub_scalar(&mut self, scalar: &f32) {
-        self.matrix_flatt = self.matrix_flatt.iter().map(|x| x - scalar).collect();
+        self.map_flatt(|x| x - scalar);
     }
 
     /// computes the dot product between the vector and this matrix
@@ -510,7 +947,33 @@ impl Matrix {
                     .sum(),
             );
         }
-        Vector::new(result)
+        Vector::new(result).checked()
+    }
+
+    /// same as `dot_vec` but returns an error instead of panicking if the vector has the wrong len
+    pub fn try_dot_vec(&self, vector: &Vector) -> Result<Vector, MathError> {
+        try_check_vector(self, vector)?;
+        Ok(self.dot_vec(vector))
+    }
+
+    /// same as `dot_vec` but all buffers are taken from the [`Workspace`]
+    ///
+    /// [`Workspace`]: crate::linear_algebra::workspace::Workspace
+    pub fn dot_vec_with(&self, vector: &Vector, ws: &mut Workspace) -> Vector {
+        check_vector(self, vector);
+
+        let matrix_flatt = self.matrix_flatt_with(ws);
+        let rows = self.rows();
+        let mut result = ws.take(self.cols());
+        for i in 0..self.cols() {
+            result.push(
+                (0..rows)
+                    .map(|j| matrix_flatt[i * rows + j] * vector.index(j))
+                    .sum(),
+            );
+        }
+        ws.give(matrix_flatt);
+        Vector::new(result).checked()
     }
 
     /// adds each component from the vector with the component of the other matrix and stors the result in this matrix   
@@ -525,18 +988,13 @@ impl Matrix {
     /// matrix.add_vec(&vector);
     /// assert_eq!(
     ///     matrix,
-    ///     Matrix::new(vec![vec![4.0, -3.0, 1.0], vec![6.0, 0.0, -1.0]])
+    ///     Matrix::new(vec![vec![4.0, 1.0, 7.0], vec![4.0, 4.0, 5.0]])
     /// );
     /// ```
-    /// note it panics if the matrices have not the same rows and cols
+    /// this is the same as `add_col_vec`, note it panics if the len of the vector is not the same as the rows
+    /// see `add_vec_axis` to choose explicitly if the vector is applied to each row or each col
     pub fn add_vec(&mut self, vector: &Vector) {
-        check_vector(self, vector);
-        for row in 0..self.rows() - 1 {
-            for col in 0..self.cols() - 1 {
-                let val = self.index(row, col) + vector.index(row);
-                self.set_index(row, col, val);
-            }
-        }
+        self.add_col_vec(vector);
     }
 
     /// subtracts each component from the vector with the component of the other matrix and stors the result in this matrix   
@@ -551,18 +1009,13 @@ impl Matrix {
     /// matrix.sub_vec(&vector);
     /// assert_eq!(
     ///     matrix,
-    ///     Matrix::new(vec![vec![0.0, -3.0, 1.0], vec![-2.0, 0.0, -1.0]])
+    ///     Matrix::new(vec![vec![0.0, -7.0, -5.0], vec![0.0, -4.0, -7.0]])
     /// );
     /// ```
-    /// note it panics if the matrices have not the same rows and cols
+    /// this is the same as `sub_col_vec`, note it panics if the len of the vector is not the same as the rows
+    /// see `sub_vec_axis` to choose explicitly if the vector is applied to each row or each col
     pub fn sub_vec(&mut self, vector: &Vector) {
-        check_vector(self, vector);
-        for row in 0..self.rows() - 1 {
-            for col in 0..self.cols() - 1 {
-                let val = self.index(row, col) - vector.index(row);
-                self.set_index(row, col, val);
-            }
-        }
+        self.sub_col_vec(vector);
     }
 
     /// multiplys each component from the vector with the component of the other matrix and stors the result in this matrix   
@@ -577,18 +1030,13 @@ impl Matrix {
     /// matrix.mul_vec(&vector);
     /// assert_eq!(
     ///     matrix,
-    ///     Matrix::new(vec![vec![4.0, -3.0, 1.0], vec![8.0, 0.0, -1.0]])
+    ///     Matrix::new(vec![vec![4.0, -12.0, 6.0], vec![4.0, 0.0, -6.0]])
     /// );
     /// ```
-    /// note it panics if the matrices have not the same rows and cols
+    /// this is the same as `mul_col_vec`, note it panics if the len of the vector is not the same as the rows
+    /// see `mul_vec_axis` to choose explicitly if the vector is applied to each row or each col
     pub fn mul_vec(&mut self, vector: &Vector) {
-        check_vector(self, vector);
-        for row in 0..self.rows() - 1 {
-            for col in 0..self.cols() - 1 {
-                let val = self.index(row, col) * vector.index(row);
-                self.set_index(row, col, val);
-            }
-        }
+        self.mul_col_vec(vector);
     }
 
     /// divides each component from the vector with the component of the other matrix and stors the result in this matrix   
@@ -603,18 +1051,13 @@ impl Matrix {
     /// matrix.div_vec(&vector);
     /// assert_eq!(
     ///     matrix,
-    ///     Matrix::new(vec![vec![1.0, -3.0, 1.0], vec![0.5, 0.0, -1.0]])
+    ///     Matrix::new(vec![vec![1.0, -0.75, 1. / 6.], vec![1.0, 0.0, -1. / 6.]])
     /// );
     /// ```
-    /// note it panics if the matrices have not the same rows and cols
+    /// this is the same as `div_col_vec`, note it panics if the len of the vector is not the same as the rows
+    /// see `div_vec_axis` to choose explicitly if the vector is applied to each row or each col
     pub fn div_vec(&mut self, vector: &Vector) {
-        check_vector(self, vector);
-        for row in 0..self.rows() - 1 {
-            for col in 0..self.cols() - 1 {
-                let val = self.index(row, col) / vector.index(row);
-                self.set_index(row, col, val);
-            }
-        }
+        self.div_col_vec(vector);
     }
 
     /// adds each component from the matrix with the component of the other matrix and stors the result in this matrix   
@@ -629,21 +1072,22 @@ impl Matrix {
     /// matrix1.add_mat(&matrix2);
     /// assert_eq!(
     ///     matrix1,
-    ///     Matrix::new(vec![vec![4.0, -3.0, 1.0], vec![9.0, 0.0, -1.0]])
+    ///     Matrix::new(vec![vec![4.0, 0.0, 6.0], vec![9.0, 1.0, 3.0]])
     /// );
     /// ```
     /// note it panics if the matrices have not the same rows and cols
     pub fn add_mat(&mut self, other: &Matrix) {
-        check_matrix(self, other);
-        for row in 0..self.rows() - 1 {
-            for col in 0..self.cols() - 1 {
-                let val = self.index(row, col) + other.index(row, col);
-                self.set_index(row, col, val);
-            }
-        }
+        self.zip_mat_inplace(other, |x, y| x + y);
     }
 
-    /// adds each component from the matrix with the component of the other matrix and stors the result in this matrix   
+    /// same as `add_mat` but returns an error instead of panicking if the shapes don't match
+    pub fn try_add_mat(&mut self, other: &Matrix) -> Result<(), MathError> {
+        try_check_matrix(self, other)?;
+        self.add_mat(other);
+        Ok(())
+    }
+
+    /// subtracts each component of the other matrix from the component of this matrix and stors the result in this matrix
     ///
     /// ## Example
     ///
@@ -655,21 +1099,22 @@ impl Matrix {
     /// matrix1.sub_mat(&matrix2);
     /// assert_eq!(
     ///   matrix1,
-    ///   Matrix::new(vec![vec![0.0, -3.0, 1.0], vec![-5.0, 0.0, -1.0]])
+    ///   Matrix::new(vec![vec![0.0, -6.0, -4.0], vec![-5.0, -1.0, -5.0]])
     /// );
     /// ```
     /// note it panics if the matrices have not the same rows and cols
     pub fn sub_mat(&mut self, other: &Matrix) {
-        check_matrix(self, other);
-        for row in 0..self.rows() - 1 {
-            for col in 0..self.cols() - 1 {
-                let val = self.index(row, col) - other.index(row, col);
-                self.set_index(row, col, val);
-            }
-        }
+        self.zip_mat_inplace(other, |x, y| x - y);
     }
 
-    /// adds each component from the matrix with the component of the other matrix and stors the result in this matrix   
+    /// same as `sub_mat` but returns an error instead of panicking if the shapes don't match
+    pub fn try_sub_mat(&mut self, other: &Matrix) -> Result<(), MathError> {
+        try_check_matrix(self, other)?;
+        self.sub_mat(other);
+        Ok(())
+    }
+
+    /// divides each component of this matrix by the component of the other matrix and stors the result in this matrix
     ///
     /// ## Example
     ///
@@ -681,21 +1126,24 @@ impl Matrix {
     /// matrix1.div_mat(&matrix2);
     /// assert_eq!(
     ///     matrix1,
-    ///     Matrix::new(vec![vec![1.0, -3.0, 1.0], vec![0.2857143, 0.0, -1.0]])
+    ///     Matrix::new(vec![vec![1.0, -1.0, 0.2], vec![0.2857143, 0.0, -0.25]])
     /// );
     /// ```
     /// note it panics if the matrices have not the same rows and cols
     pub fn div_mat(&mut self, other: &Matrix) {
-        check_matrix(self, other);
-        for row in 0..self.rows() - 1 {
-            for col in 0..self.cols() - 1 {
-                let val = self.index(row, col) / other.index(row, col);
-                self.set_index(row, col, val);
-            }
-        }
+        self.zip_mat_inplace(other, |x, y| x / y);
     }
 
-    /// adds each component from the matrix with the component of the other matrix and stors the result in this matrix   
+    /// same as `div_mat` but returns an error instead of panicking if the shapes don't match
+    pub fn try_div_mat(&mut self, other: &Matrix) -> Result<(), MathError> {
+        try_check_matrix(self, other)?;
+        self.div_mat(other);
+        Ok(())
+    }
+
+    /// multiplies each component from the matrix with the component of the other matrix and stors the result in this matrix
+    ///
+    /// this is the element-wise product, the `*` operator is the matrix product (see `dot_mat`)
     ///
     /// ## Example
     ///
@@ -704,26 +1152,546 @@ impl Matrix {
     /// let mut matrix1 = Matrix::new(vec![vec![2., -3., 1.], vec![2., 0., -1.]]);
     /// let matrix2 = Matrix::new(vec![vec![2., 3., 5.], vec![7., 1., 4.]]);
     ///
-    /// matrix1.mul_mat(&matrix2);
+    /// matrix1.mul_elem(&matrix2);
     /// assert_eq!(
     ///   matrix1,
-    ///   Matrix::new(vec![vec![4.0, -3.0, 1.0], vec![14.0, 0.0, -1.0]])
+    ///   Matrix::new(vec![vec![4.0, -9.0, 5.0], vec![14.0, 0.0, -4.0]])
     /// );
     /// ```
     /// note it panics if the matrices have not the same rows and cols
+    pub fn mul_elem(&mut self, other: &Matrix) {
+        self.zip_mat_inplace(other, |x, y| x * y);
+    }
+
+    /// same as `mul_elem` but returns an error instead of panicking if the shapes don't match
+    pub fn try_mul_elem(&mut self, other: &Matrix) -> Result<(), MathError> {
+        try_check_matrix(self, other)?;
+        self.mul_elem(other);
+        Ok(())
+    }
+
+    /// returns the [Hadamard product] of this matrix and the other matrix, the element-wise product like `mul_elem`
+    ///
+    /// [Hadamard product]: https://en.wikipedia.org/wiki/Hadamard_product_(matrices)
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Matrix;
+    /// let matrix1 = Matrix::new(vec![vec![1., 2.], vec![3., 4.]]);
+    /// let matrix2 = Matrix::new(vec![vec![5., 6.], vec![7., 8.]]);
+    /// assert_eq!(
+    ///     matrix1.hadamard(&matrix2),
+    ///     Matrix::new(vec![vec![5., 12.], vec![21., 32.]])
+    /// );
+    /// assert_eq!(
+    ///     &matrix1 * &matrix2,
+    ///     Matrix::new(vec![vec![19., 22.], vec![43., 50.]])
+    /// );
+    /// ```
+    /// note it panics if the matrices have not the same rows and cols
+    pub fn hadamard(&self, other: &Matrix) -> Matrix {
+        let mut result = self.clone();
+        result.mul_elem(other);
+        result
+    }
+
+    /// the old element-wise multiplication which was also used by the `*` operator, the same as `mul_elem`
+    ///
+    /// note it panics if the matrices have not the same rows and cols
+    #[deprecated(
+        since = "0.1.0",
+        note = "`*` is now the matrix product, use `mul_elem` or `hadamard` for the element-wise product"
+    )]
     pub fn mul_mat(&mut self, other: &Matrix) {
-        check_matrix(self, other);
-        for row in 0..self.rows() - 1 {
-            for col in 0..self.cols() - 1 {
-                let val = self.index(row, col) * other.index(row, col);
-                self.set_index(row, col, val);
+        self.mul_elem(other);
+    }
+
+    /// same as `mul_mat` but returns an error instead of panicking if the shapes don't match
+    #[deprecated(since = "0.1.0", note = "use `try_mul_elem` instead")]
+    #[allow(deprecated)]
+    pub fn try_mul_mat(&mut self, other: &Matrix) -> Result<(), MathError> {
+        try_check_matrix(self, other)?;
+        self.mul_mat(other);
+        Ok(())
+    }
+
+    /// adds each component of the other matrix to the component of this matrix in place
+    ///
+    /// the same as `add_mat`, it never allocates, the only exception is a matrix which still shares
+    /// its storage with a clone, then the values are copied once (copy-on-write)
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Matrix;
+    /// let mut matrix1 = Matrix::new(vec![vec![2., -1.], vec![3., 4.]]);
+    /// let matrix2 = Matrix::new(vec![vec![1., 2.], vec![2., 1.]]);
+    /// matrix1.add_mat_inplace(&matrix2);
+    /// assert_eq!(matrix1, Matrix::new(vec![vec![3., 1.], vec![5., 5.]]));
+    /// ```
+    /// note it panics if the matrices have not the same rows and cols
+    pub fn add_mat_inplace(&mut self, other: &Matrix) {
+        self.zip_mat_inplace(other, |x, y| x + y);
+    }
+
+    /// subtracts each component of the other matrix from the component of this matrix in place
+    ///
+    /// the same as `sub_mat`, it never allocates, the only exception is a matrix which still shares
+    /// its storage with a clone, then the values are copied once (copy-on-write)
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Matrix;
+    /// let mut matrix1 = Matrix::new(vec![vec![2., -1.], vec![3., 4.]]);
+    /// let matrix2 = Matrix::new(vec![vec![1., 2.], vec![2., 1.]]);
+    /// matrix1.sub_mat_inplace(&matrix2);
+    /// assert_eq!(matrix1, Matrix::new(vec![vec![1., -3.], vec![1., 3.]]));
+    /// ```
+    /// note it panics if the matrices have not the same rows and cols
+    pub fn sub_mat_inplace(&mut self, other: &Matrix) {
+        self.zip_mat_inplace(other, |x, y| x - y);
+    }
+
+    /// multiplies each component of the other matrix with the component of this matrix in place
+    ///
+    /// like `mul_elem` this never allocates, the only exception is a matrix which still shares
+    /// its storage with a clone, then the values are copied once (copy-on-write)
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Matrix;
+    /// let mut matrix1 = Matrix::new(vec![vec![2., -1.], vec![3., 4.]]);
+    /// let matrix2 = Matrix::new(vec![vec![1., 2.], vec![2., 1.]]);
+    /// matrix1.mul_mat_inplace(&matrix2);
+    /// assert_eq!(matrix1, Matrix::new(vec![vec![2., -2.], vec![6., 4.]]));
+    /// ```
+    /// note it panics if the matrices have not the same rows and cols
+    pub fn mul_mat_inplace(&mut self, other: &Matrix) {
+        self.zip_mat_inplace(other, |x, y| x * y);
+    }
+
+    /// divides each component of this matrix by the component of the other matrix in place
+    ///
+    /// the same as `div_mat`, it never allocates, the only exception is a matrix which still shares
+    /// its storage with a clone, then the values are copied once (copy-on-write)
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Matrix;
+    /// let mut matrix1 = Matrix::new(vec![vec![2., -1.], vec![3., 4.]]);
+    /// let matrix2 = Matrix::new(vec![vec![1., 2.], vec![2., 1.]]);
+    /// matrix1.div_mat_inplace(&matrix2);
+    /// assert_eq!(matrix1, Matrix::new(vec![vec![2., -0.5], vec![1.5, 4.]]));
+    /// ```
+    /// note it panics if the matrices have not the same rows and cols
+    pub fn div_mat_inplace(&mut self, other: &Matrix) {
+        self.zip_mat_inplace(other, |x, y| x / y);
+    }
+
+    /// adds each component of the vector to the components of each col of this matrix in place
+    ///
+    /// so `index(i, j)` becomes `index(i, j) + vector.index(j)`,
+    /// unlike `add_vec` this never allocates (except once if the storage is shared with a clone)
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Matrix;
+    /// use math::linear_algebra::Vector;
+    /// let mut matrix = Matrix::new(vec![vec![1., 2.], vec![3., 4.]]);
+    /// matrix.add_vec_inplace(&Vector::new(vec![2., 2.]));
+    /// assert_eq!(matrix, Matrix::new(vec![vec![3., 4.], vec![5., 6.]]));
+    /// ```
+    /// note it panics if the len of the vector is not the same as the rows of the matrix
+    pub fn add_vec_inplace(&mut self, vector: &Vector) {
+        self.zip_vec_inplace(vector, |x, y| x + y);
+    }
+
+    /// subtracts each component of the vector from the components of each col of this matrix in place
+    ///
+    /// so `index(i, j)` becomes `index(i, j) - vector.index(j)`,
+    /// unlike `sub_vec` this never allocates (except once if the storage is shared with a clone)
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Matrix;
+    /// use math::linear_algebra::Vector;
+    /// let mut matrix = Matrix::new(vec![vec![1., 2.], vec![3., 4.]]);
+    /// matrix.sub_vec_inplace(&Vector::new(vec![2., 2.]));
+    /// assert_eq!(matrix, Matrix::new(vec![vec![-1., 0.], vec![1., 2.]]));
+    /// ```
+    /// note it panics if the len of the vector is not the same as the rows of the matrix
+    pub fn sub_vec_inplace(&mut self, vector: &Vector) {
+        self.zip_vec_inplace(vector, |x, y| x - y);
+    }
+
+    /// multiplies each component of the vector with the components of each col of this matrix in place
+    ///
+    /// so `index(i, j)` becomes `index(i, j) * vector.index(j)`,
+    /// unlike `mul_vec` this never allocates (except once if the storage is shared with a clone)
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Matrix;
+    /// use math::linear_algebra::Vector;
+    /// let mut matrix = Matrix::new(vec![vec![1., 2.], vec![3., 4.]]);
+    /// matrix.mul_vec_inplace(&Vector::new(vec![2., 2.]));
+    /// assert_eq!(matrix, Matrix::new(vec![vec![2., 4.], vec![6., 8.]]));
+    /// ```
+    /// note it panics if the len of the vector is not the same as the rows of the matrix
+    pub fn mul_vec_inplace(&mut self, vector: &Vector) {
+        self.zip_vec_inplace(vector, |x, y| x * y);
+    }
+
+    /// divides each component of the vector by the components of each col of this matrix in place
+    ///
+    /// so `index(i, j)` becomes `index(i, j) / vector.index(j)`,
+    /// unlike `div_vec` this never allocates (except once if the storage is shared with a clone)
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Matrix;
+    /// use math::linear_algebra::Vector;
+    /// let mut matrix = Matrix::new(vec![vec![1., 2.], vec![3., 4.]]);
+    /// matrix.div_vec_inplace(&Vector::new(vec![2., 2.]));
+    /// assert_eq!(matrix, Matrix::new(vec![vec![0.5, 1.], vec![1.5, 2.]]));
+    /// ```
+    /// note it panics if the len of the vector is not the same as the rows of the matrix
+    pub fn div_vec_inplace(&mut self, vector: &Vector) {
+        self.zip_vec_inplace(vector, |x, y| x / y);
+    }
+
+    /// adds the vector to this matrix along the `axis` (see [`shape`](crate::linear_algebra::shape))
+    ///
+    /// with `Axis::Col` the vector is applied to every col and with `Axis::Row` to every row
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::shape::Axis;
+    /// use math::linear_algebra::{Matrix, Vector};
+    /// let mut matrix = Matrix::new(vec![vec![1., 2.], vec![3., 4.]]);
+    /// matrix.add_vec_axis(Axis::Row, &Vector::new(vec![2., 3.]));
+    /// assert_eq!(matrix, Matrix::new(vec![vec![3., 4.], vec![6., 7.]]));
+    /// ```
+    /// note it panics if the len of the vector doesn't fit the axis
+    pub fn add_vec_axis(&mut self, axis: Axis, vector: &Vector) {
+        self.zip_axis_inplace(vector, axis, |x, y| x + y);
+    }
+
+    /// subtracts the vector from this matrix along the `axis` (see [`shape`](crate::linear_algebra::shape))
+    ///
+    /// with `Axis::Col` the vector is applied to every col and with `Axis::Row` to every row
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::shape::Axis;
+    /// use math::linear_algebra::{Matrix, Vector};
+    /// let mut matrix = Matrix::new(vec![vec![1., 2.], vec![3., 4.]]);
+    /// matrix.sub_vec_axis(Axis::Row, &Vector::new(vec![2., 3.]));
+    /// assert_eq!(matrix, Matrix::new(vec![vec![-1., 0.], vec![0., 1.]]));
+    /// ```
+    /// note it panics if the len of the vector doesn't fit the axis
+    pub fn sub_vec_axis(&mut self, axis: Axis, vector: &Vector) {
+        self.zip_axis_inplace(vector, axis, |x, y| x - y);
+    }
+
+    /// multiplies the vector with this matrix along the `axis` (see [`shape`](crate::linear_algebra::shape))
+    ///
+    /// with `Axis::Col` the vector is applied to every col and with `Axis::Row` to every row
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::shape::Axis;
+    /// use math::linear_algebra::{Matrix, Vector};
+    /// let mut matrix = Matrix::new(vec![vec![1., 2.], vec![3., 4.]]);
+    /// matrix.mul_vec_axis(Axis::Row, &Vector::new(vec![2., 3.]));
+    /// assert_eq!(matrix, Matrix::new(vec![vec![2., 4.], vec![9., 12.]]));
+    /// ```
+    /// note it panics if the len of the vector doesn't fit the axis
+    pub fn mul_vec_axis(&mut self, axis: Axis, vector: &Vector) {
+        self.zip_axis_inplace(vector, axis, |x, y| x * y);
+    }
+
+    /// divides the vector by this matrix along the `axis` (see [`shape`](crate::linear_algebra::shape))
+    ///
+    /// with `Axis::Col` the vector is applied to every col and with `Axis::Row` to every row
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::shape::Axis;
+    /// use math::linear_algebra::{Matrix, Vector};
+    /// let mut matrix = Matrix::new(vec![vec![1., 2.], vec![3., 4.]]);
+    /// matrix.div_vec_axis(Axis::Row, &Vector::new(vec![2., 3.]));
+    /// assert_eq!(matrix, Matrix::new(vec![vec![0.5, 1.], vec![1., 4. / 3.]]));
+    /// ```
+    /// note it panics if the len of the vector doesn't fit the axis
+    pub fn div_vec_axis(&mut self, axis: Axis, vector: &Vector) {
+        self.zip_axis_inplace(vector, axis, |x, y| x / y);
+    }
+
+    /// adds the vector to every col of this matrix, so `index(i, j) + vector.index(j)`
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{Matrix, Vector};
+    /// let mut matrix = Matrix::new(vec![vec![1., 2.], vec![3., 4.]]);
+    /// matrix.add_col_vec(&Vector::new(vec![1., 2.]));
+    /// assert_eq!(matrix, Matrix::new(vec![vec![2., 4.], vec![4., 6.]]));
+    /// ```
+    /// note it panics if the len of the vector is not the same as the rows
+    pub fn add_col_vec(&mut self, vector: &Vector) {
+        self.add_vec_axis(Axis::Col, vector);
+    }
+
+    /// adds the vector to every row of this matrix, so `index(i, j) + vector.index(i)`
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{Matrix, Vector};
+    /// let mut matrix = Matrix::new(vec![vec![1., 2.], vec![3., 4.]]);
+    /// matrix.add_row_vec(&Vector::new(vec![1., 2.]));
+    /// assert_eq!(matrix, Matrix::new(vec![vec![2., 3.], vec![5., 6.]]));
+    /// ```
+    /// note it panics if the len of the vector is not the same as the cols
+    pub fn add_row_vec(&mut self, vector: &Vector) {
+        self.add_vec_axis(Axis::Row, vector);
+    }
+
+    /// subtracts the vector from every col of this matrix, so `index(i, j) - vector.index(j)`
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{Matrix, Vector};
+    /// let mut matrix = Matrix::new(vec![vec![1., 2.], vec![3., 4.]]);
+    /// matrix.sub_col_vec(&Vector::new(vec![1., 2.]));
+    /// assert_eq!(matrix, Matrix::new(vec![vec![0., 0.], vec![2., 2.]]));
+    /// ```
+    /// note it panics if the len of the vector is not the same as the rows
+    pub fn sub_col_vec(&mut self, vector: &Vector) {
+        self.sub_vec_axis(Axis::Col, vector);
+    }
+
+    /// subtracts the vector from every row of this matrix, so `index(i, j) - vector.index(i)`
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{Matrix, Vector};
+    /// let mut matrix = Matrix::new(vec![vec![1., 2.], vec![3., 4.]]);
+    /// matrix.sub_row_vec(&Vector::new(vec![1., 2.]));
+    /// assert_eq!(matrix, Matrix::new(vec![vec![0., 1.], vec![1., 2.]]));
+    /// ```
+    /// note it panics if the len of the vector is not the same as the cols
+    pub fn sub_row_vec(&mut self, vector: &Vector) {
+        self.sub_vec_axis(Axis::Row, vector);
+    }
+
+    /// multiplies the vector with every col of this matrix, so `index(i, j) * vector.index(j)`
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{Matrix, Vector};
+    /// let mut matrix = Matrix::new(vec![vec![1., 2.], vec![3., 4.]]);
+    /// matrix.mul_col_vec(&Vector::new(vec![1., 2.]));
+    /// assert_eq!(matrix, Matrix::new(vec![vec![1., 4.], vec![3., 8.]]));
+    /// ```
+    /// note it panics if the len of the vector is not the same as the rows
+    pub fn mul_col_vec(&mut self, vector: &Vector) {
+        self.mul_vec_axis(Axis::Col, vector);
+    }
+
+    /// multiplies the vector with every row of this matrix, so `index(i, j) * vector.index(i)`
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{Matrix, Vector};
+    /// let mut matrix = Matrix::new(vec![vec![1., 2.], vec![3., 4.]]);
+    /// matrix.mul_row_vec(&Vector::new(vec![1., 2.]));
+    /// assert_eq!(matrix, Matrix::new(vec![vec![1., 2.], vec![6., 8.]]));
+    /// ```
+    /// note it panics if the len of the vector is not the same as the cols
+    pub fn mul_row_vec(&mut self, vector: &Vector) {
+        self.mul_vec_axis(Axis::Row, vector);
+    }
+
+    /// divides the vector by every col of this matrix, so `index(i, j) / vector.index(j)`
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{Matrix, Vector};
+    /// let mut matrix = Matrix::new(vec![vec![1., 2.], vec![3., 4.]]);
+    /// matrix.div_col_vec(&Vector::new(vec![1., 2.]));
+    /// assert_eq!(matrix, Matrix::new(vec![vec![1., 1.], vec![3., 2.]]));
+    /// ```
+    /// note it panics if the len of the vector is not the same as the rows
+    pub fn div_col_vec(&mut self, vector: &Vector) {
+        self.div_vec_axis(Axis::Col, vector);
+    }
+
+    /// divides the vector by every row of this matrix, so `index(i, j) / vector.index(i)`
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{Matrix, Vector};
+    /// let mut matrix = Matrix::new(vec![vec![1., 2.], vec![3., 4.]]);
+    /// matrix.div_row_vec(&Vector::new(vec![1., 2.]));
+    /// assert_eq!(matrix, Matrix::new(vec![vec![1., 2.], vec![1.5, 2.]]));
+    /// ```
+    /// note it panics if the len of the vector is not the same as the cols
+    pub fn div_row_vec(&mut self, vector: &Vector) {
+        self.div_vec_axis(Axis::Row, vector);
+    }
+
+    /// computes the [matrix product] between this matrix and the other matrix
+    ///
+    /// [matrix product]: https://en.wikipedia.org/wiki/Matrix_multiplication
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Matrix;
+    /// let matrix1 = Matrix::new(vec![vec![1., 2.], vec![3., 4.]]);
+    /// let matrix2 = Matrix::new(vec![vec![5., 6.], vec![7., 8.]]);
+    /// assert_eq!(
+    ///     matrix1.dot_mat(&matrix2),
+    ///     Matrix::new(vec![vec![19., 22.], vec![43., 50.]])
+    /// );
+    /// ```
+    /// note `matrix1.dot_mat(&matrix2).dot_vec(&v)` is the same as `matrix1.dot_vec(&matrix2.dot_vec(&v))`
+    /// so it panics if the rows of this matrix are not the same as the cols of the other matrix
+    pub fn dot_mat(&self, other: &Matrix) -> Matrix {
+        check_dot(self, other);
+
+        let lhs = self.matrix_flatt();
+        let rhs = other.matrix_flatt();
+        if self.is_square() && other.is_square() {
+            let mut matrix_flatt = vec![0.; self.cols() * other.rows()];
+            if small::dot_mat(&lhs, &rhs, self.rows(), &mut matrix_flatt) {
+                return Matrix::new_flatt(matrix_flatt, self.cols(), other.rows()).checked();
             }
         }
+
+        let mut matrix_flatt = vec![0.; self.cols() * other.rows()];
+        self.dot_mat_flatt(other, &lhs, &rhs, &mut matrix_flatt, BLOCK_SIZE);
+        Matrix::new_flatt(matrix_flatt, self.cols(), other.rows()).checked()
     }
 
-    pub fn dot_mat(&self, other: &Matrix) {
-        check_matrix(self, other);
-        todo!();
+    /// same as `dot_mat` but the product is computed in tiles of `block_size` x `block_size`,
+    /// so the values of a tile stay in the cache while they are used
+    ///
+    /// `dot_mat` uses a block size of 64, the result is the same for every block size
+    /// and a block size bigger then the matrices is the naive product without tiles
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Matrix;
+    /// let matrix1 = Matrix::new_rand(50, 30);
+    /// let matrix2 = Matrix::new_rand(30, 40);
+    /// assert_eq!(matrix1.dot_mat_blocked(&matrix2, 8), matrix1.dot_mat(&matrix2));
+    /// ```
+    /// note it panics like `dot_mat` and if `block_size` is 0
+    pub fn dot_mat_blocked(&self, other: &Matrix, block_size: usize) -> Matrix {
+        check_dot(self, other);
+        if block_size == 0 {
+            panic!("the block size has to be bigger then 0");
+        }
+
+        let lhs = self.matrix_flatt();
+        let rhs = other.matrix_flatt();
+        let mut matrix_flatt = vec![0.; self.cols() * other.rows()];
+        self.dot_mat_flatt(other, &lhs, &rhs, &mut matrix_flatt, block_size);
+        Matrix::new_flatt(matrix_flatt, self.cols(), other.rows()).checked()
+    }
+
+    /// same as `dot_mat` but returns an error instead of panicking if the shapes don't match
+    pub fn try_dot_mat(&self, other: &Matrix) -> Result<Matrix, MathError> {
+        try_check_dot(self, other)?;
+        Ok(self.dot_mat(other))
+    }
+
+    /// computes the [Kronecker product], the result has the shape `self.cols() * other.cols()` x `self.rows() * other.rows()`
+    /// and `index(i * other.cols() + k, j * other.rows() + l)` is `self.index(i, j) * other.index(k, l)`
+    ///
+    /// [Kronecker product]: https://en.wikipedia.org/wiki/Kronecker_product
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Matrix;
+    /// let a = Matrix::new(vec![vec![1., 2.], vec![3., 4.]]);
+    /// let b = Matrix::new(vec![vec![0., 1.]]);
+    /// assert_eq!(
+    ///     a.kron(&b),
+    ///     Matrix::new(vec![vec![0., 1., 0., 2.], vec![0., 3., 0., 4.]])
+    /// );
+    /// ```
+    pub fn kron(&self, other: &Matrix) -> Matrix {
+        let (cols, rows) = (self.cols() * other.cols(), self.rows() * other.rows());
+        let mut matrix_flatt = Vec::with_capacity(cols * rows);
+        for i in 0..self.cols() {
+            for k in 0..other.cols() {
+                for j in 0..self.rows() {
+                    let val = self.index(i, j);
+                    matrix_flatt.extend((0..other.rows()).map(|l| val * other.index(k, l)));
+                }
+            }
+        }
+        Matrix::new_flatt(matrix_flatt, cols, rows).checked()
+    }
+
+    /// same as `dot_mat` but all buffers are taken from the [`Workspace`]
+    ///
+    /// [`Workspace`]: crate::linear_algebra::workspace::Workspace
+    pub fn dot_mat_with(&self, other: &Matrix, ws: &mut Workspace) -> Matrix {
+        check_dot(self, other);
+
+        let lhs = self.matrix_flatt_with(ws);
+        let rhs = other.matrix_flatt_with(ws);
+        let mut matrix_flatt = ws.take_zeroed(self.cols() * other.rows());
+        self.dot_mat_flatt(other, &lhs, &rhs, &mut matrix_flatt, BLOCK_SIZE);
+        ws.give(lhs);
+        ws.give(rhs);
+        // the values are copied into the shared storage, so the buffer can go back to the workspace
+        let result = Matrix {
+            cols: self.cols(),
+            rows: other.rows(),
+            matrix_flatt: Arc::from(&matrix_flatt[..]),
+            is_transpose: false,
+        };
+        ws.give(matrix_flatt);
+        result.checked()
+    }
+
+    // multiplies the flatt matrices lhs and rhs in tiles of block x block and adds the result to out,
+    // each value of out gets the products in the same order for every block size
+    fn dot_mat_flatt(
+        &self,
+        other: &Matrix,
+        lhs: &[f32],
+        rhs: &[f32],
+        out: &mut [f32],
+        block: usize,
+    ) {
+        dot_flatt(lhs, rhs, self.cols(), self.rows(), other.rows(), out, block);
     }
 
     /// returns the [determinant] of this matrix
@@ -735,118 +1703,399 @@ impl Matrix {
     /// ```rust
     /// use math::linear_algebra::Matrix;
     /// let matrix = Matrix::new(vec![vec![1., 2.], vec![3., 4.]]);
-    /// assert_eq!(matrix.det(), -5.);
+    /// assert_eq!(matrix.det(), -2.);
     /// ```
+    /// matrices up to 4x4 use a closed form, bigger matrices use Gaussian elimination with partial pivoting in `O(n^3)`
+    ///
     ///  note the matrix has to be a [square matrix]
     ///
     /// [square matrix]: https://en.wikipedia.org/wiki/Square_matrix
     pub fn det(&self) -> f32 {
         check_square(self);
-        if self.rows() == 2 {
-            self.index(0, 0) * self.index(1, 1) - self.index(1, 0) * self.index(1, 0)
+        let matrix_flatt = self.matrix_flatt();
+        if let Some(det) = small::det(&matrix_flatt, self.rows()) {
+            det
         } else {
-            let mut sign = 1.;
-            let mut sum = 0.;
-
-            for col in 0..self.cols() {
-                let sub = self.finde_sub(0, col);
-                sum += sub.det() * sign * self.index(0, col);
-                sign *= -1.;
-            }
-
-            sum
+            solve::det(&matrix_flatt, self.rows())
         }
     }
 
-    // finds the sub matrix is user for the determinant
-    fn finde_sub(&self, row: usize, col: usize) -> Self {
-        let mut flatt = Vec::with_capacity((self.cols() - 1) * (self.rows() - 1));
-
-        for i in 0..self.cols() {
-            for j in 0..self.rows() {
-                if !(i == col || j == row) {
-                    flatt.push(self.index(i, j));
-                }
-            }
-        }
-        Self::new_flatt(flatt, self.cols() - 1, self.rows() - 1)
+    /// same as `det` but returns an error instead of panicking if the matrix is not square
+    pub fn try_det(&self) -> Result<f32, MathError> {
+        try_check_square(self)?;
+        Ok(self.det())
     }
 
-    /// this returns the [eigenvalues] of this matrix
+    /// this returns the [eigenvalues] of this matrix as the real and the imaginary parts,
+    /// sorted by the real part and then by the imaginary part
+    ///
+    /// the matrix is reduced to the [Hessenberg form] and the eigenvalues are found with the shifted QR algorithm,
+    /// a value below the diagonal counts as 0 when it is smaller then `tolerance` times its diagonal neighbours
     ///
     /// [eigenvalues]: https://en.wikipedia.org/wiki/Eigenvalues_and_eigenvectors
+    /// [Hessenberg form]: https://en.wikipedia.org/wiki/Hessenberg_matrix
     ///
     /// ## Example
     ///
     /// ```rust
+    /// use math::linear_algebra::{Matrix, Vector};
+    /// let matrix = Matrix::new(vec![vec![2., 0., 0.], vec![0., 3., 4.], vec![0., 4., 9.]]);
+    /// let (re, im) = matrix.eigen_val(1e-7, 100);
+    /// assert_eq!(im, Vector::new(vec![0., 0., 0.]));
+    /// assert!((re.index(0) - 1.).abs() < 1e-5);
+    /// assert!((re.index(1) - 2.).abs() < 1e-5);
+    /// assert!((re.index(2) - 11.).abs() < 1e-5);
+    /// // a rotation has complex eigenvalues
+    /// let rot = Matrix::new(vec![vec![0., -1.], vec![1., 0.]]);
+    /// assert_eq!(rot.eigen_val(1e-7, 100).1, Vector::new(vec![-1., 1.]));
+    /// ```
+    /// note it panics if the matrix is not square or the QR algorithm needs more then `max_iterations` iterations for one eigenvalue
+    pub fn eigen_val(&self, tolerance: f32, max_iterations: usize) -> (Vector, Vector) {
+        let (re, im) = eigen::eigenvalues(self, tolerance, max_iterations);
+        (
+            Vector::new(re.iter().map(|&x| x as f32).collect()),
+            Vector::new(im.iter().map(|&x| x as f32).collect()),
+        )
+    }
+
+    /// this returns the real [eigenvalues] sorted like in `eigen_val`
+    /// and a matrix where `col(k)` is the normalized eigenvector of the `k`-th eigenvalue
     ///
+    /// the eigenvectors are found with [inverse iteration], eigenvectors of a repeated eigenvalue are orthogonal to each other
+    ///
+    /// [eigenvalues]: https://en.wikipedia.org/wiki/Eigenvalues_and_eigenvectors
+    /// [inverse iteration]: https://en.wikipedia.org/wiki/Inverse_iteration
+    /// [defective]: https://en.wikipedia.org/wiki/Defective_matrix
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{Matrix, Vector};
+    /// let matrix = Matrix::new(vec![vec![2., 1.], vec![0., 3.]]);
+    /// let (values, vectors) = matrix.eigen_vec(1e-7, 100);
+    /// assert_eq!(values, Vector::new(vec![2., 3.]));
+    /// let v = vectors.col(1);
+    /// let av = matrix.dot_vec(&v);
+    /// assert!((av.index(0) - 3. * v.index(0)).abs() < 1e-5);
+    /// assert!((av.index(1) - 3. * v.index(1)).abs() < 1e-5);
     /// ```
-    /// note the matrix has to be a [square matrix]
+    /// note it panics like `eigen_val`, if the matrix has complex eigenvalues or if it is [defective],
+    /// which means a repeated eigenvalue has fewer eigenvectors than its multiplicity like `[[1, 1], [0, 1]]`
+    pub fn eigen_vec(&self, tolerance: f32, max_iterations: usize) -> (Vector, Matrix) {
+        let (values, vectors) = eigen::eigenvectors(self, tolerance, max_iterations);
+        let n = values.len();
+        (
+            Vector::new(values.iter().map(|&x| x as f32).collect()),
+            Matrix::new_flatt(vectors.iter().map(|&x| x as f32).collect(), n, n),
+        )
+    }
+
+    /// returns true if both matrices share the same storage
     ///
-    /// [square matrix]: https://en.wikipedia.org/wiki/Square_matrix
-    pub fn eigen_val(&self) -> f32 {
-        check_square(self);
-        todo!();
+    /// this is the case for clones that were not mutated yet
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Matrix;
+    /// let matrix1 = Matrix::new(vec![vec![3., 2.], vec![4., 5.]]);
+    /// let mut matrix2 = matrix1.clone();
+    /// assert!(matrix1.shares_storage(&matrix2));
+    /// matrix2.set_index(0, 0, 1.);
+    /// assert!(!matrix1.shares_storage(&matrix2));
+    /// ```
+    pub fn shares_storage(&self, other: &Matrix) -> bool {
+        Arc::ptr_eq(&self.matrix_flatt, &other.matrix_flatt)
     }
 
-    pub fn eigen_vec(&self) -> Vector {
-        check_square(self);
-        todo!();
+
+    // with the feature `check-finite` it panics if the matrix contains NaN or infinity,
+    // it is called after each operation so corrupted data is caught where it appears
+    fn check_finite(&self) {
+        if cfg!(feature = "check-finite") {
+            if let Some(x) = self.matrix_flatt.iter().find(|x| !x.is_finite()) {
+                panic!("the matrix contains the non finite value {}", x);
+            }
+        }
+    }
+
+    fn checked(self) -> Self {
+        self.check_finite();
+        self
+    }
+
+    // returns the value at position i in the order of matrix_flatt
+    pub(crate) fn flatt_at(&self, i: usize) -> f32 {
+        if self.is_transpose {
+            self.matrix_flatt[(i % self.cols) * self.rows + i / self.cols]
+        } else {
+            self.matrix_flatt[i]
+        }
+    }
+
+    // overwrites the matrix with the shape cols x rows and the values func(i),
+    // the storage is reused if it has the right size and is not shared
+    pub(crate) fn fill_flatt<F: Fn(usize) -> f32>(&mut self, cols: usize, rows: usize, func: F) {
+        match Arc::get_mut(&mut self.matrix_flatt) {
+            Some(matrix_flatt) if matrix_flatt.len() == cols * rows => {
+                matrix_flatt
+                    .iter_mut()
+                    .enumerate()
+                    .for_each(|(i, x)| *x = func(i));
+            }
+            _ => self.matrix_flatt = (0..cols * rows).map(func).collect(),
+        }
+        self.cols = cols;
+        self.rows = rows;
+        self.is_transpose = false;
+        self.check_finite();
+    }
+
+    // combines each value with the value of the other matrix at the same index, without allocating
+    fn zip_mat_inplace<F: Fn(f32, f32) -> f32>(&mut self, other: &Matrix, func: F) {
+        check_matrix(self, other);
+        let rows = self.rows;
+        let same_layout = self.is_transpose == other.is_transpose;
+        let matrix_flatt = make_mut(&mut self.matrix_flatt);
+
+        if same_layout {
+            matrix_flatt
+                .iter_mut()
+                .zip(other.matrix_flatt.iter())
+                .for_each(|(x, &y)| *x = func(*x, y));
+        } else {
+            // the storage of other is the transpose of the storage of self
+            for (p, x) in matrix_flatt.iter_mut().enumerate() {
+                *x = func(*x, other.matrix_flatt[(p % rows) * other.rows + p / rows]);
+            }
+        }
+        self.check_finite();
+    }
+
+    // combines index(i, j) with vector.index(j) for each value, without allocating
+    fn zip_vec_inplace<F: Fn(f32, f32) -> f32>(&mut self, vector: &Vector, func: F) {
+        check_vector(self, vector);
+        self.zip_axis_inplace(vector, Axis::Col, func);
+    }
+
+    // combines index(i, j) with vector.index(j) for Axis::Col and vector.index(i) for Axis::Row
+    fn zip_axis_inplace<F: Fn(f32, f32) -> f32>(&mut self, vector: &Vector, axis: Axis, func: F) {
+        let shape = vector.shape(axis);
+        if self.shape().broadcast(&shape) != Some(self.shape()) {
+            panic!("can't broadcast shape {} to {}", shape, self.shape());
+        }
+
+        let rows = self.rows;
+        // true if the index of the vector is the index of the col in the storage
+        let along_storage_col = self.is_transpose == (axis == Axis::Col);
+        let matrix_flatt = make_mut(&mut self.matrix_flatt);
+
+        for (p, x) in matrix_flatt.iter_mut().enumerate() {
+            let k = if along_storage_col {
+                p / rows
+            } else {
+                p % rows
+            };
+            *x = func(*x, vector.index(k));
+        }
+        self.check_finite();
+    }
+
+    // applies func to each value, in place if the storage is not shared
+    fn map_flatt<F: Fn(f32) -> f32>(&mut self, func: F) {
+        match Arc::get_mut(&mut self.matrix_flatt) {
+            Some(matrix_flatt) => matrix_flatt.iter_mut().for_each(|x| *x = func(*x)),
+            None => {
+                self.matrix_flatt = self.matrix_flatt.iter().map(|&x| func(x)).collect()
+            }
+        }
+        self.check_finite();
+    }
+
+    // pushes the values in the order of matrix_flatt into buf
+    fn extend_flatt(&self, buf: &mut Vec<f32>) {
+        if self.is_transpose {
+            let start = buf.len();
+            buf.resize(start + self.cols * self.rows, 0.);
+            transpose_flatt(&self.matrix_flatt, self.cols, self.rows, &mut buf[start..]);
+        } else {
+            buf.extend_from_slice(&self.matrix_flatt);
+        }
     }
 
     fn get_row(&self, row: usize) -> Vector {
+        let mut result: Vec<f32> = Vec::with_capacity(self.cols);
+        self.extend_row(row, &mut result);
+        Vector::new(result)
+    }
+
+    fn get_col(&self, col: usize) -> Vector {
+        let mut result: Vec<f32> = Vec::with_capacity(self.rows);
+        self.extend_col(col, &mut result);
+        Vector::new(result)
+    }
+
+    fn extend_row(&self, row: usize, buf: &mut Vec<f32>) {
         if self.rows < row + 1 {
             panic!("index out of bounds max row {}", self.rows - 1)
         }
 
-        let mut result: Vec<f32> = Vec::with_capacity(self.cols);
         for i in 0..self.cols {
-            result.push(self.matrix_flatt[i * self.rows + row].clone());
+            buf.push(self.matrix_flatt[i * self.rows + row]);
         }
+    }
 
-        Vector::new(result)
+    fn swap_flatt_rows(&mut self, a: usize, b: usize) {
+        if self.rows < a.max(b) + 1 {
+            panic!("index out of bounds max row {}", self.rows as isize - 1)
+        }
+
+        let rows = self.rows;
+        let matrix_flatt = make_mut(&mut self.matrix_flatt);
+        for i in 0..self.cols {
+            matrix_flatt.swap(i * rows + a, i * rows + b);
+        }
     }
 
-    fn get_col(&self, col: usize) -> Vector {
+    fn swap_flatt_cols(&mut self, a: usize, b: usize) {
+        if self.cols < a.max(b) + 1 {
+            panic!("index out of bounds max col {}", self.cols as isize - 1)
+        }
+
+        let rows = self.rows;
+        let matrix_flatt = make_mut(&mut self.matrix_flatt);
+        for j in 0..rows {
+            matrix_flatt.swap(a * rows + j, b * rows + j);
+        }
+    }
+
+    fn extend_col(&self, col: usize, buf: &mut Vec<f32>) {
         if self.cols < col + 1 {
             panic!("index out of bounds max col {}", self.cols - 1)
         }
 
-        let mut result: Vec<f32> = Vec::with_capacity(self.rows);
-        for i in (col * self.rows)..((1 + col) * self.rows) {
-            result.push(self.matrix_flatt[i].clone());
+        buf.extend_from_slice(&self.matrix_flatt[(col * self.rows)..((1 + col) * self.rows)]);
+    }
+}
+
+// adds the product of the flatt cols x inner matrix lhs and the inner x rows matrix rhs to out,
+// computed in tiles of block x block
+pub(crate) fn dot_flatt(
+    lhs: &[f32],
+    rhs: &[f32],
+    cols: usize,
+    inner: usize,
+    rows: usize,
+    out: &mut [f32],
+    block: usize,
+) {
+    // a tile of rhs stays in the cache while it is multiplied with every line of lhs
+    for k0 in (0..rows).step_by(block) {
+        let k1 = (k0 + block).min(rows);
+        for j0 in (0..inner).step_by(block) {
+            for i in 0..cols {
+                let out_line = &mut out[i * rows + k0..i * rows + k1];
+                for j in j0..(j0 + block).min(inner) {
+                    let val = lhs[i * inner + j];
+                    let rhs_line = &rhs[j * rows + k0..j * rows + k1];
+                    for (x, &y) in out_line.iter_mut().zip(rhs_line) {
+                        *x += val * y;
+                    }
+                }
+            }
         }
+    }
+}
 
-        Vector::new(result)
+// writes the transpose of the flatt cols x rows matrix src into dst in tiles, so dst[j * cols + i] = src[i * rows + j]
+fn transpose_flatt(src: &[f32], cols: usize, rows: usize, dst: &mut [f32]) {
+    for i0 in (0..cols).step_by(BLOCK_SIZE) {
+        for j0 in (0..rows).step_by(BLOCK_SIZE) {
+            for i in i0..(i0 + BLOCK_SIZE).min(cols) {
+                for j in j0..(j0 + BLOCK_SIZE).min(rows) {
+                    dst[j * cols + i] = src[i * rows + j];
+                }
+            }
+        }
     }
 }
 
 fn check_square(mat: &Matrix) {
+    if let Err(err) = try_check_square(mat) {
+        panic!("{}", err);
+    }
+}
+
+fn try_check_square(mat: &Matrix) -> Result<(), MathError> {
     if !mat.is_square() {
-        panic!("the matrix has to be a square matrix");
+        return Err(MathError::NotSquare);
     }
 
     if mat.rows() == 1 {
-        panic!("the matrix has to have more then one row");
+        return Err(MathError::TooSmall);
+    }
+    Ok(())
+}
+
+// the values for writing, a shared storage is copied first (copy-on-write)
+fn make_mut(matrix_flatt: &mut Arc<[f32]>) -> &mut [f32] {
+    if Arc::get_mut(matrix_flatt).is_none() {
+        *matrix_flatt = Arc::from(&matrix_flatt[..]);
     }
+    Arc::get_mut(matrix_flatt).unwrap()
 }
 
 fn check_vector(mat: &Matrix, vec: &Vector) {
+    if let Err(err) = try_check_vector(mat, vec) {
+        panic!("{}", err);
+    }
+}
+
+fn try_check_vector(mat: &Matrix, vec: &Vector) -> Result<(), MathError> {
     if vec.len() != mat.rows() {
-        panic!(
-            "wrong vector shape expected {}, got {}",
-            mat.rows,
-            vec.len()
-        )
+        return Err(MathError::VectorShape {
+            expected: mat.rows(),
+            got: vec.len(),
+        });
     }
+    Ok(())
 }
 
 fn check_matrix(mat1: &Matrix, mat2: &Matrix) {
+    if let Err(err) = try_check_matrix(mat1, mat2) {
+        panic!("{}", err);
+    }
+}
+
+fn try_check_matrix(mat1: &Matrix, mat2: &Matrix) -> Result<(), MathError> {
     if mat1.rows() != mat2.rows() {
-        panic!("wrong row shape expected {}, got {}", mat1.rows, mat2.rows)
+        return Err(MathError::RowShape {
+            expected: mat1.rows(),
+            got: mat2.rows(),
+        });
     }
 
     if mat1.cols() != mat2.cols() {
-        panic!("wrong col shape expected {}, got {}", mat1.cols, mat2.cols)
+        return Err(MathError::ColShape {
+            expected: mat1.cols(),
+            got: mat2.cols(),
+        });
+    }
+    Ok(())
+}
+
+fn check_dot(mat1: &Matrix, mat2: &Matrix) {
+    if let Err(err) = try_check_dot(mat1, mat2) {
+        panic!("{}", err);
+    }
+}
+
+fn try_check_dot(mat1: &Matrix, mat2: &Matrix) -> Result<(), MathError> {
+    if mat1.rows() != mat2.cols() {
+        return Err(MathError::MatrixShape {
+            expected: mat1.rows(),
+            got: mat2.cols(),
+        });
     }
+    Ok(())
 }
@@ -0,0 +1,219 @@
+//! matrices over the integers modulo a prime `P`
+
+use crate::number::ModInt;
+
+/// a matrix of [`ModInt`] values, the indices work like `Matrix::index`
+///
+/// ## Example
+///
+/// ```rust
+/// use math::linear_algebra::modular::ModMatrix;
+/// let matrix = ModMatrix::<7>::new(vec![vec![1, 2], vec![3, 4]]);
+/// // the determinant is -2 = 5 mod 7
+/// assert_eq!(matrix.det().value(), 5);
+/// let inverse = matrix.inverse().unwrap();
+/// assert_eq!(matrix.dot_mat(&inverse), ModMatrix::identity(2));
+/// ```
+#[derive(PartialEq, Eq, Hash, Clone, Debug)]
+pub struct ModMatrix<const P: u64> {
+    cols: usize,
+    rows: usize,
+    data: Vec<ModInt<P>>,
+}
+
+impl<const P: u64> ModMatrix<P> {
+    /// creates a matrix from the values, the outer vec are the cols like in `Matrix::new`,
+    /// the values are reduced modulo `P`
+    ///
+    /// note it panics if the inner vecs don't have the same len
+    pub fn new(vec: Vec<Vec<u64>>) -> Self {
+        let rows = vec.first().map_or(0, |v| v.len());
+        let mut data = Vec::with_capacity(vec.len() * rows);
+        for line in vec.iter() {
+            if line.len() != rows {
+                panic!("wrong row shape expected {}, got {}", rows, line.len());
+            }
+            data.extend(line.iter().map(|&x| ModInt::new(x)));
+        }
+        ModMatrix {
+            cols: vec.len(),
+            rows,
+            data,
+        }
+    }
+
+    /// creates a matrix where all values are 0
+    pub fn new_zero(cols: usize, rows: usize) -> Self {
+        ModMatrix {
+            cols,
+            rows,
+            data: vec![ModInt::new(0); cols * rows],
+        }
+    }
+
+    /// creates the `n`x`n` identity matrix
+    pub fn identity(n: usize) -> Self {
+        let mut matrix = ModMatrix::new_zero(n, n);
+        for i in 0..n {
+            matrix.set_index(i, i, ModInt::new(1));
+        }
+        matrix
+    }
+
+    /// return the length of the columns like `Matrix::cols`
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// return the length of the rows like `Matrix::rows`
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// returns the value at index(i, j)
+    pub fn index(&self, i: usize, j: usize) -> ModInt<P> {
+        self.data[i * self.rows + j]
+    }
+
+    /// sets the value at index(i, j)
+    pub fn set_index(&mut self, i: usize, j: usize, val: ModInt<P>) {
+        self.data[i * self.rows + j] = val;
+    }
+
+    /// returns the transposed matrix
+    pub fn transpose(&self) -> Self {
+        let mut result = ModMatrix::new_zero(self.rows, self.cols);
+        for i in 0..self.cols {
+            for j in 0..self.rows {
+                result.set_index(j, i, self.index(i, j));
+            }
+        }
+        result
+    }
+
+    /// computes the product with the vector like `Matrix::dot_vec`
+    ///
+    /// note it panics if the vector has the wrong len
+    pub fn dot_vec(&self, vector: &[ModInt<P>]) -> Vec<ModInt<P>> {
+        if vector.len() != self.rows {
+            panic!(
+                "wrong vector shape expected {}, got {}",
+                self.rows,
+                vector.len()
+            );
+        }
+        (0..self.cols)
+            .map(|i| {
+                (0..self.rows).fold(ModInt::new(0), |sum, j| sum + self.index(i, j) * vector[j])
+            })
+            .collect()
+    }
+
+    /// computes the matrix product like `Matrix::dot_mat`
+    ///
+    /// note it panics if the shapes don't match
+    pub fn dot_mat(&self, other: &Self) -> Self {
+        if self.rows != other.cols {
+            panic!(
+                "wrong matrix shape expected {} cols, got {}",
+                self.rows, other.cols
+            );
+        }
+        let mut result = ModMatrix::new_zero(self.cols, other.rows);
+        for i in 0..self.cols {
+            for k in 0..self.rows {
+                let a = self.index(i, k);
+                for j in 0..other.rows {
+                    let val = result.index(i, j) + a * other.index(k, j);
+                    result.set_index(i, j, val);
+                }
+            }
+        }
+        result
+    }
+
+    fn check_square(&self) {
+        if self.cols != self.rows {
+            panic!("the matrix has to be a square matrix");
+        }
+    }
+
+    fn swap_lines(&mut self, a: usize, b: usize) {
+        for j in 0..self.rows {
+            self.data.swap(a * self.rows + j, b * self.rows + j);
+        }
+    }
+
+    // eliminates the entries below and above each pivot and applies the same operations to `other`,
+    // returns the determinant, which is 0 if the matrix is singular
+    fn eliminate(&mut self, mut other: Option<&mut Self>) -> ModInt<P> {
+        let n = self.cols;
+        let mut det = ModInt::new(1);
+        for k in 0..n {
+            let pivot = match (k..n).find(|&i| self.index(i, k).value() != 0) {
+                Some(pivot) => pivot,
+                None => return ModInt::new(0),
+            };
+            if pivot != k {
+                self.swap_lines(k, pivot);
+                if let Some(other) = other.as_deref_mut() {
+                    other.swap_lines(k, pivot);
+                }
+                det = -det;
+            }
+            let p = self.index(k, k);
+            det *= p;
+            let inv = p.inv();
+            for i in 0..n {
+                if i == k {
+                    continue;
+                }
+                let factor = self.index(i, k) * inv;
+                if factor.value() == 0 {
+                    continue;
+                }
+                for j in 0..self.rows {
+                    let val = self.index(i, j) - factor * self.index(k, j);
+                    self.set_index(i, j, val);
+                }
+                if let Some(other) = other.as_deref_mut() {
+                    for j in 0..other.rows {
+                        let val = other.index(i, j) - factor * other.index(k, j);
+                        other.set_index(i, j, val);
+                    }
+                }
+            }
+        }
+        det
+    }
+
+    /// computes the [determinant] modulo `P` with Gaussian elimination
+    ///
+    /// [determinant]: https://en.wikipedia.org/wiki/Determinant
+    ///
+    /// note it panics if the matrix is not square
+    pub fn det(&self) -> ModInt<P> {
+        self.check_square();
+        self.clone().eliminate(None)
+    }
+
+    /// returns the inverse modulo `P` or `None` if the matrix is singular
+    ///
+    /// note it panics if the matrix is not square
+    pub fn inverse(&self) -> Option<Self> {
+        self.check_square();
+        let mut reduced = self.clone();
+        let mut inverse = ModMatrix::identity(self.cols);
+        if reduced.eliminate(Some(&mut inverse)).value() == 0 {
+            return None;
+        }
+        // every pivot is now on the diagonal
+        for i in 0..self.cols {
+            let inv = reduced.index(i, i).inv();
+            for j in 0..self.cols {
+                inverse.set_index(i, j, inverse.index(i, j) * inv);
+            }
+        }
+        Some(inverse)
+    }
+}
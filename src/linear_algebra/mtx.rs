@@ -0,0 +1,285 @@
+//! reading and writing [Matrix Market] (`.mtx`) files
+//!
+//! the entry `(i, j)` of the file is `index(i - 1, j - 1)`, so the Matrix Market rows are
+//! the cols like in `Matrix::new`
+//!
+//! both the `array` (dense) and the `coordinate` (sparse) format can be read with the fields
+//! `real`, `integer` and `pattern` and the symmetries `general`, `symmetric` and `skew-symmetric`.
+//! a [`Matrix`] is written in the `array` format and a [`SparseMatrix`] in the `coordinate` format
+//!
+//! [Matrix Market]: https://math.nist.gov/MatrixMarket/formats.html
+//!
+//! ## Example
+//!
+//! ```rust
+//! use math::linear_algebra::sparse::SparseMatrix;
+//! use math::linear_algebra::Matrix;
+//! let data = "%%MatrixMarket matrix coordinate real symmetric
+//! % the 2x2 laplacian
+//! 2 2 3
+//! 1 1 2.0
+//! 2 1 -1.0
+//! 2 2 2.0
+//! ";
+//! let sparse = SparseMatrix::from_mtx_reader(data.as_bytes()).unwrap();
+//! assert_eq!(sparse.nnz(), 4);
+//! assert_eq!(sparse.to_dense(), Matrix::new(vec![vec![2., -1.], vec![-1., 2.]]));
+//! ```
+
+use crate::linear_algebra::sparse::SparseMatrix;
+use crate::linear_algebra::Matrix;
+use std::fs;
+use std::io;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::path::Path;
+
+const BANNER: &str = "%%MatrixMarket";
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+enum Symmetry {
+    General,
+    Symmetric,
+    SkewSymmetric,
+}
+
+fn invalid(msg: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+// reads the entries of the array or coordinate format, the mirrored values of symmetric matrices are included
+fn decode<R: Read>(reader: R) -> io::Result<SparseMatrix> {
+    let mut lines = BufReader::new(reader).lines().enumerate();
+    let banner = match lines.next() {
+        Some((_, line)) => line?,
+        None => return Err(invalid("the mtx data is empty".to_string())),
+    };
+    let words: Vec<String> = banner
+        .split_whitespace()
+        .map(|word| word.to_lowercase())
+        .collect();
+    if words.len() != 5 || !words[0].eq_ignore_ascii_case(BANNER) || words[1] != "matrix" {
+        return Err(invalid(format!(
+            "{:?} is not a matrix market header",
+            banner
+        )));
+    }
+    let coordinate = match words[2].as_str() {
+        "coordinate" => true,
+        "array" => false,
+        format => return Err(invalid(format!("unsupported format {}", format))),
+    };
+    let pattern = match words[3].as_str() {
+        "real" | "integer" => false,
+        "pattern" if coordinate => true,
+        field => return Err(invalid(format!("unsupported field {}", field))),
+    };
+    let symmetry = match words[4].as_str() {
+        "general" => Symmetry::General,
+        "symmetric" | "hermitian" => Symmetry::Symmetric,
+        "skew-symmetric" => Symmetry::SkewSymmetric,
+        symmetry => return Err(invalid(format!("unsupported symmetry {}", symmetry))),
+    };
+
+    // the size line and the entries, without comments and empty lines
+    let mut entries = lines.filter_map(|(n, line)| match line {
+        Ok(line) if line.trim().is_empty() || line.starts_with('%') => None,
+        line => Some(line.map(|line| (n + 1, line))),
+    });
+    let number = |n: usize, word: &str| {
+        word.parse::<f32>()
+            .map_err(|_| invalid(format!("{:?} in line {} is not a number", word, n)))
+    };
+    let index = |n: usize, word: &str, len: usize| match word.parse::<usize>() {
+        Ok(i) if i >= 1 && i <= len => Ok(i - 1),
+        _ => Err(invalid(format!(
+            "{:?} in line {} is not an index between 1 and {}",
+            word, n, len
+        ))),
+    };
+
+    let (n, size) = entries
+        .next()
+        .ok_or_else(|| invalid("the mtx data has no size line".to_string()))??;
+    let size: Vec<&str> = size.split_whitespace().collect();
+    let expected = if coordinate { 3 } else { 2 };
+    if size.len() != expected {
+        return Err(invalid(format!(
+            "the size line {} has {} values, expected {}",
+            n,
+            size.len(),
+            expected
+        )));
+    }
+    let dim = |word: &str| match word.parse::<usize>() {
+        Ok(d) if d > 0 => Ok(d),
+        _ => Err(invalid(format!(
+            "{:?} in line {} is not a dimension",
+            word, n
+        ))),
+    };
+    let (m, cols) = (dim(size[0])?, dim(size[1])?);
+    if symmetry != Symmetry::General && m != cols {
+        return Err(invalid(format!(
+            "a {} matrix has to be square, got {}x{}",
+            words[4], m, cols
+        )));
+    }
+
+    let mut triplets = Vec::new();
+    if coordinate {
+        let nnz: usize = size[2]
+            .parse()
+            .map_err(|_| invalid(format!("{:?} is not a number of entries", size[2])))?;
+        for _ in 0..nnz {
+            let (n, line) = entries
+                .next()
+                .ok_or_else(|| invalid(format!("the mtx data has less then {} entries", nnz)))??;
+            let words: Vec<&str> = line.split_whitespace().collect();
+            let expected = if pattern { 2 } else { 3 };
+            if words.len() != expected {
+                return Err(invalid(format!(
+                    "line {} has {} values, expected {}",
+                    n,
+                    words.len(),
+                    expected
+                )));
+            }
+            let val = if pattern { 1. } else { number(n, words[2])? };
+            triplets.push((index(n, words[0], m)?, index(n, words[1], cols)?, val));
+        }
+    } else {
+        // the values are stored column major, symmetric matrices only store the lower triangle
+        for j in 0..cols {
+            let start = match symmetry {
+                Symmetry::General => 0,
+                Symmetry::Symmetric => j,
+                Symmetry::SkewSymmetric => j + 1,
+            };
+            for i in start..m {
+                let (n, line) = entries
+                    .next()
+                    .ok_or_else(|| invalid("the mtx data has not enough values".to_string()))??;
+                triplets.push((i, j, number(n, line.trim())?));
+            }
+        }
+    }
+
+    let mirrored: Vec<(usize, usize, f32)> = triplets
+        .iter()
+        .filter(|&&(i, j, _)| i != j)
+        .filter_map(|&(i, j, val)| match symmetry {
+            Symmetry::General => None,
+            Symmetry::Symmetric => Some((j, i, val)),
+            Symmetry::SkewSymmetric => Some((j, i, -val)),
+        })
+        .collect();
+    triplets.extend(mirrored);
+    Ok(SparseMatrix::from_triplets(m, cols, &triplets))
+}
+
+impl Matrix {
+    /// reads a matrix from Matrix Market data in the `array` or `coordinate` format
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Matrix;
+    /// let data = "%%MatrixMarket matrix array real general\n2 3\n1\n4\n2\n5\n3\n6\n";
+    /// assert_eq!(
+    ///     Matrix::from_mtx_reader(data.as_bytes()).unwrap(),
+    ///     Matrix::new(vec![vec![1., 2., 3.], vec![4., 5., 6.]])
+    /// );
+    /// ```
+    /// note it returns an error of the kind `InvalidData` if the data is not a real matrix market file
+    pub fn from_mtx_reader<R: Read>(reader: R) -> io::Result<Matrix> {
+        Ok(decode(reader)?.to_dense())
+    }
+
+    /// writes the matrix in the `array` format with the field `real`
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Matrix;
+    /// let matrix = Matrix::new(vec![vec![1., 2.5]]);
+    /// let mut out = Vec::new();
+    /// matrix.to_mtx_writer(&mut out).unwrap();
+    /// assert_eq!(
+    ///     String::from_utf8(out).unwrap(),
+    ///     "%%MatrixMarket matrix array real general\n1 2\n1\n2.5\n"
+    /// );
+    /// ```
+    pub fn to_mtx_writer<W: Write>(&self, writer: W) -> io::Result<()> {
+        let mut writer = io::BufWriter::new(writer);
+        writeln!(writer, "{} matrix array real general", BANNER)?;
+        writeln!(writer, "{} {}", self.cols(), self.rows())?;
+        for j in 0..self.rows() {
+            for i in 0..self.cols() {
+                writeln!(writer, "{}", self.index(i, j))?;
+            }
+        }
+        writer.flush()
+    }
+
+    /// reads a matrix from a `.mtx` file, see [`from_mtx_reader`]
+    ///
+    /// [`from_mtx_reader`]: Matrix::from_mtx_reader
+    pub fn read_mtx<P: AsRef<Path>>(path: P) -> io::Result<Matrix> {
+        Matrix::from_mtx_reader(fs::File::open(path)?)
+    }
+
+    /// writes the matrix to a `.mtx` file, see [`to_mtx_writer`]
+    ///
+    /// [`to_mtx_writer`]: Matrix::to_mtx_writer
+    pub fn write_mtx<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        self.to_mtx_writer(fs::File::create(path)?)
+    }
+}
+
+impl SparseMatrix {
+    /// reads a sparse matrix from Matrix Market data in the `coordinate` or `array` format,
+    /// the values of duplicate entries are added up like in `from_triplets`
+    ///
+    /// note it returns an error of the kind `InvalidData` if the data is not a real matrix market file
+    pub fn from_mtx_reader<R: Read>(reader: R) -> io::Result<SparseMatrix> {
+        decode(reader)
+    }
+
+    /// writes the matrix in the `coordinate` format with the field `real`
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::sparse::SparseMatrix;
+    /// let sparse = SparseMatrix::from_triplets(2, 3, &[(1, 2, -1.5), (0, 0, 2.)]);
+    /// let mut out = Vec::new();
+    /// sparse.to_mtx_writer(&mut out).unwrap();
+    /// assert_eq!(
+    ///     String::from_utf8(out).unwrap(),
+    ///     "%%MatrixMarket matrix coordinate real general\n2 3 2\n1 1 2\n2 3 -1.5\n"
+    /// );
+    /// ```
+    pub fn to_mtx_writer<W: Write>(&self, writer: W) -> io::Result<()> {
+        let mut writer = io::BufWriter::new(writer);
+        writeln!(writer, "{} matrix coordinate real general", BANNER)?;
+        writeln!(writer, "{} {} {}", self.cols(), self.rows(), self.nnz())?;
+        for (i, j, val) in self.triplets() {
+            writeln!(writer, "{} {} {}", i + 1, j + 1, val)?;
+        }
+        writer.flush()
+    }
+
+    /// reads a sparse matrix from a `.mtx` file, see [`from_mtx_reader`]
+    ///
+    /// [`from_mtx_reader`]: SparseMatrix::from_mtx_reader
+    pub fn read_mtx<P: AsRef<Path>>(path: P) -> io::Result<SparseMatrix> {
+        SparseMatrix::from_mtx_reader(fs::File::open(path)?)
+    }
+
+    /// writes the sparse matrix to a `.mtx` file, see [`to_mtx_writer`]
+    ///
+    /// [`to_mtx_writer`]: SparseMatrix::to_mtx_writer
+    pub fn write_mtx<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        self.to_mtx_writer(fs::File::create(path)?)
+    }
+}
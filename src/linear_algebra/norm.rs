@@ -0,0 +1,85 @@
+//! [matrix norms] for convergence checks and error estimates
+//!
+//! like for `dot_vec` the matrix is read as `A[i][j] = index(i, j)`
+//!
+//! [matrix norms]: https://en.wikipedia.org/wiki/Matrix_norm
+
+use crate::linear_algebra::eigen::singular_values;
+use crate::linear_algebra::Matrix;
+
+/// the kind of norm computed by `Matrix::norm`
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum NormKind {
+    /// the square root of the sum of all squared components
+    Frobenius,
+    /// the maximum absolute column sum `max_j Σ_i |index(i, j)|`, the norm induced by the vector 1-norm
+    L1,
+    /// the maximum absolute row sum `max_i Σ_j |index(i, j)|`, the norm induced by the vector ∞-norm
+    LInf,
+    /// the largest singular value, the norm induced by the euclidean vector norm
+    Spectral,
+}
+
+impl Matrix {
+    /// computes the norm of the matrix, the norm of an empty matrix is 0
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::norm::NormKind;
+    /// use math::linear_algebra::Matrix;
+    /// let matrix = Matrix::new(vec![vec![1., -2.], vec![-3., 4.]]);
+    /// assert_eq!(matrix.norm(NormKind::L1), 6.);
+    /// assert_eq!(matrix.norm(NormKind::LInf), 7.);
+    /// assert!((matrix.norm(NormKind::Frobenius) - 30f32.sqrt()).abs() < 1e-6);
+    /// assert!((matrix.norm(NormKind::Spectral) - 5.4649857).abs() < 1e-5);
+    /// ```
+    pub fn norm(&self, kind: NormKind) -> f32 {
+        let (m, n) = (self.cols(), self.rows());
+        match kind {
+            NormKind::Frobenius => self
+                .iter_elems()
+                .map(|x| x as f64 * x as f64)
+                .sum::<f64>()
+                .sqrt() as f32,
+            NormKind::L1 => (0..n)
+                .map(|j| (0..m).map(|i| self.index(i, j).abs()).sum::<f32>())
+                .fold(0., f32::max),
+            NormKind::LInf => (0..m)
+                .map(|i| (0..n).map(|j| self.index(i, j).abs()).sum::<f32>())
+                .fold(0., f32::max),
+            NormKind::Spectral => singular_values(self).last().copied().unwrap_or(0.) as f32,
+        }
+    }
+
+    /// computes the [condition number] in the spectral norm, the ratio of the largest to the smallest singular value
+    ///
+    /// a large condition number means that `solve` and `inverse` can lose about `log10(cond)` digits,
+    /// a singular matrix has an infinite condition number, singular values below
+    /// `f32::EPSILON` times the largest one count as zero
+    ///
+    /// [condition number]: https://en.wikipedia.org/wiki/Condition_number
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Matrix;
+    /// let matrix = Matrix::new(vec![vec![2., 0.], vec![0., 0.5]]);
+    /// assert!((matrix.cond() - 4.).abs() < 1e-5);
+    /// let singular = Matrix::new(vec![vec![1., 2.], vec![2., 4.]]);
+    /// assert_eq!(singular.cond(), f32::INFINITY);
+    /// ```
+    /// note it panics if the matrix is empty
+    pub fn cond(&self) -> f32 {
+        let values = singular_values(self);
+        let (min, max) = match (values.first(), values.last()) {
+            (Some(&min), Some(&max)) => (min, max),
+            _ => panic!("the matrix has to have at least one component"),
+        };
+        // the components are f32, so a smaller singular value is only rounding noise of a zero
+        if min <= max * f32::EPSILON as f64 {
+            return f32::INFINITY;
+        }
+        (max / min) as f32
+    }
+}
@@ -0,0 +1,267 @@
+//! reading and writing NumPy [`.npy`] files
+//!
+//! a 2D array with the shape `(m, n)` is a matrix with `m` cols and `n` rows like in `Matrix::new`,
+//! so `np.array([[1, 2, 3], [4, 5, 6]])` is `Matrix::new(vec![vec![1., 2., 3.], vec![4., 5., 6.]])`
+//! and a 1D array is a vector
+//!
+//! the values are written as little endian `float32`, when reading every float, int, uint and bool
+//! dtype in both byte orders and both C and Fortran order is converted to `f32`
+//!
+//! [`.npy`]: https://numpy.org/doc/stable/reference/generated/numpy.lib.format.html
+//!
+//! ## Example
+//!
+//! ```rust
+//! use math::linear_algebra::{Matrix, Vector};
+//! let matrix = Matrix::new(vec![vec![1., 2., 3.], vec![4., 5., 6.]]);
+//! assert_eq!(Matrix::from_npy(&matrix.to_npy()).unwrap(), matrix);
+//! let vector = Vector::new(vec![1., -2.]);
+//! assert_eq!(Vector::from_npy(&vector.to_npy()).unwrap(), vector);
+//! ```
+
+use crate::linear_algebra::{Matrix, Vector};
+use std::fs;
+use std::io;
+use std::path::Path;
+
+const MAGIC: &[u8] = b"\x93NUMPY";
+
+fn invalid(msg: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+// encodes the values in C order as a version 1.0 npy file with the dtype `<f4`
+fn encode(shape: &[usize], values: impl Iterator<Item = f32>) -> Vec<u8> {
+    let shape = match shape {
+        [len] => format!("({},)", len),
+        _ => format!(
+            "({})",
+            shape
+                .iter()
+                .map(|x| x.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    };
+    let mut header = format!(
+        "{{'descr': '<f4', 'fortran_order': False, 'shape': {}, }}",
+        shape
+    );
+    // the data starts at a multiple of 64 bytes and the header ends with a newline
+    let len = MAGIC.len() + 4 + header.len() + 1;
+    header.push_str(&" ".repeat((64 - len % 64) % 64));
+    header.push('\n');
+
+    let mut bytes = MAGIC.to_vec();
+    bytes.extend_from_slice(&[1, 0]);
+    bytes.extend_from_slice(&(header.len() as u16).to_le_bytes());
+    bytes.extend_from_slice(header.as_bytes());
+    values.for_each(|x| bytes.extend_from_slice(&x.to_le_bytes()));
+    bytes
+}
+
+// decodes a npy file, returns the shape and the values in C order
+fn decode(bytes: &[u8]) -> io::Result<(Vec<usize>, Vec<f32>)> {
+    if !bytes.starts_with(MAGIC) || bytes.len() < 10 {
+        return Err(invalid("the data is not a npy file".to_string()));
+    }
+    let (header_len, start) = match bytes[6] {
+        1 => (u16::from_le_bytes([bytes[8], bytes[9]]) as usize, 10),
+        2 | 3 if bytes.len() >= 12 => (
+            u32::from_le_bytes([bytes[8], bytes[9], bytes[10], bytes[11]]) as usize,
+            12,
+        ),
+        version => return Err(invalid(format!("unsupported npy version {}", version))),
+    };
+    let header = bytes
+        .get(start..start + header_len)
+        .ok_or_else(|| invalid("unexpected end of the header".to_string()))?;
+    let header = std::str::from_utf8(header)
+        .map_err(|_| invalid("the npy header is not utf8".to_string()))?;
+
+    let descr = field(header, "descr")?;
+    let descr = descr
+        .strip_prefix(&['\'', '"'][..])
+        .and_then(|d| d.split(&['\'', '"'][..]).next())
+        .ok_or_else(|| invalid(format!("the descr {} is not a string", descr)))?;
+    let fortran_order = match field(header, "fortran_order")? {
+        f if f.starts_with("True") => true,
+        f if f.starts_with("False") => false,
+        f => return Err(invalid(format!("the fortran_order {} is not a bool", f))),
+    };
+    let shape = field(header, "shape")?;
+    let shape = shape
+        .strip_prefix('(')
+        .and_then(|s| s.split(')').next())
+        .ok_or_else(|| invalid(format!("the shape {} is not a tuple", shape)))?;
+    let shape = shape
+        .split(',')
+        .map(|x| x.trim())
+        .filter(|x| !x.is_empty())
+        .map(|x| {
+            x.parse::<usize>()
+                .map_err(|_| invalid(format!("{:?} is not a dimension", x)))
+        })
+        .collect::<io::Result<Vec<usize>>>()?;
+
+    let data = &bytes[start + header_len..];
+    let len = shape
+        .iter()
+        .try_fold(1usize, |len, &dim| len.checked_mul(dim))
+        .ok_or_else(|| invalid(format!("the shape {:?} is too large", shape)))?;
+    let mut values = values(descr, data, len)?;
+    if fortran_order && shape.len() == 2 {
+        let (m, n) = (shape[0], shape[1]);
+        let mut c_order = vec![0.; len];
+        for (k, val) in values.into_iter().enumerate() {
+            c_order[(k % m) * n + k / m] = val;
+        }
+        values = c_order;
+    }
+    Ok((shape, values))
+}
+
+// returns the text of the value of the key in the header dict
+fn field<'a>(header: &'a str, key: &str) -> io::Result<&'a str> {
+    [format!("'{}'", key), format!("\"{}\"", key)]
+        .iter()
+        .find_map(|quoted| header.find(quoted.as_str()).map(|pos| pos + quoted.len()))
+        .and_then(|pos| header[pos..].trim_start().strip_prefix(':'))
+        .map(|value| value.trim_start())
+        .ok_or_else(|| invalid(format!("the npy header has no {}", key)))
+}
+
+// converts len values of the dtype descr to f32
+fn values(descr: &str, data: &[u8], len: usize) -> io::Result<Vec<f32>> {
+    let unsupported = || invalid(format!("unsupported dtype {}", descr));
+    let mut chars = descr.chars();
+    let big_endian = match chars.next() {
+        Some('>') => true,
+        Some('<') | Some('|') => false,
+        Some('=') => cfg!(target_endian = "big"),
+        _ => return Err(unsupported()),
+    };
+    let kind = chars.next().ok_or_else(unsupported)?;
+    let size: usize = chars.as_str().parse().map_err(|_| unsupported())?;
+    if !matches!(
+        (kind, size),
+        ('f', 4) | ('f', 8) | ('i', 1 | 2 | 4 | 8) | ('u', 1 | 2 | 4 | 8) | ('b', 1)
+    ) {
+        return Err(unsupported());
+    }
+
+    // len * size can only overflow if the array is larger then any slice
+    let expected = len.saturating_mul(size);
+    if data.len() < expected {
+        return Err(invalid(format!(
+            "the npy file has {} bytes of data, expected {}",
+            data.len(),
+            expected
+        )));
+    }
+    Ok(data
+        .chunks_exact(size)
+        .take(len)
+        .map(|chunk| {
+            let mut buf = [0; 8];
+            buf[..size].copy_from_slice(chunk);
+            if big_endian {
+                buf[..size].reverse();
+            }
+            let signed = |bits: u32| (u64::from_le_bytes(buf) << (64 - bits)) as i64 >> (64 - bits);
+            match (kind, size) {
+                ('f', 4) => f32::from_le_bytes([buf[0], buf[1], buf[2], buf[3]]),
+                ('f', _) => f64::from_le_bytes(buf) as f32,
+                ('i', _) => signed(size as u32 * 8) as f32,
+                _ => u64::from_le_bytes(buf) as f32,
+            }
+        })
+        .collect())
+}
+
+impl Matrix {
+    /// decodes a 2D array from the bytes of a `.npy` file
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Matrix;
+    /// let mut bytes = b"\x93NUMPY\x01\x00\x76\x00".to_vec();
+    /// let header = "{'descr': '<i2', 'fortran_order': True, 'shape': (2, 3), }";
+    /// bytes.extend_from_slice(format!("{:<117}\n", header).as_bytes());
+    /// [1i16, 4, 2, 5, 3, 6].iter().for_each(|x| bytes.extend_from_slice(&x.to_le_bytes()));
+    /// assert_eq!(
+    ///     Matrix::from_npy(&bytes).unwrap(),
+    ///     Matrix::new(vec![vec![1., 2., 3.], vec![4., 5., 6.]])
+    /// );
+    /// ```
+    /// note it returns an error of the kind `InvalidData` if the array is not 2D or the dtype is not supported
+    pub fn from_npy(bytes: &[u8]) -> io::Result<Matrix> {
+        match decode(bytes)? {
+            (shape, values) if shape.len() == 2 => {
+                Ok(Matrix::new_flatt(values, shape[0], shape[1]))
+            }
+            (shape, _) => Err(invalid(format!(
+                "the array has {} dimensions, expected 2",
+                shape.len()
+            ))),
+        }
+    }
+
+    /// encodes the matrix as a `.npy` file with the dtype `<f4`, the inverse of [`from_npy`]
+    ///
+    /// [`from_npy`]: Matrix::from_npy
+    pub fn to_npy(&self) -> Vec<u8> {
+        encode(&[self.cols(), self.rows()], self.iter_elems())
+    }
+
+    /// reads a 2D array from a `.npy` file, see [`from_npy`]
+    ///
+    /// [`from_npy`]: Matrix::from_npy
+    pub fn read_npy<P: AsRef<Path>>(path: P) -> io::Result<Matrix> {
+        Matrix::from_npy(&fs::read(path)?)
+    }
+
+    /// writes the matrix to a `.npy` file, see [`to_npy`]
+    ///
+    /// [`to_npy`]: Matrix::to_npy
+    pub fn write_npy<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        fs::write(path, self.to_npy())
+    }
+}
+
+impl Vector {
+    /// decodes a 1D array from the bytes of a `.npy` file
+    ///
+    /// note it returns an error of the kind `InvalidData` if the array is not 1D or the dtype is not supported
+    pub fn from_npy(bytes: &[u8]) -> io::Result<Vector> {
+        match decode(bytes)? {
+            (shape, values) if shape.len() == 1 => Ok(Vector::new(values)),
+            (shape, _) => Err(invalid(format!(
+                "the array has {} dimensions, expected 1",
+                shape.len()
+            ))),
+        }
+    }
+
+    /// encodes the vector as a `.npy` file with the dtype `<f4`, the inverse of [`from_npy`]
+    ///
+    /// [`from_npy`]: Vector::from_npy
+    pub fn to_npy(&self) -> Vec<u8> {
+        encode(&[self.len()], self.vec().into_iter())
+    }
+
+    /// reads a 1D array from a `.npy` file, see [`from_npy`]
+    ///
+    /// [`from_npy`]: Vector::from_npy
+    pub fn read_npy<P: AsRef<Path>>(path: P) -> io::Result<Vector> {
+        Vector::from_npy(&fs::read(path)?)
+    }
+
+    /// writes the vector to a `.npy` file, see [`to_npy`]
+    ///
+    /// [`to_npy`]: Vector::to_npy
+    pub fn write_npy<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        fs::write(path, self.to_npy())
+    }
+}
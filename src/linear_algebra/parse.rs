@@ -0,0 +1,149 @@
+//! parsing matrices and vectors from MATLAB-style strings
+//!
+//! the lines are separated by `;` or a newline and each line becomes a col like in `Matrix::new`,
+//! the values of a line are separated by whitespace or `,` and the whole matrix can be put in `[` `]`
+//!
+//! ## Example
+//!
+//! ```rust
+//! use math::linear_algebra::parse::ParseMatrixError;
+//! use math::linear_algebra::{Matrix, Vector};
+//! let matrix: Matrix = "[1 2 3; 4 5 6]".parse().unwrap();
+//! assert_eq!(matrix, Matrix::new(vec![vec![1., 2., 3.], vec![4., 5., 6.]]));
+//! assert_eq!("1, -2.5, 3e2".parse(), Ok(Vector::new(vec![1., -2.5, 300.])));
+//!
+//! let err = "1 2; 3 x".parse::<Matrix>().unwrap_err();
+//! assert_eq!(err, ParseMatrixError::InvalidNumber { value: "x".to_string(), pos: 7 });
+//! assert_eq!(err.to_string(), "\"x\" at position 7 is not a number");
+//! ```
+
+use crate::linear_algebra::{Matrix, Vector};
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// the reason a string could not be parsed into a `Matrix` or `Vector`
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub enum ParseMatrixError {
+    /// the value at the byte position `pos` is not a number
+    InvalidNumber { value: String, pos: usize },
+    /// the line (starting at 1) has not the same number of values as the first line
+    LineLen {
+        line: usize,
+        expected: usize,
+        got: usize,
+    },
+    /// the string starts with `[` but doesn't end with `]`
+    MissingBracket,
+    /// the string contains no values
+    Empty,
+    /// a vector has to be a single line or a single value in each line
+    NotVector,
+}
+
+impl fmt::Display for ParseMatrixError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseMatrixError::InvalidNumber { value, pos } => {
+                write!(f, "{:?} at position {} is not a number", value, pos)
+            }
+            ParseMatrixError::LineLen {
+                line,
+                expected,
+                got,
+            } => write!(f, "line {} has {} values, expected {}", line, got, expected),
+            ParseMatrixError::MissingBracket => write!(f, "the closing ] is missing"),
+            ParseMatrixError::Empty => write!(f, "there are no values"),
+            ParseMatrixError::NotVector => {
+                write!(
+                    f,
+                    "a vector has to be a single line or a single value per line"
+                )
+            }
+        }
+    }
+}
+
+impl Error for ParseMatrixError {}
+
+// splits the string into lines of values
+fn parse_lines(s: &str) -> Result<Vec<Vec<f32>>, ParseMatrixError> {
+    let start = s.len() - s.trim_start().len();
+    let mut end = s.trim_end().len();
+    let mut pos = start;
+    if s[start..].starts_with('[') {
+        if !s[..end].ends_with(']') {
+            return Err(ParseMatrixError::MissingBracket);
+        }
+        pos += 1;
+        end -= 1;
+    }
+
+    let is_separator = |c: char| c.is_whitespace() || c == ',' || c == ';';
+    let mut lines = Vec::new();
+    let mut line = Vec::new();
+    while pos < end {
+        let c = s[pos..].chars().next().unwrap();
+        if c == ';' || c == '\n' {
+            if !line.is_empty() {
+                lines.push(std::mem::take(&mut line));
+            }
+            pos += 1;
+        } else if is_separator(c) {
+            pos += c.len_utf8();
+        } else {
+            let len = s[pos..end].find(is_separator).unwrap_or(end - pos);
+            let value = &s[pos..pos + len];
+            line.push(
+                value
+                    .parse::<f32>()
+                    .map_err(|_| ParseMatrixError::InvalidNumber {
+                        value: value.to_string(),
+                        pos,
+                    })?,
+            );
+            pos += len;
+        }
+    }
+    if !line.is_empty() {
+        lines.push(line);
+    }
+
+    match lines.first() {
+        None => Err(ParseMatrixError::Empty),
+        Some(first) => {
+            let expected = first.len();
+            match lines.iter().position(|line| line.len() != expected) {
+                Some(k) => Err(ParseMatrixError::LineLen {
+                    line: k + 1,
+                    expected,
+                    got: lines[k].len(),
+                }),
+                None => Ok(lines),
+            }
+        }
+    }
+}
+
+impl FromStr for Matrix {
+    type Err = ParseMatrixError;
+
+    fn from_str(s: &str) -> Result<Matrix, ParseMatrixError> {
+        Ok(Matrix::new(parse_lines(s)?))
+    }
+}
+
+impl FromStr for Vector {
+    type Err = ParseMatrixError;
+
+    fn from_str(s: &str) -> Result<Vector, ParseMatrixError> {
+        let lines = parse_lines(s)?;
+        if lines.len() == 1 {
+            Ok(Vector::new(lines.into_iter().next().unwrap()))
+        } else if lines[0].len() == 1 {
+            Ok(lines.into_iter().map(|line| line[0]).collect())
+        } else {
+            Err(ParseMatrixError::NotVector)
+        }
+    }
+}
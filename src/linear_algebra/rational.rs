@@ -0,0 +1,267 @@
+//! matrices of exact rational numbers
+
+use crate::linear_algebra::Matrix;
+use crate::number::Rational;
+
+/// a matrix of [`Rational`] values, the indices work like `Matrix::index`
+///
+/// all operations are exact, so there are no rounding errors (but the numbers can overflow)
+///
+/// ## Example
+///
+/// ```rust
+/// use math::linear_algebra::rational::RationalMatrix;
+/// use math::number::Rational;
+/// let matrix = RationalMatrix::from_ints(vec![vec![2, 1], vec![1, 3]]);
+/// assert_eq!(matrix.det(), Rational::from(5));
+/// let inverse = matrix.inverse().unwrap();
+/// assert_eq!(inverse.index(0, 0), Rational::new(3, 5));
+/// assert_eq!(matrix.dot_mat(&inverse), RationalMatrix::identity(2));
+/// ```
+#[derive(PartialEq, Eq, Hash, Clone, Debug)]
+pub struct RationalMatrix {
+    cols: usize,
+    rows: usize,
+    data: Vec<Rational>,
+}
+
+impl RationalMatrix {
+    /// creates a matrix from the values, the outer vec are the cols like in `Matrix::new`
+    ///
+    /// note it panics if the inner vecs don't have the same len
+    pub fn new(vec: Vec<Vec<Rational>>) -> Self {
+        let rows = vec.first().map_or(0, |v| v.len());
+        let mut data = Vec::with_capacity(vec.len() * rows);
+        for line in vec.iter() {
+            if line.len() != rows {
+                panic!("wrong row shape expected {}, got {}", rows, line.len());
+            }
+            data.extend_from_slice(line);
+        }
+        RationalMatrix {
+            cols: vec.len(),
+            rows,
+            data,
+        }
+    }
+
+    /// same as `new` for integer values
+    pub fn from_ints(vec: Vec<Vec<i64>>) -> Self {
+        RationalMatrix::new(
+            vec.into_iter()
+                .map(|line| line.into_iter().map(Rational::from).collect())
+                .collect(),
+        )
+    }
+
+    /// creates a matrix where all values are 0
+    pub fn new_zero(cols: usize, rows: usize) -> Self {
+        RationalMatrix {
+            cols,
+            rows,
+            data: vec![Rational::from(0); cols * rows],
+        }
+    }
+
+    /// creates the `n`x`n` identity matrix
+    pub fn identity(n: usize) -> Self {
+        let mut matrix = RationalMatrix::new_zero(n, n);
+        for i in 0..n {
+            matrix.set_index(i, i, Rational::from(1));
+        }
+        matrix
+    }
+
+    /// return the length of the columns like `Matrix::cols`
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// return the length of the rows like `Matrix::rows`
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// returns the value at index(i, j)
+    pub fn index(&self, i: usize, j: usize) -> Rational {
+        self.data[i * self.rows + j]
+    }
+
+    /// sets the value at index(i, j)
+    pub fn set_index(&mut self, i: usize, j: usize, val: Rational) {
+        self.data[i * self.rows + j] = val;
+    }
+
+    /// converts the matrix to the nearest `f32` values
+    pub fn to_matrix(&self) -> Matrix {
+        Matrix::new_flatt(
+            self.data.iter().map(|x| x.to_f32()).collect(),
+            self.cols,
+            self.rows,
+        )
+    }
+
+    /// returns the transposed matrix
+    pub fn transpose(&self) -> Self {
+        let mut result = RationalMatrix::new_zero(self.rows, self.cols);
+        for i in 0..self.cols {
+            for j in 0..self.rows {
+                result.set_index(j, i, self.index(i, j));
+            }
+        }
+        result
+    }
+
+    /// computes the product with the vector like `Matrix::dot_vec`
+    ///
+    /// note it panics if the vector has the wrong len
+    pub fn dot_vec(&self, vector: &[Rational]) -> Vec<Rational> {
+        if vector.len() != self.rows {
+            panic!(
+                "wrong vector shape expected {}, got {}",
+                self.rows,
+                vector.len()
+            );
+        }
+        (0..self.cols)
+            .map(|i| {
+                (0..self.rows).fold(Rational::from(0), |sum, j| {
+                    sum + self.index(i, j) * vector[j]
+                })
+            })
+            .collect()
+    }
+
+    /// computes the matrix product like `Matrix::dot_mat`
+    ///
+    /// note it panics if the shapes don't match
+    pub fn dot_mat(&self, other: &Self) -> Self {
+        if self.rows != other.cols {
+            panic!(
+                "wrong matrix shape expected {} cols, got {}",
+                self.rows, other.cols
+            );
+        }
+        let mut result = RationalMatrix::new_zero(self.cols, other.rows);
+        for i in 0..self.cols {
+            for k in 0..self.rows {
+                let a = self.index(i, k);
+                if a.is_zero() {
+                    continue;
+                }
+                for j in 0..other.rows {
+                    let val = result.index(i, j) + a * other.index(k, j);
+                    result.set_index(i, j, val);
+                }
+            }
+        }
+        result
+    }
+
+    fn check_square(&self) {
+        if self.cols != self.rows {
+            panic!("the matrix has to be a square matrix");
+        }
+    }
+
+    fn swap_lines(&mut self, a: usize, b: usize) {
+        for j in 0..self.rows {
+            self.data.swap(a * self.rows + j, b * self.rows + j);
+        }
+    }
+
+    // Gauss-Jordan elimination which applies the same operations to `other`,
+    // afterwards self is the identity if the matrix isn't singular. returns the determinant
+    fn eliminate(&mut self, mut other: Option<&mut Self>) -> Rational {
+        let n = self.cols;
+        let mut det = Rational::from(1);
+        for k in 0..n {
+            let pivot = match (k..n).find(|&i| !self.index(i, k).is_zero()) {
+                Some(pivot) => pivot,
+                None => return Rational::from(0),
+            };
+            if pivot != k {
+                self.swap_lines(k, pivot);
+                if let Some(other) = other.as_deref_mut() {
+                    other.swap_lines(k, pivot);
+                }
+                det = -det;
+            }
+            let p = self.index(k, k);
+            det *= p;
+            for j in 0..self.rows {
+                self.set_index(k, j, self.index(k, j) / p);
+            }
+            if let Some(other) = other.as_deref_mut() {
+                for j in 0..other.rows {
+                    other.set_index(k, j, other.index(k, j) / p);
+                }
+            }
+            for i in 0..n {
+                let factor = self.index(i, k);
+                if i == k || factor.is_zero() {
+                    continue;
+                }
+                for j in 0..self.rows {
+                    let val = self.index(i, j) - factor * self.index(k, j);
+                    self.set_index(i, j, val);
+                }
+                if let Some(other) = other.as_deref_mut() {
+                    for j in 0..other.rows {
+                        let val = other.index(i, j) - factor * other.index(k, j);
+                        other.set_index(i, j, val);
+                    }
+                }
+            }
+        }
+        det
+    }
+
+    /// computes the exact [determinant] with Gaussian elimination
+    ///
+    /// [determinant]: https://en.wikipedia.org/wiki/Determinant
+    ///
+    /// note it panics if the matrix is not square
+    pub fn det(&self) -> Rational {
+        self.check_square();
+        self.clone().eliminate(None)
+    }
+
+    /// returns the exact inverse or `None` if the matrix is singular
+    ///
+    /// note it panics if the matrix is not square
+    pub fn inverse(&self) -> Option<Self> {
+        self.check_square();
+        let mut inverse = RationalMatrix::identity(self.cols);
+        if self.clone().eliminate(Some(&mut inverse)).is_zero() {
+            None
+        } else {
+            Some(inverse)
+        }
+    }
+
+    /// solves `self.dot_vec(&x) == b` exactly and returns `x` or `None` if the matrix is singular
+    ///
+    /// note it panics if the matrix is not square or the vector has the wrong len
+    pub fn solve(&self, b: &[Rational]) -> Option<Vec<Rational>> {
+        self.check_square();
+        if b.len() != self.cols {
+            panic!("wrong vector shape expected {}, got {}", self.cols, b.len());
+        }
+        let mut rhs = RationalMatrix::new_zero(b.len(), 1);
+        for (i, &val) in b.iter().enumerate() {
+            rhs.set_index(i, 0, val);
+        }
+        if self.clone().eliminate(Some(&mut rhs)).is_zero() {
+            None
+        } else {
+            Some(rhs.data)
+        }
+    }
+}
+
+impl From<&RationalMatrix> for Matrix {
+    fn from(matrix: &RationalMatrix) -> Self {
+        matrix.to_matrix()
+    }
+}
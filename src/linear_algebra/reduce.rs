@@ -0,0 +1,100 @@
+use crate::linear_algebra::shape::Axis;
+use crate::linear_algebra::{Matrix, Vector};
+
+// computes ln(sum(exp(x))) by shifting with the max so exp can't overflow
+fn log_sum_exp<I: Iterator<Item = f32> + Clone>(iter: I) -> f32 {
+    let max = iter.clone().fold(f32::NEG_INFINITY, f32::max);
+    if max.is_infinite() {
+        // all values are -inf (or empty) or one is +inf, shifting would give NaN
+        return max;
+    }
+    max + iter.map(|x| (x - max).exp()).sum::<f32>().ln()
+}
+
+impl Vector {
+    /// computes `ln(sum(exp(x)))` without overflowing
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Vector;
+    /// let vector = Vector::new(vec![1000., 1000.]);
+    /// assert_eq!(vector.log_sum_exp(), 1000. + 2_f32.ln());
+    /// ```
+    /// note the `log_sum_exp` of an empty vector is `-inf`
+    pub fn log_sum_exp(&self) -> f32 {
+        log_sum_exp((0..self.len()).map(|i| self.index(i)))
+    }
+
+    /// returns the logarithm of the [softmax] of the vector `x - log_sum_exp(x)`
+    ///
+    /// [softmax]: https://en.wikipedia.org/wiki/Softmax_function
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Vector;
+    /// let log_p = Vector::new(vec![500., 500.]).log_softmax();
+    /// assert!((log_p.index(0) + 2_f32.ln()).abs() < 1e-4);
+    /// assert!((log_p.index(1) + 2_f32.ln()).abs() < 1e-4);
+    /// ```
+    pub fn log_softmax(&self) -> Vector {
+        let lse = self.log_sum_exp();
+        Vector::new((0..self.len()).map(|i| self.index(i) - lse).collect())
+    }
+}
+
+impl Matrix {
+    /// computes `ln(sum(exp(x)))` without overflowing for each col (`Axis::Col`) or each row (`Axis::Row`)
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::shape::Axis;
+    /// use math::linear_algebra::{Matrix, Vector};
+    /// let matrix = Matrix::new(vec![vec![0., 0.], vec![800., 800.]]);
+    /// assert_eq!(
+    ///     matrix.log_sum_exp(Axis::Col),
+    ///     Vector::new(vec![2_f32.ln(), 800. + 2_f32.ln()])
+    /// );
+    /// ```
+    /// note with `Axis::Col` the result has the len of the cols, with `Axis::Row` the len of the rows
+    pub fn log_sum_exp(&self, axis: Axis) -> Vector {
+        let lines = match axis {
+            Axis::Col => self.cols(),
+            Axis::Row => self.rows(),
+        };
+        Vector::new(
+            (0..lines)
+                .map(|k| match axis {
+                    Axis::Col => log_sum_exp((0..self.rows()).map(|j| self.index(k, j))),
+                    Axis::Row => log_sum_exp((0..self.cols()).map(|i| self.index(i, k))),
+                })
+                .collect(),
+        )
+    }
+
+    /// returns the logarithm of the [softmax] of each col (`Axis::Col`) or each row (`Axis::Row`)
+    ///
+    /// [softmax]: https://en.wikipedia.org/wiki/Softmax_function
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::shape::Axis;
+    /// use math::linear_algebra::Matrix;
+    /// let matrix = Matrix::new(vec![vec![1000., 1000.], vec![0., 0.]]);
+    /// let log_p = matrix.log_softmax(Axis::Col);
+    /// assert!(log_p.matrix_flatt().iter().all(|x| (x + 2_f32.ln()).abs() < 1e-4));
+    /// ```
+    pub fn log_softmax(&self, axis: Axis) -> Matrix {
+        let lse = self.log_sum_exp(axis);
+        let mut result = self.clone();
+        // one value per col has to be applied to every row and the other way around
+        match axis {
+            Axis::Col => result.sub_vec_axis(Axis::Row, &lse),
+            Axis::Row => result.sub_vec_axis(Axis::Col, &lse),
+        }
+        result
+    }
+}
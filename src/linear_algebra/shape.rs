@@ -0,0 +1,238 @@
+//! shapes and the broadcasting rules between matrices and vectors
+//!
+//! two shapes can be broadcast if each dimension is either the same or one of them is 1,
+//! the dimension of size 1 is then repeated (like in NumPy)
+//!
+//! a vector has no orientation on its own, an [`Axis`] says along which dimension it lies:
+//! - `Axis::Col` the vector has the len of the rows and is applied to every col
+//!   (shape `1x rows`), so `index(i, j)` is combined with `vector.index(j)`
+//! - `Axis::Row` the vector has the len of the cols and is applied to every row
+//!   (shape `cols x 1`), so `index(i, j)` is combined with `vector.index(i)`
+//!
+//! a [`StorageOrder`] says how the components are laid out in a flat buffer,
+//! `matrix_flatt` and `new_flatt` always use `StorageOrder::ColMajor`
+
+use crate::linear_algebra::{Matrix, Vector};
+use std::fmt;
+
+/// the number of cols and rows of a matrix
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct Shape {
+    pub cols: usize,
+    pub rows: usize,
+}
+
+/// the direction along which a vector lies
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Axis {
+    Row,
+    Col,
+}
+
+/// the layout of the components of a matrix in a flat buffer
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum StorageOrder {
+    /// the cols are contiguous, `index(i, j)` is at `i * rows + j` like in `matrix_flatt`
+    ColMajor,
+    /// the rows are contiguous, `index(i, j)` is at `j * cols + i`
+    RowMajor,
+}
+
+impl Shape {
+    /// creates a new shape
+    pub fn new(cols: usize, rows: usize) -> Self {
+        Self { cols, rows }
+    }
+
+    /// returns the number of components
+    pub fn len(&self) -> usize {
+        self.cols * self.rows
+    }
+
+    /// returns true if the shape has no components
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// returns the shape of a vector with the len `len` which lies along `axis`
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::shape::{Axis, Shape};
+    /// assert_eq!(Shape::vector(3, Axis::Col), Shape::new(1, 3));
+    /// assert_eq!(Shape::vector(3, Axis::Row), Shape::new(3, 1));
+    /// ```
+    pub fn vector(len: usize, axis: Axis) -> Self {
+        match axis {
+            Axis::Col => Self::new(1, len),
+            Axis::Row => Self::new(len, 1),
+        }
+    }
+
+    /// returns the shape both shapes broadcast to or `None` if they are not compatible
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::shape::Shape;
+    /// let shape = Shape::new(4, 3);
+    /// assert_eq!(shape.broadcast(&Shape::new(1, 3)), Some(shape));
+    /// assert_eq!(shape.broadcast(&Shape::new(4, 1)), Some(shape));
+    /// assert_eq!(Shape::new(4, 1).broadcast(&Shape::new(1, 3)), Some(shape));
+    /// assert_eq!(shape.broadcast(&Shape::new(2, 3)), None);
+    /// ```
+    pub fn broadcast(&self, other: &Shape) -> Option<Shape> {
+        let dim = |a: usize, b: usize| match (a, b) {
+            _ if a == b => Some(a),
+            (1, _) => Some(b),
+            (_, 1) => Some(a),
+            _ => None,
+        };
+        Some(Shape::new(
+            dim(self.cols, other.cols)?,
+            dim(self.rows, other.rows)?,
+        ))
+    }
+}
+
+impl fmt::Display for Shape {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}x{}", self.cols, self.rows)
+    }
+}
+
+impl Matrix {
+    /// returns the shape of the matrix
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::shape::Shape;
+    /// use math::linear_algebra::Matrix;
+    /// let matrix = Matrix::new(vec![vec![3., 2., 4.], vec![4., 5., 6.]]);
+    /// assert_eq!(matrix.shape(), Shape::new(2, 3));
+    /// ```
+    pub fn shape(&self) -> Shape {
+        Shape::new(self.cols(), self.rows())
+    }
+
+    /// returns a matrix with the same components in `matrix_flatt` order but with a new shape
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Matrix;
+    /// let matrix = Matrix::new(vec![vec![1., 2., 3.], vec![4., 5., 6.]]);
+    /// assert_eq!(
+    ///     matrix.reshape(3, 2),
+    ///     Matrix::new(vec![vec![1., 2.], vec![3., 4.], vec![5., 6.]])
+    /// );
+    /// ```
+    /// note it panics if `cols * rows` is not the number of components
+    pub fn reshape(&self, cols: usize, rows: usize) -> Matrix {
+        if cols * rows != self.cols() * self.rows() {
+            panic!(
+                "can't reshape a {} matrix to {}",
+                self.shape(),
+                Shape::new(cols, rows)
+            );
+        }
+        Matrix::new_flatt(self.matrix_flatt(), cols, rows)
+    }
+
+    /// returns the components in `matrix_flatt` order as a vector
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{Matrix, Vector};
+    /// let matrix = Matrix::new(vec![vec![1., 2.], vec![3., 4.]]);
+    /// assert_eq!(matrix.flatten(), Vector::new(vec![1., 2., 3., 4.]));
+    /// ```
+    pub fn flatten(&self) -> Vector {
+        Vector::new(self.matrix_flatt())
+    }
+
+    /// creates a matrix from a flat buffer in the storage order `order`
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::shape::StorageOrder;
+    /// use math::linear_algebra::Matrix;
+    /// let matrix = Matrix::from_flatt_order(vec![1., 4., 2., 5., 3., 6.], 2, 3, StorageOrder::RowMajor);
+    /// assert_eq!(matrix, Matrix::new(vec![vec![1., 2., 3.], vec![4., 5., 6.]]));
+    /// ```
+    /// note it panics if `cols * rows` is not the number of components
+    pub fn from_flatt_order(
+        matrix_flatt: Vec<f32>,
+        cols: usize,
+        rows: usize,
+        order: StorageOrder,
+    ) -> Matrix {
+        match order {
+            StorageOrder::ColMajor => Matrix::new_flatt(matrix_flatt, cols, rows),
+            StorageOrder::RowMajor => {
+                // the row-major buffer is the col-major buffer of the transpose
+                let mut matrix = Matrix::new_flatt(matrix_flatt, rows, cols);
+                matrix.transpose();
+                matrix
+            }
+        }
+    }
+
+    /// returns the components as a flat buffer in the storage order `order`
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::shape::StorageOrder;
+    /// use math::linear_algebra::Matrix;
+    /// let matrix = Matrix::new(vec![vec![1., 2., 3.], vec![4., 5., 6.]]);
+    /// assert_eq!(matrix.to_flatt_order(StorageOrder::RowMajor), vec![1., 4., 2., 5., 3., 6.]);
+    /// assert_eq!(matrix.to_flatt_order(StorageOrder::ColMajor), matrix.matrix_flatt());
+    /// ```
+    pub fn to_flatt_order(&self, order: StorageOrder) -> Vec<f32> {
+        match order {
+            StorageOrder::ColMajor => self.matrix_flatt(),
+            StorageOrder::RowMajor => {
+                let mut transposed = self.clone();
+                transposed.transpose();
+                transposed.matrix_flatt()
+            }
+        }
+    }
+
+    /// changes the shape of the matrix, the components `index(i, j)` which are inside of both shapes are kept
+    /// and the new components are set to `fill`
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Matrix;
+    /// let mut matrix = Matrix::new(vec![vec![1., 2.], vec![3., 4.]]);
+    /// matrix.resize(3, 1, 0.);
+    /// assert_eq!(matrix, Matrix::new(vec![vec![1.], vec![3.], vec![0.]]));
+    /// ```
+    pub fn resize(&mut self, cols: usize, rows: usize, fill: f32) {
+        let mut matrix_flatt = Vec::with_capacity(cols * rows);
+        for i in 0..cols {
+            for j in 0..rows {
+                if i < self.cols() && j < self.rows() {
+                    matrix_flatt.push(self.index(i, j));
+                } else {
+                    matrix_flatt.push(fill);
+                }
+            }
+        }
+        *self = Matrix::new_flatt(matrix_flatt, cols, rows);
+    }
+}
+
+impl Vector {
+    /// returns the shape of the vector if it lies along `axis`
+    pub fn shape(&self, axis: Axis) -> Shape {
+        Shape::vector(self.len(), axis)
+    }
+}
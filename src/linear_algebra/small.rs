@@ -0,0 +1,125 @@
+// closed forms for 2x2, 3x3 and 4x4 matrices
+//
+// all matrices are flatt in the order of `matrix_flatt()`, so `a[i * n + j] == index(i, j)`
+
+pub(crate) fn det(a: &[f32], n: usize) -> Option<f32> {
+    match n {
+        2 => Some(det2(a)),
+        3 => Some(det3(a)),
+        4 => Some(det4(a)),
+        _ => None,
+    }
+}
+
+// writes the inverse into out, returns false if the size has no closed form
+// and panics if the matrix is singular
+pub(crate) fn inverse(a: &[f32], n: usize, out: &mut [f32]) -> bool {
+    let det = match n {
+        2 => adjugate2(a, out),
+        3 => adjugate3(a, out),
+        4 => adjugate4(a, out),
+        _ => return false,
+    };
+    if det == 0. {
+        panic!("the matrix is singular");
+    }
+    out.iter_mut().for_each(|x| *x /= det);
+    true
+}
+
+// writes the product into out, returns false if the size has no specialized product
+pub(crate) fn dot_mat(a: &[f32], b: &[f32], n: usize, out: &mut [f32]) -> bool {
+    match n {
+        2 => dot_mat_n::<2>(a, b, out),
+        3 => dot_mat_n::<3>(a, b, out),
+        4 => dot_mat_n::<4>(a, b, out),
+        _ => return false,
+    }
+    true
+}
+
+fn dot_mat_n<const N: usize>(a: &[f32], b: &[f32], out: &mut [f32]) {
+    for i in 0..N {
+        for j in 0..N {
+            out[i * N + j] = (0..N).map(|k| a[i * N + k] * b[k * N + j]).sum();
+        }
+    }
+}
+
+fn det2(a: &[f32]) -> f32 {
+    a[0] * a[3] - a[1] * a[2]
+}
+
+// the adjugate functions write the adjugate into out and return the determinant
+fn adjugate2(a: &[f32], out: &mut [f32]) -> f32 {
+    out.copy_from_slice(&[a[3], -a[1], -a[2], a[0]]);
+    det2(a)
+}
+
+fn det3(a: &[f32]) -> f32 {
+    adjugate3(a, &mut [0.; 9])
+}
+
+fn adjugate3(a: &[f32], out: &mut [f32]) -> f32 {
+    out.copy_from_slice(&[
+        a[4] * a[8] - a[5] * a[7],
+        a[2] * a[7] - a[1] * a[8],
+        a[1] * a[5] - a[2] * a[4],
+        a[5] * a[6] - a[3] * a[8],
+        a[0] * a[8] - a[2] * a[6],
+        a[2] * a[3] - a[0] * a[5],
+        a[3] * a[7] - a[4] * a[6],
+        a[1] * a[6] - a[0] * a[7],
+        a[0] * a[4] - a[1] * a[3],
+    ]);
+    a[0] * out[0] + a[1] * out[3] + a[2] * out[6]
+}
+
+// the 2x2 minors of the first two rows (s) and the last two rows (c)
+fn minors4(a: &[f32]) -> ([f32; 6], [f32; 6]) {
+    let s = [
+        a[0] * a[5] - a[1] * a[4],
+        a[0] * a[6] - a[2] * a[4],
+        a[0] * a[7] - a[3] * a[4],
+        a[1] * a[6] - a[2] * a[5],
+        a[1] * a[7] - a[3] * a[5],
+        a[2] * a[7] - a[3] * a[6],
+    ];
+    let c = [
+        a[8] * a[13] - a[9] * a[12],
+        a[8] * a[14] - a[10] * a[12],
+        a[8] * a[15] - a[11] * a[12],
+        a[9] * a[14] - a[10] * a[13],
+        a[9] * a[15] - a[11] * a[13],
+        a[10] * a[15] - a[11] * a[14],
+    ];
+    (s, c)
+}
+
+fn det4(a: &[f32]) -> f32 {
+    let (s, c) = minors4(a);
+    s[0] * c[5] - s[1] * c[4] + s[2] * c[3] + s[3] * c[2] - s[4] * c[1] + s[5] * c[0]
+}
+
+fn adjugate4(a: &[f32], out: &mut [f32]) -> f32 {
+    let (s, c) = minors4(a);
+    out.copy_from_slice(&[
+        a[5] * c[5] - a[6] * c[4] + a[7] * c[3],
+        -a[1] * c[5] + a[2] * c[4] - a[3] * c[3],
+        a[13] * s[5] - a[14] * s[4] + a[15] * s[3],
+        -a[9] * s[5] + a[10] * s[4] - a[11] * s[3],
+        -a[4] * c[5] + a[6] * c[2] - a[7] * c[1],
+        a[0] * c[5] - a[2] * c[2] + a[3] * c[1],
+        -a[12] * s[5] + a[14] * s[2] - a[15] * s[1],
+        a[8] * s[5] - a[10] * s[2] + a[11] * s[1],
+        a[4] * c[4] - a[5] * c[2] + a[7] * c[0],
+        -a[0] * c[4] + a[1] * c[2] - a[3] * c[0],
+        a[12] * s[4] - a[13] * s[2] + a[15] * s[0],
+        -a[8] * s[4] + a[9] * s[2] - a[11] * s[0],
+        -a[4] * c[3] + a[5] * c[1] - a[6] * c[0],
+        a[0] * c[3] - a[1] * c[1] + a[2] * c[0],
+        -a[12] * s[3] + a[13] * s[1] - a[14] * s[0],
+        a[8] * s[3] - a[9] * s[1] + a[10] * s[0],
+    ]);
+    det4(a)
+}
@@ -0,0 +1,463 @@
+use crate::linear_algebra::small;
+use crate::linear_algebra::{Matrix, Vector};
+use crate::MathError;
+use std::iter::Sum;
+use std::ops::{Div, Mul, Neg, Sub};
+
+impl Matrix {
+    /// solves the [system of linear equations] `self.dot_vec(&x) == b` and returns `x`
+    /// using [Gaussian elimination] with partial pivoting
+    ///
+    /// [system of linear equations]: https://en.wikipedia.org/wiki/System_of_linear_equations
+    /// [Gaussian elimination]: https://en.wikipedia.org/wiki/Gaussian_elimination
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{Matrix, Vector};
+    /// let matrix = Matrix::new(vec![vec![2., 1.], vec![1., 3.]]);
+    /// let x = matrix.solve(&Vector::new(vec![3., 5.]));
+    /// assert_eq!(x, Vector::new(vec![0.8, 1.4]));
+    /// ```
+    /// note it panics if the matrix is not square, the vector has the wrong len or the matrix is singular
+    pub fn solve(&self, b: &Vector) -> Vector {
+        check_square(self);
+        check_rhs(self, b);
+        Vector::new(Lu::new(self).solve(&b.vec()))
+    }
+
+    /// same as `solve` but returns an error instead of panicking
+    pub fn try_solve(&self, b: &Vector) -> Result<Vector, MathError> {
+        try_check_square(self)?;
+        try_check_rhs(self, b)?;
+        Ok(Vector::new(Lu::try_new(self)?.solve(&b.vec())))
+    }
+
+    /// same as `solve` but improves the solution with [iterative refinement]
+    /// and returns the solution and the norm of the final residual `b - self.dot_vec(&x)`
+    ///
+    /// each of the at most `iterations` steps computes the residual in `f64`
+    /// and solves for a correction reusing the LU decomposition,
+    /// it stops early if the residual doesn't get smaller anymore
+    ///
+    /// [iterative refinement]: https://en.wikipedia.org/wiki/Iterative_refinement
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{Matrix, Vector};
+    /// let matrix = Matrix::new(vec![vec![2., 1.], vec![1., 3.]]);
+    /// let (x, residual) = matrix.solve_refined(&Vector::new(vec![3., 5.]), 3);
+    /// assert_eq!(x, Vector::new(vec![0.8, 1.4]));
+    /// assert!(residual < 1e-6);
+    /// ```
+    /// note it panics if the matrix is not square, the vector has the wrong len or the matrix is singular
+    pub fn solve_refined(&self, b: &Vector, iterations: usize) -> (Vector, f32) {
+        check_square(self);
+        check_rhs(self, b);
+
+        let lu = Lu::new(self);
+        let a = self.matrix_flatt();
+        let b = b.vec();
+        let n = b.len();
+
+        // the residual is computed in f64 so the correction isn't lost in round-off
+        let residual = |x: &[f32]| -> Vec<f64> {
+            (0..n)
+                .map(|i| {
+                    let ax: f64 = (0..n).map(|j| a[i * n + j] as f64 * x[j] as f64).sum();
+                    b[i] as f64 - ax
+                })
+                .collect()
+        };
+        let norm = |r: &[f64]| r.iter().map(|x| x * x).sum::<f64>().sqrt();
+
+        let mut x = lu.solve(&b);
+        let mut r = residual(&x);
+        let mut r_norm = norm(&r);
+
+        for _ in 0..iterations {
+            if r_norm == 0. {
+                break;
+            }
+            let correction = lu.solve(&r.iter().map(|&x| x as f32).collect::<Vec<_>>());
+            let refined: Vec<f32> = x.iter().zip(correction).map(|(x, d)| x + d).collect();
+            let refined_r = residual(&refined);
+            let refined_norm = norm(&refined_r);
+            if refined_norm >= r_norm {
+                break;
+            }
+            x = refined;
+            r = refined_r;
+            r_norm = refined_norm;
+        }
+
+        (Vector::new(x), r_norm as f32)
+    }
+
+    /// returns the [inverse] of this matrix, so `self.dot_mat(&inverse)` is the identity matrix
+    ///
+    /// matrices up to 4x4 use a closed form, bigger matrices are factored once and solve for each col of the identity matrix
+    ///
+    /// [inverse]: https://en.wikipedia.org/wiki/Invertible_matrix
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Matrix;
+    /// let matrix = Matrix::new(vec![vec![4., 7.], vec![2., 6.]]);
+    /// assert_eq!(
+    ///     matrix.inverse(),
+    ///     Matrix::new(vec![vec![0.6, -0.7], vec![-0.2, 0.4]])
+    /// );
+    /// ```
+    /// note it panics if the matrix is not square or singular
+    pub fn inverse(&self) -> Matrix {
+        check_square(self);
+        let n = self.cols();
+        let mut matrix_flatt = vec![0.; n * n];
+        if small::inverse(&self.matrix_flatt(), n, &mut matrix_flatt) {
+            return Matrix::new_flatt(matrix_flatt, n, n);
+        }
+
+        Matrix::new_flatt(Lu::new(self).inverse(), n, n)
+    }
+
+    /// same as `inverse` but returns an error instead of panicking
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Matrix;
+    /// use math::MathError;
+    /// let matrix = Matrix::new(vec![vec![1., 2.], vec![2., 4.]]);
+    /// assert_eq!(matrix.try_inverse(), Err(MathError::Singular));
+    /// ```
+    pub fn try_inverse(&self) -> Result<Matrix, MathError> {
+        try_check_square(self)?;
+        let n = self.cols();
+        if let Some(det) = small::det(&self.matrix_flatt(), n) {
+            if det == 0. {
+                return Err(MathError::Singular);
+            }
+            return Ok(self.inverse());
+        }
+
+        Ok(Matrix::new_flatt(Lu::try_new(self)?.inverse(), n, n))
+    }
+
+    /// returns the [least squares] solution `x` which minimizes the distance between `self.dot_vec(&x)` and `b`
+    /// computed with a Householder [QR decomposition]
+    ///
+    /// [least squares]: https://en.wikipedia.org/wiki/Linear_least_squares
+    /// [QR decomposition]: https://en.wikipedia.org/wiki/QR_decomposition
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{Matrix, Vector};
+    /// // fit the line y = c + m * x through the points (0, 1), (1, 3), (2, 5)
+    /// let matrix = Matrix::new(vec![vec![1., 0.], vec![1., 1.], vec![1., 2.]]);
+    /// let x = matrix.lstsq(&Vector::new(vec![1., 3., 5.]));
+    /// assert!((x.index(0) - 1.).abs() < 1e-5);
+    /// assert!((x.index(1) - 2.).abs() < 1e-5);
+    /// ```
+    /// note the matrix needs at least as many cols as rows and it panics if the matrix is rank deficient
+    pub fn lstsq(&self, b: &Vector) -> Vector {
+        check_rhs(self, b);
+        let (m, n) = (self.cols(), self.rows());
+        if m < n {
+            panic!(
+                "the matrix needs at least as many cols as rows, cols = {}, rows = {}",
+                m, n
+            );
+        }
+
+        let mut a = self.matrix_flatt();
+        let mut x = b.vec();
+
+        for k in 0..n {
+            let norm = (k..m)
+                .map(|i| a[i * n + k] * a[i * n + k])
+                .sum::<f32>()
+                .sqrt();
+            if norm == 0. {
+                continue;
+            }
+            let alpha = if a[k * n + k] > 0. { -norm } else { norm };
+
+            // householder vector v = a_k - alpha * e_k
+            let mut v: Vec<f32> = (k..m).map(|i| a[i * n + k]).collect();
+            v[0] -= alpha;
+            let v_norm: f32 = v.iter().map(|x| x * x).sum();
+            if v_norm == 0. {
+                continue;
+            }
+
+            for j in k..n {
+                let dot: f32 = (k..m).map(|i| v[i - k] * a[i * n + j]).sum();
+                let factor = 2. * dot / v_norm;
+                for i in k..m {
+                    a[i * n + j] -= factor * v[i - k];
+                }
+            }
+            let dot: f32 = (k..m).map(|i| v[i - k] * x[i]).sum();
+            let factor = 2. * dot / v_norm;
+            for i in k..m {
+                x[i] -= factor * v[i - k];
+            }
+        }
+
+        let scale = (0..n).map(|i| a[i * n + i].abs()).fold(0., f32::max);
+        if (0..n).any(|i| a[i * n + i].abs() <= scale * 1e-6) {
+            panic!("the matrix is rank deficient");
+        }
+
+        back_substitution(&a, n, &mut x);
+        x.truncate(n);
+        Vector::new(x)
+    }
+}
+
+/// solves the [tridiagonal system] with the `lower` and `upper` diagonals and the main diagonal `diag`
+/// using the [Thomas algorithm] in `O(n)`
+///
+/// [tridiagonal system]: https://en.wikipedia.org/wiki/Tridiagonal_matrix
+/// [Thomas algorithm]: https://en.wikipedia.org/wiki/Tridiagonal_matrix_algorithm
+///
+/// ## Example
+///
+/// ```rust
+/// use math::linear_algebra::{solve_tridiagonal, Vector};
+/// // [1 1 0]       [3]
+/// // [1 2 1] x  =  [8]
+/// // [0 1 2]       [8]
+/// let x = solve_tridiagonal(
+///     &Vector::new(vec![1., 1.]),
+///     &Vector::new(vec![1., 2., 2.]),
+///     &Vector::new(vec![1., 1.]),
+///     &Vector::new(vec![3., 8., 8.]),
+/// );
+/// assert_eq!(x, Vector::new(vec![1., 2., 3.]));
+/// ```
+/// note it panics if the diagonals have the wrong len or a pivot is 0 (it doesn't pivot,
+/// which is fine for diagonally dominant systems)
+pub fn solve_tridiagonal(lower: &Vector, diag: &Vector, upper: &Vector, rhs: &Vector) -> Vector {
+    let n = diag.len();
+    if rhs.len() != n {
+        panic!("wrong vector shape expected {}, got {}", n, rhs.len());
+    }
+    if lower.len() + 1 != n.max(1) || upper.len() + 1 != n.max(1) {
+        panic!(
+            "the lower and upper diagonal have to have the len {}, got {} and {}",
+            n.saturating_sub(1),
+            lower.len(),
+            upper.len()
+        );
+    }
+    let mut x = rhs.vec();
+    tridiagonal(&lower.vec(), &diag.vec(), &upper.vec(), &mut x);
+    Vector::new(x)
+}
+
+// the Thomas algorithm in place, x is the right hand side and becomes the solution
+pub(crate) fn tridiagonal(lower: &[f32], diag: &[f32], upper: &[f32], x: &mut [f32]) {
+    let n = x.len();
+    if n == 0 {
+        return;
+    }
+    let mut c = vec![0.; n];
+    let mut pivot = diag[0];
+    for i in 0..n {
+        if i > 0 {
+            pivot = diag[i] - lower[i - 1] * c[i - 1];
+            x[i] -= lower[i - 1] * x[i - 1];
+        }
+        if pivot == 0. {
+            panic!("the matrix is singular");
+        }
+        if i + 1 < n {
+            c[i] = upper[i] / pivot;
+        }
+        x[i] /= pivot;
+    }
+    for i in (0..n - 1).rev() {
+        x[i] -= c[i] * x[i + 1];
+    }
+}
+
+// the float types the LU decomposition works on, so `Matrix` and `Matrix64` share one implementation
+pub(crate) trait Scalar:
+    Copy
+    + PartialOrd
+    + Sum
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+    fn abs(self) -> Self;
+}
+
+macro_rules! impl_scalar {
+    ($t:ty) => {
+        impl Scalar for $t {
+            const ZERO: Self = 0.;
+            const ONE: Self = 1.;
+
+            fn abs(self) -> Self {
+                <$t>::abs(self)
+            }
+        }
+    };
+}
+
+impl_scalar!(f32);
+impl_scalar!(f64);
+
+// the LU decomposition with partial pivoting, the multipliers of L are stored below the diagonal of U
+pub(crate) struct Lu<T> {
+    n: usize,
+    a: Vec<T>,
+    // the row swaps in the order they were done
+    swaps: Vec<usize>,
+}
+
+impl Lu<f32> {
+    fn new(matrix: &Matrix) -> Self {
+        match Lu::try_new(matrix) {
+            Ok(lu) => lu,
+            Err(err) => panic!("{}", err),
+        }
+    }
+
+    fn try_new(matrix: &Matrix) -> Result<Self, MathError> {
+        Lu::factor(matrix.matrix_flatt(), matrix.cols()).ok_or(MathError::Singular)
+    }
+}
+
+impl<T: Scalar> Lu<T> {
+    // factors the n x n matrix `a` (stored like `matrix_flatt`), `None` if a col has no pivot
+    pub(crate) fn factor(mut a: Vec<T>, n: usize) -> Option<Self> {
+        let mut swaps = Vec::with_capacity(n);
+        for k in 0..n {
+            let pivot = (k..n)
+                .max_by(|&i, &j| a[i * n + k].abs().partial_cmp(&a[j * n + k].abs()).unwrap())
+                .unwrap();
+            if a[pivot * n + k] == T::ZERO {
+                return None;
+            }
+            if pivot != k {
+                for j in 0..n {
+                    a.swap(k * n + j, pivot * n + j);
+                }
+            }
+            swaps.push(pivot);
+
+            for i in (k + 1)..n {
+                let factor = a[i * n + k] / a[k * n + k];
+                for j in (k + 1)..n {
+                    a[i * n + j] = a[i * n + j] - factor * a[k * n + j];
+                }
+                a[i * n + k] = factor;
+            }
+        }
+        Some(Self { n, a, swaps })
+    }
+
+    pub(crate) fn solve(&self, b: &[T]) -> Vec<T> {
+        let n = self.n;
+        let mut x = b.to_vec();
+        for (k, &pivot) in self.swaps.iter().enumerate() {
+            x.swap(k, pivot);
+        }
+        for k in 0..n {
+            for i in (k + 1)..n {
+                x[i] = x[i] - self.a[i * n + k] * x[k];
+            }
+        }
+        // the pivots are never 0, so U can be solved without checks
+        for i in (0..n).rev() {
+            let sum: T = ((i + 1)..n).map(|j| self.a[i * n + j] * x[j]).sum();
+            x[i] = (x[i] - sum) / self.a[i * n + i];
+        }
+        x
+    }
+
+    // the product of the pivots, negated for an odd number of row swaps
+    pub(crate) fn det(&self) -> T {
+        let n = self.n;
+        let sign = self
+            .swaps
+            .iter()
+            .enumerate()
+            .filter(|&(k, &pivot)| k != pivot)
+            .fold(T::ONE, |sign, _| -sign);
+        (0..n).fold(sign, |det, k| det * self.a[k * n + k])
+    }
+
+    // solves for each col of the identity matrix with this one factorization
+    pub(crate) fn inverse(&self) -> Vec<T> {
+        let n = self.n;
+        let mut inverse = vec![T::ZERO; n * n];
+        let mut unit = vec![T::ZERO; n];
+        for k in 0..n {
+            unit[k] = T::ONE;
+            let col = self.solve(&unit);
+            unit[k] = T::ZERO;
+            for j in 0..n {
+                inverse[j * n + k] = col[j];
+            }
+        }
+        inverse
+    }
+}
+
+// the determinant with Gaussian elimination and partial pivoting, computed in `f64` so the
+// product of the pivots doesn't lose precision, it is 0 if a column has no pivot
+pub(crate) fn det(a: &[f32], n: usize) -> f32 {
+    let a = a.iter().map(|&x| x as f64).collect();
+    Lu::factor(a, n).map_or(0., |lu| lu.det()) as f32
+}
+
+// solves the upper triangular system in the first `n` rows of `a` in place
+fn back_substitution(a: &[f32], n: usize, x: &mut [f32]) {
+    for i in (0..n).rev() {
+        let sum: f32 = ((i + 1)..n).map(|j| a[i * n + j] * x[j]).sum();
+        if a[i * n + i] == 0. {
+            panic!("the matrix is singular");
+        }
+        x[i] = (x[i] - sum) / a[i * n + i];
+    }
+}
+
+fn check_square(mat: &Matrix) {
+    if let Err(err) = try_check_square(mat) {
+        panic!("{}", err);
+    }
+}
+
+fn try_check_square(mat: &Matrix) -> Result<(), MathError> {
+    if !mat.is_square() {
+        return Err(MathError::NotSquare);
+    }
+    Ok(())
+}
+
+fn check_rhs(mat: &Matrix, b: &Vector) {
+    if let Err(err) = try_check_rhs(mat, b) {
+        panic!("{}", err);
+    }
+}
+
+fn try_check_rhs(mat: &Matrix, b: &Vector) -> Result<(), MathError> {
+    if b.len() != mat.cols() {
+        return Err(MathError::VectorShape {
+            expected: mat.cols(),
+            got: b.len(),
+        });
+    }
+    Ok(())
+}
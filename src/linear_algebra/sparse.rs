@@ -0,0 +1,526 @@
+//! sparse matrices with direct solvers
+//!
+//! a [`SparseMatrix`] only stores the values which are not 0, it can be factorized with
+//! [`SparseMatrix::cholesky`] (symmetric positive definite) or [`SparseMatrix::lu`] (any non singular square matrix).
+//! the [`Ordering`] permutes the matrix first, so the factors have less fill-in (values which are 0 in
+//! the matrix but not in the factors)
+//!
+//! ## Example
+//!
+//! ```rust
+//! use math::linear_algebra::sparse::{Ordering, SparseMatrix};
+//! use math::linear_algebra::Vector;
+//! // the 1D laplacian
+//! let n = 100;
+//! let mut triplets = Vec::new();
+//! for i in 0..n {
+//!     triplets.push((i, i, 2.));
+//!     if i > 0 {
+//!         triplets.push((i, i - 1, -1.));
+//!         triplets.push((i - 1, i, -1.));
+//!     }
+//! }
+//! let matrix = SparseMatrix::from_triplets(n, n, &triplets);
+//! let b = Vector::new(vec![1.; n]);
+//! let x = matrix.cholesky(Ordering::MinimumDegree).solve(&b);
+//! let residual = matrix.dot_vec(&x);
+//! assert!((0..n).all(|i| (residual.index(i) - 1.).abs() < 1e-2));
+//! ```
+
+use crate::linear_algebra::{Matrix, Vector};
+use std::cmp::Reverse;
+use std::collections::{BTreeSet, BinaryHeap};
+
+/// a sparse matrix in the compressed sparse row format
+///
+/// like `Matrix` the first index `i` is smaller then `cols()` and the second index `j` is smaller then `rows()`,
+/// the values of each `i` are stored together
+#[derive(PartialEq, Clone, Debug)]
+pub struct SparseMatrix {
+    cols: usize,
+    rows: usize,
+    // the values of `i` are at `indptr[i]..indptr[i + 1]`
+    indptr: Vec<usize>,
+    indices: Vec<usize>,
+    values: Vec<f32>,
+}
+
+impl SparseMatrix {
+    /// creates the matrix from `(i, j, value)` triplets, the values of duplicate indices are added up
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::sparse::SparseMatrix;
+    /// use math::linear_algebra::Matrix;
+    /// let sparse = SparseMatrix::from_triplets(2, 3, &[(0, 2, 1.), (1, 0, 2.), (0, 2, 3.)]);
+    /// assert_eq!(sparse.nnz(), 2);
+    /// assert_eq!(sparse.to_dense(), Matrix::new(vec![vec![0., 0., 4.], vec![2., 0., 0.]]));
+    /// ```
+    /// note it panics if an index is out of bounds
+    pub fn from_triplets(cols: usize, rows: usize, triplets: &[(usize, usize, f32)]) -> Self {
+        let mut sorted = triplets.to_vec();
+        for &(i, j, _) in sorted.iter() {
+            if i >= cols || j >= rows {
+                panic!(
+                    "the index ({}, {}) is out of bounds for the shape {}x{}",
+                    i, j, cols, rows
+                );
+            }
+        }
+        sorted.sort_by_key(|&(i, j, _)| (i, j));
+
+        let mut indptr = vec![0; cols + 1];
+        let mut indices: Vec<usize> = Vec::with_capacity(sorted.len());
+        let mut values: Vec<f32> = Vec::with_capacity(sorted.len());
+        let mut last = None;
+        for (i, j, val) in sorted {
+            if last == Some((i, j)) {
+                *values.last_mut().unwrap() += val;
+            } else {
+                indptr[i + 1] += 1;
+                indices.push(j);
+                values.push(val);
+                last = Some((i, j));
+            }
+        }
+        for i in 0..cols {
+            indptr[i + 1] += indptr[i];
+        }
+
+        SparseMatrix {
+            cols,
+            rows,
+            indptr,
+            indices,
+            values,
+        }
+    }
+
+    /// creates the matrix from the values of a dense matrix which are not 0
+    pub fn from_dense(matrix: &Matrix) -> Self {
+        let mut triplets = Vec::new();
+        for i in 0..matrix.cols() {
+            for j in 0..matrix.rows() {
+                let val = matrix.index(i, j);
+                if val != 0. {
+                    triplets.push((i, j, val));
+                }
+            }
+        }
+        SparseMatrix::from_triplets(matrix.cols(), matrix.rows(), &triplets)
+    }
+
+    /// converts the matrix into a dense matrix
+    pub fn to_dense(&self) -> Matrix {
+        let mut matrix_flatt = vec![0.; self.cols * self.rows];
+        for i in 0..self.cols {
+            for (j, val) in self.line(i) {
+                matrix_flatt[i * self.rows + j] = val;
+            }
+        }
+        Matrix::new_flatt(matrix_flatt, self.cols, self.rows)
+    }
+
+    /// return the length of the columns like `Matrix::cols`
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// return the length of the rows like `Matrix::rows`
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// returns the number of stored values
+    pub fn nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    /// returns the value at index(i, j) like `Matrix::index`
+    pub fn index(&self, i: usize, j: usize) -> f32 {
+        let start = self.indptr[i];
+        match self.indices[start..self.indptr[i + 1]].binary_search(&j) {
+            Ok(pos) => self.values[start + pos],
+            Err(_) => 0.,
+        }
+    }
+
+    /// returns the stored `(i, j, value)` triplets sorted by `i` and then by `j`
+    pub fn triplets(&self) -> impl Iterator<Item = (usize, usize, f32)> + '_ {
+        (0..self.cols).flat_map(move |i| self.line(i).map(move |(j, val)| (i, j, val)))
+    }
+
+    // the stored `(j, value)` pairs of `i`
+    fn line(&self, i: usize) -> impl Iterator<Item = (usize, f32)> + '_ {
+        let range = self.indptr[i]..self.indptr[i + 1];
+        self.indices[range.clone()]
+            .iter()
+            .copied()
+            .zip(self.values[range].iter().copied())
+    }
+
+    /// returns the transposed matrix
+    pub fn transpose(&self) -> SparseMatrix {
+        let mut triplets = Vec::with_capacity(self.nnz());
+        for i in 0..self.cols {
+            triplets.extend(self.line(i).map(|(j, val)| (j, i, val)));
+        }
+        SparseMatrix::from_triplets(self.rows, self.cols, &triplets)
+    }
+
+    /// computes the product with the vector like `Matrix::dot_vec`
+    ///
+    /// note it panics if the vector has the wrong len
+    pub fn dot_vec(&self, vector: &Vector) -> Vector {
+        if vector.len() != self.rows {
+            panic!(
+                "wrong vector shape expected {}, got {}",
+                self.rows,
+                vector.len()
+            );
+        }
+        Vector::new(
+            (0..self.cols)
+                .map(|i| self.line(i).map(|(j, val)| val * vector.index(j)).sum())
+                .collect(),
+        )
+    }
+
+    fn check_square(&self) {
+        if self.cols != self.rows {
+            panic!("the matrix has to be a square matrix");
+        }
+    }
+
+    /// returns the permutation of the ordering for the pattern of `self + self^T`
+    ///
+    /// `perm[k]` is the index which is eliminated in step `k`
+    pub fn ordering(&self, ordering: Ordering) -> Vec<usize> {
+        self.check_square();
+        match ordering {
+            Ordering::Natural => (0..self.cols).collect(),
+            Ordering::MinimumDegree => minimum_degree(self),
+        }
+    }
+
+    /// computes the [Cholesky decomposition] `P A P^T = L L^T` where `P` is the permutation of the ordering
+    ///
+    /// [Cholesky decomposition]: https://en.wikipedia.org/wiki/Cholesky_decomposition
+    ///
+    /// note it panics if the matrix is not square or not positive definite,
+    /// it has to be symmetric but only the values of each `i` in the permuted upper triangle are read
+    pub fn cholesky(&self, ordering: Ordering) -> SparseCholesky {
+        let perm = self.ordering(ordering);
+        let n = self.cols;
+        let mut inv = vec![0; n];
+        for (k, &p) in perm.iter().enumerate() {
+            inv[p] = k;
+        }
+
+        // the columns of L below the diagonal and the steps of the columns with a value in each row
+        let mut diag = vec![0.; n];
+        let mut columns: Vec<Vec<(usize, f32)>> = vec![Vec::new(); n];
+        let mut row_steps: Vec<Vec<usize>> = vec![Vec::new(); n];
+        let mut acc = vec![0.; n];
+        let mut touched = Vec::new();
+        let mut is_touched = vec![false; n];
+
+        for k in 0..n {
+            // column k of P A P^T below the diagonal is the line perm[k] of A
+            for (j, val) in self.line(perm[k]) {
+                let i = inv[j];
+                if i >= k {
+                    acc[i] += val;
+                    if !is_touched[i] {
+                        is_touched[i] = true;
+                        touched.push(i);
+                    }
+                }
+            }
+            for &j in row_steps[k].iter() {
+                let l_kj = columns[j].iter().find(|&&(i, _)| i == k).unwrap().1;
+                for &(i, l_ij) in columns[j].iter().filter(|&&(i, _)| i >= k) {
+                    acc[i] -= l_ij * l_kj;
+                    if !is_touched[i] {
+                        is_touched[i] = true;
+                        touched.push(i);
+                    }
+                }
+            }
+
+            let pivot = acc[k];
+            if pivot <= 0. || pivot.is_nan() {
+                panic!("the matrix is not positive definite");
+            }
+            diag[k] = pivot.sqrt();
+            touched.sort_unstable();
+            for &i in touched.iter() {
+                if i > k && acc[i] != 0. {
+                    columns[k].push((i, acc[i] / diag[k]));
+                    row_steps[i].push(k);
+                }
+                acc[i] = 0.;
+                is_touched[i] = false;
+            }
+            touched.clear();
+        }
+
+        SparseCholesky {
+            perm,
+            diag,
+            columns,
+        }
+    }
+
+    /// computes the [LU decomposition] with partial pivoting of the matrix with the columns permuted by the ordering
+    ///
+    /// [LU decomposition]: https://en.wikipedia.org/wiki/LU_decomposition
+    ///
+    /// note it panics if the matrix is not square or singular
+    pub fn lu(&self, ordering: Ordering) -> SparseLu {
+        let col_perm = self.ordering(ordering);
+        let n = self.cols;
+        // the lines of the transpose are the columns of self
+        let columns_of_a = self.transpose();
+
+        const NONE: usize = usize::MAX;
+        let mut step_of_row = vec![NONE; n];
+        let mut row_of_step = vec![0; n];
+        let mut l_columns: Vec<Vec<(usize, f32)>> = Vec::with_capacity(n);
+        let mut u_columns: Vec<Vec<(usize, f32)>> = Vec::with_capacity(n);
+        let mut u_diag = Vec::with_capacity(n);
+
+        let mut acc = vec![0.; n];
+        let mut touched = Vec::new();
+        let mut is_touched = vec![false; n];
+        let mut heap = BinaryHeap::new();
+
+        for k in 0..n {
+            for (i, val) in columns_of_a.line(col_perm[k]) {
+                acc[i] = val;
+                is_touched[i] = true;
+                touched.push(i);
+                if step_of_row[i] != NONE {
+                    heap.push(Reverse(step_of_row[i]));
+                }
+            }
+
+            // solves L x = a with the steps in increasing order
+            let mut u_column = Vec::new();
+            let mut last = NONE;
+            while let Some(Reverse(j)) = heap.pop() {
+                if j == last {
+                    continue;
+                }
+                last = j;
+                let u_jk = acc[row_of_step[j]];
+                if u_jk == 0. {
+                    continue;
+                }
+                u_column.push((j, u_jk));
+                for &(i, l_ij) in l_columns[j].iter() {
+                    acc[i] -= l_ij * u_jk;
+                    if !is_touched[i] {
+                        is_touched[i] = true;
+                        touched.push(i);
+                    }
+                    if step_of_row[i] != NONE && step_of_row[i] > j {
+                        heap.push(Reverse(step_of_row[i]));
+                    }
+                }
+            }
+
+            let pivot_row = touched
+                .iter()
+                .copied()
+                .filter(|&i| step_of_row[i] == NONE)
+                .max_by(|&a, &b| acc[a].abs().partial_cmp(&acc[b].abs()).unwrap());
+            let pivot_row = match pivot_row {
+                Some(i) if acc[i] != 0. => i,
+                _ => panic!("the matrix is singular"),
+            };
+            let pivot = acc[pivot_row];
+            step_of_row[pivot_row] = k;
+            row_of_step[k] = pivot_row;
+
+            let mut l_column = Vec::new();
+            for &i in touched.iter() {
+                if step_of_row[i] == NONE && acc[i] != 0. {
+                    l_column.push((i, acc[i] / pivot));
+                }
+                acc[i] = 0.;
+                is_touched[i] = false;
+            }
+            touched.clear();
+
+            l_columns.push(l_column);
+            u_columns.push(u_column);
+            u_diag.push(pivot);
+        }
+
+        SparseLu {
+            col_perm,
+            row_of_step,
+            l_columns,
+            u_columns,
+            u_diag,
+        }
+    }
+
+    /// solves `self.dot_vec(&x) == b` with a [`SparseMatrix::lu`] decomposition and the minimum degree ordering
+    ///
+    /// note it panics if the matrix is not square, singular or the vector has the wrong len
+    pub fn solve(&self, b: &Vector) -> Vector {
+        self.lu(Ordering::MinimumDegree).solve(b)
+    }
+}
+
+/// the permutation applied before a sparse factorization
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Ordering {
+    /// the matrix isn't permuted
+    Natural,
+    /// the [minimum degree] heuristic, which eliminates the index with the fewest neighbours first
+    ///
+    /// [minimum degree]: https://en.wikipedia.org/wiki/Minimum_degree_algorithm
+    MinimumDegree,
+}
+
+// the minimum degree ordering on the graph of self + self^T
+fn minimum_degree(matrix: &SparseMatrix) -> Vec<usize> {
+    let n = matrix.cols;
+    let mut graph: Vec<BTreeSet<usize>> = vec![BTreeSet::new(); n];
+    for i in 0..n {
+        for (j, _) in matrix.line(i) {
+            if i != j {
+                graph[i].insert(j);
+                graph[j].insert(i);
+            }
+        }
+    }
+
+    let mut eliminated = vec![false; n];
+    let mut perm = Vec::with_capacity(n);
+    for _ in 0..n {
+        let node = (0..n)
+            .filter(|&i| !eliminated[i])
+            .min_by_key(|&i| graph[i].len())
+            .unwrap();
+        eliminated[node] = true;
+        perm.push(node);
+
+        // the neighbours of an eliminated node become a clique
+        let neighbours: Vec<usize> = std::mem::take(&mut graph[node]).into_iter().collect();
+        for &a in neighbours.iter() {
+            graph[a].remove(&node);
+            for &b in neighbours.iter() {
+                if a != b {
+                    graph[a].insert(b);
+                }
+            }
+        }
+    }
+    perm
+}
+
+fn check_rhs(n: usize, b: &Vector) {
+    if b.len() != n {
+        panic!("wrong vector shape expected {}, got {}", n, b.len());
+    }
+}
+
+/// the sparse Cholesky decomposition created by [`SparseMatrix::cholesky`]
+#[derive(PartialEq, Clone, Debug)]
+pub struct SparseCholesky {
+    perm: Vec<usize>,
+    diag: Vec<f32>,
+    // the values of L below the diagonal of each column
+    columns: Vec<Vec<(usize, f32)>>,
+}
+
+impl SparseCholesky {
+    /// returns the number of values in `L` (including the diagonal)
+    pub fn nnz(&self) -> usize {
+        self.diag.len() + self.columns.iter().map(|c| c.len()).sum::<usize>()
+    }
+
+    /// returns the permutation of the ordering
+    pub fn perm(&self) -> &[usize] {
+        &self.perm
+    }
+
+    /// solves the system for the right hand side `b`
+    ///
+    /// note it panics if the vector has the wrong len
+    pub fn solve(&self, b: &Vector) -> Vector {
+        let n = self.diag.len();
+        check_rhs(n, b);
+        let mut y: Vec<f32> = self.perm.iter().map(|&p| b.index(p)).collect();
+        for k in 0..n {
+            y[k] /= self.diag[k];
+            for &(i, l) in self.columns[k].iter() {
+                y[i] -= l * y[k];
+            }
+        }
+        for k in (0..n).rev() {
+            let sum: f32 = self.columns[k].iter().map(|&(i, l)| l * y[i]).sum();
+            y[k] = (y[k] - sum) / self.diag[k];
+        }
+
+        let mut x = vec![0.; n];
+        for (k, &p) in self.perm.iter().enumerate() {
+            x[p] = y[k];
+        }
+        Vector::new(x)
+    }
+}
+
+/// the sparse LU decomposition created by [`SparseMatrix::lu`]
+#[derive(PartialEq, Clone, Debug)]
+pub struct SparseLu {
+    col_perm: Vec<usize>,
+    row_of_step: Vec<usize>,
+    // the values of L below the diagonal of each step (by the original row), the diagonal is 1
+    l_columns: Vec<Vec<(usize, f32)>>,
+    // the values of U above the diagonal of each step (by the step)
+    u_columns: Vec<Vec<(usize, f32)>>,
+    u_diag: Vec<f32>,
+}
+
+impl SparseLu {
+    /// returns the number of values in `L` and `U` (including the diagonal of `U`)
+    pub fn nnz(&self) -> usize {
+        let l: usize = self.l_columns.iter().map(|c| c.len()).sum();
+        let u: usize = self.u_columns.iter().map(|c| c.len()).sum();
+        l + u + self.u_diag.len()
+    }
+
+    /// solves the system for the right hand side `b`
+    ///
+    /// note it panics if the vector has the wrong len
+    pub fn solve(&self, b: &Vector) -> Vector {
+        let n = self.u_diag.len();
+        check_rhs(n, b);
+        let mut z = b.vec();
+        let mut w = vec![0.; n];
+        for j in 0..n {
+            w[j] = z[self.row_of_step[j]];
+            for &(i, l) in self.l_columns[j].iter() {
+                z[i] -= l * w[j];
+            }
+        }
+        for k in (0..n).rev() {
+            w[k] /= self.u_diag[k];
+            for &(j, u) in self.u_columns[k].iter() {
+                w[j] -= u * w[k];
+            }
+        }
+
+        let mut x = vec![0.; n];
+        for (k, &q) in self.col_perm.iter().enumerate() {
+            x[q] = w[k];
+        }
+        Vector::new(x)
+    }
+}
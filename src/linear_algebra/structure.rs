@@ -0,0 +1,168 @@
+use crate::linear_algebra::norm::NormKind;
+use crate::linear_algebra::Matrix;
+
+impl Matrix {
+    // copies the matrix and sets all components `index(i, j)` for which `keep(j - i)` is false to 0
+    fn keep_diagonals<F: Fn(isize) -> bool>(&self, keep: F) -> Matrix {
+        let (cols, rows) = (self.cols(), self.rows());
+        let mut matrix_flatt = Vec::with_capacity(cols * rows);
+        for i in 0..cols {
+            for j in 0..rows {
+                if keep(j as isize - i as isize) {
+                    matrix_flatt.push(self.index(i, j));
+                } else {
+                    matrix_flatt.push(0.);
+                }
+            }
+        }
+        Matrix::new_flatt(matrix_flatt, cols, rows)
+    }
+
+    /// returns the upper triangle of the matrix, the components `index(i, j)` with `j - i < k` are set to 0
+    ///
+    /// `k = 0` is the main diagonal, `k > 0` is above and `k < 0` below it
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Matrix;
+    /// let matrix = Matrix::new(vec![vec![1., 2., 3.], vec![4., 5., 6.], vec![7., 8., 9.]]);
+    /// assert_eq!(
+    ///     matrix.triu(0),
+    ///     Matrix::new(vec![vec![1., 2., 3.], vec![0., 5., 6.], vec![0., 0., 9.]])
+    /// );
+    /// assert_eq!(
+    ///     matrix.triu(1),
+    ///     Matrix::new(vec![vec![0., 2., 3.], vec![0., 0., 6.], vec![0., 0., 0.]])
+    /// );
+    /// ```
+    pub fn triu(&self, k: isize) -> Matrix {
+        self.keep_diagonals(|diagonal| diagonal >= k)
+    }
+
+    /// returns the lower triangle of the matrix, the components `index(i, j)` with `j - i > k` are set to 0
+    ///
+    /// `k = 0` is the main diagonal, `k > 0` is above and `k < 0` below it
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Matrix;
+    /// let matrix = Matrix::new(vec![vec![1., 2., 3.], vec![4., 5., 6.], vec![7., 8., 9.]]);
+    /// assert_eq!(
+    ///     matrix.tril(-1),
+    ///     Matrix::new(vec![vec![0., 0., 0.], vec![4., 0., 0.], vec![7., 8., 0.]])
+    /// );
+    /// ```
+    pub fn tril(&self, k: isize) -> Matrix {
+        self.keep_diagonals(|diagonal| diagonal <= k)
+    }
+
+    /// returns true if the matrix is square and `|index(i, j) - index(j, i)| <= eps` for all components
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Matrix;
+    /// let matrix = Matrix::new(vec![vec![1., 2.], vec![2.001, 1.]]);
+    /// assert!(matrix.is_symmetric(0.01));
+    /// assert!(!matrix.is_symmetric(0.));
+    /// ```
+    pub fn is_symmetric(&self, eps: f32) -> bool {
+        let n = self.cols();
+        self.is_square()
+            && (0..n)
+                .all(|i| (i + 1..n).all(|j| (self.index(i, j) - self.index(j, i)).abs() <= eps))
+    }
+
+    /// returns true if all components outside of the main diagonal have `|index(i, j)| <= eps`,
+    /// the matrix doesn't have to be square
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Matrix;
+    /// let matrix = Matrix::new(vec![vec![1., 0., 0.], vec![0., 2., 0.]]);
+    /// assert!(matrix.is_diagonal(0.));
+    /// ```
+    pub fn is_diagonal(&self, eps: f32) -> bool {
+        (0..self.cols()).all(|i| (0..self.rows()).all(|j| i == j || self.index(i, j).abs() <= eps))
+    }
+
+    /// returns true if the matrix is square and its transpose is its inverse,
+    /// so `|(A^T A - I)[i][j]| <= eps` for all components
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Matrix;
+    /// let (sin, cos) = 0.5f32.sin_cos();
+    /// let rotation = Matrix::new(vec![vec![cos, -sin], vec![sin, cos]]);
+    /// assert!(rotation.is_orthogonal(1e-6));
+    /// ```
+    pub fn is_orthogonal(&self, eps: f32) -> bool {
+        if !self.is_square() {
+            return false;
+        }
+        let n = self.cols();
+        (0..n).all(|j| {
+            (j..n).all(|k| {
+                let dot: f32 = (0..n).map(|i| self.index(i, j) * self.index(i, k)).sum();
+                let expected = if j == k { 1. } else { 0. };
+                (dot - expected).abs() <= eps
+            })
+        })
+    }
+
+    /// returns true if all components below the main diagonal (`index(i, j)` with `j < i`) are exactly 0
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Matrix;
+    /// let matrix = Matrix::new(vec![vec![1., 2., 3.], vec![0., 4., 5.]]);
+    /// assert!(matrix.is_upper_triangular());
+    /// ```
+    pub fn is_upper_triangular(&self) -> bool {
+        (0..self.cols()).all(|i| (0..i.min(self.rows())).all(|j| self.index(i, j) == 0.))
+    }
+
+    /// returns true if the matrix is symmetric and all its eigenvalues are positive,
+    /// it tries a [Cholesky decomposition] which only exists for positive definite matrices
+    ///
+    /// the symmetry is checked with `is_symmetric` and a tolerance of `1e-6` times the largest row sum
+    ///
+    /// [Cholesky decomposition]: https://en.wikipedia.org/wiki/Cholesky_decomposition
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Matrix;
+    /// let matrix = Matrix::new(vec![vec![2., -1.], vec![-1., 2.]]);
+    /// assert!(matrix.is_positive_definite());
+    /// let indefinite = Matrix::new(vec![vec![1., 2.], vec![2., 1.]]);
+    /// assert!(!indefinite.is_positive_definite());
+    /// ```
+    pub fn is_positive_definite(&self) -> bool {
+        if !self.is_symmetric(self.norm(NormKind::LInf) * 1e-6) {
+            return false;
+        }
+        let n = self.cols();
+        // the lower triangle of the Cholesky factor
+        let mut l = vec![0f64; n * n];
+        for j in 0..n {
+            let diagonal =
+                self.index(j, j) as f64 - (0..j).map(|k| l[j * n + k].powi(2)).sum::<f64>();
+            if diagonal <= 0. || diagonal.is_nan() {
+                return false;
+            }
+            let l_jj = diagonal.sqrt();
+            l[j * n + j] = l_jj;
+            for i in j + 1..n {
+                let sum: f64 = (0..j).map(|k| l[i * n + k] * l[j * n + k]).sum();
+                l[i * n + j] = (self.index(i, j) as f64 - sum) / l_jj;
+            }
+        }
+        true
+    }
+}
@@ -1,15 +1,24 @@
 use crate::random;
+use crate::MathError;
+use std::iter::{Copied, FromIterator};
 use std::mem;
-use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use std::slice;
 
 fn check_same_len(vec1: &Vector, vec2: &Vector) {
+    if let Err(err) = try_check_same_len(vec1, vec2) {
+        panic!("{}", err);
+    }
+}
+
+fn try_check_same_len(vec1: &Vector, vec2: &Vector) -> Result<(), MathError> {
     if vec1.vec.len() != vec2.vec.len() {
-        panic!(
-            "the other vector has not the same len self.len() = {}, other.len() = {}",
-            vec1.vec.len(),
-            vec2.vec.len()
-        );
+        return Err(MathError::VectorLen {
+            expected: vec1.vec.len(),
+            got: vec2.vec.len(),
+        });
     }
+    Ok(())
 }
 
 #[derive(PartialEq, Clone, Debug)]
@@ -20,6 +29,12 @@ pub struct Vector {
     vec: Vec<f32>,
 }
 
+impl FromIterator<f32> for Vector {
+    fn from_iter<I: IntoIterator<Item = f32>>(iter: I) -> Self {
+        Vector::new(iter.into_iter().collect())
+    }
+}
+
 impl Add for Vector {
     type Output = Self;
     fn add(self, other: Self) -> Self {
@@ -83,6 +98,67 @@ impl DivAssign for Vector {
     }
 }
 
+impl Neg for Vector {
+    type Output = Self;
+
+    fn neg(mut self) -> Self {
+        self.vec.iter_mut().for_each(|x| *x = -*x);
+        self
+    }
+}
+
+impl Neg for &Vector {
+    type Output = Vector;
+
+    fn neg(self) -> Vector {
+        -self.clone()
+    }
+}
+
+impl Add<f32> for Vector {
+    type Output = Self;
+
+    fn add(mut self, scalar: f32) -> Self {
+        self.add_scalar(&scalar);
+        self
+    }
+}
+
+impl Sub<f32> for Vector {
+    type Output = Self;
+
+    fn sub(mut self, scalar: f32) -> Self {
+        self.sub_scalar(&scalar);
+        self
+    }
+}
+
+impl Mul<f32> for Vector {
+    type Output = Self;
+
+    fn mul(mut self, scalar: f32) -> Self {
+        self.mul_scalar(&scalar);
+        self
+    }
+}
+
+impl Div<f32> for Vector {
+    type Output = Self;
+
+    fn div(mut self, scalar: f32) -> Self {
+        self.div_scalar(&scalar);
+        self
+    }
+}
+
+impl Mul<Vector> for f32 {
+    type Output = Vector;
+
+    fn mul(self, other: Vector) -> Vector {
+        other * self
+    }
+}
+
 impl Vector {
     /// creates a new vector
     pub fn new(vec: Vec<f32>) -> Self {
@@ -279,6 +355,12 @@ impl Vector {
         res
     }
 
+    /// same as `dot_vec` but returns an error instead of panicking if the vectors don't have the same len
+    pub fn try_dot_vec(&self, other: &Vector) -> Result<f32, MathError> {
+        try_check_same_len(self, other)?;
+        Ok(self.dot_vec(other))
+    }
+
     /// multiplies each component from the vector with the component of the other vector and stors the result in this vector   
     ///
     /// ## Example
@@ -294,8 +376,16 @@ impl Vector {
     pub fn mul_vec(&mut self, other: &Vector) {
         check_same_len(self, other);
         for i in 0..other.len() {
-            self.vec[i] = self.vec[i] * other.vec[i];
+            self.vec[i] *= other.vec[i];
         }
+        self.check_finite();
+    }
+
+    /// same as `mul_vec` but returns an error instead of panicking if the vectors don't have the same len
+    pub fn try_mul_vec(&mut self, other: &Vector) -> Result<(), MathError> {
+        try_check_same_len(self, other)?;
+        self.mul_vec(other);
+        Ok(())
     }
 
     /// adds each component from the vector with the component of the other vector and stors the result in this vector   
@@ -313,8 +403,16 @@ impl Vector {
     pub fn add_vec(&mut self, other: &Vector) {
         check_same_len(self, other);
         for i in 0..other.len() {
-            self.vec[i] = self.vec[i] + other.vec[i];
+            self.vec[i] += other.vec[i];
         }
+        self.check_finite();
+    }
+
+    /// same as `add_vec` but returns an error instead of panicking if the vectors don't have the same len
+    pub fn try_add_vec(&mut self, other: &Vector) -> Result<(), MathError> {
+        try_check_same_len(self, other)?;
+        self.add_vec(other);
+        Ok(())
     }
 
     /// subtracts each component from the vector with the component of the other vector and stors the result in this vector   
@@ -332,8 +430,16 @@ impl Vector {
     pub fn sub_vec(&mut self, other: &Vector) {
         check_same_len(self, other);
         for i in 0..other.len() {
-            self.vec[i] = self.vec[i] - other.vec[i];
+            self.vec[i] -= other.vec[i];
         }
+        self.check_finite();
+    }
+
+    /// same as `sub_vec` but returns an error instead of panicking if the vectors don't have the same len
+    pub fn try_sub_vec(&mut self, other: &Vector) -> Result<(), MathError> {
+        try_check_same_len(self, other)?;
+        self.sub_vec(other);
+        Ok(())
     }
 
     /// divides each component from the vector with the component of the other vector and stors the result in this vector   
@@ -351,8 +457,16 @@ impl Vector {
     pub fn div_vec(&mut self, other: &Vector) {
         check_same_len(self, other);
         for i in 0..other.len() {
-            self.vec[i] = self.vec[i] / other.vec[i];
+            self.vec[i] /= other.vec[i];
         }
+        self.check_finite();
+    }
+
+    /// same as `div_vec` but returns an error instead of panicking if the vectors don't have the same len
+    pub fn try_div_vec(&mut self, other: &Vector) -> Result<(), MathError> {
+        try_check_same_len(self, other)?;
+        self.div_vec(other);
+        Ok(())
     }
 
     /// multiplies each component from the vector with a scalar value and stors the result in this vector   
@@ -366,7 +480,8 @@ impl Vector {
     /// assert_eq!(vector, Vector::new(vec![2. * 2., 3. * 2., 5. * 2.]));
     /// ```
     pub fn mul_scalar(&mut self, scalar: &f32) {
-        self.vec = self.vec.iter().map(|v| v * scalar).collect();
+        self.vec.iter_mut().for_each(|v| *v *= scalar);
+        self.check_finite();
     }
 
     /// divides each component from the vector with a scalar value and stors the result in this vector   
@@ -380,7 +495,8 @@ impl Vector {
     /// assert_eq!(vector, Vector::new(vec![2. / 2., 3. / 2., 5. / 2.]));
     /// ```
     pub fn div_scalar(&mut self, scalar: &f32) {
-        self.vec = self.vec.iter().map(|v| v / scalar).collect();
+        self.vec.iter_mut().for_each(|v| *v /= scalar);
+        self.check_finite();
     }
 
     /// adds each component from the vector with a scalar value and stors the result in this vector   
@@ -394,7 +510,8 @@ impl Vector {
     /// assert_eq!(vector, Vector::new(vec![2. + 2., 3. + 2., 5. + 2.]));
     /// ```
     pub fn add_scalar(&mut self, scalar: &f32) {
-        self.vec = self.vec.iter().map(|v| v + scalar).collect();
+        self.vec.iter_mut().for_each(|v| *v += scalar);
+        self.check_finite();
     }
 
     /// subtracts each component from the vector with a scalar value and stors the result in this vector   
@@ -408,7 +525,80 @@ impl Vector {
     /// assert_eq!(vector, Vector::new(vec![2. - 2., 3. - 2., 5. - 2.]));
     /// ```
     pub fn sub_scalar(&mut self, scalar: &f32) {
-        self.vec = self.vec.iter().map(|v| v - scalar).collect();
+        self.vec.iter_mut().for_each(|v| *v -= scalar);
+        self.check_finite();
+    }
+
+    /// applies the function to each component of the vector
+    ///
+    /// the closure is generic so it is inlined into the loop
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Vector;
+    /// let mut vector = Vector::new(vec![-2., 3., 5.]);
+    /// vector.apply(|x| x * x);
+    /// assert_eq!(vector, Vector::new(vec![4., 9., 25.]));
+    /// ```
+    pub fn apply<F: Fn(f32) -> f32>(&mut self, func: F) {
+        self.vec.iter_mut().for_each(|x| *x = func(*x));
+        self.check_finite();
+    }
+
+    /// returns true if any component of the vector is NaN
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Vector;
+    /// let mut vector = Vector::new(vec![1., f32::NAN, 3.]);
+    /// assert!(vector.has_nan());
+    /// vector.replace_nan(0.);
+    /// assert_eq!(vector, Vector::new(vec![1., 0., 3.]));
+    /// ```
+    pub fn has_nan(&self) -> bool {
+        self.vec.iter().any(|x| x.is_nan())
+    }
+
+    /// returns true if no component of the vector is NaN or infinite
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Vector;
+    /// assert!(!Vector::new(vec![1., f32::NEG_INFINITY]).is_finite());
+    /// ```
+    pub fn is_finite(&self) -> bool {
+        self.vec.iter().all(|x| x.is_finite())
+    }
+
+    /// replaces each NaN component with `value`
+    pub fn replace_nan(&mut self, value: f32) {
+        self.vec
+            .iter_mut()
+            .filter(|x| x.is_nan())
+            .for_each(|x| *x = value);
+    }
+
+    /// same as `apply` but takes a trait object, for functions which are stored as `Box<dyn Fn(f32) -> f32>`
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Vector;
+    /// let funcs: Vec<Box<dyn Fn(f32) -> f32>> = vec![Box::new(|x| x + 1.), Box::new(|x| x * 2.)];
+    /// let mut vector = Vector::new(vec![-2., 3., 5.]);
+    /// funcs.iter().for_each(|func| vector.apply_dyn(func.as_ref()));
+    /// assert_eq!(vector, Vector::new(vec![-2., 8., 12.]));
+    /// ```
+    pub fn apply_dyn(&mut self, func: &dyn Fn(f32) -> f32) {
+        self.apply(func);
+    }
+
+    /// same as `apply`
+    pub fn apply_func<F: Fn(f32) -> f32>(&mut self, func: F) {
+        self.apply(func);
     }
 
     /// getter for the internal Vec<f32> representation
@@ -424,6 +614,20 @@ impl Vector {
         self.vec.clone()
     }
 
+    /// returns an iterator over the components without cloning the vec
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Vector;
+    /// let vector = Vector::new(vec![2., 1., 6.]);
+    /// let doubled: Vector = vector.iter().map(|x| x * 2.).collect();
+    /// assert_eq!(doubled, Vector::new(vec![4., 2., 12.]));
+    /// ```
+    pub fn iter(&self) -> Copied<slice::Iter<'_, f32>> {
+        self.vec.iter().copied()
+    }
+
     /// the returns the length of the vec
     /// or in mathematical terms the [dimensions] of the vector
     ///
@@ -440,6 +644,19 @@ impl Vector {
         self.vec.len()
     }
 
+    /// returns true if the vector has no components
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Vector;
+    /// assert!(Vector::new(vec![]).is_empty());
+    /// assert!(!Vector::new(vec![1.]).is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.vec.is_empty()
+    }
+
     /// returns the value at the given index
     ///
     /// ## Example
@@ -485,4 +702,69 @@ impl Vector {
             .for_each(|&val| push_f32_bytes(val, &mut bytes));
         bytes
     }
+
+    /// creates the vector from the bytes of `bytes()`, the inverse of `bytes()`
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Vector;
+    /// let vector = Vector::new(vec![2., 1., 6.]);
+    /// assert_eq!(Vector::from_bytes(&vector.bytes()), vector);
+    /// ```
+    /// note it panics if the len in the header doesn't match the number of values
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        let vals = f32s_from_bytes(bytes);
+        if vals.is_empty() {
+            panic!("the data is to short for the header expected at least 4 bytes, got 0");
+        }
+        let len = len_from_f32(vals[0]);
+        if len != vals.len() - 1 {
+            panic!(
+                "the header has the len {}, got {} values",
+                len,
+                vals.len() - 1
+            );
+        }
+        Vector::new(vals[1..].to_vec())
+    }
+
+    // with the feature `check-finite` it panics if the vector contains NaN or infinity,
+    // it is called after each operation so corrupted data is caught where it appears
+    fn check_finite(&self) {
+        if cfg!(feature = "check-finite") {
+            if let Some(x) = self.vec.iter().find(|x| !x.is_finite()) {
+                panic!("the vector contains the non finite value {}", x);
+            }
+        }
+    }
+
+    pub(crate) fn checked(self) -> Self {
+        self.check_finite();
+        self
+    }
+
+    // gives the internal storage away so it can be reused
+    pub(crate) fn into_storage(self) -> Vec<f32> {
+        self.vec
+    }
+}
+
+// splits the bytes of `bytes()` into native endian f32 values
+pub(crate) fn f32s_from_bytes(bytes: &[u8]) -> Vec<f32> {
+    if bytes.len() % mem::size_of::<f32>() != 0 {
+        panic!("the len of the data {} is not a multiple of 4", bytes.len());
+    }
+    bytes
+        .chunks_exact(mem::size_of::<f32>())
+        .map(|chunk| f32::from_ne_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]))
+        .collect()
+}
+
+// converts a len from the header of `bytes()` back to usize
+pub(crate) fn len_from_f32(val: f32) -> usize {
+    if !(val >= 0. && val.fract() == 0.) {
+        panic!("{} in the header is not a len", val);
+    }
+    val as usize
 }
@@ -0,0 +1,141 @@
+//! rectangular blocks of a matrix
+
+use crate::linear_algebra::Matrix;
+use std::ops::{Bound, Range, RangeBounds};
+
+// converts the range to `start..end` and checks that it is inside of `0..len`
+fn to_range<R: RangeBounds<usize>>(range: R, len: usize, name: &str) -> Range<usize> {
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start + 1,
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end + 1,
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => len,
+    };
+    if start > end || end > len {
+        panic!(
+            "the {} range {}..{} is out of bounds for the len {}",
+            name, start, end, len
+        );
+    }
+    start..end
+}
+
+impl Matrix {
+    /// copies the block `index(row, col)` with `row` in `row_range` and `col` in `col_range` into a new matrix,
+    /// the ranges work like the arguments of `index`, so `row_range` is inside of `0..cols()` and `col_range` inside of `0..rows()`
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Matrix;
+    /// let matrix = Matrix::new(vec![vec![1., 2., 3.], vec![4., 5., 6.], vec![7., 8., 9.]]);
+    /// assert_eq!(
+    ///     matrix.submatrix(1.., ..2),
+    ///     Matrix::new(vec![vec![4., 5.], vec![7., 8.]])
+    /// );
+    /// ```
+    /// note it panics if a range is out of bounds
+    pub fn submatrix<R1: RangeBounds<usize>, R2: RangeBounds<usize>>(
+        &self,
+        row_range: R1,
+        col_range: R2,
+    ) -> Matrix {
+        self.view(row_range, col_range).to_matrix()
+    }
+
+    /// same as `submatrix` but borrows the matrix instead of copying the block
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Matrix;
+    /// let matrix = Matrix::new(vec![vec![1., 2., 3.], vec![4., 5., 6.], vec![7., 8., 9.]]);
+    /// let view = matrix.view(1..3, 1..3);
+    /// assert_eq!((view.cols(), view.rows()), (2, 2));
+    /// assert_eq!(view.index(1, 0), 8.);
+    /// ```
+    /// note it panics if a range is out of bounds
+    pub fn view<R1: RangeBounds<usize>, R2: RangeBounds<usize>>(
+        &self,
+        row_range: R1,
+        col_range: R2,
+    ) -> MatrixView<'_> {
+        MatrixView {
+            matrix: self,
+            row_range: to_range(row_range, self.cols(), "row"),
+            col_range: to_range(col_range, self.rows(), "col"),
+        }
+    }
+}
+
+/// a borrowed rectangular block of a `Matrix` created by `Matrix::view`
+#[derive(PartialEq, Clone, Debug)]
+pub struct MatrixView<'a> {
+    matrix: &'a Matrix,
+    row_range: Range<usize>,
+    col_range: Range<usize>,
+}
+
+impl<'a> MatrixView<'a> {
+    /// return the length of the columns of the block
+    pub fn cols(&self) -> usize {
+        self.row_range.len()
+    }
+
+    /// return the length of the rows of the block
+    pub fn rows(&self) -> usize {
+        self.col_range.len()
+    }
+
+    /// return index(row, col) relative to the start of the block
+    ///
+    /// note it panics if the index is outside of the block
+    pub fn index(&self, row: usize, col: usize) -> f32 {
+        if row >= self.cols() {
+            panic!("index out of bounds max row {}", self.cols() as isize - 1)
+        }
+        if col >= self.rows() {
+            panic!("index out of bounds max col {}", self.rows() as isize - 1)
+        }
+        self.matrix
+            .index(self.row_range.start + row, self.col_range.start + col)
+    }
+
+    /// returns a view of a block inside of this block, the ranges are relative to this block
+    ///
+    /// note it panics if a range is out of bounds
+    pub fn view<R1: RangeBounds<usize>, R2: RangeBounds<usize>>(
+        &self,
+        row_range: R1,
+        col_range: R2,
+    ) -> MatrixView<'a> {
+        let rows = to_range(row_range, self.cols(), "row");
+        let cols = to_range(col_range, self.rows(), "col");
+        MatrixView {
+            matrix: self.matrix,
+            row_range: self.row_range.start + rows.start..self.row_range.start + rows.end,
+            col_range: self.col_range.start + cols.start..self.col_range.start + cols.end,
+        }
+    }
+
+    /// copies the block into a new matrix
+    pub fn to_matrix(&self) -> Matrix {
+        let mut matrix_flatt = Vec::with_capacity(self.cols() * self.rows());
+        for i in self.row_range.clone() {
+            for j in self.col_range.clone() {
+                matrix_flatt.push(self.matrix.index(i, j));
+            }
+        }
+        Matrix::new_flatt(matrix_flatt, self.cols(), self.rows())
+    }
+}
+
+impl From<&MatrixView<'_>> for Matrix {
+    fn from(view: &MatrixView<'_>) -> Self {
+        view.to_matrix()
+    }
+}
@@ -0,0 +1,93 @@
+//! scratch buffers for iterative algorithms
+//!
+//! most methods of [`Matrix`] and [`Vector`] allocate a fresh `Vec<f32>` for every result,
+//! the `*_with` variants take their buffers from a [`Workspace`] instead
+//! and the buffers can be handed back once the result is no longer needed
+
+use crate::linear_algebra::{Matrix, Vector};
+
+/// a pool of reusable `Vec<f32>` buffers
+///
+/// ## Example
+///
+/// ```rust
+/// use math::linear_algebra::workspace::Workspace;
+/// use math::linear_algebra::{Matrix, Vector};
+/// let mut ws = Workspace::new();
+/// let matrix = Matrix::new(vec![vec![1., 2.], vec![3., 4.]]);
+/// let mut x = Vector::new(vec![1., 1.]);
+/// for _ in 0..3 {
+///     let next = matrix.dot_vec_with(&x, &mut ws);
+///     ws.recycle_vector(x);
+///     x = next;
+/// }
+/// assert_eq!(x, Vector::new(vec![91., 199.]));
+/// assert_eq!(ws.len(), 2);
+/// ```
+#[derive(Default, Debug)]
+pub struct Workspace {
+    buffers: Vec<Vec<f32>>,
+}
+
+impl Workspace {
+    /// creates an empty workspace
+    pub fn new() -> Self {
+        Self {
+            buffers: Vec::new(),
+        }
+    }
+
+    /// returns the number of buffers that are ready to be reused
+    pub fn len(&self) -> usize {
+        self.buffers.len()
+    }
+
+    /// returns true if there are no buffers to reuse
+    pub fn is_empty(&self) -> bool {
+        self.buffers.is_empty()
+    }
+
+    /// returns an empty buffer with at least the capacity `capacity`
+    ///
+    /// it prefers the smallest pooled buffer that is big enough and only allocates if there is none
+    pub fn take(&mut self, capacity: usize) -> Vec<f32> {
+        let best = self
+            .buffers
+            .iter()
+            .enumerate()
+            .filter(|(_, buf)| buf.capacity() >= capacity)
+            .min_by_key(|(_, buf)| buf.capacity())
+            .map(|(i, _)| i);
+
+        match best {
+            Some(i) => self.buffers.swap_remove(i),
+            None => Vec::with_capacity(capacity),
+        }
+    }
+
+    /// returns a buffer of len `len` filled with zeros
+    pub fn take_zeroed(&mut self, len: usize) -> Vec<f32> {
+        let mut buf = self.take(len);
+        buf.resize(len, 0.);
+        buf
+    }
+
+    /// hands a buffer back to the workspace so it can be reused
+    pub fn give(&mut self, mut buf: Vec<f32>) {
+        buf.clear();
+        self.buffers.push(buf);
+    }
+
+    /// takes a matrix which is no longer needed
+    ///
+    /// a matrix stores its values in an `Arc<[f32]>` which can't be turned back into a buffer,
+    /// so the matrix is just dropped, `dot_mat_with` already hands its buffer back itself
+    pub fn recycle_matrix(&mut self, matrix: Matrix) {
+        drop(matrix);
+    }
+
+    /// hands the storage of a vector back to the workspace
+    pub fn recycle_vector(&mut self, vector: Vector) {
+        self.give(vector.into_storage());
+    }
+}
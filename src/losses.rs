@@ -0,0 +1,156 @@
+use crate::linear_algebra::{Matrix, Vector};
+use crate::metrics::{self, EPSILON};
+
+fn elementwise<F: Fn(f32, f32) -> f32>(pred: &Vector, target: &Vector, func: F) -> Vector {
+    metrics::check_same_len(pred, target);
+    Vector::new(
+        pred.vec()
+            .iter()
+            .zip(target.vec().iter())
+            .map(|(&p, &t)| func(p, t))
+            .collect(),
+    )
+}
+
+/// returns the [mean squared error] and its gradient with respect to the prediction
+///
+/// [mean squared error]: https://en.wikipedia.org/wiki/Mean_squared_error
+///
+/// ## Example
+///
+/// ```rust
+/// use math::linear_algebra::Vector;
+/// use math::losses::mse;
+/// let pred = Vector::new(vec![1., 2.]);
+/// let target = Vector::new(vec![0., 4.]);
+/// let (loss, grad) = mse(&pred, &target);
+/// assert_eq!(loss, 2.5);
+/// assert_eq!(grad, Vector::new(vec![1., -2.]));
+/// ```
+/// note it panics if the vectors have not the same len
+pub fn mse(pred: &Vector, target: &Vector) -> (f32, Vector) {
+    let len = pred.len() as f32;
+    let grad = elementwise(pred, target, |p, t| 2. * (p - t) / len);
+    (metrics::mse(pred, target), grad)
+}
+
+/// returns the [mean absolute error] and its gradient with respect to the prediction
+///
+/// [mean absolute error]: https://en.wikipedia.org/wiki/Mean_absolute_error
+///
+/// ## Example
+///
+/// ```rust
+/// use math::linear_algebra::Vector;
+/// use math::losses::mae;
+/// let pred = Vector::new(vec![1., 2.]);
+/// let target = Vector::new(vec![0., 4.]);
+/// let (loss, grad) = mae(&pred, &target);
+/// assert_eq!(loss, 1.5);
+/// assert_eq!(grad, Vector::new(vec![0.5, -0.5]));
+/// ```
+/// note the gradient at `pred == target` is 0
+pub fn mae(pred: &Vector, target: &Vector) -> (f32, Vector) {
+    let len = pred.len() as f32;
+    let grad = elementwise(pred, target, |p, t| {
+        if p == t {
+            0.
+        } else {
+            (p - t).signum() / len
+        }
+    });
+    (metrics::mae(pred, target), grad)
+}
+
+/// returns the [huber loss] and its gradient with respect to the prediction,
+/// errors smaller then `delta` are squared and larger errors are linear
+///
+/// [huber loss]: https://en.wikipedia.org/wiki/Huber_loss
+///
+/// ## Example
+///
+/// ```rust
+/// use math::linear_algebra::Vector;
+/// use math::losses::huber;
+/// let pred = Vector::new(vec![1., 2.]);
+/// let target = Vector::new(vec![0.5, 5.]);
+/// let (loss, grad) = huber(&pred, &target, 1.);
+/// assert_eq!(loss, (0.5 * 0.5 * 0.5 + (3. - 0.5)) / 2.);
+/// assert_eq!(grad, Vector::new(vec![0.25, -0.5]));
+/// ```
+pub fn huber(pred: &Vector, target: &Vector, delta: f32) -> (f32, Vector) {
+    let len = pred.len() as f32;
+    let loss = elementwise(pred, target, |p, t| {
+        let error = (p - t).abs();
+        if error <= delta {
+            0.5 * error * error
+        } else {
+            delta * (error - 0.5 * delta)
+        }
+    });
+    let grad = elementwise(pred, target, |p, t| {
+        let error = p - t;
+        if error.abs() <= delta {
+            error / len
+        } else {
+            delta * error.signum() / len
+        }
+    });
+    (loss.vec().iter().sum::<f32>() / len, grad)
+}
+
+/// returns the [binary cross entropy] and its gradient with respect to the prediction,
+/// the prediction are probabilities and the target are labels 0 or 1
+///
+/// [binary cross entropy]: https://en.wikipedia.org/wiki/Cross_entropy
+///
+/// ## Example
+///
+/// ```rust
+/// use math::linear_algebra::Vector;
+/// use math::losses::binary_cross_entropy;
+/// let pred = Vector::new(vec![0.5, 0.8]);
+/// let target = Vector::new(vec![1., 0.]);
+/// let (loss, grad) = binary_cross_entropy(&pred, &target);
+/// assert_eq!(loss, -((0.5 as f32).ln() + (0.2 as f32).ln()) / 2.);
+/// assert_eq!(grad, Vector::new(vec![-1., 2.5]));
+/// ```
+/// note the prediction is clamped to `[1e-7, 1 - 1e-7]` so the loss stays finite
+pub fn binary_cross_entropy(pred: &Vector, target: &Vector) -> (f32, Vector) {
+    let len = pred.len() as f32;
+    // the loss and the gradient use the same clamped prediction, so both stay finite at 0 and 1
+    let pred: Vector = pred.iter().map(|p| p.clamp(EPSILON, 1. - EPSILON)).collect();
+    let loss = elementwise(&pred, target, |p, t| -(t * p.ln() + (1. - t) * (1. - p).ln()));
+    let grad = elementwise(&pred, target, |p, t| (p - t) / (p * (1. - p)) / len);
+    (loss.vec().iter().sum::<f32>() / len, grad)
+}
+
+/// returns the mean [categorical cross entropy] of a batch and its gradient with respect to the prediction,
+/// each `col` of the matrices is one sample with the predicted probabilities and the target distribution
+///
+/// [categorical cross entropy]: https://en.wikipedia.org/wiki/Cross_entropy
+///
+/// ## Example
+///
+/// ```rust
+/// use math::linear_algebra::Matrix;
+/// use math::losses::categorical_cross_entropy;
+/// let pred = Matrix::new(vec![vec![0.5, 0.5], vec![0.25, 0.75]]);
+/// let target = Matrix::new(vec![vec![1., 0.], vec![0., 1.]]);
+/// let (loss, grad) = categorical_cross_entropy(&pred, &target);
+/// assert_eq!(loss, -((0.5 as f32).ln() + (0.75 as f32).ln()) / 2.);
+/// assert_eq!(grad, Matrix::new(vec![vec![-1., 0.], vec![0., -0.6666667]]));
+/// ```
+/// note the prediction is clamped to `[1e-7, 1]` so the loss stays finite
+pub fn categorical_cross_entropy(pred: &Matrix, target: &Matrix) -> (f32, Matrix) {
+    let loss = metrics::cross_entropy_mat(pred, target);
+    let len = pred.cols() as f32;
+    let grad = pred
+        .matrix_flatt()
+        .iter()
+        .zip(target.matrix_flatt().iter())
+        // the same clamp as the loss in `metrics::cross_entropy`
+        .map(|(p, t)| -t / p.max(EPSILON) / len)
+        .collect();
+    (loss, Matrix::new_flatt(grad, pred.cols(), pred.rows()))
+}
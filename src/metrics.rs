@@ -0,0 +1,243 @@
+use crate::linear_algebra::{Matrix, Vector};
+
+// the smallest probability in the logarithm of the cross entropies
+pub(crate) const EPSILON: f32 = 1e-7;
+
+pub(crate) fn check_same_len(pred: &Vector, target: &Vector) {
+    if pred.len() != target.len() {
+        panic!(
+            "the prediction and target have not the same len pred.len() = {}, target.len() = {}",
+            pred.len(),
+            target.len()
+        );
+    }
+}
+
+// the class index stored in a label
+fn label(x: f32) -> usize {
+    if !(x >= 0. && x.fract() == 0.) {
+        panic!("a label has to be a non-negative integer, got {}", x);
+    }
+    x as usize
+}
+
+fn check_same_shape(pred: &Matrix, target: &Matrix) {
+    if pred.cols() != target.cols() || pred.rows() != target.rows() {
+        panic!(
+            "the prediction and target have not the same shape pred = {}x{}, target = {}x{}",
+            pred.cols(),
+            pred.rows(),
+            target.cols(),
+            target.rows()
+        );
+    }
+}
+
+fn argmax(vec: &[f32]) -> usize {
+    let mut max = 0;
+    for i in 1..vec.len() {
+        if vec[i] > vec[max] {
+            max = i;
+        }
+    }
+    max
+}
+
+/// returns the [mean squared error] between the prediction and the target
+///
+/// [mean squared error]: https://en.wikipedia.org/wiki/Mean_squared_error
+///
+/// ## Example
+///
+/// ```rust
+/// use math::linear_algebra::Vector;
+/// use math::metrics::mse;
+/// let pred = Vector::new(vec![1., 2., 3.]);
+/// let target = Vector::new(vec![1., 4., 6.]);
+/// assert_eq!(mse(&pred, &target), (0. + 4. + 9.) / 3.);
+/// ```
+/// note it panics if the vectors have not the same len
+pub fn mse(pred: &Vector, target: &Vector) -> f32 {
+    check_same_len(pred, target);
+    let sum: f32 = pred
+        .vec()
+        .iter()
+        .zip(target.vec().iter())
+        .map(|(p, t)| (p - t) * (p - t))
+        .sum();
+    sum / pred.len() as f32
+}
+
+/// returns the [mean absolute error] between the prediction and the target
+///
+/// [mean absolute error]: https://en.wikipedia.org/wiki/Mean_absolute_error
+///
+/// ## Example
+///
+/// ```rust
+/// use math::linear_algebra::Vector;
+/// use math::metrics::mae;
+/// let pred = Vector::new(vec![1., 2., 3.]);
+/// let target = Vector::new(vec![1., 4., 6.]);
+/// assert_eq!(mae(&pred, &target), (0. + 2. + 3.) / 3.);
+/// ```
+/// note it panics if the vectors have not the same len
+pub fn mae(pred: &Vector, target: &Vector) -> f32 {
+    check_same_len(pred, target);
+    let sum: f32 = pred
+        .vec()
+        .iter()
+        .zip(target.vec().iter())
+        .map(|(p, t)| (p - t).abs())
+        .sum();
+    sum / pred.len() as f32
+}
+
+/// returns the [root mean squared error] between the prediction and the target
+///
+/// [root mean squared error]: https://en.wikipedia.org/wiki/Root-mean-square_deviation
+///
+/// ## Example
+///
+/// ```rust
+/// use math::linear_algebra::Vector;
+/// use math::metrics::rmse;
+/// let pred = Vector::new(vec![1., 2., 3.]);
+/// let target = Vector::new(vec![1., 4., 6.]);
+/// assert_eq!(rmse(&pred, &target), ((0. + 4. + 9.) / 3. as f32).sqrt());
+/// ```
+/// note it panics if the vectors have not the same len
+pub fn rmse(pred: &Vector, target: &Vector) -> f32 {
+    mse(pred, target).sqrt()
+}
+
+/// returns the [cross entropy] between the predicted probabilities and the target distribution
+///
+/// [cross entropy]: https://en.wikipedia.org/wiki/Cross_entropy
+///
+/// ## Example
+///
+/// ```rust
+/// use math::linear_algebra::Vector;
+/// use math::metrics::cross_entropy;
+/// let pred = Vector::new(vec![0.25, 0.5, 0.25]);
+/// let target = Vector::new(vec![0., 1., 0.]);
+/// assert_eq!(cross_entropy(&pred, &target), -(0.5 as f32).ln());
+/// ```
+/// note the predictions are clamped to `[1e-7, 1]` so a wrong prediction of 0 doesn't return infinity
+pub fn cross_entropy(pred: &Vector, target: &Vector) -> f32 {
+    check_same_len(pred, target);
+    -pred
+        .vec()
+        .iter()
+        .zip(target.vec().iter())
+        .map(|(p, t)| t * p.max(EPSILON).ln())
+        .sum::<f32>()
+}
+
+/// returns the mean [cross entropy] of a batch, each `col` of the matrices is one sample
+///
+/// [cross entropy]: https://en.wikipedia.org/wiki/Cross_entropy
+///
+/// ## Example
+///
+/// ```rust
+/// use math::linear_algebra::Matrix;
+/// use math::metrics::cross_entropy_mat;
+/// let pred = Matrix::new(vec![vec![0.5, 0.5], vec![0.25, 0.75]]);
+/// let target = Matrix::new(vec![vec![1., 0.], vec![0., 1.]]);
+/// assert_eq!(
+///     cross_entropy_mat(&pred, &target),
+///     -((0.5 as f32).ln() + (0.75 as f32).ln()) / 2.
+/// );
+/// ```
+/// note it panics if the matrices have not the same rows and cols
+pub fn cross_entropy_mat(pred: &Matrix, target: &Matrix) -> f32 {
+    check_same_shape(pred, target);
+    let sum: f32 = (0..pred.cols())
+        .map(|i| cross_entropy(&pred.col(i), &target.col(i)))
+        .sum();
+    sum / pred.cols() as f32
+}
+
+/// returns the fraction of labels that were predicted correctly
+///
+/// ## Example
+///
+/// ```rust
+/// use math::linear_algebra::Vector;
+/// use math::metrics::accuracy;
+/// let pred = Vector::new(vec![1., 0., 2., 1.]);
+/// let target = Vector::new(vec![1., 1., 2., 0.]);
+/// assert_eq!(accuracy(&pred, &target), 0.5);
+/// ```
+/// note the labels are the class index stored as a `f32`
+/// and it panics if a label is not a non-negative integer or the vectors have not the same len
+pub fn accuracy(pred: &Vector, target: &Vector) -> f32 {
+    check_same_len(pred, target);
+    let correct = pred
+        .vec()
+        .iter()
+        .zip(target.vec().iter())
+        .filter(|(&p, &t)| label(p) == label(t))
+        .count();
+    correct as f32 / pred.len() as f32
+}
+
+/// returns the fraction of samples where the largest prediction is at the same class as the largest target,
+/// each `col` of the matrices is one sample (for example a one-hot encoding)
+///
+/// ## Example
+///
+/// ```rust
+/// use math::linear_algebra::Matrix;
+/// use math::metrics::accuracy_mat;
+/// let pred = Matrix::new(vec![vec![0.9, 0.1], vec![0.7, 0.3], vec![0.2, 0.8]]);
+/// let target = Matrix::new(vec![vec![1., 0.], vec![0., 1.], vec![0., 1.]]);
+/// assert_eq!(accuracy_mat(&pred, &target), 2. / 3.);
+/// ```
+/// note it panics if the matrices have not the same rows and cols
+pub fn accuracy_mat(pred: &Matrix, target: &Matrix) -> f32 {
+    check_same_shape(pred, target);
+    let correct = (0..pred.cols())
+        .filter(|&i| argmax(&pred.col(i).vec()) == argmax(&target.col(i).vec()))
+        .count();
+    correct as f32 / pred.cols() as f32
+}
+
+/// builds the [confusion matrix] for `classes` classes,
+/// `index(target, pred)` counts how often the class `target` was predicted as `pred`
+///
+/// [confusion matrix]: https://en.wikipedia.org/wiki/Confusion_matrix
+///
+/// ## Example
+///
+/// ```rust
+/// use math::linear_algebra::{Matrix, Vector};
+/// use math::metrics::confusion_matrix;
+/// let pred = Vector::new(vec![0., 1., 1., 0., 1.]);
+/// let target = Vector::new(vec![0., 1., 0., 0., 1.]);
+/// assert_eq!(
+///     confusion_matrix(&pred, &target, 2),
+///     Matrix::new(vec![vec![2., 1.], vec![0., 2.]])
+/// );
+/// ```
+/// note the labels are the class index stored as a `f32`
+/// and it panics if a label is not a non-negative integer smaller than `classes`
+pub fn confusion_matrix(pred: &Vector, target: &Vector, classes: usize) -> Matrix {
+    check_same_len(pred, target);
+    let mut matrix_flatt = vec![0.; classes * classes];
+    for (&p, &t) in pred.vec().iter().zip(target.vec().iter()) {
+        let (p, t) = (label(p), label(t));
+        if p >= classes || t >= classes {
+            panic!(
+                "label out of bounds max label {}, got pred = {}, target = {}",
+                classes - 1,
+                p,
+                t
+            );
+        }
+        matrix_flatt[t * classes + p] += 1.;
+    }
+    Matrix::new_flatt(matrix_flatt, classes, classes)
+}
@@ -1,5 +1,3 @@
-use std::mem;
-
 /// implementation for the [fast inverse square root] 1/sqrt(x)
 ///
 /// [fast inverse square root]:https://en.wikipedia.org/wiki/Fast_inverse_square_root
@@ -19,20 +17,17 @@ use std::mem;
 ///
 /// note this isn't that useful because you need a lot of calculation to feal the difference
 pub fn q_rsqrt(number: f32) -> f32 {
-    let mut i: i32;
-    let x2: f32;
-    let mut y: f32;
     const THREEHALVES: f32 = 1.5;
 
-    x2 = number * 0.5;
-    y = number;
+    let x2 = number * 0.5;
+    let mut y = number;
 
     // Evil floating point bit level hacking
-    i = unsafe { mem::transmute(y) };
+    let mut i = y.to_bits() as i32;
 
     // What the fuck?
     i = 0x5f3759df - (i >> 1);
-    y = unsafe { mem::transmute(i) };
+    y = f32::from_bits(i as u32);
 
     // 1st iteration
     y = y * (THREEHALVES - (x2 * y * y));
@@ -40,5 +35,5 @@ pub fn q_rsqrt(number: f32) -> f32 {
     // 2nd iteration, this can be removed
     // y = y * (THREEHALVES - (x2 * y * y));
 
-    return y;
+    y
 }
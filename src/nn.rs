@@ -0,0 +1,288 @@
+use crate::linear_algebra::{Matrix, Vector};
+use crate::random::Random;
+
+/// the activation function which is applied after the affine transformation of a layer
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum Activation {
+    Identity,
+    Sigmoid,
+    Tanh,
+    Relu,
+}
+
+impl Activation {
+    /// applies the activation function to `x`
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::nn::Activation;
+    /// assert_eq!(Activation::Relu.apply(-2.), 0.);
+    /// assert_eq!(Activation::Sigmoid.apply(0.), 0.5);
+    /// ```
+    pub fn apply(&self, x: f32) -> f32 {
+        match self {
+            Activation::Identity => x,
+            Activation::Sigmoid => 1. / (1. + (-x).exp()),
+            Activation::Tanh => x.tanh(),
+            Activation::Relu => x.max(0.),
+        }
+    }
+
+    /// returns the derivative of the activation function at `x`
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::nn::Activation;
+    /// assert_eq!(Activation::Relu.derivative(-2.), 0.);
+    /// assert_eq!(Activation::Sigmoid.derivative(0.), 0.25);
+    /// ```
+    pub fn derivative(&self, x: f32) -> f32 {
+        match self {
+            Activation::Identity => 1.,
+            Activation::Sigmoid => {
+                let s = self.apply(x);
+                s * (1. - s)
+            }
+            Activation::Tanh => 1. - x.tanh() * x.tanh(),
+            Activation::Relu => {
+                if x > 0. {
+                    1.
+                } else {
+                    0.
+                }
+            }
+        }
+    }
+}
+
+/// a fully connected layer which computes `activation(weights.dot_vec(input) + bias)`
+///
+/// ## Example
+///
+/// ```rust
+/// use math::linear_algebra::{Matrix, Vector};
+/// use math::nn::{Activation, Dense};
+/// use math::random::Random;
+/// let mut dense = Dense::new(3, 2, Activation::Relu, &mut Random::new());
+/// dense.set_weights(Matrix::new(vec![vec![1., 0., -1.], vec![2., 1., 0.]]));
+/// dense.set_bias(Vector::new(vec![0., -1.]));
+/// assert_eq!(dense.forward(&Vector::new(vec![1., 2., 3.])), Vector::new(vec![0., 3.]));
+/// ```
+pub struct Dense {
+    weights: Matrix,
+    bias: Vector,
+    activation: Activation,
+    weights_grad: Matrix,
+    bias_grad: Vector,
+    input: Option<Vector>,
+    pre_activation: Option<Vector>,
+}
+
+impl Dense {
+    /// creates a layer with `inputs` inputs and `outputs` outputs,
+    /// the weights are initialized with [Xavier initialization] drawn from `rng` and the bias with 0,
+    /// so the layers of a network which share one `rng` start with different weights
+    ///
+    /// [Xavier initialization]: https://en.wikipedia.org/wiki/Weight_initialization
+    pub fn new(inputs: usize, outputs: usize, activation: Activation, rng: &mut Random) -> Self {
+        let limit = (6. / (inputs + outputs) as f32).sqrt();
+        let weights = Matrix::new_flatt(
+            (0..outputs * inputs)
+                .map(|_| (rng.f32() * 2. - 1.) * limit)
+                .collect(),
+            outputs,
+            inputs,
+        );
+
+        Dense {
+            weights,
+            bias: Vector::new_zero(outputs),
+            activation,
+            weights_grad: Matrix::new_zero(outputs, inputs),
+            bias_grad: Vector::new_zero(outputs),
+            input: None,
+            pre_activation: None,
+        }
+    }
+
+    /// returns the number of inputs of the layer
+    pub fn inputs(&self) -> usize {
+        self.weights.rows()
+    }
+
+    /// returns the number of outputs of the layer
+    pub fn outputs(&self) -> usize {
+        self.weights.cols()
+    }
+
+    /// getter for the weights, each `col` are the weights of one output
+    pub fn weights(&self) -> &Matrix {
+        &self.weights
+    }
+
+    /// getter for the bias
+    pub fn bias(&self) -> &Vector {
+        &self.bias
+    }
+
+    /// getter for the activation function
+    pub fn activation(&self) -> Activation {
+        self.activation
+    }
+
+    /// getter for the gradient of the weights computed by the last `backward`
+    pub fn weights_grad(&self) -> &Matrix {
+        &self.weights_grad
+    }
+
+    /// getter for the gradient of the bias computed by the last `backward`
+    pub fn bias_grad(&self) -> &Vector {
+        &self.bias_grad
+    }
+
+    /// sets the weights
+    ///
+    /// note it panics if the shape is not `outputs` cols and `inputs` rows
+    pub fn set_weights(&mut self, weights: Matrix) {
+        if weights.cols() != self.outputs() || weights.rows() != self.inputs() {
+            panic!(
+                "wrong weights shape expected {}x{}, got {}x{}",
+                self.outputs(),
+                self.inputs(),
+                weights.cols(),
+                weights.rows()
+            );
+        }
+        self.weights = weights;
+    }
+
+    /// sets the bias
+    ///
+    /// note it panics if the len is not `outputs`
+    pub fn set_bias(&mut self, bias: Vector) {
+        if bias.len() != self.outputs() {
+            panic!(
+                "wrong bias shape expected {}, got {}",
+                self.outputs(),
+                bias.len()
+            );
+        }
+        self.bias = bias;
+    }
+
+    /// computes the output of the layer and remembers the input for the `backward` pass
+    pub fn forward(&mut self, input: &Vector) -> Vector {
+        let mut pre_activation = self.weights.dot_vec(input);
+        pre_activation.add_vec(&self.bias);
+
+        let mut output = pre_activation.clone();
+        let activation = self.activation;
+        output.apply_func(|x| activation.apply(x));
+
+        self.input = Some(input.clone());
+        self.pre_activation = Some(pre_activation);
+        output
+    }
+
+    /// computes the output of the layer for a batch without remembering anything,
+    /// each `col` of the input is one sample and each `col` of the result is the output of this sample
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{Matrix, Vector};
+    /// use math::nn::{Activation, Dense};
+    /// use math::random::Random;
+    /// let mut dense = Dense::new(2, 2, Activation::Identity, &mut Random::new());
+    /// dense.set_weights(Matrix::new(vec![vec![1., 2.], vec![3., 4.]]));
+    /// dense.set_bias(Vector::new(vec![1., 1.]));
+    /// let input = Matrix::new(vec![vec![1., 0.], vec![0., 1.]]);
+    /// assert_eq!(
+    ///     dense.predict_batch(&input),
+    ///     Matrix::new(vec![vec![2., 4.], vec![3., 5.]])
+    /// );
+    /// ```
+    pub fn predict_batch(&self, input: &Matrix) -> Matrix {
+        let mut weights = self.weights.clone();
+        weights.transpose();
+
+        let output = input.dot_mat(&weights);
+        let bias = self.bias.vec();
+        let activation = self.activation;
+        let rows = output.rows();
+        let matrix_flatt = output
+            .matrix_flatt()
+            .iter()
+            .enumerate()
+            .map(|(i, &x)| activation.apply(x + bias[i % rows]))
+            .collect();
+        Matrix::new_flatt(matrix_flatt, output.cols(), rows)
+    }
+
+    /// takes the gradient of the loss with respect to the output of the last `forward` call,
+    /// stores the gradients of the weights and bias and returns the gradient with respect to the input
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::{Matrix, Vector};
+    /// use math::nn::{Activation, Dense};
+    /// use math::random::Random;
+    /// let mut dense = Dense::new(2, 1, Activation::Identity, &mut Random::new());
+    /// dense.set_weights(Matrix::new(vec![vec![2., 3.]]));
+    /// dense.forward(&Vector::new(vec![1., 4.]));
+    /// let input_grad = dense.backward(&Vector::new(vec![1.]));
+    /// assert_eq!(input_grad, Vector::new(vec![2., 3.]));
+    /// assert_eq!(dense.weights_grad(), &Matrix::new(vec![vec![1., 4.]]));
+    /// ```
+    /// note it panics if `forward` wasn't called before
+    pub fn backward(&mut self, output_grad: &Vector) -> Vector {
+        let (input, pre_activation) = match (&self.input, &self.pre_activation) {
+            (Some(input), Some(pre_activation)) => (input, pre_activation),
+            _ => panic!("forward has to be called before backward"),
+        };
+
+        let mut delta = pre_activation.clone();
+        let activation = self.activation;
+        delta.apply_func(|x| activation.derivative(x));
+        delta.mul_vec(output_grad);
+
+        let input_vec = input.vec();
+        let mut weights_grad = Vec::with_capacity(delta.len() * input_vec.len());
+        for d in delta.vec() {
+            for &x in input_vec.iter() {
+                weights_grad.push(d * x);
+            }
+        }
+        self.weights_grad = Matrix::new_flatt(weights_grad, delta.len(), input_vec.len());
+
+        let mut weights = self.weights.clone();
+        weights.transpose();
+        let input_grad = weights.dot_vec(&delta);
+
+        self.bias_grad = delta;
+        input_grad
+    }
+
+    /// updates the weights and bias with the gradients of the last `backward` call (gradient descent)
+    pub fn update(&mut self, learning_rate: f32) {
+        let mut weights_grad = self.weights_grad.clone();
+        weights_grad.mul_scalar(&learning_rate);
+        self.weights = Matrix::new_flatt(
+            self.weights
+                .matrix_flatt()
+                .iter()
+                .zip(weights_grad.matrix_flatt().iter())
+                .map(|(w, g)| w - g)
+                .collect(),
+            self.outputs(),
+            self.inputs(),
+        );
+
+        let mut bias_grad = self.bias_grad.clone();
+        bias_grad.mul_scalar(&learning_rate);
+        self.bias.sub_vec(&bias_grad);
+    }
+}
@@ -0,0 +1,188 @@
+//! coherent noise for simulations and terrain generation
+//!
+//! the value at `index(i, j)` is the noise at the point `(j, i) * frequency`,
+//! with more then one octave the noise is summed up with half the amplitude and double the frequency each octave
+//! and normalized again, so the values stay between about -1 and 1
+//!
+//! ## Example
+//!
+//! ```rust
+//! use math::noise::perlin2d;
+//! let terrain = perlin2d(64, 64, 0.05, 4, 42);
+//! assert_eq!(terrain, perlin2d(64, 64, 0.05, 4, 42));
+//! assert!(terrain.matrix_flatt().iter().all(|x| x.abs() <= 1.));
+//! ```
+
+use crate::linear_algebra::Matrix;
+
+// the permutation table of the classic implementation repeated twice, shuffled with the seed
+struct Permutation {
+    perm: [u8; 512],
+}
+
+impl Permutation {
+    fn new(seed: u64) -> Self {
+        // splitmix64, so similar seeds give unrelated tables
+        let mut state = seed;
+        let mut next = || {
+            state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+            let mut z = state;
+            z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+            z ^ (z >> 31)
+        };
+
+        let mut table = [0u8; 256];
+        for (i, val) in table.iter_mut().enumerate() {
+            *val = i as u8;
+        }
+        for i in (1..256).rev() {
+            let j = (next() % (i as u64 + 1)) as usize;
+            table.swap(i, j);
+        }
+
+        let mut perm = [0; 512];
+        for (i, val) in perm.iter_mut().enumerate() {
+            *val = table[i & 255];
+        }
+        Permutation { perm }
+    }
+
+    fn hash(&self, x: i32, y: i32) -> usize {
+        let x = (x & 255) as usize;
+        let y = (y & 255) as usize;
+        self.perm[self.perm[x] as usize + y] as usize
+    }
+}
+
+fn fade(t: f32) -> f32 {
+    t * t * t * (t * (t * 6. - 15.) + 10.)
+}
+
+fn lerp(a: f32, b: f32, t: f32) -> f32 {
+    a + t * (b - a)
+}
+
+// the dot product of the offset with one of 8 gradients
+fn grad(hash: usize, x: f32, y: f32) -> f32 {
+    match hash & 7 {
+        0 => x + y,
+        1 => -x + y,
+        2 => x - y,
+        3 => -x - y,
+        4 => x,
+        5 => -x,
+        6 => y,
+        _ => -y,
+    }
+}
+
+fn perlin(perm: &Permutation, x: f32, y: f32) -> f32 {
+    let (x0, y0) = (x.floor(), y.floor());
+    let (xi, yi) = (x0 as i32, y0 as i32);
+    let (xf, yf) = (x - x0, y - y0);
+    let (u, v) = (fade(xf), fade(yf));
+
+    let n00 = grad(perm.hash(xi, yi), xf, yf);
+    let n10 = grad(perm.hash(xi + 1, yi), xf - 1., yf);
+    let n01 = grad(perm.hash(xi, yi + 1), xf, yf - 1.);
+    let n11 = grad(perm.hash(xi + 1, yi + 1), xf - 1., yf - 1.);
+    // the largest possible value of the interpolation is 1 along the diagonal gradients
+    lerp(lerp(n00, n10, u), lerp(n01, n11, u), v).clamp(-1., 1.)
+}
+
+fn simplex(perm: &Permutation, x: f32, y: f32) -> f32 {
+    let f2 = 0.5 * (3f32.sqrt() - 1.);
+    let g2 = (3. - 3f32.sqrt()) / 6.;
+
+    // skew the input into the simplex grid and find the triangle of the point
+    let s = (x + y) * f2;
+    let (i, j) = ((x + s).floor(), (y + s).floor());
+    let t = (i + j) * g2;
+    let (x0, y0) = (x - (i - t), y - (j - t));
+    let (i1, j1) = if x0 > y0 { (1, 0) } else { (0, 1) };
+
+    let corners = [
+        (x0, y0, 0, 0),
+        (x0 - i1 as f32 + g2, y0 - j1 as f32 + g2, i1, j1),
+        (x0 - 1. + 2. * g2, y0 - 1. + 2. * g2, 1, 1),
+    ];
+    let (i, j) = (i as i32, j as i32);
+    let sum: f32 = corners
+        .iter()
+        .map(|&(dx, dy, di, dj)| {
+            let t = 0.5 - dx * dx - dy * dy;
+            if t < 0. {
+                0.
+            } else {
+                t.powi(4) * grad(perm.hash(i + di, j + dj), dx, dy)
+            }
+        })
+        .sum();
+    // scales the result to about -1 and 1
+    (70. * sum).clamp(-1., 1.)
+}
+
+fn fractal<F: Fn(&Permutation, f32, f32) -> f32>(
+    cols: usize,
+    rows: usize,
+    frequency: f32,
+    octaves: usize,
+    seed: u64,
+    noise: F,
+) -> Matrix {
+    if octaves == 0 {
+        panic!("the noise needs at least one octave");
+    }
+    let perm = Permutation::new(seed);
+    let total: f32 = (0..octaves).map(|o| 0.5f32.powi(o as i32)).sum();
+
+    let mut matrix_flatt = Vec::with_capacity(cols * rows);
+    for i in 0..cols {
+        for j in 0..rows {
+            let (mut amplitude, mut freq, mut value) = (1., frequency, 0.);
+            for _ in 0..octaves {
+                value += amplitude * noise(&perm, j as f32 * freq, i as f32 * freq);
+                amplitude *= 0.5;
+                freq *= 2.;
+            }
+            matrix_flatt.push(value / total);
+        }
+    }
+    Matrix::new_flatt(matrix_flatt, cols, rows)
+}
+
+/// fills a `cols` x `rows` matrix with [Perlin noise] (the improved version from 2002)
+///
+/// [Perlin noise]: https://en.wikipedia.org/wiki/Perlin_noise
+///
+/// note it panics if `octaves` is 0
+///
+/// ## Example
+///
+/// ```rust
+/// use math::noise::perlin2d;
+/// let noise = perlin2d(2, 3, 1., 1, 7);
+/// // the noise is 0 on the integer grid
+/// assert_eq!(noise.matrix_flatt(), vec![0.; 6]);
+/// ```
+pub fn perlin2d(cols: usize, rows: usize, frequency: f32, octaves: usize, seed: u64) -> Matrix {
+    fractal(cols, rows, frequency, octaves, seed, perlin)
+}
+
+/// fills a `cols` x `rows` matrix with [simplex noise], which has less directional artifacts then Perlin noise
+///
+/// [simplex noise]: https://en.wikipedia.org/wiki/Simplex_noise
+///
+/// note it panics if `octaves` is 0
+///
+/// ## Example
+///
+/// ```rust
+/// use math::noise::simplex2d;
+/// let noise = simplex2d(32, 32, 0.1, 2, 1);
+/// assert_ne!(noise, simplex2d(32, 32, 0.1, 2, 2));
+/// ```
+pub fn simplex2d(cols: usize, rows: usize, frequency: f32, octaves: usize, seed: u64) -> Matrix {
+    fractal(cols, rows, frequency, octaves, seed, simplex)
+}
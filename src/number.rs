@@ -0,0 +1,340 @@
+//! exact number types for the cases where floating point errors are wrong
+
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+use std::fmt;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// an integer [modulo] `P`
+///
+/// [modulo]: https://en.wikipedia.org/wiki/Modular_arithmetic
+///
+/// ## Example
+///
+/// ```rust
+/// use math::number::ModInt;
+/// type M7 = ModInt<7>;
+/// assert_eq!(M7::new(5) + M7::new(4), M7::new(2));
+/// assert_eq!(M7::new(3) * M7::new(5), M7::new(1));
+/// assert_eq!(M7::new(3).inv(), M7::new(5));
+/// assert_eq!(M7::from_i64(-1).value(), 6);
+/// ```
+/// note `inv` and the division only work if `P` is a prime
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug, Default, PartialOrd, Ord)]
+pub struct ModInt<const P: u64>(u64);
+
+impl<const P: u64> ModInt<P> {
+    /// creates the value `value mod P`
+    pub fn new(value: u64) -> Self {
+        ModInt(value % P)
+    }
+
+    /// creates the value `value mod P`, negative values wrap around to `P - 1`, `P - 2`, ...
+    pub fn from_i64(value: i64) -> Self {
+        ModInt(value.rem_euclid(P as i64) as u64)
+    }
+
+    /// returns the value between 0 and `P - 1`
+    pub fn value(self) -> u64 {
+        self.0
+    }
+
+    /// computes `self^exp` with [exponentiation by squaring]
+    ///
+    /// [exponentiation by squaring]: https://en.wikipedia.org/wiki/Exponentiation_by_squaring
+    pub fn pow(self, mut exp: u64) -> Self {
+        let mut base = self;
+        let mut result = ModInt::new(1);
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result *= base;
+            }
+            base *= base;
+            exp >>= 1;
+        }
+        result
+    }
+
+    /// returns the multiplicative inverse with [Fermat's little theorem]
+    ///
+    /// [Fermat's little theorem]: https://en.wikipedia.org/wiki/Fermat%27s_little_theorem
+    ///
+    /// note it panics if the value is 0
+    pub fn inv(self) -> Self {
+        if self.0 == 0 {
+            panic!("0 has no inverse modulo {}", P);
+        }
+        self.pow(P - 2)
+    }
+}
+
+impl<const P: u64> From<u64> for ModInt<P> {
+    fn from(value: u64) -> Self {
+        ModInt::new(value)
+    }
+}
+
+impl<const P: u64> fmt::Display for ModInt<P> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl<const P: u64> Add for ModInt<P> {
+    type Output = Self;
+    fn add(self, other: Self) -> Self {
+        // the sum can overflow u64 for moduli larger then 2^63
+        ModInt(((self.0 as u128 + other.0 as u128) % P as u128) as u64)
+    }
+}
+
+impl<const P: u64> Sub for ModInt<P> {
+    type Output = Self;
+    fn sub(self, other: Self) -> Self {
+        self + (-other)
+    }
+}
+
+impl<const P: u64> Mul for ModInt<P> {
+    type Output = Self;
+    fn mul(self, other: Self) -> Self {
+        ModInt(((self.0 as u128 * other.0 as u128) % P as u128) as u64)
+    }
+}
+
+impl<const P: u64> Div for ModInt<P> {
+    type Output = Self;
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, other: Self) -> Self {
+        self * other.inv()
+    }
+}
+
+impl<const P: u64> Neg for ModInt<P> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        if self.0 == 0 {
+            self
+        } else {
+            ModInt(P - self.0)
+        }
+    }
+}
+
+impl<const P: u64> AddAssign for ModInt<P> {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl<const P: u64> SubAssign for ModInt<P> {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl<const P: u64> MulAssign for ModInt<P> {
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+
+impl<const P: u64> DivAssign for ModInt<P> {
+    fn div_assign(&mut self, other: Self) {
+        *self = *self / other;
+    }
+}
+
+/// an exact [rational number] `numer / denom`, which is always reduced and has a positive denominator
+///
+/// [rational number]: https://en.wikipedia.org/wiki/Rational_number
+///
+/// ## Example
+///
+/// ```rust
+/// use math::number::Rational;
+/// let third = Rational::new(1, 3);
+/// assert_eq!(third + third + third, Rational::from(1));
+/// assert_eq!(Rational::new(4, -6), Rational::new(-2, 3));
+/// assert_eq!(format!("{}", Rational::new(6, 4)), "3/2");
+/// ```
+/// note all operations panic if the numerator or denominator doesn't fit in to an `i64`
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+pub struct Rational {
+    numer: i64,
+    denom: i64,
+}
+
+fn gcd(mut a: i128, mut b: i128) -> i128 {
+    while b != 0 {
+        let t = a % b;
+        a = b;
+        b = t;
+    }
+    a.abs()
+}
+
+impl Rational {
+    /// creates the reduced fraction `numer / denom`
+    ///
+    /// note it panics if `denom` is 0
+    pub fn new(numer: i64, denom: i64) -> Self {
+        Rational::reduce(numer as i128, denom as i128)
+    }
+
+    fn reduce(numer: i128, denom: i128) -> Self {
+        if denom == 0 {
+            panic!("the denominator can't be 0");
+        }
+        let g = gcd(numer, denom);
+        let sign = denom.signum();
+        let (numer, denom) = (sign * numer / g, sign * denom / g);
+        match (i64::try_from(numer), i64::try_from(denom)) {
+            (Ok(numer), Ok(denom)) => Rational { numer, denom },
+            _ => panic!("the rational {}/{} overflowed", numer, denom),
+        }
+    }
+
+    /// returns the numerator
+    pub fn numer(self) -> i64 {
+        self.numer
+    }
+
+    /// returns the denominator, which is always positive
+    pub fn denom(self) -> i64 {
+        self.denom
+    }
+
+    /// returns true if the value is 0
+    pub fn is_zero(self) -> bool {
+        self.numer == 0
+    }
+
+    /// returns the absolute value
+    pub fn abs(self) -> Self {
+        Rational {
+            numer: self.numer.abs(),
+            denom: self.denom,
+        }
+    }
+
+    /// returns `1 / self`
+    ///
+    /// note it panics if the value is 0
+    pub fn recip(self) -> Self {
+        Rational::reduce(self.denom as i128, self.numer as i128)
+    }
+
+    /// returns the nearest f32
+    pub fn to_f32(self) -> f32 {
+        (self.numer as f64 / self.denom as f64) as f32
+    }
+}
+
+impl Default for Rational {
+    fn default() -> Self {
+        Rational::from(0)
+    }
+}
+
+impl From<i64> for Rational {
+    fn from(value: i64) -> Self {
+        Rational {
+            numer: value,
+            denom: 1,
+        }
+    }
+}
+
+impl fmt::Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.denom == 1 {
+            write!(f, "{}", self.numer)
+        } else {
+            write!(f, "{}/{}", self.numer, self.denom)
+        }
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Rational {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.numer as i128 * other.denom as i128).cmp(&(other.numer as i128 * self.denom as i128))
+    }
+}
+
+impl Add for Rational {
+    type Output = Self;
+    fn add(self, other: Self) -> Self {
+        Rational::reduce(
+            self.numer as i128 * other.denom as i128 + other.numer as i128 * self.denom as i128,
+            self.denom as i128 * other.denom as i128,
+        )
+    }
+}
+
+impl Sub for Rational {
+    type Output = Self;
+    fn sub(self, other: Self) -> Self {
+        self + (-other)
+    }
+}
+
+impl Mul for Rational {
+    type Output = Self;
+    fn mul(self, other: Self) -> Self {
+        Rational::reduce(
+            self.numer as i128 * other.numer as i128,
+            self.denom as i128 * other.denom as i128,
+        )
+    }
+}
+
+impl Div for Rational {
+    type Output = Self;
+    fn div(self, other: Self) -> Self {
+        if other.is_zero() {
+            panic!("division by 0");
+        }
+        Rational::reduce(
+            self.numer as i128 * other.denom as i128,
+            self.denom as i128 * other.numer as i128,
+        )
+    }
+}
+
+impl Neg for Rational {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Rational::reduce(-(self.numer as i128), self.denom as i128)
+    }
+}
+
+impl AddAssign for Rational {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for Rational {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl MulAssign for Rational {
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+
+impl DivAssign for Rational {
+    fn div_assign(&mut self, other: Self) {
+        *self = *self / other;
+    }
+}
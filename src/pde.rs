@@ -0,0 +1,153 @@
+//! finite difference solvers for partial differential equations on a `Matrix` grid
+//!
+//! the value at `index(i, j)` is the value at the grid point `(i, j) * dx`
+
+use crate::linear_algebra::solve::tridiagonal;
+use crate::linear_algebra::Matrix;
+
+/// the time stepping scheme of the [`HeatEquation`]
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum Scheme {
+    /// forward Euler (FTCS), cheap but only stable for `alpha * dt / dx^2 <= 1/4`
+    Explicit,
+    /// the [alternating direction implicit] method of Peaceman and Rachford,
+    /// each half step is implicit along one axis and solves one tridiagonal system per line.
+    /// it is stable for any `dt` and converges to the steady state of the equation
+    ///
+    /// [alternating direction implicit]: https://en.wikipedia.org/wiki/Alternating-direction_implicit_method
+    Implicit,
+}
+
+/// the [heat equation] `du/dt = alpha * (d^2u/dx^2 + d^2u/dy^2)` on a 2D grid
+///
+/// the values on the edges of the grid are kept fixed (Dirichlet boundary conditions)
+///
+/// [heat equation]: https://en.wikipedia.org/wiki/Heat_equation
+///
+/// ## Example
+///
+/// ```rust
+/// use math::linear_algebra::Matrix;
+/// use math::pde::{HeatEquation, Scheme};
+/// let mut grid = Matrix::new_zero(5, 5);
+/// grid.set_index(2, 2, 1.);
+/// let heat = HeatEquation::new(1., 1., 0.2, Scheme::Explicit);
+/// let snapshots = heat.run(&grid, 10, 5);
+/// assert_eq!(snapshots.len(), 3);
+/// // the heat spreads out
+/// assert!(snapshots[2].index(2, 2) < 0.2);
+/// assert!(snapshots[2].index(1, 2) > 0.);
+/// ```
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct HeatEquation {
+    alpha: f32,
+    dx: f32,
+    dt: f32,
+    scheme: Scheme,
+}
+
+impl HeatEquation {
+    /// creates the equation with the diffusivity `alpha`, the grid spacing `dx` and the time step `dt`
+    ///
+    /// note it panics if the explicit scheme would be unstable (`alpha * dt / dx^2 > 1/4`)
+    pub fn new(alpha: f32, dx: f32, dt: f32, scheme: Scheme) -> Self {
+        let heat = HeatEquation {
+            alpha,
+            dx,
+            dt,
+            scheme,
+        };
+        if scheme == Scheme::Explicit && heat.ratio() > 0.25 {
+            panic!(
+                "the explicit scheme is unstable for alpha * dt / dx^2 = {} > 0.25",
+                heat.ratio()
+            );
+        }
+        heat
+    }
+
+    fn ratio(&self) -> f32 {
+        self.alpha * self.dt / (self.dx * self.dx)
+    }
+
+    /// returns the grid after one time step
+    pub fn step(&self, grid: &Matrix) -> Matrix {
+        let (n, m) = (grid.cols(), grid.rows());
+        let mut u = grid.matrix_flatt();
+        let r = self.ratio();
+
+        match self.scheme {
+            Scheme::Explicit => {
+                let old = u.clone();
+                for i in 1..n.saturating_sub(1) {
+                    for j in 1..m.saturating_sub(1) {
+                        let k = i * m + j;
+                        let neighbours = old[k - m] + old[k + m] + old[k - 1] + old[k + 1];
+                        u[k] = old[k] + r * (neighbours - 4. * old[k]);
+                    }
+                }
+            }
+            Scheme::Implicit => {
+                // implicit along j and explicit along i, then the other way around
+                adi_half_step(&mut u, n, m, m, 1, r / 2.);
+                adi_half_step(&mut u, m, n, 1, m, r / 2.);
+            }
+        }
+        Matrix::new_flatt(u, n, m)
+    }
+
+    /// runs `steps` time steps and returns the initial grid and every `every`-th grid
+    ///
+    /// note it panics if `every` is 0
+    pub fn run(&self, initial: &Matrix, steps: usize, every: usize) -> Vec<Matrix> {
+        if every == 0 {
+            panic!("every has to be at least 1");
+        }
+        let mut snapshots = vec![initial.clone()];
+        let mut grid = initial.clone();
+        for step in 1..=steps {
+            grid = self.step(&grid);
+            if step % every == 0 {
+                snapshots.push(grid.clone());
+            }
+        }
+        snapshots
+    }
+}
+
+// solves (1 + 2r) v_k - r v_{k-1} - r v_{k+1} = u_k + r (u_{k-line_stride} - 2 u_k + u_{k+line_stride})
+// for the inner points of each of the `lines` lines, so it is implicit along the lines and explicit across them.
+// the `len` points of a line are `stride` apart and the lines start `line_stride` apart
+fn adi_half_step(
+    u: &mut [f32],
+    lines: usize,
+    len: usize,
+    line_stride: usize,
+    stride: usize,
+    r: f32,
+) {
+    if lines < 3 || len < 3 {
+        return;
+    }
+    let old = u.to_vec();
+    let inner = len - 2;
+    let off = vec![-r; inner - 1];
+    let diag = vec![1. + 2. * r; inner];
+    let mut x = vec![0.; inner];
+
+    for line in 1..lines - 1 {
+        let at = |k: usize| line * line_stride + k * stride;
+        for (k, val) in x.iter_mut().enumerate() {
+            let p = at(k + 1);
+            *val = old[p] + r * (old[p - line_stride] - 2. * old[p] + old[p + line_stride]);
+        }
+        // the fixed boundary values move to the right hand side
+        x[0] += r * old[at(0)];
+        x[inner - 1] += r * old[at(len - 1)];
+
+        tridiagonal(&off, &diag, &off, &mut x);
+        for (k, &val) in x.iter().enumerate() {
+            u[at(k + 1)] = val;
+        }
+    }
+}
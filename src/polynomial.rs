@@ -0,0 +1,343 @@
+//! polynomials with `f32` coefficients
+//!
+//! the coefficients are stored in increasing order, so `coeffs()[k]` belongs to `x^k`,
+//! and the trailing zeros are removed so the last coefficient is never 0
+//!
+//! ## Example
+//!
+//! ```rust
+//! use math::polynomial::Polynomial;
+//! // (x - 1) (x + 2) = x^2 + x - 2
+//! let p = Polynomial::new(vec![-1., 1.]) * Polynomial::new(vec![2., 1.]);
+//! assert_eq!(p.coeffs(), &[-2., 1., 1.]);
+//! assert_eq!(p.eval(3.), 10.);
+//! assert_eq!(p.derivative(), Polynomial::new(vec![1., 2.]));
+//! let (q, r) = p.div_rem(&Polynomial::new(vec![-1., 1.]));
+//! assert_eq!((q, r), (Polynomial::new(vec![2., 1.]), Polynomial::zero()));
+//! assert_eq!(p.to_string(), "x^2 + x - 2");
+//! ```
+
+use crate::linear_algebra::{Matrix, Vector};
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
+/// a polynomial `sum coeffs[k] x^k`
+#[derive(PartialEq, Clone, Debug, Default)]
+pub struct Polynomial {
+    coeffs: Vec<f32>,
+}
+
+impl Polynomial {
+    /// creates the polynomial with the coefficients in increasing order, trailing zeros are removed
+    pub fn new(mut coeffs: Vec<f32>) -> Self {
+        while coeffs.last() == Some(&0.) {
+            coeffs.pop();
+        }
+        Polynomial { coeffs }
+    }
+
+    /// creates the polynomial which is 0 everywhere, it has no coefficients
+    pub fn zero() -> Self {
+        Polynomial { coeffs: Vec::new() }
+    }
+
+    /// creates the polynomial which is `val` everywhere
+    pub fn constant(val: f32) -> Self {
+        Polynomial::new(vec![val])
+    }
+
+    /// creates the monomial `coeff * x^degree`
+    pub fn monomial(coeff: f32, degree: usize) -> Self {
+        let mut coeffs = vec![0.; degree + 1];
+        coeffs[degree] = coeff;
+        Polynomial::new(coeffs)
+    }
+
+    /// getter for the coefficients in increasing order
+    pub fn coeffs(&self) -> &[f32] {
+        &self.coeffs
+    }
+
+    /// returns the degree, the zero polynomial has the degree 0 like a constant
+    pub fn degree(&self) -> usize {
+        self.coeffs.len().saturating_sub(1)
+    }
+
+    /// returns true if this is the zero polynomial
+    pub fn is_zero(&self) -> bool {
+        self.coeffs.is_empty()
+    }
+
+    /// evaluates the polynomial at `x` with [Horner's method]
+    ///
+    /// [Horner's method]: https://en.wikipedia.org/wiki/Horner%27s_method
+    pub fn eval(&self, x: f32) -> f32 {
+        self.coeffs.iter().rev().fold(0., |acc, &c| acc * x + c)
+    }
+
+    /// evaluates the polynomial at every value of `x`, see [`polyval`]
+    pub fn eval_vec(&self, x: &Vector) -> Vector {
+        x.vec().into_iter().map(|x| self.eval(x)).collect()
+    }
+
+    /// returns the derivative
+    pub fn derivative(&self) -> Polynomial {
+        Polynomial::new(
+            self.coeffs
+                .iter()
+                .enumerate()
+                .skip(1)
+                .map(|(k, c)| c * k as f32)
+                .collect(),
+        )
+    }
+
+    /// returns the antiderivative with the constant term 0
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::polynomial::Polynomial;
+    /// let p = Polynomial::new(vec![1., 2., 3.]);
+    /// assert_eq!(p.antiderivative(), Polynomial::new(vec![0., 1., 1., 1.]));
+    /// assert_eq!(p.antiderivative().derivative(), p);
+    /// ```
+    pub fn antiderivative(&self) -> Polynomial {
+        let mut coeffs = Vec::with_capacity(self.coeffs.len() + 1);
+        coeffs.push(0.);
+        coeffs.extend(
+            self.coeffs
+                .iter()
+                .enumerate()
+                .map(|(k, c)| c / (k + 1) as f32),
+        );
+        Polynomial::new(coeffs)
+    }
+
+    /// returns the integral from `a` to `b`
+    pub fn integrate(&self, a: f32, b: f32) -> f32 {
+        let antiderivative = self.antiderivative();
+        antiderivative.eval(b) - antiderivative.eval(a)
+    }
+
+    /// [polynomial long division], returns the quotient and the remainder
+    /// where the remainder has a smaller degree then the divisor
+    ///
+    /// [polynomial long division]: https://en.wikipedia.org/wiki/Polynomial_long_division
+    ///
+    /// note it panics if the divisor is the zero polynomial
+    pub fn div_rem(&self, divisor: &Polynomial) -> (Polynomial, Polynomial) {
+        let lead = match divisor.coeffs.last() {
+            Some(&lead) => lead,
+            None => panic!("division by the zero polynomial"),
+        };
+        let n = divisor.coeffs.len();
+        if self.coeffs.len() < n {
+            return (Polynomial::zero(), self.clone());
+        }
+
+        let mut rem = self.coeffs.clone();
+        let mut quot = vec![0.; rem.len() - n + 1];
+        for k in (0..quot.len()).rev() {
+            let factor = rem[k + n - 1] / lead;
+            quot[k] = factor;
+            for (r, d) in rem[k..k + n].iter_mut().zip(divisor.coeffs.iter()) {
+                *r -= factor * d;
+            }
+            // the leading coefficient is removed exactly, even if rounding left a tiny value
+            rem[k + n - 1] = 0.;
+        }
+        rem.truncate(n - 1);
+        (Polynomial::new(quot), Polynomial::new(rem))
+    }
+
+    // combines the coefficients of both polynomials, the missing ones are 0
+    fn zip<F: Fn(f32, f32) -> f32>(&self, other: &Polynomial, func: F) -> Polynomial {
+        let len = self.coeffs.len().max(other.coeffs.len());
+        let coeff = |p: &Polynomial, k: usize| p.coeffs.get(k).copied().unwrap_or(0.);
+        Polynomial::new(
+            (0..len)
+                .map(|k| func(coeff(self, k), coeff(other, k)))
+                .collect(),
+        )
+    }
+}
+
+/// returns the polynomial of the degree which fits the samples `(x, y)` best in the [least squares] sense,
+/// it solves the Vandermonde system with `Matrix::lstsq`
+///
+/// [least squares]: https://en.wikipedia.org/wiki/Polynomial_regression
+///
+/// ## Example
+///
+/// ```rust
+/// use math::linear_algebra::Vector;
+/// use math::polynomial::{polyfit, polyval};
+/// let x = Vector::new(vec![0., 1., 2., 3.]);
+/// let y = Vector::new(vec![1.1, 2.9, 5.1, 6.9]);
+/// let line = polyfit(&x, &y, 1);
+/// assert!((line.coeffs()[0] - 1.06).abs() < 1e-5 && (line.coeffs()[1] - 1.96).abs() < 1e-5);
+/// let fitted = polyval(&line, &x);
+/// assert!((fitted.index(3) - 6.94).abs() < 1e-5);
+/// ```
+/// high degrees make the system badly conditioned, so the samples should be in a small range around 0
+///
+/// note it panics if `x` and `y` have a different len or there are less then `degree + 1` distinct samples
+pub fn polyfit(x: &Vector, y: &Vector, degree: usize) -> Polynomial {
+    if x.len() != y.len() {
+        panic!("x and y need the same len, got {} and {}", x.len(), y.len());
+    }
+    if x.len() <= degree {
+        panic!(
+            "a polynomial of degree {} needs at least {} samples, got {}",
+            degree,
+            degree + 1,
+            x.len()
+        );
+    }
+    // each line of the Vandermonde matrix is [1, x, x^2, ...]
+    let vandermonde = Matrix::new(
+        x.vec()
+            .into_iter()
+            .map(|x| {
+                (0..=degree)
+                    .scan(1., |pow, _| {
+                        let val = *pow;
+                        *pow *= x;
+                        Some(val)
+                    })
+                    .collect()
+            })
+            .collect(),
+    );
+    Polynomial::new(vandermonde.lstsq(y).vec())
+}
+
+/// evaluates the polynomial at every value of `x`
+pub fn polyval(poly: &Polynomial, x: &Vector) -> Vector {
+    poly.eval_vec(x)
+}
+
+impl From<Vec<f32>> for Polynomial {
+    fn from(coeffs: Vec<f32>) -> Self {
+        Polynomial::new(coeffs)
+    }
+}
+
+impl fmt::Display for Polynomial {
+    /// writes the terms in decreasing order like `2x^2 - x + 0.5`
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_zero() {
+            return write!(f, "0");
+        }
+        let mut first = true;
+        for (k, &c) in self.coeffs.iter().enumerate().rev() {
+            if c == 0. {
+                continue;
+            }
+            let abs = c.abs();
+            match (first, c < 0.) {
+                (true, true) => write!(f, "-")?,
+                (true, false) => {}
+                (false, true) => write!(f, " - ")?,
+                (false, false) => write!(f, " + ")?,
+            }
+            first = false;
+            if abs != 1. || k == 0 {
+                write!(f, "{}", abs)?;
+            }
+            match k {
+                0 => {}
+                1 => write!(f, "x")?,
+                _ => write!(f, "x^{}", k)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Add for &Polynomial {
+    type Output = Polynomial;
+
+    fn add(self, other: &Polynomial) -> Polynomial {
+        self.zip(other, |a, b| a + b)
+    }
+}
+
+impl Sub for &Polynomial {
+    type Output = Polynomial;
+
+    fn sub(self, other: &Polynomial) -> Polynomial {
+        self.zip(other, |a, b| a - b)
+    }
+}
+
+impl Mul for &Polynomial {
+    type Output = Polynomial;
+
+    fn mul(self, other: &Polynomial) -> Polynomial {
+        if self.is_zero() || other.is_zero() {
+            return Polynomial::zero();
+        }
+        let mut coeffs = vec![0.; self.coeffs.len() + other.coeffs.len() - 1];
+        for (i, a) in self.coeffs.iter().enumerate() {
+            for (j, b) in other.coeffs.iter().enumerate() {
+                coeffs[i + j] += a * b;
+            }
+        }
+        Polynomial::new(coeffs)
+    }
+}
+
+impl Div for &Polynomial {
+    type Output = Polynomial;
+
+    /// the quotient of `div_rem`
+    fn div(self, other: &Polynomial) -> Polynomial {
+        self.div_rem(other).0
+    }
+}
+
+impl Rem for &Polynomial {
+    type Output = Polynomial;
+
+    /// the remainder of `div_rem`
+    fn rem(self, other: &Polynomial) -> Polynomial {
+        self.div_rem(other).1
+    }
+}
+
+// the operators on owned polynomials use the ones on references
+macro_rules! impl_owned {
+    ($trait:ident, $fn:ident) => {
+        impl $trait for Polynomial {
+            type Output = Polynomial;
+
+            fn $fn(self, other: Polynomial) -> Polynomial {
+                (&self).$fn(&other)
+            }
+        }
+    };
+}
+
+impl_owned!(Add, add);
+impl_owned!(Sub, sub);
+impl_owned!(Mul, mul);
+impl_owned!(Div, div);
+impl_owned!(Rem, rem);
+
+impl Mul<f32> for Polynomial {
+    type Output = Polynomial;
+
+    fn mul(self, other: f32) -> Polynomial {
+        Polynomial::new(self.coeffs.iter().map(|c| c * other).collect())
+    }
+}
+
+impl Neg for Polynomial {
+    type Output = Polynomial;
+
+    fn neg(self) -> Polynomial {
+        self * -1.
+    }
+}
@@ -0,0 +1,307 @@
+use crate::linear_algebra::{Matrix, Vector};
+use crate::statistics::Interpolation;
+
+// applies `func(value, feature)` to every value of the data, each `col` is one sample
+fn map_features<F: Fn(f32, usize) -> f32>(data: &Matrix, func: F) -> Matrix {
+    let rows = data.rows();
+    let matrix_flatt = data
+        .matrix_flatt()
+        .iter()
+        .enumerate()
+        .map(|(i, &x)| func(x, i % rows))
+        .collect();
+    Matrix::new_flatt(matrix_flatt, data.cols(), rows)
+}
+
+// `name` is the kind of the caller for the panic message
+fn check_fitted(name: &str, features: Option<usize>, data: &Matrix) {
+    match features {
+        None => panic!("the {} has to be fitted before it can transform", name),
+        Some(features) if features != data.rows() => panic!(
+            "wrong number of features expected {}, got {}",
+            features,
+            data.rows()
+        ),
+        _ => {}
+    }
+}
+
+/// scales each feature to a mean of 0 and a standard deviation of 1 ([standard score])
+///
+/// [standard score]: https://en.wikipedia.org/wiki/Standard_score
+///
+/// ## Example
+///
+/// ```rust
+/// use math::linear_algebra::Matrix;
+/// use math::preprocessing::StandardScaler;
+/// let train = Matrix::new(vec![vec![1., 10.], vec![3., 30.]]);
+/// let mut scaler = StandardScaler::new();
+/// scaler.fit(&train);
+/// assert_eq!(
+///     scaler.transform(&train),
+///     Matrix::new(vec![vec![-1., -1.], vec![1., 1.]])
+/// );
+/// assert_eq!(
+///     scaler.transform(&Matrix::new(vec![vec![5., 20.]])),
+///     Matrix::new(vec![vec![3., 0.]])
+/// );
+/// ```
+/// note each `col` of the data is one sample and each `row` is one feature
+#[derive(PartialEq, Clone, Debug)]
+pub struct StandardScaler {
+    normalizer: Normalizer,
+}
+
+impl Default for StandardScaler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl StandardScaler {
+    /// creates a scaler which still has to be fitted
+    pub fn new() -> Self {
+        StandardScaler {
+            normalizer: Normalizer::new(Normalization::ZScore),
+        }
+    }
+
+    /// learns the mean and the (population) standard deviation of each feature
+    pub fn fit(&mut self, data: &Matrix) {
+        self.normalizer.fit(data);
+    }
+
+    /// fits the scaler and transforms the data
+    pub fn fit_transform(&mut self, data: &Matrix) -> Matrix {
+        self.fit(data);
+        self.transform(data)
+    }
+
+    /// getter for the mean of each feature
+    pub fn mean(&self) -> Option<&Vector> {
+        self.normalizer.center()
+    }
+
+    /// getter for the standard deviation of each feature
+    pub fn std(&self) -> Option<&Vector> {
+        self.normalizer.scale()
+    }
+
+    /// scales the data with the learned parameters
+    ///
+    /// note it panics if the scaler isn't fitted or the number of features is wrong
+    pub fn transform(&self, data: &Matrix) -> Matrix {
+        self.normalizer.transform_as("scaler", data)
+    }
+
+    /// reverts the `transform`
+    ///
+    /// note it panics if the scaler isn't fitted or the number of features is wrong
+    pub fn inverse_transform(&self, data: &Matrix) -> Matrix {
+        self.normalizer.inverse_transform_as("scaler", data)
+    }
+}
+
+/// scales each feature linear so the smallest value is 0 and the largest is 1 ([feature scaling])
+///
+/// [feature scaling]: https://en.wikipedia.org/wiki/Feature_scaling
+///
+/// ## Example
+///
+/// ```rust
+/// use math::linear_algebra::Matrix;
+/// use math::preprocessing::MinMaxScaler;
+/// let train = Matrix::new(vec![vec![1., 10.], vec![3., 30.], vec![2., 50.]]);
+/// let mut scaler = MinMaxScaler::new();
+/// let scaled = scaler.fit_transform(&train);
+/// assert_eq!(
+///     scaled,
+///     Matrix::new(vec![vec![0., 0.], vec![1., 0.5], vec![0.5, 1.]])
+/// );
+/// assert_eq!(scaler.inverse_transform(&scaled), train);
+/// ```
+/// note each `col` of the data is one sample and each `row` is one feature
+#[derive(PartialEq, Clone, Debug)]
+pub struct MinMaxScaler {
+    normalizer: Normalizer,
+    max: Option<Vector>,
+}
+
+impl Default for MinMaxScaler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MinMaxScaler {
+    /// creates a scaler which still has to be fitted
+    pub fn new() -> Self {
+        MinMaxScaler {
+            normalizer: Normalizer::new(Normalization::MinMax),
+            max: None,
+        }
+    }
+
+    /// learns the min and max of each feature
+    pub fn fit(&mut self, data: &Matrix) {
+        self.normalizer.fit(data);
+        // the normalizer only keeps the range, which is 1 for constant features
+        self.max = Some(
+            (0..data.rows())
+                .map(|j| data.row(j).max().unwrap_or(0.))
+                .collect(),
+        );
+    }
+
+    /// fits the scaler and transforms the data
+    pub fn fit_transform(&mut self, data: &Matrix) -> Matrix {
+        self.fit(data);
+        self.transform(data)
+    }
+
+    /// getter for the min of each feature
+    pub fn min(&self) -> Option<&Vector> {
+        self.normalizer.center()
+    }
+
+    /// getter for the max of each feature
+    pub fn max(&self) -> Option<&Vector> {
+        self.max.as_ref()
+    }
+
+    /// scales the data with the learned parameters
+    ///
+    /// note it panics if the scaler isn't fitted or the number of features is wrong
+    pub fn transform(&self, data: &Matrix) -> Matrix {
+        self.normalizer.transform_as("scaler", data)
+    }
+
+    /// reverts the `transform`
+    ///
+    /// note it panics if the scaler isn't fitted or the number of features is wrong
+    pub fn inverse_transform(&self, data: &Matrix) -> Matrix {
+        self.normalizer.inverse_transform_as("scaler", data)
+    }
+}
+
+/// the scaling of a [`Normalizer`]
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Normalization {
+    /// `(x - mean) / std` like [`StandardScaler`]
+    ZScore,
+    /// `(x - min) / (max - min)` like [`MinMaxScaler`]
+    MinMax,
+    /// `(x - median) / iqr` with the interquartile range `q75 - q25`, so outliers have less influence
+    Robust,
+}
+
+/// scales each feature with one of the [`Normalization`] methods
+///
+/// every method is stored as `(x - center) / scale`, features with a scale of 0 (like constant ones) are only shifted
+///
+/// ## Example
+///
+/// ```rust
+/// use math::linear_algebra::{Matrix, Vector};
+/// use math::preprocessing::{Normalization, Normalizer};
+/// // the outlier 100 doesn't change the median and the interquartile range much
+/// let train = Matrix::new(vec![vec![1.], vec![2.], vec![3.], vec![4.], vec![100.]]);
+/// let mut normalizer = Normalizer::new(Normalization::Robust);
+/// let scaled = normalizer.fit_transform(&train);
+/// assert_eq!(normalizer.center(), Some(&Vector::new(vec![3.])));
+/// assert_eq!(normalizer.scale(), Some(&Vector::new(vec![2.])));
+/// assert_eq!(scaled, Matrix::new(vec![vec![-1.], vec![-0.5], vec![0.], vec![0.5], vec![48.5]]));
+/// assert_eq!(normalizer.inverse_transform(&scaled), train);
+/// ```
+/// note each `col` of the data is one sample and each `row` is one feature
+#[derive(PartialEq, Clone, Debug)]
+pub struct Normalizer {
+    method: Normalization,
+    center: Option<Vector>,
+    scale: Option<Vector>,
+}
+
+impl Normalizer {
+    /// creates a normalizer with the method which still has to be fitted
+    pub fn new(method: Normalization) -> Self {
+        Normalizer {
+            method,
+            center: None,
+            scale: None,
+        }
+    }
+
+    /// learns the center and scale of each feature, `NaN` values are ignored for the robust scaling
+    pub fn fit(&mut self, data: &Matrix) {
+        let mut center = Vec::with_capacity(data.rows());
+        let mut scale = Vec::with_capacity(data.rows());
+        for j in 0..data.rows() {
+            let feature = data.row(j);
+            let (c, s) = match self.method {
+                Normalization::ZScore => (feature.mean(), feature.std()),
+                Normalization::MinMax => (feature.min(), feature.range()),
+                Normalization::Robust => {
+                    let q25 = feature.quantile(0.25, Interpolation::Linear);
+                    let q75 = feature.quantile(0.75, Interpolation::Linear);
+                    (feature.median(), q75.zip(q25).map(|(a, b)| a - b))
+                }
+            };
+            center.push(c.unwrap_or(0.));
+            // constant features are only shifted
+            scale.push(s.filter(|&s| s > 0.).unwrap_or(1.));
+        }
+        self.center = Some(Vector::new(center));
+        self.scale = Some(Vector::new(scale));
+    }
+
+    /// fits the normalizer and transforms the data
+    pub fn fit_transform(&mut self, data: &Matrix) -> Matrix {
+        self.fit(data);
+        self.transform(data)
+    }
+
+    /// getter for the method
+    pub fn method(&self) -> Normalization {
+        self.method
+    }
+
+    /// getter for the center of each feature (the mean, min or median)
+    pub fn center(&self) -> Option<&Vector> {
+        self.center.as_ref()
+    }
+
+    /// getter for the scale of each feature (the standard deviation, range or interquartile range)
+    pub fn scale(&self) -> Option<&Vector> {
+        self.scale.as_ref()
+    }
+
+    /// scales the data with the learned parameters
+    ///
+    /// note it panics if the normalizer isn't fitted or the number of features is wrong
+    pub fn transform(&self, data: &Matrix) -> Matrix {
+        self.transform_as("normalizer", data)
+    }
+
+    /// reverts the `transform`
+    ///
+    /// note it panics if the normalizer isn't fitted or the number of features is wrong
+    pub fn inverse_transform(&self, data: &Matrix) -> Matrix {
+        self.inverse_transform_as("normalizer", data)
+    }
+
+    // `transform` for the scalers which wrap a normalizer, `name` is used in the panic message
+    fn transform_as(&self, name: &str, data: &Matrix) -> Matrix {
+        check_fitted(name, self.center.as_ref().map(|c| c.len()), data);
+        let center = self.center.as_ref().unwrap().vec();
+        let scale = self.scale.as_ref().unwrap().vec();
+        map_features(data, |x, j| (x - center[j]) / scale[j])
+    }
+
+    fn inverse_transform_as(&self, name: &str, data: &Matrix) -> Matrix {
+        check_fitted(name, self.center.as_ref().map(|c| c.len()), data);
+        let center = self.center.as_ref().unwrap().vec();
+        let scale = self.scale.as_ref().unwrap().vec();
+        map_features(data, |x, j| x * scale[j] + center[j])
+    }
+}
@@ -1,3 +1,5 @@
+use crate::linear_algebra::{Matrix, Vector};
+
 #[derive(PartialEq, Clone, Copy, Debug)]
 struct Xorshift32State {
     a: u32,
@@ -16,11 +18,13 @@ struct Xorshift128State {
     d: u32,
 }
 
+#[allow(dead_code)]
 #[derive(PartialEq, Clone, Copy, Debug)]
 struct Splitmix64State {
     s: u64,
 }
 
+#[allow(dead_code)]
 #[derive(PartialEq, Clone, Copy, Debug)]
 struct XorwowState {
     a: u32,
@@ -31,23 +35,27 @@ struct XorwowState {
     counter: u32,
 }
 
+#[allow(dead_code)]
 #[derive(PartialEq, Clone, Copy, Debug)]
 struct Xorshift1024sState {
     array: [u64; 16],
     index: usize,
 }
 
+#[allow(dead_code)]
 #[derive(PartialEq, Clone, Copy, Debug)]
 struct Xorshift128pState {
     a: u64,
     b: u64,
 }
 
+#[allow(dead_code)]
 #[derive(PartialEq, Clone, Copy, Debug)]
 struct Xoshiro256ssState {
     s: [u64; 4],
 }
 
+#[allow(dead_code)]
 #[derive(PartialEq, Clone, Copy, Debug)]
 struct Xoshiro256pState {
     s: [u64; 4],
@@ -60,6 +68,12 @@ pub struct Xorshift {
     xorshift128_state: Xorshift128State,
 }
 
+impl Default for Xorshift {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Xorshift {
     /// initialising seeds for the random number generator with seeds
     pub fn new() -> Self {
@@ -153,15 +167,17 @@ impl Xorshift {
         result
     }
 
+    #[allow(dead_code)]
     fn splitmix64(&mut self, state: &mut Splitmix64State) -> u64 {
-        state.s += 0x9E3779B97f4A7C15;
+        state.s += 0x9E37_79B9_7F4A_7C15;
         let mut result = state.s;
         result = (result ^ (result >> 30)) * 0xBF58476D1CE4E5B9;
         result = (result ^ (result >> 27)) * 0x94D049BB133111EB;
-        return result ^ (result >> 31);
+        result ^ (result >> 31)
     }
 
     // as an example; one could do this same thing for any of the other generators
+    #[allow(dead_code)]
     fn xorshift128_init(&mut self, seed: u64) -> Xorshift128State {
         let mut smstate = Splitmix64State { s: seed };
 
@@ -177,6 +193,7 @@ impl Xorshift {
     }
 
     /* The state array must be initialized to not be all zero in the first four words */
+    #[allow(dead_code)]
     fn xorwow(state: &mut XorwowState) -> u32 {
         /* Algorithm "xorwow" from p. 5 of Marsaglia, "Xorshift RNGs" */
         let mut t = state.e;
@@ -190,10 +207,11 @@ impl Xorshift {
         t ^= s ^ (s << 4);
         state.a = t;
         state.counter += 362437;
-        return t + state.counter;
+        t + state.counter
     }
 
     /* The state must be seeded so that there is at least one non-zero element in array */
+    #[allow(dead_code)]
     fn xorshift1024s(state: &mut Xorshift1024sState) -> u64 {
         let mut index = state.index + 1;
         let s = state.array[index - 1];
@@ -204,10 +222,11 @@ impl Xorshift {
         t ^= s ^ (s >> 30); // c
         state.array[index] = t;
         state.index = index;
-        return t * 1181783497276652981;
+        t * 1181783497276652981
     }
 
     /* The state must be seeded so that it is not all zero */
+    #[allow(dead_code)]
     fn xorshift128p(state: &mut Xorshift128pState) -> u64 {
         let mut t = state.a;
         let s = state.b;
@@ -216,13 +235,15 @@ impl Xorshift {
         t ^= t >> 17; // b
         t ^= s ^ (s >> 26); // c
         state.b = t;
-        return t + s;
+        t + s
     }
 
+    #[allow(dead_code, clippy::manual_rotate)]
     fn rol64(&self, x: u64, k: usize) -> u64 {
-        return (x << k) | (x >> (64 - k));
+        (x << k) | (x >> (64 - k))
     }
 
+    #[allow(dead_code, unused_assignments)]
     fn xoshiro256ss(&mut self, state: &mut Xoshiro256ssState) -> u64 {
         let mut s = state.s;
         let result = self.rol64(s[1] * 5, 7) * 9;
@@ -236,9 +257,10 @@ impl Xorshift {
         s[2] ^= t;
         s[3] = self.rol64(s[3], 45);
 
-        return result;
+        result
     }
 
+    #[allow(dead_code, unused_assignments)]
     fn xoshiro256p(&mut self, state: &mut Xoshiro256pState) -> u64 {
         let mut s = state.s;
         let result = s[0] + s[3];
@@ -252,7 +274,7 @@ impl Xorshift {
         s[2] ^= t;
         s[3] = self.rol64(s[3], 45);
 
-        return result;
+        result
     }
 }
 
@@ -264,6 +286,12 @@ pub struct Random {
     xorshift: Xorshift,
 }
 
+impl Default for Random {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Random {
     /// initializes the random number generator (currently Xorshift)
     pub fn new() -> Self {
@@ -297,4 +325,293 @@ impl Random {
     pub fn f64(&mut self) -> f64 {
         (self.xorshift.xorshift64() as f64) / (u64::MAX as f64)
     }
+
+    /// generates a usize between 0 (inclusive) and `max` (exclusive)
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::random::Random;
+    /// let mut rand = Random::new();
+    /// assert_eq!(rand.usize(10), 4);
+    /// ```
+    /// note it panics if `max` is 0
+    pub fn usize(&mut self, max: usize) -> usize {
+        if max == 0 {
+            panic!("max has to be greater than 0");
+        }
+        ((self.f64() * max as f64) as usize).min(max - 1)
+    }
+
+    /// shuffles the slice in place with the [Fisher-Yates shuffle]
+    ///
+    /// [Fisher-Yates shuffle]: https://en.wikipedia.org/wiki/Fisher%E2%80%93Yates_shuffle
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::random::Random;
+    /// let mut rand = Random::new();
+    /// let mut vec = vec![1, 2, 3, 4, 5];
+    /// rand.shuffle(&mut vec);
+    /// assert_eq!(vec, vec![5, 2, 4, 1, 3]);
+    /// ```
+    pub fn shuffle<T>(&mut self, slice: &mut [T]) {
+        for i in (1..slice.len()).rev() {
+            let j = self.usize(i + 1);
+            slice.swap(i, j);
+        }
+    }
+}
+
+/// draws a uniform sample of `k` values from a stream of unknown length with [reservoir sampling]
+///
+/// only `k` values are stored at any time, if the stream has less then `k` values all of them are returned
+///
+/// [reservoir sampling]: https://en.wikipedia.org/wiki/Reservoir_sampling
+///
+/// ## Example
+///
+/// ```rust
+/// use math::random::{reservoir_sample, Random};
+/// let mut rand = Random::new();
+/// let sample = reservoir_sample((0..1000).map(|x| x as f32), 3, &mut rand);
+/// assert_eq!(sample.len(), 3);
+/// assert_eq!(reservoir_sample(vec![1., 2.], 3, &mut rand).vec(), vec![1., 2.]);
+/// ```
+pub fn reservoir_sample<I: IntoIterator<Item = f32>>(
+    iter: I,
+    k: usize,
+    rng: &mut Random,
+) -> Vector {
+    let mut reservoir = Vec::with_capacity(k);
+    for (i, value) in iter.into_iter().enumerate() {
+        if i < k {
+            reservoir.push(value);
+        } else {
+            let j = rng.usize(i + 1);
+            if j < k {
+                reservoir[j] = value;
+            }
+        }
+    }
+    Vector::new(reservoir)
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------------- //
+// ----------------------------------------------------------------------------------------------------------------------------------------------------- //
+// ----------------------------------------------------------------------------------------------------------------------------------------------------- //
+
+const PRIMES: [u32; 16] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53];
+
+/// generates the quasi random [Halton sequence] this is a low-discrepancy sequence
+/// which fills the space a lot more evenly then normal random numbers
+///
+/// [Halton sequence]: https://en.wikipedia.org/wiki/Halton_sequence
+///
+/// ## Example
+///
+/// ```rust
+/// use math::random::Halton;
+/// use math::linear_algebra::Vector;
+/// let mut halton = Halton::new(2);
+/// assert_eq!(halton.next_vec(), Vector::new(vec![0.5, 0.33333334]));
+/// assert_eq!(halton.next_vec(), Vector::new(vec![0.25, 0.6666667]));
+/// ```
+/// note each dimension uses the next prime as base so at most 16 dimensions are supported
+pub struct Halton {
+    dim: usize,
+    index: u64,
+}
+
+impl Halton {
+    /// creates a Halton sequence with `dim` dimensions
+    pub fn new(dim: usize) -> Self {
+        if dim == 0 || dim > PRIMES.len() {
+            panic!(
+                "the dimension has to be between 1 and {}, got {}",
+                PRIMES.len(),
+                dim
+            );
+        }
+        Halton { dim, index: 0 }
+    }
+
+    /// returns the number of dimensions of each point
+    pub fn dim(&self) -> usize {
+        self.dim
+    }
+
+    /// returns the next point of the sequence each value is between 0 and 1
+    pub fn next_vec(&mut self) -> Vector {
+        self.index += 1;
+        Vector::new(
+            PRIMES[..self.dim]
+                .iter()
+                .map(|&base| radical_inverse(self.index, base))
+                .collect(),
+        )
+    }
+
+    /// returns the next `len` points of the sequence as a matrix
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::random::Halton;
+    /// let mut halton = Halton::new(2);
+    /// let matrix = halton.matrix(4);
+    /// assert_eq!(matrix.cols(), 4);
+    /// assert_eq!(matrix.col(2).vec(), vec![0.75, 0.11111111]);
+    /// ```
+    /// note each point is one `col` of the matrix
+    pub fn matrix(&mut self, len: usize) -> Matrix {
+        points_to_matrix(len, self.dim, || self.next_vec())
+    }
+}
+
+fn radical_inverse(mut index: u64, base: u32) -> f32 {
+    let mut result = 0.;
+    let mut fraction = 1. / base as f64;
+    while index > 0 {
+        result += (index % base as u64) as f64 * fraction;
+        index /= base as u64;
+        fraction /= base as f64;
+    }
+    result as f32
+}
+
+// direction numbers (s, a, m_1..m_s) for the dimensions 2.. from Joe and Kuo
+const SOBOL_DIRECTIONS: [(u32, u32, [u32; 5]); 9] = [
+    (1, 0, [1, 0, 0, 0, 0]),
+    (2, 1, [1, 3, 0, 0, 0]),
+    (3, 1, [1, 3, 1, 0, 0]),
+    (3, 2, [1, 1, 1, 0, 0]),
+    (4, 1, [1, 1, 3, 3, 0]),
+    (4, 4, [1, 3, 5, 13, 0]),
+    (5, 2, [1, 1, 5, 5, 17]),
+    (5, 4, [1, 1, 5, 5, 5]),
+    (5, 7, [1, 1, 7, 11, 19]),
+];
+
+/// generates the quasi random [Sobol sequence] this is a low-discrepancy sequence
+/// mostly used for quasi-Monte Carlo integration
+///
+/// [Sobol sequence]: https://en.wikipedia.org/wiki/Sobol_sequence
+///
+/// ## Example
+///
+/// ```rust
+/// use math::random::Sobol;
+/// use math::linear_algebra::Vector;
+/// let mut sobol = Sobol::new(2);
+/// assert_eq!(sobol.next_vec(), Vector::new(vec![0., 0.]));
+/// assert_eq!(sobol.next_vec(), Vector::new(vec![0.5, 0.5]));
+/// assert_eq!(sobol.next_vec(), Vector::new(vec![0.75, 0.25]));
+/// assert_eq!(sobol.next_vec(), Vector::new(vec![0.25, 0.75]));
+/// ```
+/// note the direction numbers are from [Joe and Kuo] and at most 10 dimensions are supported
+///
+/// [Joe and Kuo]: https://web.maths.unsw.edu.au/~fkuo/sobol/
+pub struct Sobol {
+    dim: usize,
+    index: u64,
+    directions: Vec<[u64; 64]>,
+    state: Vec<u64>,
+}
+
+impl Sobol {
+    /// creates a Sobol sequence with `dim` dimensions
+    pub fn new(dim: usize) -> Self {
+        if dim == 0 || dim > SOBOL_DIRECTIONS.len() + 1 {
+            panic!(
+                "the dimension has to be between 1 and {}, got {}",
+                SOBOL_DIRECTIONS.len() + 1,
+                dim
+            );
+        }
+
+        let mut directions = Vec::with_capacity(dim);
+
+        let mut first = [0; 64];
+        for (k, v) in first.iter_mut().enumerate() {
+            *v = 1 << (63 - k);
+        }
+        directions.push(first);
+
+        for &(s, a, m) in SOBOL_DIRECTIONS.iter().take(dim - 1) {
+            let s = s as usize;
+            let mut v = [0; 64];
+            for k in 0..64 {
+                if k < s {
+                    v[k] = (m[k] as u64) << (63 - k);
+                } else {
+                    v[k] = v[k - s] ^ (v[k - s] >> s);
+                    for j in 1..s {
+                        v[k] ^= ((a as u64 >> (s - 1 - j)) & 1) * v[k - j];
+                    }
+                }
+            }
+            directions.push(v);
+        }
+
+        Sobol {
+            dim,
+            index: 0,
+            directions,
+            state: vec![0; dim],
+        }
+    }
+
+    /// returns the number of dimensions of each point
+    pub fn dim(&self) -> usize {
+        self.dim
+    }
+
+    /// returns the next point of the sequence each value is between 0 and 1
+    ///
+    /// note the first point is always the origin
+    pub fn next_vec(&mut self) -> Vector {
+        let result = Vector::new(
+            self.state
+                .iter()
+                .map(|&x| (x as f64 / 18446744073709551616.) as f32)
+                .collect(),
+        );
+
+        // gray code the next point only differs in the bit of the rightmost zero of the index,
+        // the index is a u64 so the 64 directions are enough for every reachable point
+        let bit = (!self.index).trailing_zeros() as usize;
+        for (x, v) in self.state.iter_mut().zip(self.directions.iter()) {
+            *x ^= v[bit];
+        }
+        self.index += 1;
+
+        result
+    }
+
+    /// returns the next `len` points of the sequence as a matrix
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::random::Sobol;
+    /// let mut sobol = Sobol::new(3);
+    /// let matrix = sobol.matrix(4);
+    /// assert_eq!(matrix.cols(), 4);
+    /// assert_eq!(matrix.rows(), 3);
+    /// assert_eq!(matrix.col(3).vec(), vec![0.25, 0.75, 0.75]);
+    /// ```
+    /// note each point is one `col` of the matrix
+    pub fn matrix(&mut self, len: usize) -> Matrix {
+        points_to_matrix(len, self.dim, || self.next_vec())
+    }
+}
+
+fn points_to_matrix<F: FnMut() -> Vector>(len: usize, dim: usize, mut next: F) -> Matrix {
+    let mut matrix_flatt = Vec::with_capacity(len * dim);
+    for _ in 0..len {
+        matrix_flatt.extend(next().vec());
+    }
+    Matrix::new_flatt(matrix_flatt, len, dim)
 }
@@ -0,0 +1,497 @@
+use crate::linear_algebra::shape::Axis;
+use crate::linear_algebra::{Matrix, Vector};
+use std::iter::FromIterator;
+
+/// count, mean, variance, min and max of a stream of values without storing them
+///
+/// the mean and variance are updated with [Welford's algorithm] in `f64`,
+/// so they stay accurate for long streams and values with a large offset
+///
+/// [Welford's algorithm]: https://en.wikipedia.org/wiki/Algorithms_for_calculating_variance#Welford's_online_algorithm
+///
+/// ## Example
+///
+/// ```rust
+/// use math::linear_algebra::Vector;
+/// use math::statistics::RunningStats;
+/// let mut stats = RunningStats::new();
+/// stats.push(2.);
+/// stats.push_vector(&Vector::new(vec![4., 4., 4., 5., 5., 7., 9.]));
+/// assert_eq!(stats.count(), 8);
+/// assert_eq!(stats.mean(), Some(5.));
+/// assert_eq!(stats.variance(), Some(4.));
+/// assert_eq!(stats.std(), Some(2.));
+/// assert_eq!(stats.min(), Some(2.));
+/// assert_eq!(stats.max(), Some(9.));
+/// ```
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct RunningStats {
+    count: usize,
+    mean: f64,
+    // the sum of the squared differences from the mean
+    m2: f64,
+    min: f32,
+    max: f32,
+}
+
+impl Default for RunningStats {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RunningStats {
+    /// creates an accumulator without any values
+    pub fn new() -> Self {
+        RunningStats {
+            count: 0,
+            mean: 0.,
+            m2: 0.,
+            min: f32::INFINITY,
+            max: f32::NEG_INFINITY,
+        }
+    }
+
+    /// adds one value
+    pub fn push(&mut self, value: f32) {
+        self.count += 1;
+        let delta = value as f64 - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (value as f64 - self.mean);
+        self.min = self.min.min(value);
+        self.max = self.max.max(value);
+    }
+
+    /// adds all values of the vector
+    pub fn push_vector(&mut self, vector: &Vector) {
+        (0..vector.len()).for_each(|i| self.push(vector.index(i)));
+    }
+
+    /// combines the values of both accumulators like they were pushed into one
+    ///
+    /// this allows to compute the statistics of chunks in parallel
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::statistics::RunningStats;
+    /// let mut a: RunningStats = vec![1., 2., 3.].into_iter().collect();
+    /// let b: RunningStats = vec![4., 5.].into_iter().collect();
+    /// a.merge(&b);
+    /// assert_eq!(a, (1..=5).map(|x| x as f32).collect());
+    /// ```
+    pub fn merge(&mut self, other: &RunningStats) {
+        if other.count == 0 {
+            return;
+        }
+        let count = self.count + other.count;
+        let delta = other.mean - self.mean;
+        self.mean += delta * other.count as f64 / count as f64;
+        self.m2 += other.m2 + delta * delta * (self.count * other.count) as f64 / count as f64;
+        self.count = count;
+        self.min = self.min.min(other.min);
+        self.max = self.max.max(other.max);
+    }
+
+    /// returns the number of values
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// returns the mean or `None` if there are no values
+    pub fn mean(&self) -> Option<f32> {
+        if self.count == 0 {
+            None
+        } else {
+            Some(self.mean as f32)
+        }
+    }
+
+    /// returns the population variance or `None` if there are no values
+    pub fn variance(&self) -> Option<f32> {
+        if self.count == 0 {
+            None
+        } else {
+            Some((self.m2 / self.count as f64) as f32)
+        }
+    }
+
+    /// returns the sample variance (divided by `count - 1`) or `None` if there are less then 2 values
+    pub fn sample_variance(&self) -> Option<f32> {
+        if self.count < 2 {
+            None
+        } else {
+            Some((self.m2 / (self.count - 1) as f64) as f32)
+        }
+    }
+
+    /// returns the population standard deviation or `None` if there are no values
+    pub fn std(&self) -> Option<f32> {
+        self.variance().map(f32::sqrt)
+    }
+
+    /// returns the smallest value or `None` if there are no values
+    pub fn min(&self) -> Option<f32> {
+        if self.count == 0 {
+            None
+        } else {
+            Some(self.min)
+        }
+    }
+
+    /// returns the largest value or `None` if there are no values
+    pub fn max(&self) -> Option<f32> {
+        if self.count == 0 {
+            None
+        } else {
+            Some(self.max)
+        }
+    }
+}
+
+impl Extend<f32> for RunningStats {
+    fn extend<I: IntoIterator<Item = f32>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|value| self.push(value));
+    }
+}
+
+impl FromIterator<f32> for RunningStats {
+    fn from_iter<I: IntoIterator<Item = f32>>(iter: I) -> Self {
+        let mut stats = RunningStats::new();
+        stats.extend(iter);
+        stats
+    }
+}
+
+// the mean and the central moments sum (x - mean)^k / n for k = 2, 3, 4 in f64 with two passes
+fn moments(vector: &Vector) -> Option<(f64, f64, f64, f64)> {
+    if vector.is_empty() {
+        return None;
+    }
+    let n = vector.len() as f64;
+    let mean = vector.iter().map(|x| x as f64).sum::<f64>() / n;
+    let (mut m2, mut m3, mut m4) = (0., 0., 0.);
+    for x in vector.iter() {
+        let d = x as f64 - mean;
+        m2 += d * d;
+        m3 += d * d * d;
+        m4 += d * d * d * d;
+    }
+    Some((mean, m2 / n, m3 / n, m4 / n))
+}
+
+/// descriptive statistics, they return `None` for an empty vector like [`RunningStats`]
+///
+/// ## Example
+///
+/// ```rust
+/// use math::linear_algebra::Vector;
+/// let vector = Vector::new(vec![2., 4., 4., 4., 5., 5., 7., 9.]);
+/// assert_eq!(vector.mean(), Some(5.));
+/// assert_eq!(vector.variance(), Some(4.));
+/// assert_eq!(vector.std(), Some(2.));
+/// assert_eq!(vector.range(), Some(7.));
+/// assert_eq!(Vector::new(vec![]).mean(), None);
+/// ```
+impl Vector {
+    /// returns the arithmetic mean
+    pub fn mean(&self) -> Option<f32> {
+        moments(self).map(|(mean, ..)| mean as f32)
+    }
+
+    /// returns the population variance `sum (x - mean)^2 / n`
+    pub fn variance(&self) -> Option<f32> {
+        moments(self).map(|(_, m2, ..)| m2 as f32)
+    }
+
+    /// returns the sample variance `sum (x - mean)^2 / (n - 1)` or `None` if there are less then 2 values
+    pub fn sample_variance(&self) -> Option<f32> {
+        let n = self.len() as f64;
+        if self.len() < 2 {
+            return None;
+        }
+        moments(self).map(|(_, m2, ..)| (m2 * n / (n - 1.)) as f32)
+    }
+
+    /// returns the population standard deviation, the square root of `variance`
+    pub fn std(&self) -> Option<f32> {
+        self.variance().map(f32::sqrt)
+    }
+
+    /// returns the sample standard deviation, the square root of `sample_variance`
+    pub fn sample_std(&self) -> Option<f32> {
+        self.sample_variance().map(f32::sqrt)
+    }
+
+    /// returns the [skewness] `m3 / m2^(3/2)` with the central moments `m_k = sum (x - mean)^k / n`,
+    /// it is positive if the tail on the right is longer
+    ///
+    /// [skewness]: https://en.wikipedia.org/wiki/Skewness
+    ///
+    /// note the skewness of constant values is `NaN`
+    pub fn skewness(&self) -> Option<f32> {
+        moments(self).map(|(_, m2, m3, _)| (m3 / m2.powf(1.5)) as f32)
+    }
+
+    /// returns the excess [kurtosis] `m4 / m2^2 - 3` with the central moments `m_k = sum (x - mean)^k / n`,
+    /// so the normal distribution has a kurtosis of 0
+    ///
+    /// [kurtosis]: https://en.wikipedia.org/wiki/Kurtosis
+    ///
+    /// note the kurtosis of constant values is `NaN`
+    pub fn kurtosis(&self) -> Option<f32> {
+        moments(self).map(|(_, m2, _, m4)| (m4 / (m2 * m2) - 3.) as f32)
+    }
+
+    /// returns the smallest value, `NaN` values are ignored
+    pub fn min(&self) -> Option<f32> {
+        self.iter().reduce(f32::min)
+    }
+
+    /// returns the largest value, `NaN` values are ignored
+    pub fn max(&self) -> Option<f32> {
+        self.iter().reduce(f32::max)
+    }
+
+    /// returns the difference between the largest and the smallest value
+    pub fn range(&self) -> Option<f32> {
+        Some(self.max()? - self.min()?)
+    }
+
+    /// returns the middle value or the mean of the two middle values, `NaN` values are ignored
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Vector;
+    /// assert_eq!(Vector::new(vec![3., 1., 2.]).median(), Some(2.));
+    /// assert_eq!(Vector::new(vec![4., 1., 3., 2.]).median(), Some(2.5));
+    /// ```
+    pub fn median(&self) -> Option<f32> {
+        self.quantile(0.5, Interpolation::Linear)
+    }
+
+    /// returns the `q` quantile, so a fraction `q` of the values is smaller, `NaN` values are ignored
+    ///
+    /// the sorted values are at the positions `0, 1 / (n - 1), ..., 1`,
+    /// the [`Interpolation`] decides the result if `q` lies between two of them
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Vector;
+    /// use math::statistics::Interpolation;
+    /// let vector = Vector::new(vec![10., 40., 20., 30.]);
+    /// assert_eq!(vector.quantile(0.5, Interpolation::Linear), Some(25.));
+    /// assert_eq!(vector.quantile(0.5, Interpolation::Lower), Some(20.));
+    /// assert_eq!(vector.quantile(0.5, Interpolation::Higher), Some(30.));
+    /// assert_eq!(vector.quantile(0.25, Interpolation::Linear), Some(17.5));
+    /// ```
+    /// note it panics if `q` is not between 0 and 1
+    pub fn quantile(&self, q: f32, interpolation: Interpolation) -> Option<f32> {
+        if !(0. ..=1.).contains(&q) {
+            panic!("the quantile has to be between 0 and 1, got {}", q);
+        }
+        let sorted = sorted(self);
+        if sorted.is_empty() {
+            return None;
+        }
+        let pos = q as f64 * (sorted.len() - 1) as f64;
+        let (lower, higher) = (pos.floor() as usize, pos.ceil() as usize);
+        let (a, b) = (sorted[lower], sorted[higher]);
+        let frac = pos - lower as f64;
+        Some(match interpolation {
+            Interpolation::Linear => (a as f64 + frac * (b as f64 - a as f64)) as f32,
+            Interpolation::Lower => a,
+            Interpolation::Higher => b,
+            // the ties go to the even position like `f32::round_ties_even`
+            Interpolation::Nearest => match frac.partial_cmp(&0.5) {
+                Some(std::cmp::Ordering::Less) => a,
+                Some(std::cmp::Ordering::Greater) => b,
+                _ if lower % 2 == 0 => a,
+                _ => b,
+            },
+            Interpolation::Midpoint => (a + b) / 2.,
+        })
+    }
+
+    /// returns the `p` percentile, the same as `quantile(p / 100, interpolation)`
+    ///
+    /// note it panics if `p` is not between 0 and 100
+    pub fn percentile(&self, p: f32, interpolation: Interpolation) -> Option<f32> {
+        if !(0. ..=100.).contains(&p) {
+            panic!("the percentile has to be between 0 and 100, got {}", p);
+        }
+        self.quantile(p / 100., interpolation)
+    }
+
+    /// returns the most frequent value, `NaN` values are ignored
+    ///
+    /// for data which is only nearly discrete the sorted values are grouped, each group starts at
+    /// the smallest value which isn't in a group yet and contains every value at most `tolerance` above it.
+    /// the result is the mean of the largest group, if several groups have the same size the first one wins.
+    /// with a `tolerance` of 0 it is the most frequent exact value
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Vector;
+    /// assert_eq!(Vector::new(vec![3., 1., 3., 2., 1., 3.]).mode(0.), Some(3.));
+    /// let noisy = Vector::new(vec![1.01, 2., 0.99, 1.]);
+    /// assert_eq!(noisy.mode(0.), Some(0.99));
+    /// assert!((noisy.mode(0.05).unwrap() - 1.).abs() < 1e-6);
+    /// ```
+    /// note it panics if the tolerance is negative
+    pub fn mode(&self, tolerance: f32) -> Option<f32> {
+        if tolerance < 0. {
+            panic!("the tolerance can't be negative, got {}", tolerance);
+        }
+        let sorted = sorted(self);
+        let mut best: Option<&[f32]> = None;
+        let mut start = 0;
+        while start < sorted.len() {
+            let len = sorted[start..]
+                .iter()
+                .take_while(|&&x| x - sorted[start] <= tolerance)
+                .count();
+            let group = &sorted[start..start + len];
+            if best.map_or(true, |best| group.len() > best.len()) {
+                best = Some(group);
+            }
+            start += len;
+        }
+        best.map(|group| (group.iter().map(|&x| x as f64).sum::<f64>() / group.len() as f64) as f32)
+    }
+}
+
+// the values without NaN in increasing order
+fn sorted(vector: &Vector) -> Vec<f32> {
+    let mut sorted: Vec<f32> = vector.iter().filter(|x| !x.is_nan()).collect();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    sorted
+}
+
+/// how [`Vector::quantile`] picks the result if the quantile lies between the sorted values `a` and `b`
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum Interpolation {
+    /// the linear interpolation between `a` and `b`
+    Linear,
+    /// always `a`
+    Lower,
+    /// always `b`
+    Higher,
+    /// the nearer value of `a` and `b`, exactly in the middle the one at the even position
+    Nearest,
+    /// `(a + b) / 2`
+    Midpoint,
+}
+
+impl Matrix {
+    /// returns the sample [covariance matrix] (divided by `n - 1` for `n` observations) of the variables,
+    /// with `Axis::Col` each col is one variable and each row one observation, with `Axis::Row` it is the other way around
+    ///
+    /// the result is a symmetric matrix with the variances on the diagonal, it is computed in `f64`
+    ///
+    /// [covariance matrix]: https://en.wikipedia.org/wiki/Covariance_matrix
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::shape::Axis;
+    /// use math::linear_algebra::Matrix;
+    /// // the variables x = [1, 2, 3] and y = [2, 4, 6]
+    /// let data = Matrix::new(vec![vec![1., 2., 3.], vec![2., 4., 6.]]);
+    /// assert_eq!(
+    ///     data.covariance(Axis::Col),
+    ///     Matrix::new(vec![vec![1., 2.], vec![2., 4.]])
+    /// );
+    /// assert_eq!(
+    ///     data.transposed().covariance(Axis::Row),
+    ///     data.covariance(Axis::Col)
+    /// );
+    /// ```
+    /// note it panics if there are less then 2 observations
+    pub fn covariance(&self, variables: Axis) -> Matrix {
+        let centered = centered_variables(self, variables);
+        let (k, n) = (centered.len(), centered.first().map_or(0, Vec::len));
+        if n < 2 {
+            panic!("the covariance needs at least 2 observations, got {}", n);
+        }
+        let mut cov = vec![0.; k * k];
+        for a in 0..k {
+            for b in a..k {
+                let sum: f64 = centered[a]
+                    .iter()
+                    .zip(centered[b].iter())
+                    .map(|(x, y)| x * y)
+                    .sum();
+                let val = (sum / (n - 1) as f64) as f32;
+                cov[a * k + b] = val;
+                cov[b * k + a] = val;
+            }
+        }
+        Matrix::new_flatt(cov, k, k)
+    }
+
+    /// returns the [Pearson correlation] matrix of the variables like `covariance`,
+    /// it is the covariance divided by the standard deviations so the values are between -1 and 1
+    ///
+    /// [Pearson correlation]: https://en.wikipedia.org/wiki/Pearson_correlation_coefficient
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::shape::Axis;
+    /// use math::linear_algebra::Matrix;
+    /// let data = Matrix::new(vec![vec![1., 2., 3.], vec![6., 4., 2.]]);
+    /// assert_eq!(
+    ///     data.correlation(Axis::Col),
+    ///     Matrix::new(vec![vec![1., -1.], vec![-1., 1.]])
+    /// );
+    /// ```
+    /// note the correlation with a constant variable is `NaN` and it panics if there are less then 2 observations
+    pub fn correlation(&self, variables: Axis) -> Matrix {
+        let centered = centered_variables(self, variables);
+        let (k, n) = (centered.len(), centered.first().map_or(0, Vec::len));
+        if n < 2 {
+            panic!("the correlation needs at least 2 observations, got {}", n);
+        }
+        let norms: Vec<f64> = centered
+            .iter()
+            .map(|var| var.iter().map(|x| x * x).sum::<f64>().sqrt())
+            .collect();
+        let mut corr = vec![0.; k * k];
+        for a in 0..k {
+            for b in a..k {
+                let val = if a == b && norms[a] > 0. {
+                    1.
+                } else {
+                    let sum: f64 = centered[a]
+                        .iter()
+                        .zip(centered[b].iter())
+                        .map(|(x, y)| x * y)
+                        .sum();
+                    // rounding can push the value slightly outside of [-1, 1]
+                    ((sum / (norms[a] * norms[b])) as f32).clamp(-1., 1.)
+                };
+                corr[a * k + b] = val;
+                corr[b * k + a] = val;
+            }
+        }
+        Matrix::new_flatt(corr, k, k)
+    }
+}
+
+// the values of each variable minus its mean in f64
+fn centered_variables(data: &Matrix, variables: Axis) -> Vec<Vec<f64>> {
+    let vars: Vec<Vector> = match variables {
+        Axis::Col => (0..data.cols()).map(|i| data.col(i)).collect(),
+        Axis::Row => (0..data.rows()).map(|j| data.row(j)).collect(),
+    };
+    vars.iter()
+        .map(|var| {
+            let mean = moments(var).map_or(0., |(mean, ..)| mean);
+            var.iter().map(|x| x as f64 - mean).collect()
+        })
+        .collect()
+}
@@ -0,0 +1,207 @@
+//! helpers for comparing matrices and vectors in tests
+//!
+//! use the [`assert_matrix_eq!`] and [`assert_vector_eq!`] macros, the functions here only build the diff they print
+//!
+//! [`assert_matrix_eq!`]: crate::assert_matrix_eq
+//! [`assert_vector_eq!`]: crate::assert_vector_eq
+
+use crate::linear_algebra::{Matrix, Vector};
+
+/// the absolute tolerance used when no tolerance is given
+pub const DEFAULT_ABS: f32 = 1e-6;
+
+/// the relative tolerance used when no tolerance is given
+pub const DEFAULT_REL: f32 = 1e-6;
+
+// the maximum number of differing elements listed in a diff
+const MAX_LISTED: usize = 10;
+
+/// returns true if `|a - b| <= abs` or `|a - b| <= rel * max(|a|, |b|)`
+///
+/// equal infinities are close and `NaN` is never close to anything
+pub fn is_close(a: f32, b: f32, abs: f32, rel: f32) -> bool {
+    if a == b {
+        return true;
+    }
+    let diff = (a - b).abs();
+    diff <= abs || diff <= rel * a.abs().max(b.abs())
+}
+
+/// returns a readable diff of the elements which are not close or `None` if all are close
+///
+/// ## Example
+///
+/// ```rust
+/// use math::linear_algebra::Matrix;
+/// use math::testing::matrix_diff;
+/// let a = Matrix::new(vec![vec![1., 2.], vec![3., 4.]]);
+/// let b = Matrix::new(vec![vec![1., 2.], vec![3., 4.5]]);
+/// assert_eq!(matrix_diff(&a, &a, 0., 0.), None);
+/// assert!(matrix_diff(&a, &b, 1e-6, 0.).unwrap().contains("(1, 1): 4 != 4.5"));
+/// ```
+pub fn matrix_diff(left: &Matrix, right: &Matrix, abs: f32, rel: f32) -> Option<String> {
+    if left.cols() != right.cols() || left.rows() != right.rows() {
+        return Some(format!(
+            "wrong matrix shape {}x{} != {}x{}",
+            left.cols(),
+            left.rows(),
+            right.cols(),
+            right.rows()
+        ));
+    }
+
+    let diffs = (0..left.cols())
+        .flat_map(|i| (0..left.rows()).map(move |j| (i, j)))
+        .map(|(i, j)| {
+            (
+                format!("({}, {})", i, j),
+                left.index(i, j),
+                right.index(i, j),
+            )
+        });
+    diff(diffs, left.cols() * left.rows(), abs, rel)
+}
+
+/// returns a readable diff of the elements which are not close or `None` if all are close
+///
+/// ## Example
+///
+/// ```rust
+/// use math::linear_algebra::Vector;
+/// use math::testing::vector_diff;
+/// let a = Vector::new(vec![1., 2., 3.]);
+/// let b = Vector::new(vec![1., 2.1, 3.]);
+/// assert_eq!(vector_diff(&a, &b, 0., 0.1), None);
+/// assert!(vector_diff(&a, &b, 0., 0.01).unwrap().contains("[1]: 2 != 2.1"));
+/// ```
+pub fn vector_diff(left: &Vector, right: &Vector, abs: f32, rel: f32) -> Option<String> {
+    if left.len() != right.len() {
+        return Some(format!(
+            "wrong vector length {} != {}",
+            left.len(),
+            right.len()
+        ));
+    }
+
+    let diffs = (0..left.len()).map(|i| (format!("[{}]", i), left.index(i), right.index(i)));
+    diff(diffs, left.len(), abs, rel)
+}
+
+fn diff<I: Iterator<Item = (String, f32, f32)>>(
+    values: I,
+    len: usize,
+    abs: f32,
+    rel: f32,
+) -> Option<String> {
+    let differing: Vec<_> = values
+        .filter(|(_, a, b)| !is_close(*a, *b, abs, rel))
+        .collect();
+    if differing.is_empty() {
+        return None;
+    }
+
+    let mut result = format!(
+        "{} of {} elements differ (abs = {:e}, rel = {:e})",
+        differing.len(),
+        len,
+        abs,
+        rel
+    );
+    for (pos, a, b) in differing.iter().take(MAX_LISTED) {
+        result += &format!("\n  {}: {} != {} (diff {:e})", pos, a, b, (a - b).abs());
+    }
+    if differing.len() > MAX_LISTED {
+        result += &format!("\n  ... and {} more", differing.len() - MAX_LISTED);
+    }
+    Some(result)
+}
+
+/// asserts that two matrices have the same shape and all elements are close
+///
+/// the tolerance can be set with `abs = ..` and `rel = ..`, see [`is_close`]
+/// if one of them is given the other one is 0, if none is given both are the defaults
+///
+/// [`is_close`]: crate::testing::is_close
+///
+/// ## Example
+///
+/// ```rust
+/// use math::assert_matrix_eq;
+/// use math::linear_algebra::Matrix;
+/// let a = Matrix::new(vec![vec![1., 2.], vec![3., 4.]]);
+/// let b = Matrix::new(vec![vec![1.001, 2.], vec![3., 4.]]);
+/// assert_matrix_eq!(a, a.clone());
+/// assert_matrix_eq!(a, b, abs = 0.01);
+/// assert_matrix_eq!(a, b, rel = 0.01);
+/// assert_matrix_eq!(a, b, abs = 0., rel = 0.01);
+/// ```
+#[macro_export]
+macro_rules! assert_matrix_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::assert_matrix_eq!(
+            $left,
+            $right,
+            abs = $crate::testing::DEFAULT_ABS,
+            rel = $crate::testing::DEFAULT_REL
+        )
+    };
+    ($left:expr, $right:expr, abs = $abs:expr $(,)?) => {
+        $crate::assert_matrix_eq!($left, $right, abs = $abs, rel = 0.)
+    };
+    ($left:expr, $right:expr, rel = $rel:expr $(,)?) => {
+        $crate::assert_matrix_eq!($left, $right, abs = 0., rel = $rel)
+    };
+    ($left:expr, $right:expr, abs = $abs:expr, rel = $rel:expr $(,)?) => {
+        if let Some(diff) = $crate::testing::matrix_diff(&$left, &$right, $abs, $rel) {
+            panic!(
+                "assertion failed: `{} == {}`\n{}",
+                stringify!($left),
+                stringify!($right),
+                diff
+            );
+        }
+    };
+}
+
+/// asserts that two vectors have the same length and all elements are close
+///
+/// takes the same tolerances as [`assert_matrix_eq!`]
+///
+/// [`assert_matrix_eq!`]: crate::assert_matrix_eq
+///
+/// ## Example
+///
+/// ```rust
+/// use math::assert_vector_eq;
+/// use math::linear_algebra::Vector;
+/// let a = Vector::new(vec![0.1 + 0.2, 1.]);
+/// assert_vector_eq!(a, Vector::new(vec![0.3, 1.]));
+/// assert_vector_eq!(a, Vector::new(vec![0.31, 1.]), abs = 0.1);
+/// ```
+#[macro_export]
+macro_rules! assert_vector_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::assert_vector_eq!(
+            $left,
+            $right,
+            abs = $crate::testing::DEFAULT_ABS,
+            rel = $crate::testing::DEFAULT_REL
+        )
+    };
+    ($left:expr, $right:expr, abs = $abs:expr $(,)?) => {
+        $crate::assert_vector_eq!($left, $right, abs = $abs, rel = 0.)
+    };
+    ($left:expr, $right:expr, rel = $rel:expr $(,)?) => {
+        $crate::assert_vector_eq!($left, $right, abs = 0., rel = $rel)
+    };
+    ($left:expr, $right:expr, abs = $abs:expr, rel = $rel:expr $(,)?) => {
+        if let Some(diff) = $crate::testing::vector_diff(&$left, &$right, $abs, $rel) {
+            panic!(
+                "assertion failed: `{} == {}`\n{}",
+                stringify!($left),
+                stringify!($right),
+                diff
+            );
+        }
+    };
+}
@@ -0,0 +1,487 @@
+use crate::linear_algebra::{Matrix, Vector};
+
+fn mean(series: &[f32]) -> f32 {
+    series.iter().sum::<f32>() / series.len() as f32
+}
+
+/// returns the [autocorrelation] of the series for the lags `0..=max_lag`
+///
+/// [autocorrelation]: https://en.wikipedia.org/wiki/Autocorrelation
+///
+/// ## Example
+///
+/// ```rust
+/// use math::linear_algebra::Vector;
+/// use math::timeseries::acf;
+/// let series = Vector::new(vec![1., 2., 3., 4.]);
+/// assert_eq!(acf(&series, 2), Vector::new(vec![1., 0.25, -0.3]));
+/// ```
+/// note it panics if `max_lag` is not smaller then the len of the series
+pub fn acf(series: &Vector, max_lag: usize) -> Vector {
+    let vec = series.vec();
+    if max_lag >= vec.len() {
+        panic!(
+            "the max lag has to be smaller then the len of the series max_lag = {}, len = {}",
+            max_lag,
+            vec.len()
+        );
+    }
+
+    let m = mean(&vec);
+    let centered: Vec<f32> = vec.iter().map(|x| x - m).collect();
+    let variance: f32 = centered.iter().map(|x| x * x).sum();
+
+    Vector::new(
+        (0..=max_lag)
+            .map(|lag| {
+                if variance == 0. {
+                    return if lag == 0 { 1. } else { 0. };
+                }
+                let cov: f32 = (lag..centered.len())
+                    .map(|t| centered[t] * centered[t - lag])
+                    .sum();
+                cov / variance
+            })
+            .collect(),
+    )
+}
+
+/// returns the [partial autocorrelation] of the series for the lags `0..=max_lag`
+/// computed with the Durbin-Levinson recursion
+///
+/// [partial autocorrelation]: https://en.wikipedia.org/wiki/Partial_autocorrelation_function
+///
+/// ## Example
+///
+/// ```rust
+/// use math::linear_algebra::Vector;
+/// use math::timeseries::{acf, pacf};
+/// let series = Vector::new(vec![1., 3., 2., 5., 4., 6.]);
+/// let pacf = pacf(&series, 2);
+/// assert_eq!(pacf.index(0), 1.);
+/// assert_eq!(pacf.index(1), acf(&series, 1).index(1));
+/// ```
+/// note it panics if `max_lag` is not smaller then the len of the series
+pub fn pacf(series: &Vector, max_lag: usize) -> Vector {
+    let rho = acf(series, max_lag).vec();
+    let mut result = vec![1.];
+    let mut phi: Vec<f32> = Vec::new();
+
+    for k in 1..=max_lag {
+        let num = rho[k] - (1..k).map(|j| phi[j - 1] * rho[k - j]).sum::<f32>();
+        let den = 1. - (1..k).map(|j| phi[j - 1] * rho[j]).sum::<f32>();
+        let phi_kk = if den == 0. { 0. } else { num / den };
+
+        let mut next: Vec<f32> = (1..k)
+            .map(|j| phi[j - 1] - phi_kk * phi[k - j - 1])
+            .collect();
+        next.push(phi_kk);
+        phi = next;
+        result.push(phi_kk);
+    }
+    Vector::new(result)
+}
+
+/// returns the series differenced `order` times, each time the len gets smaller by 1
+///
+/// ## Example
+///
+/// ```rust
+/// use math::linear_algebra::Vector;
+/// use math::timeseries::difference;
+/// let series = Vector::new(vec![1., 4., 9., 16.]);
+/// assert_eq!(difference(&series, 1), Vector::new(vec![3., 5., 7.]));
+/// assert_eq!(difference(&series, 2), Vector::new(vec![2., 2.]));
+/// ```
+/// note it panics if the `order` is not smaller then the len of the series
+pub fn difference(series: &Vector, order: usize) -> Vector {
+    if order >= series.len() {
+        panic!(
+            "the order has to be smaller then the len of the series order = {}, len = {}",
+            order,
+            series.len()
+        );
+    }
+
+    let mut vec = series.vec();
+    for _ in 0..order {
+        vec = vec.windows(2).map(|w| w[1] - w[0]).collect();
+    }
+    Vector::new(vec)
+}
+
+/// removes the linear trend (least squares line over the index) from the series
+///
+/// ## Example
+///
+/// ```rust
+/// use math::linear_algebra::Vector;
+/// use math::timeseries::detrend;
+/// let series = Vector::new(vec![1., 3., 5., 7.]);
+/// assert_eq!(detrend(&series), Vector::new(vec![0., 0., 0., 0.]));
+/// ```
+pub fn detrend(series: &Vector) -> Vector {
+    let vec = series.vec();
+    let len = vec.len();
+    if len < 2 {
+        return Vector::new_zero(len);
+    }
+
+    let t_mean = (len - 1) as f32 / 2.;
+    let y_mean = mean(&vec);
+    let mut cov = 0.;
+    let mut var = 0.;
+    for (t, y) in vec.iter().enumerate() {
+        let dt = t as f32 - t_mean;
+        cov += dt * (y - y_mean);
+        var += dt * dt;
+    }
+    let slope = cov / var;
+
+    Vector::new(
+        vec.iter()
+            .enumerate()
+            .map(|(t, y)| y - (y_mean + slope * (t as f32 - t_mean)))
+            .collect(),
+    )
+}
+
+/// how the rolling statistics handle the first values which don't have a full window before them
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum Edge {
+    /// only full windows are used, so the result has `len - window + 1` values
+    Valid,
+    /// the first values use the shorter windows which are available, so the result has the same len
+    Shrink,
+    /// the first `window - 1` values are `NaN`, so the result has the same len
+    Nan,
+}
+
+// applies func to the trailing window of every value, the windows end at the value like in `Edge`
+fn rolling<F: Fn(&[f32]) -> f32>(series: &Vector, window: usize, edge: Edge, func: F) -> Vector {
+    if window == 0 {
+        panic!("the window has to be at least 1");
+    }
+    let vec = series.vec();
+    let full = vec.windows(window).map(&func);
+    let head = window.saturating_sub(1).min(vec.len());
+    match edge {
+        Edge::Valid => full.collect(),
+        Edge::Shrink => (1..=head)
+            .map(|end| func(&vec[..end]))
+            .chain(full)
+            .collect(),
+        Edge::Nan => (0..head).map(|_| f32::NAN).chain(full).collect(),
+    }
+}
+
+/// returns the [moving average] over the trailing window, the value at `t` is the mean of `t - window + 1..=t`
+///
+/// [moving average]: https://en.wikipedia.org/wiki/Moving_average
+///
+/// ## Example
+///
+/// ```rust
+/// use math::linear_algebra::Vector;
+/// use math::timeseries::{moving_average, Edge};
+/// let series = Vector::new(vec![1., 3., 2., 6.]);
+/// assert_eq!(moving_average(&series, 2, Edge::Valid), Vector::new(vec![2., 2.5, 4.]));
+/// assert_eq!(moving_average(&series, 2, Edge::Shrink), Vector::new(vec![1., 2., 2.5, 4.]));
+/// ```
+/// note it panics if the window is 0
+pub fn moving_average(series: &Vector, window: usize, edge: Edge) -> Vector {
+    rolling(series, window, edge, |w| {
+        (w.iter().map(|&x| x as f64).sum::<f64>() / w.len() as f64) as f32
+    })
+}
+
+/// returns the moving population standard deviation over the trailing window like `moving_average`
+///
+/// ## Example
+///
+/// ```rust
+/// use math::linear_algebra::Vector;
+/// use math::timeseries::{moving_std, Edge};
+/// let series = Vector::new(vec![1., 3., 3., 7.]);
+/// assert_eq!(moving_std(&series, 2, Edge::Valid), Vector::new(vec![1., 0., 2.]));
+/// ```
+/// note it panics if the window is 0
+pub fn moving_std(series: &Vector, window: usize, edge: Edge) -> Vector {
+    rolling(series, window, edge, |w| {
+        let len = w.len() as f64;
+        let mean = w.iter().map(|&x| x as f64).sum::<f64>() / len;
+        let var = w.iter().map(|&x| (x as f64 - mean).powi(2)).sum::<f64>() / len;
+        var.sqrt() as f32
+    })
+}
+
+/// returns the [exponential moving average] `s_t = alpha * x_t + (1 - alpha) * s_t-1` which starts at `s_0 = x_0`
+///
+/// a smaller `alpha` smooths more, `alpha = 2 / (window + 1)` is roughly comparable to a moving average over the window.
+/// unlike the fitted values of [`ExponentialSmoothing::single`] the value at `t` already includes `x_t`
+///
+/// [exponential moving average]: https://en.wikipedia.org/wiki/Moving_average#Exponential_moving_average
+///
+/// ## Example
+///
+/// ```rust
+/// use math::linear_algebra::Vector;
+/// use math::timeseries::exponential_moving_average;
+/// let series = Vector::new(vec![3., 5., 4.]);
+/// assert_eq!(exponential_moving_average(&series, 0.5), Vector::new(vec![3., 4., 4.]));
+/// ```
+/// note it panics if `alpha` is not in `(0, 1]`
+pub fn exponential_moving_average(series: &Vector, alpha: f32) -> Vector {
+    if !(alpha > 0. && alpha <= 1.) {
+        panic!("alpha has to be in (0, 1], got {}", alpha);
+    }
+    let mut state: Option<f32> = None;
+    series
+        .iter()
+        .map(|x| {
+            let s = state.map_or(x, |s| alpha * x + (1. - alpha) * s);
+            state = Some(s);
+            s
+        })
+        .collect()
+}
+
+/// [exponential smoothing] of a series with a `forecast` for future values,
+/// supports single, double (Holt) and triple (additive Holt-Winters) smoothing
+///
+/// [exponential smoothing]: https://en.wikipedia.org/wiki/Exponential_smoothing
+///
+/// ## Example
+///
+/// ```rust
+/// use math::linear_algebra::Vector;
+/// use math::timeseries::ExponentialSmoothing;
+/// let series = Vector::new(vec![3., 5., 4.]);
+/// let smoothing = ExponentialSmoothing::single(&series, 0.5);
+/// assert_eq!(smoothing.fitted(), &Vector::new(vec![3., 3., 4.]));
+/// assert_eq!(smoothing.forecast(2), Vector::new(vec![4., 4.]));
+/// ```
+#[derive(PartialEq, Clone, Debug)]
+pub struct ExponentialSmoothing {
+    level: f32,
+    trend: f32,
+    season: Vec<f32>,
+    // the index in `season` of the next time step
+    season_index: usize,
+    fitted: Vector,
+}
+
+impl ExponentialSmoothing {
+    /// single exponential smoothing, `alpha` is the smoothing factor of the level
+    ///
+    /// note it panics if the series is empty
+    pub fn single(series: &Vector, alpha: f32) -> Self {
+        Self::fit(series, alpha, None, None)
+    }
+
+    /// double exponential smoothing (Holt's linear trend), `beta` is the smoothing factor of the trend
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Vector;
+    /// use math::timeseries::ExponentialSmoothing;
+    /// let series = Vector::new(vec![1., 3., 5., 7.]);
+    /// let smoothing = ExponentialSmoothing::double(&series, 0.5, 0.5);
+    /// assert_eq!(smoothing.forecast(2), Vector::new(vec![9., 11.]));
+    /// ```
+    /// note it panics if the series has less then 2 values
+    pub fn double(series: &Vector, alpha: f32, beta: f32) -> Self {
+        if series.len() < 2 {
+            panic!("double exponential smoothing needs at least 2 values");
+        }
+        Self::fit(series, alpha, Some(beta), None)
+    }
+
+    /// triple exponential smoothing (additive Holt-Winters), `gamma` is the smoothing factor
+    /// of the seasonal component which repeats every `period` values
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use math::linear_algebra::Vector;
+    /// use math::timeseries::ExponentialSmoothing;
+    /// let series = Vector::new(vec![1., 3., 1., 3., 1., 3.]);
+    /// let smoothing = ExponentialSmoothing::triple(&series, 0.5, 0.5, 0.5, 2);
+    /// assert_eq!(smoothing.fitted(), &series);
+    /// assert_eq!(smoothing.forecast(3), Vector::new(vec![1., 3., 1.]));
+    /// ```
+    /// note it panics if the series has less then 2 periods
+    pub fn triple(series: &Vector, alpha: f32, beta: f32, gamma: f32, period: usize) -> Self {
+        if period == 0 || series.len() < 2 * period {
+            panic!(
+                "triple exponential smoothing needs at least 2 periods, period = {}, len = {}",
+                period,
+                series.len()
+            );
+        }
+        Self::fit(series, alpha, Some(beta), Some((gamma, period)))
+    }
+
+    fn fit(series: &Vector, alpha: f32, beta: Option<f32>, gamma: Option<(f32, usize)>) -> Self {
+        let vec = series.vec();
+        if vec.is_empty() {
+            panic!("the series can't be empty");
+        }
+
+        let (mut level, mut trend, mut season) = match gamma {
+            Some((_, period)) => {
+                // the mean of the first period is the level in the middle of the period
+                let first = mean(&vec[..period]);
+                let second = mean(&vec[period..2 * period]);
+                let trend = (second - first) / period as f32;
+                let middle = (period - 1) as f32 / 2.;
+                let season = vec[..period]
+                    .iter()
+                    .enumerate()
+                    .map(|(i, y)| y - (first + trend * (i as f32 - middle)))
+                    .collect();
+                (first - trend * (middle + 1.), trend, season)
+            }
+            None => match beta {
+                Some(_) => (vec[0], vec[1] - vec[0], vec![0.]),
+                None => (vec[0], 0., vec![0.]),
+            },
+        };
+        let beta = beta.unwrap_or(0.);
+        let gamma = gamma.map(|(gamma, _)| gamma).unwrap_or(0.);
+
+        let mut fitted = Vec::with_capacity(vec.len());
+        for (t, &y) in vec.iter().enumerate() {
+            let s = t % season.len();
+            let prediction = level + trend + season[s];
+            if t == 0 && season.len() == 1 {
+                // there is nothing to predict the first value from
+                fitted.push(y);
+                continue;
+            }
+            fitted.push(prediction);
+
+            let last_level = level;
+            level = alpha * (y - season[s]) + (1. - alpha) * (level + trend);
+            trend = beta * (level - last_level) + (1. - beta) * trend;
+            season[s] = gamma * (y - level) + (1. - gamma) * season[s];
+        }
+
+        ExponentialSmoothing {
+            level,
+            trend,
+            season_index: vec.len() % season.len(),
+            season,
+            fitted: Vector::new(fitted),
+        }
+    }
+
+    /// returns the one step ahead prediction for each value of the series
+    pub fn fitted(&self) -> &Vector {
+        &self.fitted
+    }
+
+    /// returns the predictions for the next `steps` values
+    pub fn forecast(&self, steps: usize) -> Vector {
+        Vector::new(
+            (1..=steps)
+                .map(|h| {
+                    let s = (self.season_index + h - 1) % self.season.len();
+                    self.level + h as f32 * self.trend + self.season[s]
+                })
+                .collect(),
+        )
+    }
+}
+
+/// an [autoregressive model] `x_t = c + phi_1 * x_(t-1) + ... + phi_p * x_(t-p)`
+/// fitted with least squares
+///
+/// [autoregressive model]: https://en.wikipedia.org/wiki/Autoregressive_model
+///
+/// ## Example
+///
+/// ```rust
+/// use math::linear_algebra::Vector;
+/// use math::timeseries::AR;
+/// // x_t = 1 + 0.5 * x_(t-1)
+/// let series = Vector::new(vec![0., 1., 1.5, 1.75, 1.875]);
+/// let ar = AR::fit(&series, 1);
+/// assert!((ar.intercept() - 1.).abs() < 1e-4);
+/// assert!((ar.coefficients().index(0) - 0.5).abs() < 1e-4);
+/// assert!((ar.forecast(1).index(0) - 1.9375).abs() < 1e-4);
+/// ```
+#[derive(PartialEq, Clone, Debug)]
+pub struct AR {
+    intercept: f32,
+    coefficients: Vector,
+    history: Vec<f32>,
+}
+
+impl AR {
+    /// fits an AR model of order `p` to the series
+    ///
+    /// note it panics if the series has not more then `2 * p` values
+    pub fn fit(series: &Vector, p: usize) -> Self {
+        let vec = series.vec();
+        if vec.len() <= 2 * p {
+            panic!(
+                "the series is to short for the order p = {}, len = {}",
+                p,
+                vec.len()
+            );
+        }
+
+        let mut design = Vec::with_capacity((vec.len() - p) * (p + 1));
+        for t in p..vec.len() {
+            design.push(1.);
+            for i in 1..=p {
+                design.push(vec[t - i]);
+            }
+        }
+        let design = Matrix::new_flatt(design, vec.len() - p, p + 1);
+        let solution = design.lstsq(&Vector::new(vec[p..].to_vec())).vec();
+
+        AR {
+            intercept: solution[0],
+            coefficients: Vector::new(solution[1..].to_vec()),
+            history: vec[vec.len() - p..].to_vec(),
+        }
+    }
+
+    /// returns the order `p` of the model
+    pub fn order(&self) -> usize {
+        self.coefficients.len()
+    }
+
+    /// getter for the constant `c`
+    pub fn intercept(&self) -> f32 {
+        self.intercept
+    }
+
+    /// getter for the coefficients `phi_1..phi_p`
+    pub fn coefficients(&self) -> &Vector {
+        &self.coefficients
+    }
+
+    /// returns the predictions for the next `steps` values,
+    /// each prediction is used as input for the following ones
+    pub fn forecast(&self, steps: usize) -> Vector {
+        let phi = self.coefficients.vec();
+        let mut history = self.history.clone();
+        let mut result = Vec::with_capacity(steps);
+        for _ in 0..steps {
+            let len = history.len();
+            let next = self.intercept
+                + phi
+                    .iter()
+                    .enumerate()
+                    .map(|(i, p)| p * history[len - 1 - i])
+                    .sum::<f32>();
+            history.push(next);
+            result.push(next);
+        }
+        Vector::new(result)
+    }
+}
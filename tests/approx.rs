@@ -0,0 +1,106 @@
+#[cfg(test)]
+mod tests {
+    use math::approx::*;
+
+    #[test]
+    fn chebyshev_polynomial_exact() {
+        // a polynomial of the same degree is reproduced exactly
+        let poly = |x: f32| 2. * x * x * x - x + 0.5;
+        let cheb = chebyshev_fit(poly, -1., 1., 3);
+        // 2x^3 - x = (T_3 + 3 T_1) / 2 - T_1
+        let expected = [0.5, 0.5, 0., 0.5];
+        for (c, e) in cheb.coeffs().iter().zip(expected.iter()) {
+            assert!((c - e).abs() < 1e-6);
+        }
+        for &x in [-1., -0.3, 0.2, 0.9].iter() {
+            assert!((cheb.eval(x) - poly(x)).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn chebyshev_converges() {
+        let err = |degree: usize| {
+            let cheb = chebyshev_fit(|x| x.sin(), 0., 3., degree);
+            (0..=100)
+                .map(|i| {
+                    let x = 3. * i as f32 / 100.;
+                    (cheb.eval(x) - x.sin()).abs()
+                })
+                .fold(0., f32::max)
+        };
+        assert!(err(4) < 1e-2);
+        assert!(err(8) < 1e-5);
+        assert!(err(8) < err(4));
+    }
+
+    #[test]
+    fn chebyshev_derivative() {
+        let cheb = chebyshev_fit(|x| x.exp(), -2., 1., 14);
+        let deriv = cheb.derivative();
+        assert_eq!(deriv.interval(), (-2., 1.));
+        for &x in [-1.5, 0., 0.7].iter() {
+            assert!((deriv.eval(x) - x.exp()).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "is empty")]
+    fn chebyshev_empty_interval() {
+        chebyshev_fit(|x| x, 1., 1., 3);
+    }
+
+    fn exp_series(len: usize) -> Vec<f32> {
+        let mut coeffs = vec![1.];
+        for k in 1..len {
+            let last = coeffs[k - 1];
+            coeffs.push(last / k as f32);
+        }
+        coeffs
+    }
+
+    #[test]
+    fn pade_exp() {
+        let exp = pade(&exp_series(7), 3, 3);
+        assert_eq!(exp.denom().len(), 4);
+        assert_eq!(exp.denom()[0], 1.);
+        // the denominator of the diagonal approximant of exp is the numerator at -x
+        for (p, q) in exp
+            .numer()
+            .iter()
+            .zip(exp.denom().iter())
+            .skip(1)
+            .step_by(2)
+        {
+            assert!((p + q).abs() < 1e-5);
+        }
+        for &x in [-1., 0.5, 1.].iter() {
+            assert!((exp.eval(x) - x.exp()).abs() < 1e-4);
+        }
+        // better then the taylor polynomial with the same coefficients
+        let taylor: f32 = exp_series(7).iter().sum();
+        assert!((exp.eval(1.) - 1f32.exp()).abs() < (taylor - 1f32.exp()).abs());
+    }
+
+    #[test]
+    fn pade_taylor() {
+        // without a denominator it is the taylor polynomial
+        let taylor = pade(&[1., 2., 3.], 2, 0);
+        assert_eq!(taylor.numer(), &[1., 2., 3.]);
+        assert_eq!(taylor.denom(), &[1.]);
+        assert_eq!(taylor.eval(2.), 17.);
+    }
+
+    #[test]
+    fn pade_geometric() {
+        // 1 / (1 - x) is rational, so the [0/1] approximant is exact
+        let geometric = pade(&[1., 1., 1., 1.], 0, 1);
+        assert_eq!(geometric.denom(), &[1., -1.]);
+        assert_eq!(geometric.eval(0.5), 2.);
+    }
+
+    #[test]
+    #[should_panic(expected = "expected at least 5 coefficients")]
+    fn pade_too_few_coefficients() {
+        pade(&[1., 1., 0.5], 2, 2);
+    }
+}
@@ -0,0 +1,107 @@
+#[cfg(test)]
+mod tests {
+    use math::linear_algebra::batch::MatrixBatch;
+    use math::linear_algebra::Matrix;
+
+    fn batch() -> MatrixBatch {
+        MatrixBatch::new(&[
+            Matrix::new(vec![vec![1., 2., 3.], vec![4., 5., 6.]]),
+            Matrix::new(vec![vec![-1., 0., 1.], vec![2., 2., 2.]]),
+        ])
+    }
+
+    #[test]
+    fn new() {
+        let batch = batch();
+        assert_eq!((batch.len(), batch.cols(), batch.rows()), (2, 2, 3));
+        assert_eq!(batch.data().len(), 12);
+        assert_eq!(batch.index(1, 1, 0), 2.);
+        assert_eq!(
+            batch.get(0),
+            Matrix::new(vec![vec![1., 2., 3.], vec![4., 5., 6.]])
+        );
+        assert_eq!(batch.iter().count(), 2);
+
+        // transposed matrices are stored by their values
+        let mut transposed = Matrix::new(vec![vec![1., 4.], vec![2., 5.], vec![3., 6.]]);
+        transposed.transpose();
+        assert_eq!(MatrixBatch::new(&[transposed]).data(), &batch.data()[..6]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn new_wrong_shape() {
+        MatrixBatch::new(&[Matrix::new_zero(2, 2), Matrix::new_zero(2, 3)]);
+    }
+
+    #[test]
+    fn set() {
+        let mut batch = MatrixBatch::new_zero(3, 2, 2);
+        let matrix = Matrix::new(vec![vec![1., 2.], vec![3., 4.]]);
+        batch.set(1, &matrix);
+        batch.set_index(2, 0, 1, 5.);
+        assert_eq!(batch.get(0), Matrix::new_zero(2, 2));
+        assert_eq!(batch.get(1), matrix);
+        assert_eq!(batch.index(2, 0, 1), 5.);
+    }
+
+    #[test]
+    fn add_broadcast() {
+        let batch = batch();
+        assert_eq!((&batch + &batch).get(1), batch.get(1) * 2.);
+
+        let matrix = Matrix::new(vec![vec![1., 1., 1.], vec![0., 0., 0.]]);
+        let sum = &batch + &matrix;
+        assert_eq!(
+            sum.get(0),
+            Matrix::new(vec![vec![2., 3., 4.], vec![4., 5., 6.]])
+        );
+        assert_eq!(
+            sum.get(1),
+            Matrix::new(vec![vec![0., 1., 2.], vec![2., 2., 2.]])
+        );
+
+        // a bias of shape 1 x rows is added to every col
+        let bias = Matrix::new(vec![vec![10., 20., 30.]]);
+        let diff = &batch - &bias;
+        assert_eq!(
+            diff.get(1),
+            Matrix::new(vec![vec![-11., -20., -29.], vec![-8., -18., -28.]])
+        );
+        assert_eq!(
+            batch.mul_elem(&batch).get(0),
+            batch.get(0).hadamard(&batch.get(0))
+        );
+        assert_eq!((-batch.clone()).get(0), -batch.get(0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn add_wrong_len() {
+        let batch = MatrixBatch::new_zero(2, 2, 2);
+        let _ = &batch + &MatrixBatch::new_zero(3, 2, 2);
+    }
+
+    #[test]
+    fn dot() {
+        let batch = batch();
+        let rhs = MatrixBatch::new(&[Matrix::new_rand(3, 4), Matrix::new_rand(3, 4)]);
+        let product = batch.dot(&rhs);
+        assert_eq!((product.len(), product.cols(), product.rows()), (2, 2, 4));
+        for k in 0..2 {
+            assert_eq!(product.get(k), batch.get(k).dot_mat(&rhs.get(k)));
+        }
+
+        let matrix = Matrix::new_rand(3, 2);
+        let product = batch.dot_mat(&matrix);
+        for k in 0..2 {
+            assert_eq!(product.get(k), batch.get(k).dot_mat(&matrix));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn dot_wrong_shape() {
+        batch().dot_mat(&Matrix::new_zero(2, 2));
+    }
+}
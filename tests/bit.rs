@@ -0,0 +1,98 @@
+#[cfg(test)]
+mod tests {
+    use math::linear_algebra::bit::BitMatrix;
+
+    // a deterministic pseudo random matrix
+    fn matrix(cols: usize, rows: usize, seed: u64) -> BitMatrix {
+        let mut state = seed;
+        let mut matrix = BitMatrix::new_zero(cols, rows);
+        for i in 0..cols {
+            for j in 0..rows {
+                state = state
+                    .wrapping_mul(6364136223846793005)
+                    .wrapping_add(1442695040888963407);
+                matrix.set_index(i, j, state >> 63 == 1);
+            }
+        }
+        matrix
+    }
+
+    #[test]
+    fn index() {
+        let mut matrix = BitMatrix::new_zero(2, 130);
+        matrix.set_index(1, 129, true);
+        matrix.set_index(0, 64, true);
+        assert!(matrix.index(1, 129));
+        assert!(matrix.index(0, 64));
+        assert!(!matrix.index(1, 64));
+        matrix.set_index(1, 129, false);
+        assert!(!matrix.index(1, 129));
+        assert!(matrix.transpose().index(64, 0));
+    }
+
+    #[test]
+    fn inverse_large() {
+        // with more then 64 columns the lines span multiple words
+        for seed in 0..5 {
+            let matrix = matrix(70, 70, seed);
+            match matrix.inverse() {
+                Some(inverse) => {
+                    assert_eq!(matrix.dot_mat(&inverse), BitMatrix::identity(70));
+                    assert_eq!(inverse.dot_mat(&matrix), BitMatrix::identity(70));
+                    assert_eq!(matrix.rank(), 70);
+                }
+                None => assert!(matrix.rank() < 70),
+            }
+        }
+    }
+
+    #[test]
+    fn solve() {
+        let matrix = matrix(40, 90, 3);
+        let x: Vec<bool> = (0..90).map(|j| j % 3 == 0).collect();
+        let b = matrix.dot_vec(&x);
+        let solution = matrix.solve(&b).unwrap();
+        assert_eq!(matrix.dot_vec(&solution), b);
+    }
+
+    #[test]
+    fn inconsistent() {
+        let matrix = BitMatrix::new(vec![
+            vec![true, false, true],
+            vec![false, true, true],
+            vec![true, true, false],
+        ]);
+        assert_eq!(matrix.rank(), 2);
+        assert_eq!(matrix.inverse(), None);
+        assert_eq!(matrix.solve(&[true, false, false]), None);
+        assert_eq!(
+            matrix.solve(&[true, false, true]),
+            Some(vec![true, false, false])
+        );
+    }
+
+    #[test]
+    fn lights_out() {
+        // each button of a 3x3 lights out puzzle toggles itself and its neighbours
+        let mut matrix = BitMatrix::new_zero(9, 9);
+        for cell in 0..9 {
+            let (r, c) = (cell / 3, cell % 3);
+            for button in 0..9 {
+                let (br, bc) = (button / 3, button % 3);
+                if (r as i32 - br as i32).abs() + (c as i32 - bc as i32).abs() <= 1 {
+                    matrix.set_index(cell, button, true);
+                }
+            }
+        }
+        let lights = vec![true; 9];
+        let buttons = matrix.solve(&lights).unwrap();
+        assert_eq!(matrix.dot_vec(&buttons), lights);
+        assert_eq!(matrix.rank(), 9);
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn index_out_of_bounds() {
+        BitMatrix::new_zero(2, 2).index(0, 2);
+    }
+}
@@ -0,0 +1,47 @@
+#[cfg(test)]
+mod tests {
+    use math::linear_algebra::Matrix;
+
+    #[test]
+    fn build() {
+        let mut builder = Matrix::with_capacity(3, 2);
+        assert!(builder.is_empty());
+        for i in 0..3 {
+            builder.push_col(&[i as f32, 2. * i as f32]);
+        }
+        assert!(builder.is_full());
+        assert_eq!(
+            builder.build(),
+            Matrix::new(vec![vec![0., 0.], vec![1., 2.], vec![2., 4.]])
+        );
+    }
+
+    #[test]
+    fn empty() {
+        let matrix = Matrix::with_capacity(0, 3).build();
+        assert_eq!(matrix.cols(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "the matrix is not full expected 4 values, got 3")]
+    fn build_not_full() {
+        let mut builder = Matrix::with_capacity(2, 2);
+        builder.extend(vec![1., 2., 3.]);
+        builder.build();
+    }
+
+    #[test]
+    #[should_panic(expected = "the matrix is already full with 2 values")]
+    fn push_full() {
+        let mut builder = Matrix::with_capacity(1, 2);
+        builder.extend(vec![1., 2., 3.]);
+    }
+
+    #[test]
+    #[should_panic(expected = "the previous col is not completely filled")]
+    fn push_col_unaligned() {
+        let mut builder = Matrix::with_capacity(2, 2);
+        builder.push(1.);
+        builder.push_col(&[1., 2.]);
+    }
+}
@@ -0,0 +1,66 @@
+#[cfg(test)]
+mod tests {
+    use math::clustering::KMeans;
+    use math::linear_algebra::Matrix;
+
+    fn blobs() -> Matrix {
+        let centers = [(0., 0.), (10., 0.), (0., 10.)];
+        let offsets = [(0.5, 0.1), (-0.3, 0.4), (0.2, -0.6), (-0.4, 0.1)];
+        let mut samples = Vec::new();
+        for &(cx, cy) in centers.iter() {
+            for &(dx, dy) in offsets.iter() {
+                samples.push(vec![cx + dx, cy + dy]);
+            }
+        }
+        Matrix::new(samples)
+    }
+
+    #[test]
+    fn three_blobs() {
+        let mut kmeans = KMeans::new(3);
+        let labels = kmeans.fit_predict(&blobs());
+        for blob in labels.chunks(4) {
+            assert!(blob.iter().all(|&l| l == blob[0]));
+        }
+        assert_ne!(labels[0], labels[4]);
+        assert_ne!(labels[0], labels[8]);
+        assert_ne!(labels[4], labels[8]);
+
+        // the centroids are the means of the blobs
+        let centroids = kmeans.centroids().unwrap();
+        assert_eq!((centroids.cols(), centroids.rows()), (3, 2));
+        let center = centroids.col(labels[4]);
+        assert!((center.index(0) - 10.).abs() < 1e-5);
+        assert!(center.index(1).abs() < 1e-5);
+
+        let new = Matrix::new(vec![vec![9., 1.], vec![-1., 11.]]);
+        assert_eq!(kmeans.predict(&new), vec![labels[4], labels[8]]);
+    }
+
+    #[test]
+    fn deterministic() {
+        let mut a = KMeans::new(3);
+        let mut b = KMeans::new(3).max_iterations(50);
+        assert_eq!(a.fit_predict(&blobs()), b.fit_predict(&blobs()));
+        assert_eq!(a.centroids(), b.centroids());
+    }
+
+    #[test]
+    fn duplicate_samples() {
+        let data = Matrix::new(vec![vec![1., 1.]; 4]);
+        let labels = KMeans::new(2).fit_predict(&data);
+        assert!(labels.iter().all(|&l| l == labels[0]));
+    }
+
+    #[test]
+    #[should_panic(expected = "has to be fitted")]
+    fn predict_before_fit() {
+        KMeans::new(2).predict(&blobs());
+    }
+
+    #[test]
+    #[should_panic(expected = "expected at least 3 samples")]
+    fn too_few_samples() {
+        KMeans::new(3).fit(&Matrix::new(vec![vec![1.], vec![2.]]));
+    }
+}
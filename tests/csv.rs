@@ -0,0 +1,50 @@
+#[cfg(test)]
+mod tests {
+    use math::linear_algebra::csv::CsvOptions;
+    use math::linear_algebra::Matrix;
+    use std::io;
+
+    #[test]
+    fn round_trip() {
+        let mut matrix = Matrix::new(vec![vec![1., -2.5, 3.], vec![0.125, 5., 1e-3]]);
+        matrix.transpose();
+
+        for options in [
+            CsvOptions::default(),
+            CsvOptions::default().delimiter(';').header(true),
+        ] {
+            let mut out = Vec::new();
+            matrix.to_csv_writer(&mut out, &options).unwrap();
+            let read = Matrix::from_csv_reader(out.as_slice(), &options).unwrap();
+            assert_eq!(read.matrix_flatt(), matrix.matrix_flatt());
+            assert_eq!(read.cols(), 3);
+        }
+    }
+
+    #[test]
+    fn header_and_blank_lines() {
+        let data = "a,b,c\n\n 1 , \"2\" ,3\r\n4,5,6\n\n";
+        let options = CsvOptions::default().header(true);
+        assert_eq!(
+            Matrix::from_csv_reader(data.as_bytes(), &options).unwrap(),
+            Matrix::new(vec![vec![1., 2., 3.], vec![4., 5., 6.]])
+        );
+        // without the header option the names are no numbers
+        let err = Matrix::from_csv_reader(data.as_bytes(), &CsvOptions::default()).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(err.to_string(), "\"a\" in line 1 is not a number");
+    }
+
+    #[test]
+    fn errors() {
+        let options = CsvOptions::default();
+        let err = Matrix::from_csv_reader("1,2\n3\n".as_bytes(), &options).unwrap_err();
+        assert_eq!(err.to_string(), "line 2 has 1 values, expected 2");
+
+        let err = Matrix::from_csv_reader("\n\n".as_bytes(), &options).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+
+        let header_only = CsvOptions::default().header(true);
+        assert!(Matrix::from_csv_reader("x,y\n".as_bytes(), &header_only).is_err());
+    }
+}
@@ -0,0 +1,94 @@
+#[cfg(test)]
+mod tests {
+    use math::curve::*;
+    use math::linear_algebra::Vector;
+
+    fn points(coords: &[[f32; 2]]) -> Vec<Vector> {
+        coords.iter().map(|p| Vector::new(p.to_vec())).collect()
+    }
+
+    fn assert_close(a: &Vector, b: &Vector) {
+        assert!(
+            a.vec()
+                .iter()
+                .zip(b.vec().iter())
+                .all(|(x, y)| (x - y).abs() < 1e-4),
+            "{:?} != {:?}",
+            a,
+            b
+        );
+    }
+
+    #[test]
+    fn bezier_cubic() {
+        let p = points(&[[0., 0.], [1., 3.], [3., 3.], [4., 0.]]);
+        assert_eq!(bezier(&p, 0.), p[0]);
+        assert_eq!(bezier(&p, 1.), p[3]);
+        // the Bernstein form at t = 0.25
+        let t: f32 = 0.25;
+        let s = 1. - t;
+        let expected: Vec<f32> = (0..2)
+            .map(|i| {
+                s * s * s * p[0].index(i)
+                    + 3. * s * s * t * p[1].index(i)
+                    + 3. * s * t * t * p[2].index(i)
+                    + t * t * t * p[3].index(i)
+            })
+            .collect();
+        assert_close(&bezier(&p, t), &Vector::new(expected));
+    }
+
+    #[test]
+    fn bezier_derivative_matches_difference() {
+        let p = points(&[[0., 0.], [1., 3.], [3., 3.], [4., 0.]]);
+        // the tangents at the ends point to the neighbouring control points
+        assert_eq!(bezier_derivative(&p, 0.), Vector::new(vec![3., 9.]));
+        assert_eq!(bezier_derivative(&p, 1.), Vector::new(vec![3., -9.]));
+        let h = 1e-2;
+        let numeric = (bezier(&p, 0.6 + h) - bezier(&p, 0.6 - h)) / (2. * h);
+        let d = bezier_derivative(&p, 0.6);
+        assert!((numeric - d).vec().iter().all(|x| x.abs() < 1e-2));
+        assert_eq!(bezier_derivative(&p[..1], 0.5), Vector::new_zero(2));
+    }
+
+    #[test]
+    fn bspline_linear_is_polyline() {
+        let p = points(&[[0., 0.], [1., 2.], [3., 2.]]);
+        assert_close(&bspline(&p, 1, 0.), &p[0]);
+        assert_close(&bspline(&p, 1, 0.5), &p[1]);
+        assert_close(&bspline(&p, 1, 1.), &p[2]);
+        assert_close(&bspline(&p, 1, 0.25), &Vector::new(vec![0.5, 1.]));
+        // the derivative of each segment is the difference times the number of segments
+        assert_close(&bspline_derivative(&p, 1, 0.25), &Vector::new(vec![2., 4.]));
+        assert_close(&bspline_derivative(&p, 1, 0.75), &Vector::new(vec![4., 0.]));
+    }
+
+    #[test]
+    fn bspline_cubic() {
+        let p = points(&[[0., 0.], [1., 3.], [3., 3.], [4., 0.], [6., 1.]]);
+        // at the knots the uniform cubic B-spline is (p_i + 4 p_i+1 + p_i+2) / 6
+        for (i, &t) in [0., 0.5, 1.].iter().enumerate() {
+            let expected = (p[i].clone() + p[i + 1].clone() * 4. + p[i + 2].clone()) / 6.;
+            assert_close(&bspline(&p, 3, t), &expected);
+            // and the derivative is (p_i+2 - p_i) / 2 times the number of segments
+            let tangent = (p[i + 2].clone() - p[i].clone()) / 2. * 2.;
+            assert_close(&bspline_derivative(&p, 3, t), &tangent);
+        }
+        let h = 1e-2;
+        let numeric = (bspline(&p, 3, 0.3 + h) - bspline(&p, 3, 0.3 - h)) / (2. * h);
+        let d = bspline_derivative(&p, 3, 0.3);
+        assert!((numeric - d).vec().iter().all(|x| x.abs() < 1e-2));
+    }
+
+    #[test]
+    #[should_panic(expected = "a B-spline of degree 3 needs at least 4 control points, got 3")]
+    fn bspline_too_few_points() {
+        bspline(&points(&[[0., 0.], [1., 1.], [2., 0.]]), 3, 0.5);
+    }
+
+    #[test]
+    #[should_panic(expected = "all control points need the len 2, got 1")]
+    fn wrong_dimension() {
+        bezier(&[Vector::new(vec![0., 0.]), Vector::new(vec![1.])], 0.5);
+    }
+}
@@ -0,0 +1,64 @@
+#[cfg(test)]
+mod tests {
+    use math::data::*;
+    use math::linear_algebra::{Matrix, Vector};
+    use math::random::Random;
+
+    fn dataset(len: usize) -> (Matrix, Vector) {
+        let x = Matrix::new((0..len).map(|i| vec![i as f32, -(i as f32)]).collect());
+        let y = Vector::new((0..len).map(|i| i as f32).collect());
+        (x, y)
+    }
+
+    #[test]
+    fn split() {
+        let (x, y) = dataset(10);
+        let (x_train, y_train, x_test, y_test) = train_test_split(&x, &y, 0.8, &mut Random::new());
+        assert_eq!((x_train.cols(), x_train.rows()), (8, 2));
+        assert_eq!((x_test.cols(), y_test.len()), (2, 2));
+
+        let mut all: Vec<f32> = y_train.vec();
+        all.extend(y_test.vec());
+        all.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert_eq!(all, y.vec());
+
+        for i in 0..x_train.cols() {
+            assert_eq!(
+                x_train.col(i).vec(),
+                vec![y_train.index(i), -y_train.index(i)]
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "the number of samples is not the same x.cols() = 3, y.len() = 2")]
+    fn split_panic() {
+        let (x, _) = dataset(3);
+        let (_, y) = dataset(2);
+        train_test_split(&x, &y, 0.5, &mut Random::new());
+    }
+
+    #[test]
+    fn batches() {
+        let (x, _) = dataset(7);
+        let batches = Batches::new(&x, 3);
+        assert_eq!(batches.num_batches(), 3);
+        let sizes: Vec<usize> = batches.map(|b| b.cols()).collect();
+        assert_eq!(sizes, vec![3, 3, 1]);
+    }
+
+    #[test]
+    fn shuffled_batches() {
+        let (x, y) = dataset(9);
+        let mut seen = Vec::new();
+        for (batch_x, batch_y) in LabeledBatches::new(&x, &y, 4).shuffle(&mut Random::new()) {
+            for i in 0..batch_x.cols() {
+                assert_eq!(batch_x.col(i).index(0), batch_y.index(i));
+                seen.push(batch_y.index(i));
+            }
+        }
+        assert_ne!(seen, y.vec());
+        seen.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert_eq!(seen, y.vec());
+    }
+}
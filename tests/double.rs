@@ -0,0 +1,75 @@
+#[cfg(test)]
+mod tests {
+    use math::linear_algebra::double::{Matrix64, Vector64};
+    use math::linear_algebra::{Matrix, Vector};
+
+    #[test]
+    fn same_layout_as_matrix() {
+        let matrix = Matrix::new(vec![vec![1., 2., 3.], vec![4., 5., 6.]]);
+        let double = Matrix64::from(&matrix);
+        assert_eq!((double.cols(), double.rows()), (2, 3));
+        assert_eq!(double.index(1, 2), 6.);
+        let v = Vector::new(vec![1., 0., -1.]);
+        assert_eq!(
+            double.dot_vec(&Vector64::from(&v)).to_vector(),
+            matrix.dot_vec(&v)
+        );
+        let other = Matrix::new(vec![vec![1., 2.], vec![3., 4.], vec![5., 6.]]);
+        assert_eq!(
+            (&double * &Matrix64::from(&other)).to_matrix(),
+            matrix.dot_mat(&other)
+        );
+        assert_eq!(double.transpose().index(2, 1), 6.);
+        assert_eq!(Matrix::from(&double), matrix);
+    }
+
+    #[test]
+    fn hilbert_solve() {
+        // the hilbert matrix is too badly conditioned for f32
+        let n = 8;
+        let mut hilbert = Matrix64::new_zero(n, n);
+        for i in 0..n {
+            for j in 0..n {
+                hilbert.set_index(i, j, 1. / (i + j + 1) as f64);
+            }
+        }
+        let x = Vector64::new(vec![1.; n]);
+        let b = hilbert.dot_vec(&x);
+        let solved = hilbert.solve(&b);
+        assert!((solved - x).mag() < 1e-5);
+
+        let product = &hilbert * &hilbert.inverse();
+        let err = product - Matrix64::identity(n);
+        assert!(err.matrix_flatt().iter().all(|x| x.abs() < 1e-4));
+    }
+
+    #[test]
+    fn det() {
+        let matrix = Matrix64::new(vec![vec![0., 2., 1.], vec![1., 0., 0.], vec![3., 1., 4.]]);
+        assert!((matrix.det() - -7.).abs() < 1e-12);
+        assert_eq!(Matrix64::new(vec![vec![1., 2.], vec![2., 4.]]).det(), 0.);
+    }
+
+    #[test]
+    fn vector_ops() {
+        let mut a = Vector64::new(vec![1., 2., 3.]);
+        a += &Vector64::new(vec![1., 1., 1.]);
+        a -= &Vector64::new(vec![0., 0., 1.]);
+        assert_eq!(a, Vector64::new(vec![2., 3., 3.]));
+        assert_eq!(a.dot_vec(&a), 22.);
+        a.mul_scalar(0.5);
+        assert_eq!(a.vec(), &[1., 1.5, 1.5]);
+    }
+
+    #[test]
+    #[should_panic(expected = "the matrix is singular")]
+    fn singular() {
+        Matrix64::new(vec![vec![1., 2.], vec![2., 4.]]).solve(&Vector64::new(vec![1., 1.]));
+    }
+
+    #[test]
+    #[should_panic(expected = "wrong row shape expected 2, got 3")]
+    fn add_wrong_shape() {
+        let _ = Matrix64::new_zero(2, 2) + Matrix64::new_zero(2, 3);
+    }
+}
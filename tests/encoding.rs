@@ -0,0 +1,58 @@
+#[cfg(test)]
+mod tests {
+    use math::linear_algebra::encoding::DType;
+    use math::linear_algebra::{Matrix, Vector};
+
+    #[test]
+    fn header() {
+        let matrix = Matrix::new(vec![vec![1., 2., 3.], vec![4., 5., 6.]]);
+        let bytes = matrix.encode(DType::F64);
+        assert_eq!(&bytes[0..4], b"MATH");
+        assert_eq!(bytes[4..6], 1_u16.to_le_bytes());
+        assert_eq!(bytes[6], 1);
+        assert_eq!(bytes[8..16], 3_u64.to_le_bytes());
+        assert_eq!(bytes[16..24], 2_u64.to_le_bytes());
+        assert_eq!(bytes[24..32], 1_f64.to_le_bytes());
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut matrix = Matrix::new(vec![vec![1.5, -2.], vec![0.1, 4e10], vec![7., 8.]]);
+        matrix.transpose();
+        for &dtype in [DType::F32, DType::F64].iter() {
+            let decoded = Matrix::decode(&matrix.encode(dtype));
+            assert_eq!(decoded.matrix_flatt(), matrix.matrix_flatt());
+            assert_eq!((decoded.cols(), decoded.rows()), (2, 3));
+        }
+    }
+
+    #[test]
+    fn vector_as_matrix() {
+        let vector = Vector::new(vec![1., 2., 3.]);
+        let matrix = Matrix::decode(&vector.encode(DType::F32));
+        assert_eq!(matrix.col(0), vector);
+    }
+
+    #[test]
+    #[should_panic(expected = "the data doesn't start with the magic bytes")]
+    fn wrong_magic() {
+        let mut bytes = Vector::new(vec![1.]).encode(DType::F32);
+        bytes[0] = b'X';
+        Vector::decode(&bytes);
+    }
+
+    #[test]
+    #[should_panic(expected = "wrong payload len for 1 cols and 2 rows of F32, got 4 bytes")]
+    fn truncated() {
+        let bytes = Vector::new(vec![1., 2.]).encode(DType::F32);
+        Vector::decode(&bytes[..bytes.len() - 4]);
+    }
+
+    #[test]
+    #[should_panic(expected = "unknown dtype 7")]
+    fn unknown_dtype() {
+        let mut bytes = Vector::new(vec![1.]).encode(DType::F32);
+        bytes[6] = 7;
+        Vector::decode(&bytes);
+    }
+}
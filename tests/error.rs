@@ -0,0 +1,166 @@
+#[cfg(test)]
+mod tests {
+    use math::linear_algebra::{Matrix, Vector};
+    use math::MathError;
+
+    #[test]
+    fn matrix_shapes() {
+        let mut a = Matrix::new(vec![vec![1., 2.], vec![3., 4.]]);
+        let b = Matrix::new(vec![vec![1., 2., 3.], vec![4., 5., 6.]]);
+        let c = Matrix::new(vec![vec![1., 2.]]);
+        assert_eq!(
+            a.try_add_mat(&b),
+            Err(MathError::RowShape {
+                expected: 2,
+                got: 3
+            })
+        );
+        assert_eq!(
+            a.try_sub_mat(&c),
+            Err(MathError::ColShape {
+                expected: 2,
+                got: 1
+            })
+        );
+        assert!(a.try_mul_elem(&b).is_err());
+        assert!(a.try_div_mat(&b).is_err());
+        assert_eq!(
+            a.try_dot_mat(&c),
+            Err(MathError::MatrixShape {
+                expected: 2,
+                got: 1
+            })
+        );
+        assert_eq!(a.try_dot_mat(&b), Ok(a.dot_mat(&b)));
+        assert_eq!(
+            a.try_dot_vec(&Vector::new(vec![1.])),
+            Err(MathError::VectorShape {
+                expected: 2,
+                got: 1
+            })
+        );
+        // nothing was changed by the failed operations
+        assert_eq!(a, Matrix::new(vec![vec![1., 2.], vec![3., 4.]]));
+        assert_eq!(a.try_add_mat(&a.clone()), Ok(()));
+        assert_eq!(a, Matrix::new(vec![vec![2., 4.], vec![6., 8.]]));
+        let b = Matrix::new(vec![vec![1., 2.], vec![2., 4.]]);
+        assert_eq!(a.try_sub_mat(&b), Ok(()));
+        assert_eq!(a, Matrix::new(vec![vec![1., 2.], vec![4., 4.]]));
+        assert_eq!(a.try_div_mat(&b), Ok(()));
+        assert_eq!(a, Matrix::new(vec![vec![1., 1.], vec![2., 1.]]));
+        assert_eq!(a.try_mul_elem(&b), Ok(()));
+        assert_eq!(a, Matrix::new(vec![vec![1., 2.], vec![4., 4.]]));
+    }
+
+    #[test]
+    fn transposed_shapes() {
+        // the shapes are reported as seen from outside, not from the storage of a lazy transpose
+        let mut a = Matrix::new(vec![vec![1., 2., 3.], vec![4., 5., 6.]]);
+        let mut b = Matrix::new(vec![vec![1., 2., 3.], vec![4., 5., 6.]]);
+        b.transpose();
+        assert_eq!(
+            a.try_add_mat(&b),
+            Err(MathError::RowShape {
+                expected: 3,
+                got: 2
+            })
+        );
+        let mut c = Matrix::new_zero(3, 3);
+        c.transpose();
+        assert_eq!(
+            a.try_sub_mat(&c),
+            Err(MathError::ColShape {
+                expected: 2,
+                got: 3
+            })
+        );
+        assert_eq!(
+            b.try_dot_vec(&Vector::new(vec![1., 2., 3.])),
+            Err(MathError::VectorShape {
+                expected: 2,
+                got: 3
+            })
+        );
+        // the transposed operand still works when the shapes match
+        assert_eq!(a.try_add_mat(&b.transposed()), Ok(()));
+    }
+
+    #[test]
+    fn square() {
+        let b = Matrix::new(vec![vec![1., 2., 3.], vec![4., 5., 6.]]);
+        assert_eq!(b.try_det(), Err(MathError::NotSquare));
+        assert_eq!(b.try_inverse(), Err(MathError::NotSquare));
+        assert_eq!(
+            b.try_solve(&Vector::new(vec![1., 2.])),
+            Err(MathError::NotSquare)
+        );
+        assert_eq!(
+            Matrix::new(vec![vec![2.]]).try_det(),
+            Err(MathError::TooSmall)
+        );
+        assert_eq!(
+            Matrix::new(vec![vec![1., 2.], vec![3., 4.]]).try_det(),
+            Ok(-2.)
+        );
+    }
+
+    #[test]
+    fn singular() {
+        let mut singular = Matrix::new_zero(5, 5);
+        for i in 0..5 {
+            singular.set_index(i, 0, 1.);
+            singular.set_index(i, i, 2.);
+        }
+        singular.set_index(0, 0, 0.);
+        singular.set_index(1, 1, 0.);
+        singular.set_index(1, 0, 0.);
+        assert_eq!(singular.try_inverse(), Err(MathError::Singular));
+        assert_eq!(
+            singular.try_solve(&Vector::new(vec![1.; 5])),
+            Err(MathError::Singular)
+        );
+
+        let matrix = Matrix::new(vec![
+            vec![4., 1., 0., 0., 0.],
+            vec![1., 4., 1., 0., 0.],
+            vec![0., 1., 4., 1., 0.],
+            vec![0., 0., 1., 4., 1.],
+            vec![0., 0., 0., 1., 4.],
+        ]);
+        assert_eq!(matrix.try_inverse(), Ok(matrix.inverse()));
+        let b = Vector::new(vec![1., 2., 3., 4., 5.]);
+        assert_eq!(matrix.try_solve(&b), Ok(matrix.solve(&b)));
+    }
+
+    #[test]
+    fn vectors() {
+        let mut a = Vector::new(vec![1., 2.]);
+        let b = Vector::new(vec![1., 2., 3.]);
+        let err = MathError::VectorLen {
+            expected: 2,
+            got: 3,
+        };
+        assert_eq!(a.try_add_vec(&b), Err(err));
+        assert_eq!(a.try_sub_vec(&b), Err(err));
+        assert_eq!(a.try_mul_vec(&b), Err(err));
+        assert_eq!(a.try_div_vec(&b), Err(err));
+        assert_eq!(a.try_dot_vec(&b), Err(err));
+        assert_eq!(a.try_dot_vec(&a.clone()), Ok(5.));
+    }
+
+    #[test]
+    fn messages() {
+        // the errors have the same message as the panics
+        assert_eq!(
+            MathError::RowShape {
+                expected: 3,
+                got: 4
+            }
+            .to_string(),
+            "wrong row shape expected 3, got 4"
+        );
+        assert_eq!(MathError::Singular.to_string(), "the matrix is singular");
+        let err: Box<dyn std::error::Error> = Box::new(MathError::NotSquare);
+        assert_eq!(err.to_string(), "the matrix has to be a square matrix");
+    }
+}
@@ -0,0 +1,53 @@
+#[cfg(test)]
+mod tests {
+    use math::linear_algebra::expr::Expr;
+    use math::linear_algebra::Matrix;
+
+    #[test]
+    fn fused_chain() {
+        let a = Matrix::new(vec![vec![1., -4., 2.], vec![3., 2., -1.]]);
+        let b = Matrix::new(vec![vec![1., 1., 5.], vec![-5., 1., 2.]]);
+        let c = Matrix::new(vec![vec![2., 2., 2.], vec![4., 4., 4.]]);
+
+        let expected = Matrix::new(vec![vec![1., 0., 0.], vec![3., 1.25, 0.25]]);
+
+        assert_eq!(((&a - &b) / &c).add_scalar(1.).relu().eval(), expected);
+    }
+
+    #[test]
+    fn transposed() {
+        let mut a = Matrix::new(vec![vec![1., 2., 3.], vec![4., 5., 6.]]);
+        a.transpose();
+        let b = Matrix::new(vec![vec![1., 1.], vec![2., 2.], vec![3., 3.]]);
+        assert_eq!(
+            (a.lazy() * &b).eval(),
+            Matrix::new(vec![vec![1., 4.], vec![4., 10.], vec![9., 18.]])
+        );
+    }
+
+    #[test]
+    fn assign_to_reuses_storage() {
+        let a = Matrix::new(vec![vec![1., 2.], vec![3., 4.]]);
+        let mut target = Matrix::new_zero(2, 2);
+        (&a + &a).mul_scalar(0.5).assign_to(&mut target);
+        assert_eq!(target, a);
+
+        let mut shared = a.clone();
+        a.lazy().sub_scalar(1.).assign_to(&mut shared);
+        assert_eq!(shared, Matrix::new(vec![vec![0., 1.], vec![2., 3.]]));
+        assert_eq!(a, Matrix::new(vec![vec![1., 2.], vec![3., 4.]]));
+
+        let mut transposed = Matrix::new_zero(1, 4);
+        transposed.transpose();
+        a.lazy().map(|x| x * x).assign_to(&mut transposed);
+        assert_eq!(transposed, Matrix::new(vec![vec![1., 4.], vec![9., 16.]]));
+    }
+
+    #[test]
+    #[should_panic(expected = "wrong row shape expected 2, got 3")]
+    fn shape_panic() {
+        let a = Matrix::new_zero(2, 2);
+        let b = Matrix::new_zero(2, 3);
+        let _ = &a + &b;
+    }
+}
@@ -0,0 +1,64 @@
+#[cfg(all(test, feature = "ffi"))]
+mod tests {
+    use math::ffi::*;
+
+    #[test]
+    fn matmul() {
+        unsafe {
+            let a = math_matrix_new([1., 2., 3., 4.].as_ptr(), 2, 2);
+            let b = math_matrix_new([5., 6., 7., 8.].as_ptr(), 2, 2);
+            let c = math_matrix_matmul(a, b);
+            assert!(!c.is_null());
+
+            let mut out = [0.; 4];
+            assert!(math_matrix_copy_data(c, out.as_mut_ptr(), 4));
+            assert_eq!(out, [19., 22., 43., 50.]);
+            assert!(!math_matrix_copy_data(c, out.as_mut_ptr(), 3));
+
+            math_matrix_free(a);
+            math_matrix_free(b);
+            math_matrix_free(c);
+        }
+    }
+
+    #[test]
+    fn matrix_new_overflow() {
+        unsafe {
+            let data = [1.; 4];
+            assert!(math_matrix_new(data.as_ptr(), usize::MAX, 2).is_null());
+            assert!(math_matrix_new(std::ptr::null(), 2, 2).is_null());
+        }
+    }
+
+    #[test]
+    fn matmul_wrong_shape() {
+        unsafe {
+            let a = math_matrix_zero(2, 3);
+            assert!(math_matrix_matmul(a, a).is_null());
+            math_matrix_free(a);
+        }
+    }
+
+    #[test]
+    fn solve() {
+        unsafe {
+            let a = math_matrix_new([2., 1., 1., 3.].as_ptr(), 2, 2);
+            let b = math_vector_new([3., 5.].as_ptr(), 2);
+            let x = math_matrix_solve(a, b);
+            assert_eq!(math_vector_len(x), 2);
+            assert_eq!(math_vector_get(x, 0), 0.8);
+            assert_eq!(math_vector_get(x, 1), 1.4);
+            assert!(math_vector_get(x, 2).is_nan());
+
+            assert!(math_matrix_set(a, 1, 0, 2.));
+            assert!(math_matrix_set(a, 1, 1, 1.));
+            assert!(!math_matrix_set(a, 2, 0, 1.));
+            assert_eq!(math_matrix_get(a, 1, 0), 2.);
+            assert!(math_matrix_solve(a, b).is_null());
+
+            math_vector_free(x);
+            math_vector_free(b);
+            math_matrix_free(a);
+        }
+    }
+}
@@ -0,0 +1,125 @@
+#[cfg(test)]
+mod tests {
+    use math::fft::{fft, ifft, irfft, rfft};
+    use math::linear_algebra::Vector;
+
+    // the O(n^2) definition of the transform
+    fn dft(re: &[f32], im: &[f32]) -> (Vec<f32>, Vec<f32>) {
+        let n = re.len();
+        let mut out_re = vec![0.; n];
+        let mut out_im = vec![0.; n];
+        for k in 0..n {
+            let (mut sum_re, mut sum_im) = (0f64, 0f64);
+            for j in 0..n {
+                let angle = -2. * std::f64::consts::PI * (j * k) as f64 / n as f64;
+                let (sin, cos) = angle.sin_cos();
+                sum_re += re[j] as f64 * cos - im[j] as f64 * sin;
+                sum_im += re[j] as f64 * sin + im[j] as f64 * cos;
+            }
+            out_re[k] = sum_re as f32;
+            out_im[k] = sum_im as f32;
+        }
+        (out_re, out_im)
+    }
+
+    fn signal(n: usize, seed: f32) -> Vec<f32> {
+        (0..n)
+            .map(|j| (j as f32 * seed).sin() + 0.5 * (j as f32 * 0.3).cos())
+            .collect()
+    }
+
+    fn assert_close(a: &[f32], b: &[f32]) {
+        assert_eq!(a.len(), b.len());
+        for (x, y) in a.iter().zip(b.iter()) {
+            assert!((x - y).abs() < 1e-4, "{:?} != {:?}", a, b);
+        }
+    }
+
+    #[test]
+    fn fft_matches_dft() {
+        // powers of two use radix-2, the other lens Bluestein's algorithm
+        for &n in [1, 2, 3, 5, 8, 12, 16, 17, 30].iter() {
+            let (re, im) = (signal(n, 1.3), signal(n, 0.7));
+            let (out_re, out_im) = fft(&Vector::new(re.clone()), &Vector::new(im.clone()));
+            let (exp_re, exp_im) = dft(&re, &im);
+            assert_close(&out_re.vec(), &exp_re);
+            assert_close(&out_im.vec(), &exp_im);
+        }
+    }
+
+    #[test]
+    fn ifft_round_trip() {
+        for &n in [1, 4, 7, 10, 32].iter() {
+            let (re, im) = (signal(n, 2.1), signal(n, -0.4));
+            let (f_re, f_im) = fft(&Vector::new(re.clone()), &Vector::new(im.clone()));
+            let (back_re, back_im) = ifft(&f_re, &f_im);
+            assert_close(&back_re.vec(), &re);
+            assert_close(&back_im.vec(), &im);
+        }
+    }
+
+    #[test]
+    fn ifft_scaling() {
+        // the spectrum of a constant is a single value n at 0
+        let (re, im) = ifft(
+            &Vector::new(vec![6., 0., 0.]),
+            &Vector::new(vec![0., 0., 0.]),
+        );
+        assert_close(&re.vec(), &[2., 2., 2.]);
+        assert_close(&im.vec(), &[0., 0., 0.]);
+    }
+
+    #[test]
+    fn rfft_matches_fft() {
+        for &n in [1, 2, 3, 6, 8, 9, 16, 21].iter() {
+            let x = signal(n, 0.9);
+            let (re, im) = rfft(&Vector::new(x.clone()));
+            assert_eq!(re.len(), n / 2 + 1);
+            let (exp_re, exp_im) = dft(&x, &vec![0.; n]);
+            assert_close(&re.vec(), &exp_re[..n / 2 + 1]);
+            assert_close(&im.vec(), &exp_im[..n / 2 + 1]);
+            // the dropped half is the conjugate of the returned one
+            for k in 1..n - n / 2 {
+                assert!((exp_re[n - k] - re.index(k)).abs() < 1e-4);
+                assert!((exp_im[n - k] + im.index(k)).abs() < 1e-4);
+            }
+        }
+    }
+
+    #[test]
+    fn irfft_round_trip() {
+        for &n in [1, 2, 5, 8, 11, 24].iter() {
+            let x = signal(n, 1.7);
+            let (re, im) = rfft(&Vector::new(x.clone()));
+            assert_close(&irfft(&re, &im, n).vec(), &x);
+        }
+    }
+
+    #[test]
+    fn irfft_len() {
+        // 4 and 5 samples both have 3 values in the spectrum
+        let re = Vector::new(vec![4., 0., 0.]);
+        let im = Vector::new(vec![0., 0., 0.]);
+        assert_close(&irfft(&re, &im, 4).vec(), &[1.; 4]);
+        assert_close(&irfft(&re, &im, 5).vec(), &[0.8; 5]);
+    }
+
+    #[test]
+    #[should_panic(expected = "a signal of len 6 has 4 values in the spectrum, got 3")]
+    fn irfft_wrong_len() {
+        let v = Vector::new(vec![1., 2., 3.]);
+        irfft(&v, &v, 6);
+    }
+
+    #[test]
+    #[should_panic(expected = "the real and imaginary part need the same len, got 2 and 3")]
+    fn fft_wrong_len() {
+        fft(&Vector::new(vec![1., 2.]), &Vector::new(vec![1., 2., 3.]));
+    }
+
+    #[test]
+    #[should_panic(expected = "can't transform an empty signal")]
+    fn rfft_empty() {
+        rfft(&Vector::new(vec![]));
+    }
+}
@@ -0,0 +1,51 @@
+#[cfg(test)]
+mod tests {
+    use math::linear_algebra::{Matrix, Vector};
+
+    #[test]
+    fn matrix_validation() {
+        let mut matrix = Matrix::new(vec![vec![1., f32::NAN], vec![f32::INFINITY, 4.]]);
+        assert!(matrix.has_nan());
+        assert!(!matrix.is_finite());
+
+        let shared = matrix.clone();
+        matrix.replace_nan(-1.);
+        assert!(!matrix.has_nan());
+        assert!(!matrix.is_finite());
+        assert!(shared.has_nan());
+        assert_eq!(matrix.index(0, 1), -1.);
+    }
+
+    #[test]
+    fn vector_validation() {
+        let mut vector = Vector::new(vec![f32::NAN, 2., f32::NAN]);
+        assert!(vector.has_nan());
+        vector.replace_nan(0.);
+        assert!(vector.is_finite());
+        assert_eq!(vector, Vector::new(vec![0., 2., 0.]));
+    }
+
+    #[cfg(feature = "check-finite")]
+    #[test]
+    #[should_panic(expected = "the matrix contains the non finite value inf")]
+    fn check_finite_matrix() {
+        let mut matrix = Matrix::new(vec![vec![1., 2.], vec![3., 4.]]);
+        matrix.div_scalar(&0.);
+    }
+
+    #[cfg(feature = "check-finite")]
+    #[test]
+    #[should_panic(expected = "the vector contains the non finite value NaN")]
+    fn check_finite_vector() {
+        let mut vector = Vector::new(vec![0., 2.]);
+        vector.div_vec(&Vector::new(vec![0., 1.]));
+    }
+
+    #[cfg(not(feature = "check-finite"))]
+    #[test]
+    fn no_check_finite() {
+        let mut matrix = Matrix::new(vec![vec![1., 2.], vec![3., 4.]]);
+        matrix.div_scalar(&0.);
+        assert!(!matrix.is_finite());
+    }
+}
@@ -0,0 +1,108 @@
+#[cfg(test)]
+mod tests {
+    use math::linear_algebra::fixed::{Matrix2, Matrix3, Matrix4, SMatrix, SVector, SquareMatrix};
+    use math::linear_algebra::{Matrix, Vector};
+
+    #[test]
+    fn matches_matrix() {
+        let data = [
+            [6., 1., 1., 4.],
+            [4., -2., 5., -7.],
+            [2., 8., 7., 3.],
+            [4., 1., 4., 2.],
+        ];
+        let fixed = Matrix4::new(data);
+        let matrix = Matrix::new(data.iter().map(|col| col.to_vec()).collect());
+
+        assert_eq!(fixed.to_matrix(), matrix);
+        assert_eq!(Matrix4::from_matrix(&matrix), fixed);
+        assert_eq!(fixed.det(), matrix.det());
+        assert_eq!(fixed.dot_mat(&fixed).to_matrix(), matrix.dot_mat(&matrix));
+        assert_eq!(
+            fixed.dot_vec(&[1., 2., 3., 4.]).to_vec(),
+            matrix
+                .dot_vec(&math::linear_algebra::Vector::new(vec![1., 2., 3., 4.]))
+                .vec()
+        );
+
+        let mut transposed = matrix.clone();
+        transposed.transpose();
+        assert_eq!(
+            fixed.transpose().to_matrix().matrix_flatt(),
+            transposed.matrix_flatt()
+        );
+        assert_eq!(Matrix4::from_matrix(&transposed), fixed.transpose());
+    }
+
+    #[test]
+    fn inverse() {
+        let matrix = Matrix3::new([[2., -3., 1.], [2., 0., -1.], [1., 4., 5.]]);
+        let product = matrix.dot_mat(&matrix.inverse());
+        let diff = product - Matrix3::identity();
+        assert!(diff.data().iter().flatten().all(|x| x.abs() < 1e-6));
+
+        let big = SquareMatrix::<5>::new([
+            [2., 0., 0., 0., 0.],
+            [0., 1., 0., 0., 0.],
+            [0., 0., 4., 0., 0.],
+            [0., 0., 0., 1., 0.],
+            [0., 0., 0., 0., 5.],
+        ]);
+        assert_eq!(big.det(), 40.);
+        assert_eq!(big.inverse().index(2, 2), 0.25);
+    }
+
+    #[test]
+    fn ops() {
+        let mut matrix = Matrix2::new([[1., 2.], [3., 4.]]);
+        matrix += Matrix2::identity();
+        assert_eq!(matrix, Matrix2::new([[2., 2.], [3., 5.]]));
+        matrix.mul_scalar(2.);
+        matrix.set_index(0, 1, 0.);
+        assert_eq!(
+            matrix - Matrix2::default(),
+            Matrix2::from([[4., 0.], [6., 10.]])
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "wrong matrix shape expected 2x2, got 2x3")]
+    fn from_matrix_panic() {
+        Matrix2::from_matrix(&Matrix::new_zero(2, 3));
+    }
+
+    #[test]
+    fn rectangular() {
+        let a = SMatrix::<2, 3>::new([[1., 2., 3.], [4., 5., 6.]]);
+        let b = SMatrix::<3, 2>::new([[1., 0.], [0., 1.], [1., 1.]]);
+        let product: SMatrix<2, 2> = a * b;
+        assert_eq!(product.to_matrix(), a.to_matrix().dot_mat(&b.to_matrix()));
+        assert_eq!(a.transpose(), SMatrix::from([[1., 4.], [2., 5.], [3., 6.]]));
+        assert_eq!(SMatrix::<2, 3>::from_matrix(&a.to_matrix()), a);
+        assert_eq!(-a + a * 2., a);
+    }
+
+    #[test]
+    fn vector() {
+        let matrix = SMatrix::<2, 3>::new([[1., 2., 3.], [4., 5., 6.]]);
+        let vector = SVector::new([1., 0., -1.]);
+        assert_eq!(matrix * vector, SVector::new([-2., -2.]));
+        assert_eq!(
+            (matrix * vector).to_vector(),
+            matrix.to_matrix().dot_vec(&vector.to_vector())
+        );
+        assert_eq!(vector.dot(&vector), 2.);
+        assert_eq!(SVector::new([3., 4.]).norm(), 5.);
+        assert_eq!(vector - vector, SVector::default());
+        assert_eq!(
+            SVector::<3>::from_vector(&Vector::new(vec![1., 0., -1.])),
+            vector
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "wrong vector len expected 3, got 2")]
+    fn from_vector_panic() {
+        SVector::<3>::from_vector(&Vector::new(vec![1., 2.]));
+    }
+}
@@ -0,0 +1,78 @@
+#[cfg(test)]
+mod tests {
+    use math::linear_algebra::fixed::SVector;
+    use math::linear_algebra::fixed_vec::{Vec2, Vec3, Vec4};
+    use math::linear_algebra::Vector;
+
+    #[test]
+    fn ops() {
+        let mut a = Vec3::new(1., 2., 3.);
+        let b = Vec3::splat(1.);
+        assert_eq!(a + b, Vec3::new(2., 3., 4.));
+        assert_eq!(a - b, Vec3::new(0., 1., 2.));
+        assert_eq!(2. * a, a * 2.);
+        assert_eq!(a / 2., Vec3::new(0.5, 1., 1.5));
+        assert_eq!(-a, Vec3::new(-1., -2., -3.));
+        a += b;
+        a *= 2.;
+        assert_eq!(a, Vec3::new(4., 6., 8.));
+        a[2] = 0.;
+        assert_eq!(a[1], 6.);
+        assert_eq!(a.z, 0.);
+        assert_eq!(Vec4::zero(), Vec4::default());
+    }
+
+    #[test]
+    fn products() {
+        let a = Vec3::new(1., 2., 3.);
+        let b = Vec3::new(4., 5., 6.);
+        assert_eq!(a.dot(b), 32.);
+        assert_eq!(a.cross(b), Vec3::new(-3., 6., -3.));
+        assert_eq!(a.cross(b).dot(a), 0.);
+        assert_eq!(Vec2::new(1., 0.).cross(Vec2::new(0., 1.)), 1.);
+        assert_eq!(Vec2::new(1., 2.).perp(), Vec2::new(-2., 1.));
+
+        assert_eq!(Vec2::new(3., 4.).length(), 5.);
+        assert_eq!(Vec4::new(1., 1., 1., 1.).length_squared(), 4.);
+        assert!((Vec3::new(1., 2., 2.).normalize().length() - 1.).abs() < 1e-6);
+        assert_eq!(Vec2::new(0., 0.).distance(Vec2::new(3., 4.)), 5.);
+        assert_eq!(
+            Vec2::new(0., 0.).lerp(Vec2::new(2., 4.), 0.5),
+            Vec2::new(1., 2.)
+        );
+    }
+
+    #[test]
+    fn swizzle() {
+        let v = Vec4::new(1., 2., 3., 4.);
+        assert_eq!(v.xyz(), Vec3::new(1., 2., 3.));
+        assert_eq!(v.zyx(), Vec3::new(3., 2., 1.));
+        assert_eq!(v.wzyx(), Vec4::new(4., 3., 2., 1.));
+        assert_eq!(v.zw(), Vec2::new(3., 4.));
+        assert_eq!(v.xyz().zx(), Vec2::new(3., 1.));
+        assert_eq!(Vec2::new(1., 2.).yx(), Vec2::new(2., 1.));
+        assert_eq!(
+            Vec2::new(1., 2.).extend(3.).extend(1.),
+            Vec4::new(1., 2., 3., 1.)
+        );
+        assert_eq!(Vec4::new(2., 4., 6., 2.).project(), Vec3::new(1., 2., 3.));
+    }
+
+    #[test]
+    fn conversions() {
+        let v = Vec3::new(1., 2., 3.);
+        assert_eq!(v.to_array(), [1., 2., 3.]);
+        assert_eq!(Vec3::from([1., 2., 3.]), v);
+        assert_eq!(v.to_vector(), Vector::new(vec![1., 2., 3.]));
+        assert_eq!(Vec3::from_vector(&Vector::new(vec![1., 2., 3.])), v);
+        assert_eq!(SVector::from(v), SVector::new([1., 2., 3.]));
+        assert_eq!(Vec3::from(SVector::new([1., 2., 3.])), v);
+    }
+
+    #[test]
+    #[should_panic]
+    fn index_out_of_bounds() {
+        let mut v = Vec2::zero();
+        v[2] = 1.;
+    }
+}
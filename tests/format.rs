@@ -0,0 +1,77 @@
+#[cfg(test)]
+mod tests {
+    use math::linear_algebra::format::{Brackets, PrintOptions};
+    use math::linear_algebra::{Matrix, Vector};
+
+    #[test]
+    fn latex() {
+        let mut matrix = Matrix::new(vec![vec![1., 2., 3.], vec![0.5, 0., -1.]]);
+        assert_eq!(
+            matrix.to_latex(),
+            "\\begin{bmatrix}\n1 & 2 & 3 \\\\\n0.5 & 0 & -1\n\\end{bmatrix}"
+        );
+
+        matrix.transpose();
+        assert_eq!(
+            matrix.to_latex(),
+            "\\begin{bmatrix}\n1 & 0.5 \\\\\n2 & 0 \\\\\n3 & -1\n\\end{bmatrix}"
+        );
+    }
+
+    #[test]
+    fn markdown() {
+        let mut matrix = Matrix::new(vec![vec![1., 2., 3.], vec![0.5, 0., -1.]]);
+        assert_eq!(
+            matrix.to_markdown(),
+            "| 0 | 1 | 2 |\n| --- | --- | --- |\n| 1 | 2 | 3 |\n| 0.5 | 0 | -1 |"
+        );
+
+        matrix.transpose();
+        assert_eq!(
+            matrix.to_markdown(),
+            "| 0 | 1 |\n| --- | --- |\n| 1 | 0.5 |\n| 2 | 0 |\n| 3 | -1 |"
+        );
+    }
+
+    #[test]
+    fn pretty() {
+        let mut matrix = Matrix::new(vec![vec![1., 2., 3.], vec![0.5, 0., -10.]]);
+        assert_eq!(matrix.to_string(), "[1.0, 2.0,   3.0]\n[0.5, 0.0, -10.0]");
+
+        matrix.transpose();
+        let options = PrintOptions::default()
+            .brackets(Brackets::Round)
+            .precision(1);
+        assert_eq!(
+            matrix.to_pretty(&options),
+            "(1.0,   0.5)\n(2.0,   0.0)\n(3.0, -10.0)"
+        );
+        assert_eq!(
+            matrix.to_pretty(&options.align(false).max_cols(Some(2))),
+            "(1.0, 0.5)\n...\n(3.0, -10.0)"
+        );
+    }
+
+    #[test]
+    fn pretty_big() {
+        let matrix = Matrix::new_flatt((0..100).map(|x| x as f32).collect(), 50, 2);
+        let text = matrix.to_string();
+        assert_eq!(text.lines().count(), 21);
+        assert_eq!(text.lines().nth(10), Some("..."));
+        assert_eq!(text.lines().last(), Some("[98.0, 99.0]"));
+
+        let all = PrintOptions::default().max_cols(None);
+        assert_eq!(matrix.to_pretty(&all).lines().count(), 50);
+    }
+
+    #[test]
+    fn pretty_vector() {
+        let vector = Vector::new(vec![1., -2., 0.125]);
+        assert_eq!(vector.to_string(), "[1.0, -2.0, 0.125]");
+        assert_eq!(format!("{:.2}", vector), "[1.00, -2.00, 0.12]");
+        let options = PrintOptions::default()
+            .brackets(Brackets::None)
+            .max_rows(Some(1));
+        assert_eq!(vector.to_pretty(&options), "1.0 ...");
+    }
+}
@@ -0,0 +1,385 @@
+#[cfg(test)]
+mod tests {
+    use math::geometry::*;
+    use math::linear_algebra::fixed::{Matrix3, Matrix4};
+    use math::linear_algebra::{Matrix, Vector};
+    use std::f32::consts::{FRAC_PI_2, PI};
+
+    const ORDERS: [EulerOrder; 6] = [
+        EulerOrder::XYZ,
+        EulerOrder::XZY,
+        EulerOrder::YXZ,
+        EulerOrder::YZX,
+        EulerOrder::ZXY,
+        EulerOrder::ZYX,
+    ];
+
+    fn assert_close(a: &Matrix3, b: &Matrix3) {
+        for i in 0..3 {
+            for j in 0..3 {
+                assert!(
+                    (a.index(i, j) - b.index(i, j)).abs() < 1e-5,
+                    "{:?} != {:?}",
+                    a,
+                    b
+                );
+            }
+        }
+    }
+
+    fn axis_matrix(axis: usize, angle: f32) -> Matrix3 {
+        let (s, c) = angle.sin_cos();
+        match axis {
+            0 => Matrix3::new([[1., 0., 0.], [0., c, -s], [0., s, c]]),
+            1 => Matrix3::new([[c, 0., s], [0., 1., 0.], [-s, 0., c]]),
+            _ => Matrix3::new([[c, -s, 0.], [s, c, 0.], [0., 0., 1.]]),
+        }
+    }
+
+    #[test]
+    fn euler_order() {
+        let angles = [0.3, -0.7, 1.1];
+        let (x, y, z) = (
+            |a| axis_matrix(0, a),
+            |a| axis_matrix(1, a),
+            |a| axis_matrix(2, a),
+        );
+        assert_close(
+            &euler_to_matrix(angles, EulerOrder::XYZ),
+            &x(0.3).dot_mat(&y(-0.7)).dot_mat(&z(1.1)),
+        );
+        assert_close(
+            &euler_to_matrix(angles, EulerOrder::ZYX),
+            &z(0.3).dot_mat(&y(-0.7)).dot_mat(&x(1.1)),
+        );
+        assert_close(
+            &euler_to_matrix(angles, EulerOrder::YXZ),
+            &y(0.3).dot_mat(&x(-0.7)).dot_mat(&z(1.1)),
+        );
+    }
+
+    #[test]
+    fn euler_round_trip() {
+        for &order in ORDERS.iter() {
+            for &angles in [[0.3, -0.7, 1.1], [-2.5, 1.2, 3.], [0., 0., 0.]].iter() {
+                let back = matrix_to_euler(&euler_to_matrix(angles, order), order);
+                for k in 0..3 {
+                    assert!((back[k] - angles[k]).abs() < 1e-4, "{:?} {:?}", order, back);
+                }
+                let q = Quaternion::from_euler(angles, order);
+                let back = q.to_euler(order);
+                assert_close(&euler_to_matrix(back, order), &q.to_matrix());
+            }
+        }
+    }
+
+    #[test]
+    fn gimbal_lock() {
+        for &order in ORDERS.iter() {
+            for &b in [FRAC_PI_2, -FRAC_PI_2].iter() {
+                let rot = euler_to_matrix([0.4, b, 0.9], order);
+                let angles = matrix_to_euler(&rot, order);
+                assert!((angles[1] - b).abs() < 1e-3, "{:?} {:?}", order, angles);
+                assert_eq!(angles[2], 0.);
+                assert_close(&euler_to_matrix(angles, order), &rot);
+            }
+        }
+    }
+
+    #[test]
+    fn quaternion_matrix() {
+        let q = Quaternion::from_axis_angle([1., 2., 2.], 2.);
+        assert!((q.norm() - 1.).abs() < 1e-6);
+        let back = Quaternion::from_matrix(&q.to_matrix());
+        assert_close(&back.to_matrix(), &q.to_matrix());
+
+        // all branches of Shepperd's method
+        for &(axis, angle) in [
+            ([1., 0., 0.], PI),
+            ([0., 1., 0.], PI),
+            ([0., 0., 1.], PI),
+            ([1., 1., 0.], 3.),
+        ]
+        .iter()
+        {
+            let q = Quaternion::from_axis_angle(axis, angle);
+            assert_close(
+                &Quaternion::from_matrix(&q.to_matrix()).to_matrix(),
+                &q.to_matrix(),
+            );
+        }
+
+        assert_eq!(Quaternion::default().to_matrix(), Matrix3::identity());
+    }
+
+    #[test]
+    fn quaternion_mul() {
+        let a = Quaternion::from_axis_angle([0., 0., 1.], 0.5);
+        let b = Quaternion::from_axis_angle([1., 0., 0.], -1.2);
+        assert_close(&(a * b).to_matrix(), &a.to_matrix().dot_mat(&b.to_matrix()));
+        assert_close(&(a * a.conjugate()).to_matrix(), &Matrix3::identity());
+
+        let p = (a * b).rotate(&[1., 2., 3.]);
+        let expected = a.rotate(&b.rotate(&[1., 2., 3.]));
+        for k in 0..3 {
+            assert!((p[k] - expected[k]).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "the rotation axis has a length of 0")]
+    fn zero_axis() {
+        Quaternion::from_axis_angle([0., 0., 0.], 1.);
+    }
+
+    #[test]
+    fn transforms() {
+        let t = translation([1., 2., 3.]);
+        assert_eq!(transform_point(&t, &[1., 1., 1.]), [2., 3., 4.]);
+        assert_eq!(transform_direction(&t, &[1., 1., 1.]), [1., 1., 1.]);
+        assert_eq!(
+            transform_point(&scaling([2., 3., 4.]), &[1., 1., 1.]),
+            [2., 3., 4.]
+        );
+        assert_eq!(rotation(&Quaternion::identity()), Matrix4::identity());
+
+        let mut projective = Matrix4::identity();
+        projective.set_index(3, 3, 2.);
+        assert_eq!(transform_point(&projective, &[2., 4., 6.]), [1., 2., 3.]);
+    }
+
+    fn assert_close3(a: [f32; 3], b: [f32; 3]) {
+        assert!(
+            a.iter().zip(b.iter()).all(|(x, y)| (x - y).abs() < 1e-5),
+            "{:?} != {:?}",
+            a,
+            b
+        );
+    }
+
+    #[test]
+    fn perspective_frustum() {
+        let proj = perspective(FRAC_PI_2, 16. / 9., 0.1, 100.);
+        // the corners of the near and far plane are mapped to the corners of the cube
+        let half_height = 0.1 * (FRAC_PI_2 / 2.).tan();
+        assert_close3(
+            transform_point(&proj, &[half_height * 16. / 9., half_height, -0.1]),
+            [1., 1., -1.],
+        );
+        assert_close3(transform_point(&proj, &[0., 0., -100.]), [0., 0., 1.]);
+    }
+
+    #[test]
+    fn orthographic_box() {
+        let proj = orthographic(-2., 2., -1., 1., 0., 10.);
+        assert_close3(transform_point(&proj, &[-2., -1., 0.]), [-1., -1., -1.]);
+        assert_close3(transform_point(&proj, &[2., 1., -10.]), [1., 1., 1.]);
+        assert_close3(transform_direction(&proj, &[1., 1., 0.]), [0.5, 1., 0.]);
+    }
+
+    #[test]
+    fn look_at_camera() {
+        let eye = [3., 2., 1.];
+        let target = [3., 2., -4.];
+        let view = look_at(eye, target, [0., 1., 0.]);
+        assert_close3(transform_point(&view, &eye), [0., 0., 0.]);
+        assert_close3(transform_point(&view, &target), [0., 0., -5.]);
+
+        // looking along x, the up vector is kept and x becomes -z
+        let view = look_at([0., 0., 0.], [1., 0., 0.], [0., 1., 0.]);
+        assert_close3(transform_direction(&view, &[1., 0., 0.]), [0., 0., -1.]);
+        assert_close3(transform_direction(&view, &[0., 1., 0.]), [0., 1., 0.]);
+    }
+
+    #[test]
+    #[should_panic(expected = "up is parallel to the view direction")]
+    fn look_at_parallel_up() {
+        look_at([0., 0., 0.], [0., 2., 0.], [0., 1., 0.]);
+    }
+
+    #[test]
+    #[should_panic(expected = "near has to be smaller then far, got 2 and 1")]
+    fn perspective_wrong_depth() {
+        perspective(1., 1., 2., 1.);
+    }
+
+    fn assert_close2(a: [f32; 2], b: [f32; 2]) {
+        assert!(
+            (a[0] - b[0]).abs() < 1e-5 && (a[1] - b[1]).abs() < 1e-5,
+            "{:?} != {:?}",
+            a,
+            b
+        );
+    }
+
+    #[test]
+    fn transform2() {
+        let t = Transform2::translation([1., 2.]);
+        assert_eq!(t.transform_point(&[1., 1.]), [2., 3.]);
+        assert_eq!(t.transform_vector(&[1., 1.]), [1., 1.]);
+        assert_eq!(
+            Transform2::scaling([2., 3.]).transform_point(&[1., 1.]),
+            [2., 3.]
+        );
+        assert_eq!(
+            Transform2::shearing(2., 0.).transform_point(&[1., 1.]),
+            [3., 1.]
+        );
+        assert_close2(
+            Transform2::rotation(FRAC_PI_2).transform_point(&[1., 0.]),
+            [0., 1.],
+        );
+        assert_eq!(Transform2::default(), Transform2::identity());
+    }
+
+    #[test]
+    fn transform2_composition() {
+        let transform = Transform2::rotation(FRAC_PI_2)
+            .translate([1., 0.])
+            .scale([2., 1.])
+            .shear(0., 1.);
+        let manual = Transform2::shearing(0., 1.)
+            * Transform2::scaling([2., 1.])
+            * Transform2::translation([1., 0.])
+            * Transform2::rotation(FRAC_PI_2);
+        assert_eq!(transform, manual);
+        // (1, 0) -> (0, 1) -> (1, 1) -> (2, 1) -> (2, 3)
+        assert_close2(transform.transform_point(&[1., 0.]), [2., 3.]);
+        assert_close2(transform.inverse().transform_point(&[2., 3.]), [1., 0.]);
+
+        let mut acc = Transform2::identity();
+        acc *= Transform2::translation([1., 1.]);
+        assert_eq!(acc.matrix().index(0, 2), 1.);
+    }
+
+    #[test]
+    fn axis_angle() {
+        for axis in 0..3 {
+            let mut unit = vec![0., 0., 0.];
+            unit[axis] = 1.;
+            let vector = Vector::new(unit);
+            let rot = Matrix::rotation_axis_angle(&vector, 0.7);
+            assert_close(&Matrix3::from_matrix(&rot), &axis_matrix(axis, 0.7));
+        }
+
+        let axis = [1., -2., 0.5];
+        let len = (1.0f32 + 4. + 0.25).sqrt();
+        let rot = Matrix::rotation_axis_angle(&Vector::new(axis.to_vec()), 2.5);
+        assert_close(
+            &Matrix3::from_matrix(&rot),
+            &Quaternion::from_axis_angle(axis, 2.5).to_matrix(),
+        );
+        let (found, angle) = rot.to_axis_angle();
+        assert!((angle - 2.5).abs() < 1e-5);
+        for (i, a) in axis.iter().enumerate() {
+            assert!((found.index(i) - a / len).abs() < 1e-5);
+        }
+
+        // a negative angle is the positive angle around the flipped axis
+        let rot = Matrix::rotation_axis_angle(&Vector::new(vec![0., 0., 1.]), -1.);
+        let (found, angle) = rot.to_axis_angle();
+        assert!((angle - 1.).abs() < 1e-5 && (found.index(2) + 1.).abs() < 1e-5);
+    }
+
+    #[test]
+    fn axis_angle_edge_cases() {
+        let (axis, angle) = Matrix3::identity().to_matrix().to_axis_angle();
+        assert_eq!((axis.vec(), angle), (vec![1., 0., 0.], 0.));
+
+        let rot = Matrix::rotation_axis_angle(&Vector::new(vec![0., 1., 1.]), PI);
+        let (axis, angle) = rot.to_axis_angle();
+        assert!((angle - PI).abs() < 1e-5);
+        let expected = std::f32::consts::FRAC_1_SQRT_2;
+        assert!(axis.index(0).abs() < 1e-5);
+        assert!((axis.index(1).abs() - expected).abs() < 1e-5);
+        assert_eq!(axis.index(1).signum(), axis.index(2).signum());
+    }
+
+    #[test]
+    #[should_panic(expected = "the rotation axis has a length of 0")]
+    fn axis_angle_zero_axis() {
+        Matrix::rotation_axis_angle(&Vector::new(vec![0., 0., 0.]), 1.);
+    }
+
+    #[test]
+    fn transform_stack() {
+        let mut stack = TransformStack::new();
+        assert_eq!(stack.depth(), 0);
+        stack.scale([2., 2., 2.]);
+
+        stack.push();
+        stack.translate([1., 0., 0.]);
+        assert_eq!(stack.depth(), 1);
+        // the translation is scaled by the parent
+        assert_eq!(stack.transform_point(&[0., 0., 0.]), [2., 0., 0.]);
+
+        stack.push();
+        stack.load_identity();
+        assert_eq!(stack.top(), &Matrix4::identity());
+        stack.pop();
+
+        let child = stack.pop();
+        assert_eq!(
+            child,
+            scaling([2., 2., 2.]).dot_mat(&translation([1., 0., 0.]))
+        );
+        assert_eq!(stack.top(), &scaling([2., 2., 2.]));
+        assert_eq!(stack, {
+            let mut other = TransformStack::default();
+            other.apply(&scaling([2., 2., 2.]));
+            other
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "pop without a matching push")]
+    fn transform_stack_pop() {
+        TransformStack::new().pop();
+    }
+
+    #[test]
+    fn ray_triangle() {
+        let (a, b, c) = ([1., 0., 0.], [0., 2., 0.], [0., 0., 3.]);
+        let ray = Ray::new([0., 0., 0.], [1., 1., 1.]);
+        let hit = ray.intersect_triangle(&a, &b, &c).unwrap();
+        let p = ray.at(hit.t);
+        let w = 1. - hit.u - hit.v;
+        for k in 0..3 {
+            let bary = w * a[k] + hit.u * b[k] + hit.v * c[k];
+            assert!((p[k] - bary).abs() < 1e-6);
+        }
+        assert!((hit.t - 6. / 11.).abs() < 1e-6);
+
+        // the back side is hit too
+        let back = Ray::new([1., 1., 1.], [-1., -1., -1.]);
+        assert!((back.intersect_triangle(&a, &b, &c).unwrap().t - 5. / 11.).abs() < 1e-6);
+    }
+
+    #[test]
+    fn ray_triangle_miss() {
+        let (a, b, c) = ([0., 0., 0.], [1., 0., 0.], [0., 1., 0.]);
+        // outside of the triangle
+        assert_eq!(
+            Ray::new([0.8, 0.8, 1.], [0., 0., -1.]).intersect_triangle(&a, &b, &c),
+            None
+        );
+        assert_eq!(
+            Ray::new([-0.1, 0.5, 1.], [0., 0., -1.]).intersect_triangle(&a, &b, &c),
+            None
+        );
+        // behind the origin
+        assert_eq!(
+            Ray::new([0.2, 0.2, 1.], [0., 0., 1.]).intersect_triangle(&a, &b, &c),
+            None
+        );
+        // parallel
+        assert_eq!(
+            Ray::new([0.2, 0.2, 1.], [1., 0., 0.]).intersect_triangle(&a, &b, &c),
+            None
+        );
+        // on the edge
+        assert!(Ray::new([0.5, 0., 1.], [0., 0., -1.])
+            .intersect_triangle(&a, &b, &c)
+            .is_some());
+    }
+}
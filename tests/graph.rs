@@ -0,0 +1,120 @@
+#[cfg(test)]
+mod tests {
+    use math::graph::*;
+    use math::linear_algebra::Matrix;
+
+    const INF: f32 = f32::INFINITY;
+
+    fn weights() -> Matrix {
+        Matrix::new(vec![
+            vec![0., 3., INF, 7., INF],
+            vec![8., 0., 2., INF, INF],
+            vec![5., INF, 0., 1., INF],
+            vec![2., INF, INF, 0., INF],
+            vec![INF, 1., INF, INF, 0.],
+        ])
+    }
+
+    #[test]
+    fn floyd_warshall_distances() {
+        let dist = floyd_warshall(&weights());
+        let expected = Matrix::new(vec![
+            vec![0., 3., 5., 6., INF],
+            vec![5., 0., 2., 3., INF],
+            vec![3., 6., 0., 1., INF],
+            vec![2., 5., 7., 0., INF],
+            vec![6., 1., 3., 4., 0.],
+        ]);
+        assert_eq!(dist, expected);
+    }
+
+    #[test]
+    fn repeated_min_plus() {
+        // squaring the weights n times gives the paths with up to 2^n edges
+        let mut dist = weights();
+        for _ in 0..3 {
+            dist = min_plus(&dist, &dist);
+        }
+        assert_eq!(dist, floyd_warshall(&weights()));
+    }
+
+    #[test]
+    fn negative_weights() {
+        let weights = Matrix::new(vec![
+            vec![0., 4., 2.],
+            vec![INF, 0., INF],
+            vec![INF, -3., 0.],
+        ]);
+        let dist = floyd_warshall(&weights);
+        assert_eq!(dist.index(0, 1), -1.);
+        assert_eq!(dist.index(1, 0), INF);
+    }
+
+    #[test]
+    fn min_plus_shape() {
+        let a = Matrix::new(vec![vec![1., 2., 3.]]);
+        let b = Matrix::new(vec![vec![1., 0.], vec![0., 5.], vec![-1., INF]]);
+        assert_eq!(min_plus(&a, &b), Matrix::new(vec![vec![2., 1.]]));
+    }
+
+    #[test]
+    #[should_panic(expected = "negative cycle")]
+    fn negative_cycle() {
+        floyd_warshall(&Matrix::new(vec![vec![0., 1.], vec![-2., 0.]]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn min_plus_wrong_shape() {
+        min_plus(&Matrix::new_zero(2, 3), &Matrix::new_zero(2, 3));
+    }
+
+    #[test]
+    fn spectral_clustering_cliques() {
+        // three cliques of 4 nodes, connected in a ring by weak edges
+        let n = 12;
+        let mut adjacency = Matrix::new_zero(n, n);
+        for i in 0..n {
+            for j in 0..n {
+                if i != j && i / 4 == j / 4 {
+                    adjacency.set_index(i, j, 1.);
+                }
+            }
+        }
+        for &(i, j) in [(3, 4), (7, 8), (11, 0)].iter() {
+            adjacency.set_index(i, j, 0.1);
+            adjacency.set_index(j, i, 0.1);
+        }
+        let labels = spectral_clustering(&adjacency, 3);
+        for clique in labels.chunks(4) {
+            assert!(clique.iter().all(|&l| l == clique[0]));
+        }
+        assert_ne!(labels[0], labels[4]);
+        assert_ne!(labels[0], labels[8]);
+        assert_ne!(labels[4], labels[8]);
+    }
+
+    #[test]
+    fn spectral_clustering_components() {
+        // an isolated node and two disconnected edges
+        let adjacency = Matrix::new(vec![
+            vec![0., 1., 0., 0., 0.],
+            vec![1., 0., 0., 0., 0.],
+            vec![0., 0., 0., 2., 0.],
+            vec![0., 0., 2., 0., 0.],
+            vec![0., 0., 0., 0., 0.],
+        ]);
+        let labels = spectral_clustering(&adjacency, 3);
+        assert_eq!(labels[0], labels[1]);
+        assert_eq!(labels[2], labels[3]);
+        assert_ne!(labels[0], labels[2]);
+        assert_ne!(labels[0], labels[4]);
+        assert_ne!(labels[2], labels[4]);
+    }
+
+    #[test]
+    #[should_panic(expected = "k has to be between 1 and 2")]
+    fn spectral_clustering_large_k() {
+        spectral_clustering(&Matrix::new_zero(2, 2), 3);
+    }
+}
@@ -0,0 +1,102 @@
+#[cfg(test)]
+mod tests {
+    use math::grid::*;
+    use math::linear_algebra::Matrix;
+
+    fn field<F: Fn(f32, f32) -> f32>(n: usize, m: usize, dx: f32, func: F) -> Matrix {
+        let mut field = Matrix::new_zero(n, m);
+        for i in 0..n {
+            for j in 0..m {
+                field.set_index(i, j, func(i as f32 * dx, j as f32 * dx));
+            }
+        }
+        field
+    }
+
+    #[test]
+    fn quadratic() {
+        let dx = 0.5;
+        let u = field(5, 6, dx, |x, y| x * x + 3. * y * y + x * y);
+        let lap = laplacian(&u, dx, Boundary::Zero);
+        let (di, dj) = gradient(&u, dx, Boundary::Zero);
+        for i in 1..4 {
+            for j in 1..5 {
+                let (x, y) = (i as f32 * dx, j as f32 * dx);
+                assert!((lap.index(i, j) - 8.).abs() < 1e-4);
+                assert!((di.index(i, j) - (2. * x + y)).abs() < 1e-4);
+                assert!((dj.index(i, j) - (6. * y + x)).abs() < 1e-4);
+            }
+        }
+    }
+
+    #[test]
+    fn boundaries() {
+        let u = Matrix::new(vec![vec![1., 2., 3.], vec![4., 5., 6.]]);
+        assert_eq!(
+            laplacian(&u, 1., Boundary::Zero),
+            Matrix::new(vec![vec![2., 1., -4.], vec![-10., -8., -16.]])
+        );
+        assert_eq!(
+            laplacian(&u, 1., Boundary::Clamp),
+            Matrix::new(vec![vec![4., 3., 2.], vec![-2., -3., -4.]])
+        );
+        assert_eq!(
+            laplacian(&u, 1., Boundary::Periodic),
+            Matrix::new(vec![vec![9., 6., 3.], vec![-3., -6., -9.]])
+        );
+
+        let (di, dj) = gradient(&u, 1., Boundary::Clamp);
+        assert_eq!(di, Matrix::new(vec![vec![1.5; 3], vec![1.5; 3]]));
+        assert_eq!(
+            dj,
+            Matrix::new(vec![vec![0.5, 1., 0.5], vec![0.5, 1., 0.5]])
+        );
+
+        // constant fields have no divergence unless the outside is 0
+        let ones = Matrix::new(vec![vec![1.; 3]; 3]);
+        assert_eq!(
+            divergence(&ones, &ones, 1., Boundary::Periodic),
+            Matrix::new(vec![vec![0.; 3]; 3])
+        );
+        assert_eq!(
+            divergence(&ones, &ones, 1., Boundary::Clamp),
+            Matrix::new(vec![vec![0.; 3]; 3])
+        );
+        assert_eq!(divergence(&ones, &ones, 1., Boundary::Zero).index(0, 0), 1.);
+    }
+
+    #[test]
+    fn periodic_sum() {
+        // with periodic boundaries the discrete derivatives sum to 0
+        let u = field(6, 7, 1., |x, y| (x * 1.3).sin() + (y * 0.4).cos() * x);
+        let (di, dj) = gradient(&u, 1., Boundary::Periodic);
+        let lap = laplacian(&u, 1., Boundary::Periodic);
+        for m in [di, dj, lap].iter() {
+            assert!(m.matrix_flatt().iter().sum::<f32>().abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn transposed() {
+        let u = field(4, 3, 1., |x, y| x * x * y);
+        let mut t = u.clone();
+        t.transpose();
+        let mut lap = laplacian(&t, 1., Boundary::Clamp);
+        lap.transpose();
+        assert_eq!(
+            lap.matrix_flatt(),
+            laplacian(&u, 1., Boundary::Clamp).matrix_flatt()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "wrong matrix shape expected 2x2, got 2x3")]
+    fn divergence_shape() {
+        divergence(
+            &Matrix::new_zero(2, 2),
+            &Matrix::new_zero(2, 3),
+            1.,
+            Boundary::Zero,
+        );
+    }
+}
@@ -0,0 +1,66 @@
+#[cfg(test)]
+mod tests {
+    use math::linear_algebra::half::*;
+    use math::linear_algebra::{Matrix, Vector};
+
+    #[test]
+    fn special_values() {
+        assert_eq!(F16::from_f32(0.).to_bits(), 0x0000);
+        assert_eq!(F16::from_f32(-0.).to_bits(), 0x8000);
+        assert_eq!(F16::from_f32(65504.).to_bits(), 0x7bff);
+        assert_eq!(F16::from_f32(65520.).to_bits(), 0x7c00);
+        assert_eq!(F16::from_f32(f32::INFINITY).to_bits(), 0x7c00);
+        assert_eq!(F16::from_f32(f32::NEG_INFINITY).to_bits(), 0xfc00);
+        assert!(F16::from_f32(f32::NAN).to_f32().is_nan());
+    }
+
+    #[test]
+    fn subnormal() {
+        let smallest = 2_f32.powi(-24);
+        assert_eq!(F16::from_f32(smallest).to_bits(), 0x0001);
+        assert_eq!(F16::from_bits(0x0001).to_f32(), smallest);
+        assert_eq!(F16::from_bits(0x03ff).to_f32(), 1023. * smallest);
+        // half of the smallest subnormal rounds to even (0)
+        assert_eq!(F16::from_f32(smallest / 2.).to_bits(), 0x0000);
+        assert_eq!(F16::from_f32(smallest * 0.75).to_bits(), 0x0001);
+    }
+
+    #[test]
+    fn round_trip() {
+        // every f16 survives the round trip through f32
+        for bits in 0..=u16::MAX {
+            let half = F16::from_bits(bits);
+            if half.to_f32().is_nan() {
+                continue;
+            }
+            assert_eq!(F16::from_f32(half.to_f32()), half);
+        }
+    }
+
+    #[test]
+    fn round_to_even() {
+        // 1 + 2^-11 is exactly between 1 and the next f16
+        assert_eq!(F16::from_f32(1. + 2_f32.powi(-11)).to_bits(), 0x3c00);
+        assert_eq!(F16::from_f32(1. + 3. * 2_f32.powi(-11)).to_bits(), 0x3c02);
+    }
+
+    #[test]
+    fn half_matrix_transpose() {
+        let mut matrix = Matrix::new(vec![vec![1., 2., 3.], vec![4., 5., 6.]]);
+        matrix.transpose();
+        let half = HalfMatrix::from(&matrix);
+        assert_eq!((half.cols(), half.rows()), (3, 2));
+        assert_eq!(half.index(2, 1), 6.);
+        assert_eq!(Matrix::from(&half).matrix_flatt(), matrix.matrix_flatt());
+        assert_eq!(half.bytes().len(), 12);
+    }
+
+    #[test]
+    fn half_vector() {
+        let vector = Vector::new(vec![0.5, -2., 100.]);
+        let half = HalfVector::from(&vector);
+        assert_eq!(half.len(), 3);
+        assert_eq!(half.index(2), 100.);
+        assert_eq!(Vector::from(&half), vector);
+    }
+}
@@ -0,0 +1,68 @@
+#[cfg(test)]
+mod tests {
+    use math::hmm::HMM;
+    use math::linear_algebra::{Matrix, Vector};
+
+    fn weather() -> HMM {
+        HMM::new(
+            Vector::new(vec![0.6, 0.4]),
+            Matrix::new(vec![vec![0.7, 0.3], vec![0.4, 0.6]]),
+            Matrix::new(vec![vec![0.5, 0.4, 0.1], vec![0.1, 0.3, 0.6]]),
+        )
+    }
+
+    #[test]
+    fn forward_backward() {
+        let hmm = weather();
+        let observations = [0, 1, 2, 1];
+        let alpha = hmm.forward(&observations);
+        let beta = hmm.backward(&observations);
+        let likelihood = hmm.log_likelihood(&observations).exp();
+
+        // sum_i alpha(t, i) * beta(t, i) is the likelihood for every t
+        for t in 0..observations.len() {
+            let sum: f32 = (0..2).map(|i| alpha.index(t, i) * beta.index(t, i)).sum();
+            assert!((sum - likelihood).abs() < 1e-6);
+        }
+
+        // initial probability times the probability to observe 0
+        assert!((alpha.index(0, 0) - 0.3).abs() < 1e-6);
+        assert!((alpha.index(0, 1) - 0.04).abs() < 1e-6);
+    }
+
+    #[test]
+    fn viterbi() {
+        let hmm = weather();
+        let (states, log_prob) = hmm.viterbi(&[0, 1, 2]);
+        assert_eq!(states, vec![0, 0, 1]);
+        // 0.6 * 0.5 * 0.7 * 0.4 * 0.3 * 0.6
+        assert!((log_prob - (0.01512_f32).ln()).abs() < 1e-5);
+    }
+
+    #[test]
+    fn baum_welch() {
+        let mut hmm = HMM::new(
+            Vector::new(vec![0.5, 0.5]),
+            Matrix::new(vec![vec![0.5, 0.5], vec![0.3, 0.7]]),
+            Matrix::new(vec![vec![0.3, 0.7], vec![0.8, 0.2]]),
+        );
+        let observations = [0, 0, 1, 1, 0, 0, 1, 1, 1, 0, 0, 1];
+        let before = hmm.log_likelihood(&observations);
+        let after = hmm.baum_welch(&observations, 50);
+        assert!(after > before);
+
+        // the rows stay probability distributions
+        for i in 0..2 {
+            let transition: f32 = hmm.transition().col(i).vec().iter().sum();
+            let emission: f32 = hmm.emission().col(i).vec().iter().sum();
+            assert!((transition - 1.).abs() < 1e-5);
+            assert!((emission - 1.).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "observation out of bounds max observation 2, got 3")]
+    fn observation_panic() {
+        weather().viterbi(&[0, 3]);
+    }
+}
@@ -0,0 +1,183 @@
+#[cfg(test)]
+mod tests {
+    use math::linear_algebra::image::{Colormap, Padding};
+    use math::linear_algebra::Matrix;
+    use std::io;
+
+    #[test]
+    fn pgm_round_trip() {
+        let mut matrix = Matrix::new(vec![vec![0., 1., 0.2], vec![0.4, 0.6, 0.8]]);
+        matrix.transpose();
+        let bytes = matrix.to_pgm();
+        assert!(bytes.starts_with(b"P5\n2 3\n255\n"));
+
+        let decoded = Matrix::from_pgm(&bytes).unwrap();
+        assert_eq!((decoded.cols(), decoded.rows()), (3, 2));
+        for i in 0..3 {
+            for j in 0..2 {
+                assert!((decoded.index(i, j) - matrix.index(i, j)).abs() < 1. / 255.);
+            }
+        }
+    }
+
+    #[test]
+    fn pgm_16_bit() {
+        let mut bytes = b"P5 2 1 65535\n".to_vec();
+        bytes.extend_from_slice(&[0xff, 0xff, 0x00, 0x00]);
+        assert_eq!(
+            Matrix::from_pgm(&bytes).unwrap(),
+            Matrix::new(vec![vec![1., 0.]])
+        );
+    }
+
+    #[test]
+    fn pgm_invalid() {
+        assert!(Matrix::from_pgm(b"P6\n1 1\n255\n\x00").is_err());
+        assert!(Matrix::from_pgm(b"P2\n2 1\n255\n1").is_err());
+        assert!(Matrix::from_pgm(b"P2\n1 1\n10\n11").is_err());
+        assert!(Matrix::from_pgm(b"P5\n2 2\n255\n\x00\x00").is_err());
+        assert!(Matrix::from_pgm(b"P2\n1 1\n0\n0").is_err());
+        // the size overflows usize
+        assert!(Matrix::from_pgm(b"P5\n100000000000 100000000000\n255\n").is_err());
+        assert!(Matrix::from_pgm(b"P2\n100000000000 100000000000\n255\n1").is_err());
+        // the size fits but the bytes per pixel overflow
+        let mut bytes = format!("P5\n{} 1\n65535\n", usize::MAX).into_bytes();
+        bytes.extend_from_slice(&[0, 0]);
+        assert!(Matrix::from_pgm(&bytes).is_err());
+    }
+
+    #[test]
+    fn image_file() {
+        let dir = std::env::temp_dir();
+        let path = dir.join(format!("math_image_{}.pgm", std::process::id()));
+        let matrix = Matrix::new(vec![vec![0., 1.], vec![1., 0.]]);
+        matrix.to_image(&path).unwrap();
+        assert_eq!(Matrix::from_image(&path).unwrap(), matrix);
+        std::fs::remove_file(&path).unwrap();
+
+        let err = matrix.to_image(dir.join("math_image.jpg")).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::Unsupported);
+    }
+
+    #[test]
+    fn colormap() {
+        for colormap in [
+            Colormap::Gray,
+            Colormap::Hot,
+            Colormap::Jet,
+            Colormap::Viridis,
+        ] {
+            assert_eq!(colormap.color(-1.), colormap.color(0.));
+            assert_eq!(colormap.color(2.), colormap.color(1.));
+            assert_eq!(colormap.color(f32::NAN), colormap.color(0.));
+        }
+        assert_eq!(Colormap::Hot.color(0.), [0, 0, 0]);
+        assert_eq!(Colormap::Jet.color(0.5), [128, 255, 128]);
+        assert_eq!(Colormap::Jet.color(1.), [128, 0, 0]);
+        assert_eq!(Colormap::Viridis.color(1.), [253, 231, 37]);
+        assert_eq!(Colormap::Viridis.color(0.0625), [70, 23, 103]);
+    }
+
+    #[test]
+    fn heatmap() {
+        let matrix = Matrix::new(vec![vec![10., 20.], vec![f32::NAN, 30.]]);
+        let bytes = matrix.heatmap(Colormap::Gray);
+        assert_eq!(bytes, b"P5\n2 2\n255\n\x00\x80\x00\xff");
+
+        let bytes = matrix.heatmap(Colormap::Viridis);
+        assert!(bytes.starts_with(b"P6\n2 2\n255\n"));
+        assert_eq!(bytes.len(), 11 + 4 * 3);
+        assert_eq!(&bytes[11..14], &Colormap::Viridis.color(0.));
+
+        let constant = Matrix::new(vec![vec![3., 3.]]);
+        assert_eq!(constant.heatmap(Colormap::Gray), b"P5\n2 1\n255\n\x00\x00");
+    }
+
+    #[test]
+    fn heatmap_file() {
+        let path = std::env::temp_dir().join(format!("math_heatmap_{}.ppm", std::process::id()));
+        let matrix = Matrix::new(vec![vec![0., 1.], vec![2., 3.]]);
+        matrix.to_heatmap(&path, Colormap::Jet).unwrap();
+        assert_eq!(std::fs::read(&path).unwrap(), matrix.heatmap(Colormap::Jet));
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn convolve2d_valid() {
+        let image = Matrix::new(vec![
+            vec![1., 2., 3., 4.],
+            vec![5., 6., 7., 8.],
+            vec![9., 10., 11., 12.],
+        ]);
+        let kernel = Matrix::new(vec![vec![1., 2.], vec![3., 4.]]);
+        // the kernel is not flipped: 1 * 1 + 2 * 2 + 3 * 5 + 4 * 6
+        assert_eq!(
+            image.convolve2d(&kernel, Padding::Valid, 1),
+            Matrix::new(vec![vec![44., 54., 64.], vec![84., 94., 104.]])
+        );
+        assert_eq!(
+            image.convolve2d(&kernel, Padding::Valid, 2),
+            Matrix::new(vec![vec![44., 64.]])
+        );
+        // a transposed matrix is convolved like its logical values
+        let mut transposed = image.clone();
+        transposed.transpose();
+        let mut expected = image.convolve2d(&kernel.transposed(), Padding::Valid, 1);
+        expected.transpose();
+        assert_eq!(transposed.convolve2d(&kernel, Padding::Valid, 1), expected);
+    }
+
+    #[test]
+    fn convolve2d_zero() {
+        let image = Matrix::new(vec![vec![1., 2.], vec![3., 4.]]);
+        let ones = Matrix::new_flatt(vec![1.; 9], 3, 3);
+        // every position sums its neighbours inside of the matrix
+        assert_eq!(
+            image.convolve2d(&ones, Padding::Zero, 1),
+            Matrix::new(vec![vec![10., 10.], vec![10., 10.]])
+        );
+        // an even kernel pads 0 values before and 1 after
+        let kernel = Matrix::new(vec![vec![1., 1.], vec![1., 1.]]);
+        assert_eq!(
+            image.convolve2d(&kernel, Padding::Zero, 1),
+            Matrix::new(vec![vec![10., 6.], vec![7., 4.]])
+        );
+        let identity = Matrix::new(vec![vec![0., 0., 0.], vec![0., 1., 0.], vec![0., 0., 0.]]);
+        assert_eq!(image.convolve2d(&identity, Padding::Zero, 1), image);
+    }
+
+    #[test]
+    fn convolve2d_reflect() {
+        // the row 1 2 3 is padded to 2 1 2 3 2
+        let image = Matrix::new(vec![vec![1., 2., 3.]]);
+        let kernel = Matrix::new(vec![vec![1., 1., 1.]]);
+        assert_eq!(
+            image.convolve2d(&kernel, Padding::Reflect, 1),
+            Matrix::new(vec![vec![5., 6., 7.]])
+        );
+        assert_eq!(
+            image.convolve2d(&kernel, Padding::Reflect, 2),
+            Matrix::new(vec![vec![5., 7.]])
+        );
+        // a constant image stays constant under a normalized blur
+        let constant = Matrix::new_flatt(vec![2.; 12], 3, 4);
+        let blur = Matrix::new_flatt(vec![1. / 25.; 25], 5, 5);
+        let blurred = constant.convolve2d(&blur, Padding::Reflect, 1);
+        assert_eq!((blurred.cols(), blurred.rows()), (3, 4));
+        assert!(blurred.iter_elems().all(|val| (val - 2.).abs() < 1e-5));
+    }
+
+    #[test]
+    #[should_panic(expected = "the kernel 3x3 is bigger then the matrix 2x2")]
+    fn convolve2d_big_kernel() {
+        let image = Matrix::new(vec![vec![1., 2.], vec![3., 4.]]);
+        image.convolve2d(&Matrix::new_flatt(vec![1.; 9], 3, 3), Padding::Valid, 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "the stride has to be at least 1")]
+    fn convolve2d_stride() {
+        let image = Matrix::new(vec![vec![1., 2.], vec![3., 4.]]);
+        image.convolve2d(&image, Padding::Zero, 0);
+    }
+}
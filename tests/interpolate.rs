@@ -0,0 +1,97 @@
+#[cfg(test)]
+mod tests {
+    use math::interpolate::*;
+    use math::linear_algebra::Vector;
+    use math::polynomial::Polynomial;
+
+    fn samples() -> (Vector, Vector) {
+        // y = 2x^3 - x + 0.5 at unevenly spaced points
+        let p = Polynomial::new(vec![0.5, -1., 0., 2.]);
+        let x = Vector::new(vec![-1., 0.5, 2., 3., -2.5]);
+        let y = x.vec().into_iter().map(|x| p.eval(x)).collect();
+        (x, y)
+    }
+
+    #[test]
+    fn newton_reproduces_polynomial() {
+        let (x, y) = samples();
+        let newton = Newton::new(&x, &y);
+        assert_eq!(newton.len(), 5);
+        for &t in [-3., -0.2, 1., 4.].iter() {
+            let expected = 2. * t * t * t - t + 0.5;
+            assert!((newton.eval(t) - expected).abs() < 1e-3);
+        }
+        let poly = newton.to_polynomial();
+        let expected = [0.5, -1., 0., 2.];
+        for (k, &c) in expected.iter().enumerate() {
+            assert!((poly.coeffs().get(k).copied().unwrap_or(0.) - c).abs() < 1e-4);
+        }
+        assert!(poly.degree() <= 4);
+    }
+
+    #[test]
+    fn newton_add_point() {
+        let (x, y) = samples();
+        let mut newton = Newton::new(
+            &Vector::new(vec![x.index(0)]),
+            &Vector::new(vec![y.index(0)]),
+        );
+        for i in 1..x.len() {
+            newton.add_point(x.index(i), y.index(i));
+        }
+        assert_eq!(newton, Newton::new(&x, &y));
+        assert_eq!(newton.eval_vec(&x), y);
+    }
+
+    #[test]
+    fn divided_differences_table() {
+        let x = Vector::new(vec![0., 1., 2., 3.]);
+        let y = Vector::new(vec![1., 2., 5., 10.]);
+        // y = x^2 + 1, the third difference is 0
+        assert_eq!(
+            divided_differences(&x, &y),
+            Vector::new(vec![1., 1., 1., 0.])
+        );
+    }
+
+    #[test]
+    fn lagrange_matches_newton() {
+        let (x, y) = samples();
+        let newton = Newton::new(&x, &y);
+        for &t in [-3., -0.2, 1., 4.].iter() {
+            assert!((lagrange(&x, &y, t) - newton.eval(t)).abs() < 1e-3);
+        }
+        // at the samples the values are exact
+        assert_eq!(lagrange(&x, &y, x.index(2)), y.index(2));
+
+        let poly = lagrange_polynomial(&x, &y);
+        let expected = newton.to_polynomial();
+        for (a, b) in poly.coeffs().iter().zip(expected.coeffs().iter()) {
+            assert!((a - b).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn single_sample() {
+        let x = Vector::new(vec![2.]);
+        let y = Vector::new(vec![7.]);
+        assert_eq!(Newton::new(&x, &y).eval(10.), 7.);
+        assert_eq!(lagrange(&x, &y, 10.), 7.);
+        assert_eq!(lagrange_polynomial(&x, &y), Polynomial::constant(7.));
+    }
+
+    #[test]
+    #[should_panic(expected = "the x value 1 is repeated")]
+    fn repeated_x() {
+        Newton::new(
+            &Vector::new(vec![1., 2., 1.]),
+            &Vector::new(vec![0., 0., 0.]),
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "x and y need the same len, got 2 and 1")]
+    fn wrong_len() {
+        lagrange(&Vector::new(vec![1., 2.]), &Vector::new(vec![0.]), 0.);
+    }
+}
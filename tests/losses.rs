@@ -0,0 +1,73 @@
+#[cfg(test)]
+mod tests {
+    use math::linear_algebra::{Matrix, Vector};
+    use math::losses::*;
+
+    // compares the gradient with the central difference of the loss
+    fn check_grad<F: Fn(&Vector) -> (f32, Vector)>(loss: F, pred: Vector) {
+        let (_, grad) = loss(&pred);
+        let eps = 1e-3;
+        for i in 0..pred.len() {
+            let mut plus = pred.vec();
+            plus[i] += eps;
+            let mut minus = pred.vec();
+            minus[i] -= eps;
+            let numerical = (loss(&Vector::new(plus)).0 - loss(&Vector::new(minus)).0) / (2. * eps);
+            assert!(
+                (numerical - grad.index(i)).abs() < 1e-2,
+                "index {} numerical {} analytical {}",
+                i,
+                numerical,
+                grad.index(i)
+            );
+        }
+    }
+
+    #[test]
+    fn gradients() {
+        let target = Vector::new(vec![0.5, -1., 2., 0.]);
+        let pred = Vector::new(vec![0.1, 1., 1.5, -0.3]);
+        check_grad(|p| mse(p, &target), pred.clone());
+        check_grad(|p| mae(p, &target), pred.clone());
+        check_grad(|p| huber(p, &target, 0.6), pred);
+
+        let target = Vector::new(vec![1., 0., 1.]);
+        let pred = Vector::new(vec![0.3, 0.4, 0.9]);
+        check_grad(|p| binary_cross_entropy(p, &target), pred);
+    }
+
+    #[test]
+    fn huber_limits() {
+        let pred = Vector::new(vec![0.5, 1.]);
+        let target = Vector::new(vec![0., 0.]);
+        // for a large delta huber is half the mse
+        assert_eq!(huber(&pred, &target, 10.).0, mse(&pred, &target).0 / 2.);
+    }
+
+    #[test]
+    fn categorical() {
+        let pred = Matrix::new(vec![vec![0.2, 0.3, 0.5], vec![0.1, 0.1, 0.8]]);
+        let target = Matrix::new(vec![vec![0., 0., 1.], vec![0., 1., 0.]]);
+        let (loss, grad) = categorical_cross_entropy(&pred, &target);
+        assert_eq!(loss, -((0.5_f32).ln() + (0.1_f32).ln()) / 2.);
+        assert_eq!(grad.matrix_flatt(), vec![0., 0., -1., 0., -5., 0.]);
+    }
+
+    #[test]
+    fn cross_entropy_limits() {
+        // the gradient is computed from the same clamped prediction as the loss
+        let pred = Vector::new(vec![0., 1., 0., 1.]);
+        let target = Vector::new(vec![0., 0., 1., 1.]);
+        let (loss, grad) = binary_cross_entropy(&pred, &target);
+        assert!(loss.is_finite());
+        let clamped = Vector::new(vec![1e-7, 1. - 1e-7, 1e-7, 1. - 1e-7]);
+        assert_eq!(grad, binary_cross_entropy(&clamped, &target).1);
+        assert!(grad.vec().iter().all(|g| g.is_finite()));
+
+        let pred = Matrix::new(vec![vec![0., 1.]]);
+        let target = Matrix::new(vec![vec![1., 0.]]);
+        let (loss, grad) = categorical_cross_entropy(&pred, &target);
+        assert_eq!(loss, -(1e-7_f32).ln());
+        assert_eq!(grad.matrix_flatt(), vec![-1. / 1e-7, 0.]);
+    }
+}
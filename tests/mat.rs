@@ -0,0 +1,115 @@
+#[cfg(all(test, feature = "mat"))]
+mod tests {
+    use math::linear_algebra::{mat, Matrix};
+    use std::convert::TryInto;
+    use std::io;
+
+    fn header(endian: &[u8; 2]) -> Vec<u8> {
+        let mut bytes = vec![b' '; 124];
+        bytes.extend_from_slice(&[1, 0]);
+        bytes.extend_from_slice(endian);
+        bytes
+    }
+
+    // a matrix element written by hand in big endian with a small name element and int16 data
+    fn big_endian_element(class: u32, name: &[u8], data: &[i16], dims: [i32; 2]) -> Vec<u8> {
+        let mut body = Vec::new();
+        body.extend_from_slice(&6u32.to_be_bytes());
+        body.extend_from_slice(&8u32.to_be_bytes());
+        body.extend_from_slice(&class.to_be_bytes());
+        body.extend_from_slice(&0u32.to_be_bytes());
+        body.extend_from_slice(&5u32.to_be_bytes());
+        body.extend_from_slice(&8u32.to_be_bytes());
+        body.extend_from_slice(&dims[0].to_be_bytes());
+        body.extend_from_slice(&dims[1].to_be_bytes());
+        body.extend_from_slice(&((name.len() as u32) << 16 | 1).to_be_bytes());
+        body.extend_from_slice(name);
+        body.resize(body.len() + 4 - name.len(), 0);
+        body.extend_from_slice(&3u32.to_be_bytes());
+        body.extend_from_slice(&((data.len() * 2) as u32).to_be_bytes());
+        data.iter()
+            .for_each(|x| body.extend_from_slice(&x.to_be_bytes()));
+        body.resize((body.len() + 7) / 8 * 8, 0);
+
+        let mut bytes = 14u32.to_be_bytes().to_vec();
+        bytes.extend_from_slice(&(body.len() as u32).to_be_bytes());
+        bytes.extend(body);
+        bytes
+    }
+
+    #[test]
+    fn round_trip() {
+        let a = Matrix::new(vec![vec![1., 2., 3.], vec![4., 5., 6.]]);
+        let mut b = a.clone();
+        b.transpose();
+        let bytes = mat::encode(&[("a", &a), ("long_name_2", &b)]);
+        assert_eq!(&bytes[0..10], b"MATLAB 5.0");
+        assert_eq!(&bytes[124..128], &[0, 1, b'I', b'M']);
+
+        let vars = mat::decode(&bytes).unwrap();
+        assert_eq!(vars.len(), 2);
+        assert_eq!(vars[0], ("a".to_string(), a.clone()));
+        assert_eq!(vars[1].0, "long_name_2");
+        assert_eq!(vars[1].1.matrix_flatt(), b.matrix_flatt());
+        assert_eq!(vars[1].1.cols(), 3);
+
+        // the values are stored column major
+        let data: Vec<f64> = bytes[bytes.len() - 48..]
+            .chunks(8)
+            .map(|c| f64::from_le_bytes(c.try_into().unwrap()))
+            .collect();
+        assert_eq!(data, vec![1., 2., 3., 4., 5., 6.]);
+    }
+
+    #[test]
+    fn file() {
+        let path = std::env::temp_dir().join(format!("math_mat_{}.mat", std::process::id()));
+        let a = Matrix::new(vec![vec![0.5], vec![-2.]]);
+        a.save_mat(&path, "x").unwrap();
+        assert_eq!(Matrix::load_mat(&path, "x").unwrap(), a);
+        assert_eq!(
+            Matrix::load_mat(&path, "y").unwrap_err().kind(),
+            io::ErrorKind::NotFound
+        );
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn big_endian() {
+        let mut bytes = header(b"MI");
+        // a char array is skipped
+        bytes.extend(big_endian_element(4, b"s", &[104, 105], [1, 2]));
+        bytes.extend(big_endian_element(10, b"ab", &[1, -2, 3, 4, 5, -6], [2, 3]));
+
+        let vars = mat::decode(&bytes).unwrap();
+        assert_eq!(
+            vars,
+            vec![(
+                "ab".to_string(),
+                Matrix::new(vec![vec![1., 3., 5.], vec![-2., 4., -6.]])
+            )]
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        assert!(mat::decode(&[0; 10]).is_err());
+        assert!(mat::decode(&header(b"XX")).is_err());
+
+        let mut bytes = header(b"IM");
+        bytes.extend_from_slice(&15u32.to_le_bytes());
+        bytes.extend_from_slice(&0u32.to_le_bytes());
+        let err = mat::decode(&bytes).unwrap_err();
+        assert!(err.to_string().contains("compressed"));
+
+        let mut bytes = header(b"MI");
+        bytes.extend(big_endian_element(10, b"a", &[1, 2, 3], [2, 2]));
+        assert!(mat::decode(&bytes).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "is not a valid variable name")]
+    fn invalid_name() {
+        mat::encode(&[("1a", &Matrix::new(vec![vec![1.]]))]);
+    }
+}
@@ -6,19 +6,19 @@ mod tests {
     #[test]
     fn det() {
         let matrix = Matrix::new(vec![vec![1., 2.], vec![3., 4.]]);
-        assert_eq!(matrix.det(), -5.);
+        assert_eq!(matrix.det(), -2.);
 
         let matrix = Matrix::new(vec![vec![3., 8.], vec![4., 6.]]);
-        assert_eq!(matrix.det(), 2.);
+        assert_eq!(matrix.det(), -14.);
 
         let matrix = Matrix::new(vec![vec![4., 6.], vec![3., 8.]]);
-        assert_eq!(matrix.det(), 23.);
+        assert_eq!(matrix.det(), 14.);
 
         let matrix = Matrix::new(vec![vec![2., -3., 1.], vec![2., 0., -1.], vec![1., 4., 5.]]);
-        assert_eq!(matrix.det(), -122.);
+        assert_eq!(matrix.det(), 49.);
 
         let matrix = Matrix::new(vec![vec![6., 1., 1.], vec![4., -2., 5.], vec![2., 8., 7.]]);
-        assert_eq!(matrix.det(), -410.);
+        assert_eq!(matrix.det(), -306.);
 
         let matrix = Matrix::new(vec![
             vec![6., 1., 1., 4.],
@@ -26,7 +26,16 @@ mod tests {
             vec![2., 8., 7., 3.],
             vec![4., 1., 4., 2.],
         ]);
-        assert_eq!(matrix.det(), 2148.);
+        assert_eq!(matrix.det(), -1046.);
+
+        let matrix = Matrix::new(vec![
+            vec![2., 0., 0., 0., 0.],
+            vec![0., 6., 1., 1., 4.],
+            vec![0., 4., -2., 5., -7.],
+            vec![0., 2., 8., 7., 3.],
+            vec![0., 4., 1., 4., 2.],
+        ]);
+        assert_eq!(matrix.det(), -2092.);
     }
 
     #[test]
@@ -42,13 +51,16 @@ mod tests {
         matrix.add_vec(&vector);
         assert_eq!(
             matrix,
-            Matrix::new(vec![vec![4.0, -3.0, 1.0], vec![6.0, 0.0, -1.0]])
+            Matrix::new(vec![vec![4.0, 1.0, 7.0], vec![4.0, 4.0, 5.0]])
         );
 
         matrix.transpose();
         let vector = Vector::new(vec![-2., 6.]);
         matrix.add_vec(&vector);
-        assert_eq!(matrix.matrix_flatt(), vec![2.0, 4.0, -3.0, 0.0, 1.0, -1.0]);
+        assert_eq!(
+            matrix.matrix_flatt(),
+            vec![2.0, 10.0, -1.0, 10.0, 5.0, 11.0]
+        );
     }
 
     #[test]
@@ -58,13 +70,16 @@ mod tests {
         matrix.sub_vec(&vector);
         assert_eq!(
             matrix,
-            Matrix::new(vec![vec![0.0, -3.0, 1.0], vec![-2.0, 0.0, -1.0]])
+            Matrix::new(vec![vec![0.0, -7.0, -5.0], vec![0.0, -4.0, -7.0]])
         );
 
         matrix.transpose();
         let vector = Vector::new(vec![-2., 6.]);
         matrix.sub_vec(&vector);
-        assert_eq!(matrix.matrix_flatt(), vec![2.0, 0.0, -3.0, 0.0, 1.0, -1.0]);
+        assert_eq!(
+            matrix.matrix_flatt(),
+            vec![2.0, -6.0, -5.0, -10.0, -3.0, -13.0]
+        );
     }
 
     #[test]
@@ -74,7 +89,7 @@ mod tests {
         matrix.mul_vec(&vector);
         assert_eq!(
             matrix,
-            Matrix::new(vec![vec![4.0, -3.0, 1.0], vec![8.0, 0.0, -1.0]])
+            Matrix::new(vec![vec![4.0, -12.0, 6.0], vec![4.0, 0.0, -6.0]])
         );
 
         matrix.transpose();
@@ -82,7 +97,7 @@ mod tests {
         matrix.mul_vec(&vector);
         assert_eq!(
             matrix.matrix_flatt(),
-            vec![-8.0, -16.0, -3.0, 0.0, 1.0, -1.0]
+            vec![-8.0, 24.0, 24.0, 0.0, -12.0, -36.0]
         );
     }
 
@@ -93,7 +108,7 @@ mod tests {
         matrix.div_vec(&vector);
         assert_eq!(
             matrix,
-            Matrix::new(vec![vec![1.0, -3.0, 1.0], vec![0.5, 0.0, -1.0]])
+            Matrix::new(vec![vec![1.0, -0.75, 1. / 6.], vec![1.0, 0.0, -1. / 6.]])
         );
 
         matrix.transpose();
@@ -101,7 +116,7 @@ mod tests {
         matrix.div_vec(&vector);
         assert_eq!(
             matrix.matrix_flatt(),
-            vec![-0.5, -0.25, -3.0, 0.0, 1.0, -1.0]
+            vec![-0.5, 1. / 6., 0.375, 0.0, -1. / 12., -1. / 36.]
         );
     }
 
@@ -113,13 +128,13 @@ mod tests {
         matrix1.add_mat(&matrix2);
         assert_eq!(
             matrix1,
-            Matrix::new(vec![vec![4.0, -3.0, 1.0], vec![9.0, 0.0, -1.0]])
+            Matrix::new(vec![vec![4.0, 0.0, 6.0], vec![9.0, 1.0, 3.0]])
         );
 
         matrix1.transpose();
         let matrix2 = Matrix::new(vec![vec![2., -4.], vec![7., 1.], vec![-3., 5.]]);
         matrix1.add_mat(&matrix2);
-        assert_eq!(matrix1.matrix_flatt(), vec![6.0, 5.0, -3.0, 0.0, 1.0, -1.0]);
+        assert_eq!(matrix1.matrix_flatt(), vec![6.0, 5.0, 7.0, 2.0, 3.0, 8.0]);
     }
 
     #[test]
@@ -130,7 +145,7 @@ mod tests {
         matrix1.sub_mat(&matrix2);
         assert_eq!(
             matrix1,
-            Matrix::new(vec![vec![0.0, -3.0, 1.0], vec![-5.0, 0.0, -1.0]])
+            Matrix::new(vec![vec![0.0, -6.0, -4.0], vec![-5.0, -1.0, -5.0]])
         );
 
         matrix1.transpose();
@@ -138,11 +153,12 @@ mod tests {
         matrix1.sub_mat(&matrix2);
         assert_eq!(
             matrix1.matrix_flatt(),
-            vec![-2.0, -1.0, -3.0, 0.0, 1.0, -1.0]
+            vec![-2.0, -1.0, -13.0, -2.0, -1.0, -10.0]
         );
     }
 
     #[test]
+    #[allow(deprecated)]
     fn mul_mat() {
         let mut matrix1 = Matrix::new(vec![vec![2., -3., 1.], vec![2., 0., -1.]]);
         let matrix2 = Matrix::new(vec![vec![2., 3., 5.], vec![7., 1., 4.]]);
@@ -150,7 +166,7 @@ mod tests {
         matrix1.mul_mat(&matrix2);
         assert_eq!(
             matrix1,
-            Matrix::new(vec![vec![4.0, -3.0, 1.0], vec![14.0, 0.0, -1.0]])
+            Matrix::new(vec![vec![4.0, -9.0, 5.0], vec![14.0, 0.0, -4.0]])
         );
 
         matrix1.transpose();
@@ -158,8 +174,14 @@ mod tests {
         matrix1.mul_mat(&matrix2);
         assert_eq!(
             matrix1.matrix_flatt(),
-            vec![8.0, -56.0, -3.0, 0.0, 1.0, -1.0]
+            vec![8.0, -56.0, -63.0, 0.0, -15.0, -20.0]
         );
+
+        // the same as the element-wise product it was deprecated for
+        let mut matrix3 = Matrix::new(vec![vec![1., 2.], vec![3., 4.]]);
+        let matrix4 = matrix3.clone();
+        matrix3.mul_mat(&matrix4);
+        assert_eq!(matrix3, matrix4.hadamard(&matrix4));
     }
 
     #[test]
@@ -170,7 +192,7 @@ mod tests {
         matrix1.div_mat(&matrix2);
         assert_eq!(
             matrix1,
-            Matrix::new(vec![vec![1.0, -3.0, 1.0], vec![0.2857143, 0.0, -1.0]])
+            Matrix::new(vec![vec![1.0, -1.0, 0.2], vec![0.2857143, 0.0, -0.25]])
         );
 
         matrix1.transpose();
@@ -178,7 +200,7 @@ mod tests {
         matrix1.div_mat(&matrix2);
         assert_eq!(
             matrix1.matrix_flatt(),
-            vec![0.5, -0.071428575, -3.0, 0.0, 1.0, -1.0]
+            vec![0.5, -0.071428575, -0.14285715, 0.0, -0.06666667, -0.05]
         );
     }
 
@@ -190,13 +212,13 @@ mod tests {
         matrix1 += matrix2;
         assert_eq!(
             matrix1,
-            Matrix::new(vec![vec![4.0, -3.0, 1.0], vec![9.0, 0.0, -1.0]])
+            Matrix::new(vec![vec![4.0, 0.0, 6.0], vec![9.0, 1.0, 3.0]])
         );
 
         matrix1.transpose();
         let matrix2 = Matrix::new(vec![vec![2., -4.], vec![7., 1.], vec![-3., 5.]]);
         matrix1 += matrix2;
-        assert_eq!(matrix1.matrix_flatt(), vec![6.0, 5.0, -3.0, 0.0, 1.0, -1.0]);
+        assert_eq!(matrix1.matrix_flatt(), vec![6.0, 5.0, 7.0, 2.0, 3.0, 8.0]);
     }
 
     #[test]
@@ -207,7 +229,7 @@ mod tests {
         matrix1 -= matrix2;
         assert_eq!(
             matrix1,
-            Matrix::new(vec![vec![0.0, -3.0, 1.0], vec![-5.0, 0.0, -1.0]])
+            Matrix::new(vec![vec![0.0, -6.0, -4.0], vec![-5.0, -1.0, -5.0]])
         );
 
         matrix1.transpose();
@@ -215,27 +237,24 @@ mod tests {
         matrix1 -= matrix2;
         assert_eq!(
             matrix1.matrix_flatt(),
-            vec![-2.0, -1.0, -3.0, 0.0, 1.0, -1.0]
+            vec![-2.0, -1.0, -13.0, -2.0, -1.0, -10.0]
         );
     }
 
     #[test]
     fn mul_assign() {
         let mut matrix1 = Matrix::new(vec![vec![2., -3., 1.], vec![2., 0., -1.]]);
-        let matrix2 = Matrix::new(vec![vec![2., 3., 5.], vec![7., 1., 4.]]);
+        let matrix2 = Matrix::new(vec![vec![2., 3.], vec![7., 1.], vec![4., -2.]]);
 
         matrix1 *= matrix2;
-        assert_eq!(
-            matrix1,
-            Matrix::new(vec![vec![4.0, -3.0, 1.0], vec![14.0, 0.0, -1.0]])
-        );
+        assert_eq!(matrix1, Matrix::new(vec![vec![-13., 1.], vec![0., 8.]]));
 
+        let mut matrix1 = Matrix::new(vec![vec![2., -3., 1.], vec![2., 0., -1.]]);
         matrix1.transpose();
-        let matrix2 = Matrix::new(vec![vec![2., -4.], vec![7., 1.], vec![-3., 5.]]);
-        matrix1 *= matrix2;
+        matrix1 *= Matrix::new(vec![vec![1., 2.], vec![3., 4.]]);
         assert_eq!(
-            matrix1.matrix_flatt(),
-            vec![8.0, -56.0, -3.0, 0.0, 1.0, -1.0]
+            matrix1,
+            Matrix::new(vec![vec![8., 12.], vec![-3., -6.], vec![-2., -2.]])
         );
     }
 
@@ -247,7 +266,7 @@ mod tests {
         matrix1 /= matrix2;
         assert_eq!(
             matrix1,
-            Matrix::new(vec![vec![1.0, -3.0, 1.0], vec![0.2857143, 0.0, -1.0]])
+            Matrix::new(vec![vec![1.0, -1.0, 0.2], vec![0.2857143, 0.0, -0.25]])
         );
 
         matrix1.transpose();
@@ -255,7 +274,7 @@ mod tests {
         matrix1 /= matrix2;
         assert_eq!(
             matrix1.matrix_flatt(),
-            vec![0.5, -0.071428575, -3.0, 0.0, 1.0, -1.0]
+            vec![0.5, -0.071428575, -0.14285715, 0.0, -0.06666667, -0.05]
         );
     }
 
@@ -266,7 +285,7 @@ mod tests {
 
         assert_eq!(
             matrix1 + matrix2,
-            Matrix::new(vec![vec![4.0, -3.0, 1.0], vec![9.0, 0.0, -1.0]])
+            Matrix::new(vec![vec![4.0, 0.0, 6.0], vec![9.0, 1.0, 3.0]])
         );
     }
 
@@ -277,19 +296,45 @@ mod tests {
 
         assert_eq!(
             matrix1 - matrix2,
-            Matrix::new(vec![vec![0.0, -3.0, 1.0], vec![-5.0, 0.0, -1.0]])
+            Matrix::new(vec![vec![0.0, -6.0, -4.0], vec![-5.0, -1.0, -5.0]])
         );
     }
 
     #[test]
     fn mul() {
         let matrix1 = Matrix::new(vec![vec![2., -3., 1.], vec![2., 0., -1.]]);
-        let matrix2 = Matrix::new(vec![vec![2., 3., 5.], vec![7., 1., 4.]]);
+        let matrix2 = Matrix::new(vec![vec![2., 3.], vec![7., 1.], vec![4., -2.]]);
 
         assert_eq!(
-            matrix1 * matrix2,
-            Matrix::new(vec![vec![4.0, -3.0, 1.0], vec![14.0, 0.0, -1.0]])
+            &matrix1 * &matrix2,
+            Matrix::new(vec![vec![-13., 1.], vec![0., 8.]])
         );
+        assert_eq!(matrix1.clone() * matrix2.clone(), matrix1.dot_mat(&matrix2));
+    }
+
+    #[test]
+    #[should_panic]
+    fn mul_wrong_shape() {
+        let matrix1 = Matrix::new(vec![vec![2., -3., 1.], vec![2., 0., -1.]]);
+        let matrix2 = Matrix::new(vec![vec![2., 3., 5.], vec![7., 1., 4.]]);
+        let _ = matrix1 * matrix2;
+    }
+
+    #[test]
+    fn mul_elem() {
+        let mut matrix1 = Matrix::new(vec![vec![2., -3., 1.], vec![2., 0., -1.]]);
+        let matrix2 = Matrix::new(vec![vec![2., 3., 5.], vec![7., 1., 4.]]);
+        let expected = Matrix::new(vec![vec![4., -9., 5.], vec![14., 0., -4.]]);
+
+        assert_eq!(matrix1.hadamard(&matrix2), expected);
+        matrix1.mul_elem(&matrix2);
+        assert_eq!(matrix1, expected);
+
+        let mut matrix1 = Matrix::new(vec![vec![2., -3., 1.], vec![2., 0., -1.]]);
+        matrix1.transpose();
+        let matrix2 = Matrix::new(vec![vec![2., -4.], vec![7., 1.], vec![-3., 5.]]);
+        matrix1.mul_elem(&matrix2);
+        assert_eq!(matrix1.matrix_flatt(), vec![4., -8., -21., 0., -3., -5.]);
     }
 
     #[test]
@@ -299,7 +344,7 @@ mod tests {
 
         assert_eq!(
             matrix1 / matrix2,
-            Matrix::new(vec![vec![1.0, -3.0, 1.0], vec![0.2857143, 0.0, -1.0]])
+            Matrix::new(vec![vec![1.0, -1.0, 0.2], vec![0.2857143, 0.0, -0.25]])
         );
     }
 
@@ -403,6 +448,53 @@ mod tests {
         );
     }
 
+    #[test]
+    fn dot_mat_blocked() {
+        let matrix1 =
+            Matrix::new_flatt((0..70 * 45).map(|x| (x % 13) as f32 - 6.).collect(), 70, 45);
+        let mut matrix2 =
+            Matrix::new_flatt((0..33 * 45).map(|x| (x % 7) as f32 * 0.5).collect(), 33, 45);
+        matrix2.transpose();
+
+        let naive = matrix1.dot_mat_blocked(&matrix2, 100);
+        assert_eq!((naive.cols(), naive.rows()), (70, 33));
+        assert_eq!(
+            naive.index(3, 5),
+            (0..45)
+                .map(|j| matrix1.index(3, j) * matrix2.index(j, 5))
+                .sum()
+        );
+        for block_size in [1, 7, 16, 64] {
+            assert_eq!(matrix1.dot_mat_blocked(&matrix2, block_size), naive);
+        }
+        assert_eq!(matrix1.dot_mat(&matrix2), naive);
+    }
+
+    #[test]
+    #[should_panic(expected = "the block size has to be bigger then 0")]
+    fn dot_mat_blocked_zero() {
+        let matrix = Matrix::new(vec![vec![1., 2.], vec![3., 4.]]);
+        let _ = matrix.dot_mat_blocked(&matrix, 0);
+    }
+
+    #[test]
+    fn from_bytes() {
+        let mut matrix = Matrix::new(vec![vec![2., 3., 5.], vec![7., 1., 4.]]);
+        assert_eq!(Matrix::from_bytes(&matrix.bytes()), matrix);
+
+        matrix.transpose();
+        let read = Matrix::from_bytes(&matrix.bytes());
+        assert_eq!(read.matrix_flatt(), matrix.matrix_flatt());
+        assert_eq!((read.cols(), read.rows()), (3, 2));
+    }
+
+    #[test]
+    #[should_panic(expected = "the header has 2 rows and 2 cols, got 3 values")]
+    fn from_bytes_wrong_len() {
+        let bytes = Matrix::new(vec![vec![2., 3.], vec![7., 4.]]).bytes();
+        let _ = Matrix::from_bytes(&bytes[..bytes.len() - 4]);
+    }
+
     #[test]
     #[should_panic(expected = "wrong row shape expected 3, got 4")]
     fn new() {
@@ -412,9 +504,9 @@ mod tests {
     #[test]
     fn is_square() {
         let matrix = Matrix::new(vec![vec![3., 2., 4.], vec![4., 5., 6.]]);
-        assert_eq!(matrix.is_square(), false);
+        assert!(!matrix.is_square());
         let matrix = Matrix::new(vec![vec![3., 2.], vec![4., 5.]]);
-        assert_eq!(matrix.is_square(), true);
+        assert!(matrix.is_square());
     }
 
     #[test]
@@ -470,13 +562,53 @@ mod tests {
     }
 
     #[test]
+    #[allow(deprecated)]
     fn transpose() {
         let mut matrix = Matrix::new(vec![vec![3., 2., 4.], vec![4., 5., 6.]]);
-        assert_eq!(matrix.is_transpose(), false);
+        assert!(!matrix.is_transpose());
         matrix.transpose();
-        assert_eq!(matrix.is_transpose(), true);
+        assert!(matrix.is_transpose());
         matrix.transpose();
-        assert_eq!(matrix.is_transpose(), false);
+        assert!(!matrix.is_transpose());
+    }
+
+    #[test]
+    fn transpose_inplace() {
+        for (cols, rows) in [(3, 3), (70, 70), (2, 5), (67, 130)] {
+            let flatt: Vec<f32> = (0..cols * rows).map(|x| x as f32).collect();
+            let matrix = Matrix::new_flatt(flatt, cols, rows);
+            let mut lazy = matrix.clone();
+            lazy.transpose();
+
+            let mut inplace = matrix.clone();
+            inplace.transpose_inplace();
+            assert_eq!(inplace.matrix_flatt(), lazy.matrix_flatt());
+            assert_eq!((inplace.cols(), inplace.rows()), (rows, cols));
+            assert_eq!(inplace, lazy);
+            assert_eq!(matrix.transposed(), lazy);
+
+            // transposing a lazy transpose only drops the flag
+            lazy.transpose_inplace();
+            assert_eq!(lazy, matrix);
+            assert!(lazy.shares_storage(&matrix));
+        }
+        // the original is untouched even though the buffer was shared
+        let matrix = Matrix::new(vec![vec![1., 2.], vec![3., 4.]]);
+        let mut transposed = matrix.clone();
+        transposed.transpose_inplace();
+        assert_eq!(matrix.index(0, 1), 2.);
+        assert_eq!(transposed.index(0, 1), 3.);
+    }
+
+    #[test]
+    fn eq_ignores_storage() {
+        let matrix = Matrix::new(vec![vec![1., 2., 3.], vec![4., 5., 6.]]);
+        let mut lazy = Matrix::new(vec![vec![1., 4.], vec![2., 5.], vec![3., 6.]]);
+        lazy.transpose();
+        assert_eq!(lazy, matrix);
+        assert_eq!(format!("{:?}", lazy), format!("{:?}", matrix));
+        lazy.set_index(0, 0, 0.);
+        assert_ne!(lazy, matrix);
     }
 
     #[test]
@@ -546,4 +678,731 @@ mod tests {
             Vector::new(vec![1., -3.])
         );
     }
+
+    #[test]
+    fn dot_mat() {
+        let matrix1 = Matrix::new(vec![vec![1., 2., 3.], vec![4., 5., 6.]]);
+        let matrix2 = Matrix::new(vec![vec![7., 8.], vec![9., 10.], vec![11., 12.]]);
+        assert_eq!(
+            matrix1.dot_mat(&matrix2),
+            Matrix::new(vec![vec![58., 64.], vec![139., 154.]])
+        );
+
+        let vector = Vector::new(vec![1., -2.]);
+        assert_eq!(
+            matrix1.dot_mat(&matrix2).dot_vec(&vector),
+            matrix1.dot_vec(&matrix2.dot_vec(&vector))
+        );
+
+        let mut matrix3 = matrix2.clone();
+        matrix3.transpose();
+        let mut matrix4 = matrix1.clone();
+        matrix4.transpose();
+        assert_eq!(
+            matrix3.dot_mat(&matrix4),
+            Matrix::new(vec![vec![58., 139.], vec![64., 154.]])
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "wrong matrix shape expected 3 cols, got 2")]
+    fn dot_mat_panic() {
+        let matrix = Matrix::new(vec![vec![1., 2., 3.], vec![4., 5., 6.]]);
+        let _ = matrix.dot_mat(&matrix);
+    }
+
+    #[test]
+    fn apply_func() {
+        let mut matrix = Matrix::new(vec![vec![1., 2., 3.], vec![4., 5., 6.]]);
+        matrix.transpose();
+        matrix.apply_func(|x| x * 10.);
+        assert_eq!(matrix.matrix_flatt(), vec![10., 40., 20., 50., 30., 60.]);
+    }
+
+    #[test]
+    fn solve() {
+        let matrix = Matrix::new(vec![vec![0., 2., 1.], vec![1., 1., 1.], vec![2., 1., 3.]]);
+        let x = Vector::new(vec![1., -2., 3.]);
+        let b = matrix.dot_vec(&x);
+        let solution = matrix.solve(&b);
+        for i in 0..3 {
+            assert!((solution.index(i) - x.index(i)).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "the matrix is singular")]
+    fn solve_singular() {
+        let matrix = Matrix::new(vec![vec![1., 2.], vec![2., 4.]]);
+        matrix.solve(&Vector::new(vec![1., 2.]));
+    }
+
+    #[test]
+    fn lstsq() {
+        // the square case is the same as solve
+        let matrix = Matrix::new(vec![vec![2., 1.], vec![1., 3.]]);
+        let b = Vector::new(vec![3., 5.]);
+        let x = matrix.lstsq(&b);
+        assert!((x.index(0) - 0.8).abs() < 1e-5);
+        assert!((x.index(1) - 1.4).abs() < 1e-5);
+
+        // fit the line through (1, 6), (2, 5), (3, 7), (4, 10)
+        let matrix = Matrix::new(vec![vec![1., 1.], vec![1., 2.], vec![1., 3.], vec![1., 4.]]);
+        let b = Vector::new(vec![6., 5., 7., 10.]);
+        let x = matrix.lstsq(&b);
+        assert!((x.index(0) - 3.5).abs() < 1e-4);
+        assert!((x.index(1) - 1.4).abs() < 1e-4);
+    }
+
+    #[test]
+    #[should_panic(expected = "the matrix is rank deficient")]
+    fn lstsq_rank_deficient() {
+        let matrix = Matrix::new(vec![vec![1., 2.], vec![2., 4.], vec![3., 6.]]);
+        matrix.lstsq(&Vector::new(vec![1., 2., 3.]));
+    }
+
+    #[test]
+    fn copy_on_write() {
+        let matrix1 = Matrix::new(vec![vec![1., 2.], vec![3., 4.]]);
+        let mut matrix2 = matrix1.clone();
+        assert!(matrix1.shares_storage(&matrix2));
+
+        matrix2.mul_scalar(&2.);
+        assert!(!matrix1.shares_storage(&matrix2));
+        assert_eq!(matrix1, Matrix::new(vec![vec![1., 2.], vec![3., 4.]]));
+        assert_eq!(matrix2, Matrix::new(vec![vec![2., 4.], vec![6., 8.]]));
+
+        let mut matrix3 = matrix2.clone();
+        matrix3.transpose();
+        assert!(matrix2.shares_storage(&matrix3));
+        matrix3.set_index(0, 1, 0.);
+        assert_eq!(matrix2.index(1, 0), 6.);
+        assert_eq!(matrix3.index(0, 1), 0.);
+    }
+
+    #[test]
+    fn send_between_threads() {
+        let matrix = Matrix::new(vec![vec![1., 2.], vec![3., 4.]]);
+        let shared = matrix.clone();
+        let sum = std::thread::spawn(move || shared.matrix_flatt().iter().sum::<f32>())
+            .join()
+            .unwrap();
+        assert_eq!(sum, 10.);
+    }
+
+    #[test]
+    fn mat_inplace() {
+        let mut matrix1 = Matrix::new(vec![vec![1., 2., 3.], vec![4., 5., 6.]]);
+        let mut matrix2 = Matrix::new(vec![vec![1., 2.], vec![3., 4.], vec![5., 6.]]);
+        matrix2.transpose();
+        matrix1.add_mat_inplace(&matrix2);
+        assert_eq!(
+            matrix1,
+            Matrix::new(vec![vec![2., 5., 8.], vec![6., 9., 12.]])
+        );
+
+        let mut matrix3 = matrix1.clone();
+        matrix3.transpose();
+        let mut matrix4 = matrix2.clone();
+        matrix4.transpose();
+        matrix3.sub_mat_inplace(&matrix4);
+        assert_eq!(matrix1.index(1, 2), 12.);
+        assert_eq!(matrix3.matrix_flatt(), vec![1., 4., 2., 5., 3., 6.]);
+    }
+
+    #[test]
+    fn mat_inplace_same_as_mat() {
+        // the last row and col are included like in the in place versions
+        let a = Matrix::new(vec![vec![1., 2.], vec![3., 4.]]);
+        let mut inplace = a.clone();
+        inplace.add_mat_inplace(&a);
+        let mut result = a.clone();
+        result.add_mat(&a);
+        assert_eq!(result, inplace);
+        assert_eq!(a.clone() + a.clone(), inplace);
+        assert_eq!(inplace.matrix_flatt(), vec![2., 4., 6., 8.]);
+
+        let mut result = a.clone();
+        result.sub_mat(&a);
+        assert_eq!(result, Matrix::new_zero(2, 2));
+        let mut result = a.clone();
+        result.div_mat(&a);
+        assert_eq!(result.matrix_flatt(), vec![1.; 4]);
+    }
+
+    #[test]
+    fn vec_inplace() {
+        let mut matrix = Matrix::new(vec![vec![1., 2., 3.], vec![4., 5., 6.]]);
+        matrix.mul_vec_inplace(&Vector::new(vec![1., 0., 2.]));
+        assert_eq!(
+            matrix,
+            Matrix::new(vec![vec![1., 0., 6.], vec![4., 0., 12.]])
+        );
+
+        matrix.transpose();
+        matrix.add_vec_inplace(&Vector::new(vec![1., 2.]));
+        assert_eq!(matrix.matrix_flatt(), vec![2., 6., 1., 2., 7., 14.]);
+    }
+
+    #[test]
+    #[should_panic(expected = "wrong vector shape expected 3, got 2")]
+    fn vec_inplace_panic() {
+        let mut matrix = Matrix::new(vec![vec![1., 2., 3.], vec![4., 5., 6.]]);
+        matrix.div_vec_inplace(&Vector::new(vec![1., 2.]));
+    }
+
+    #[test]
+    fn col_vec() {
+        let mut matrix = Matrix::new(vec![vec![1., 2., 3.], vec![4., 5., 6.]]);
+        matrix.add_col_vec(&Vector::new(vec![1., 1., 1.]));
+        matrix.mul_col_vec(&Vector::new(vec![1., 2., 3.]));
+        assert_eq!(
+            matrix,
+            Matrix::new(vec![vec![2., 6., 12.], vec![5., 12., 21.]])
+        );
+        matrix.sub_col_vec(&Vector::new(vec![2., 6., 12.]));
+        matrix.div_col_vec(&Vector::new(vec![3., 3., 9.]));
+        assert_eq!(
+            matrix,
+            Matrix::new(vec![vec![0., 0., 0.], vec![1., 2., 1.]])
+        );
+    }
+
+    #[test]
+    fn row_vec() {
+        let mut matrix = Matrix::new(vec![vec![1., 2., 3.], vec![4., 5., 6.]]);
+        matrix.add_row_vec(&Vector::new(vec![1., -1.]));
+        matrix.mul_row_vec(&Vector::new(vec![2., 1.]));
+        assert_eq!(
+            matrix,
+            Matrix::new(vec![vec![4., 6., 8.], vec![3., 4., 5.]])
+        );
+        matrix.sub_row_vec(&Vector::new(vec![4., 3.]));
+        matrix.div_row_vec(&Vector::new(vec![2., 1.]));
+        assert_eq!(
+            matrix,
+            Matrix::new(vec![vec![0., 1., 2.], vec![0., 1., 2.]])
+        );
+
+        matrix.transpose();
+        matrix.add_row_vec(&Vector::new(vec![1., 2., 3.]));
+        assert_eq!(matrix.matrix_flatt(), vec![1., 1., 3., 3., 5., 5.]);
+    }
+
+    #[test]
+    #[should_panic(expected = "can't broadcast shape 3x1 to 2x3")]
+    fn row_vec_panic() {
+        let mut matrix = Matrix::new(vec![vec![1., 2., 3.], vec![4., 5., 6.]]);
+        matrix.add_row_vec(&Vector::new(vec![1., 2., 3.]));
+    }
+
+    #[test]
+    fn apply() {
+        let mut matrix = Matrix::new(vec![vec![1., 2.], vec![3., 4.]]);
+        let offset = 0.5;
+        matrix.transpose();
+        matrix.apply(|x| x * x + offset);
+        assert_eq!(matrix.matrix_flatt(), vec![1.5, 9.5, 4.5, 16.5]);
+
+        let func: Box<dyn Fn(f32) -> f32> = Box::new(|x| x - offset);
+        matrix.apply_dyn(func.as_ref());
+        assert_eq!(matrix.matrix_flatt(), vec![1., 9., 4., 16.]);
+    }
+
+    fn assert_identity(matrix: &Matrix) {
+        for i in 0..matrix.cols() {
+            for j in 0..matrix.rows() {
+                let expected = if i == j { 1. } else { 0. };
+                assert!((matrix.index(i, j) - expected).abs() < 1e-5);
+            }
+        }
+    }
+
+    #[test]
+    fn inverse() {
+        let matrices = vec![
+            Matrix::new(vec![vec![1., 2.], vec![3., 4.]]),
+            Matrix::new(vec![vec![2., -3., 1.], vec![2., 0., -1.], vec![1., 4., 5.]]),
+            Matrix::new(vec![
+                vec![6., 1., 1., 4.],
+                vec![4., -2., 5., -7.],
+                vec![2., 8., 7., 3.],
+                vec![4., 1., 4., 2.],
+            ]),
+            Matrix::new(vec![
+                vec![2., 1., 0., 0., 1.],
+                vec![0., 6., 1., 1., 4.],
+                vec![0., 4., -2., 5., -7.],
+                vec![1., 2., 8., 7., 3.],
+                vec![0., 4., 1., 4., 2.],
+            ]),
+        ];
+        for matrix in matrices {
+            let inverse = matrix.inverse();
+            assert_identity(&matrix.dot_mat(&inverse));
+            assert_identity(&inverse.dot_mat(&matrix));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "the matrix is singular")]
+    fn inverse_singular() {
+        let matrix = Matrix::new(vec![vec![1., 2., 3.], vec![2., 4., 6.], vec![0., 1., 0.]]);
+        matrix.inverse();
+    }
+
+    #[test]
+    fn dot_mat_small() {
+        for n in 2..6 {
+            let matrix1 = Matrix::new_flatt((0..n * n).map(|x| x as f32).collect(), n, n);
+            let mut matrix2 = Matrix::new_flatt((0..n * n).map(|x| (x % 3) as f32).collect(), n, n);
+            matrix2.transpose();
+            let product = matrix1.dot_mat(&matrix2);
+            for i in 0..n {
+                for j in 0..n {
+                    let expected: f32 = (0..n)
+                        .map(|k| matrix1.index(i, k) * matrix2.index(k, j))
+                        .sum();
+                    assert_eq!(product.index(i, j), expected);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn solve_refined() {
+        // the hilbert matrix is badly conditioned
+        let n = 6;
+        let matrix = Matrix::new(
+            (0..n)
+                .map(|i| (0..n).map(|j| 1. / (i + j + 1) as f32).collect())
+                .collect(),
+        );
+        let expected = Vector::new(vec![1.; n]);
+        let b = matrix.dot_vec(&expected);
+
+        let residual = |x: &Vector| {
+            let mut r = matrix.dot_vec(x);
+            r.sub_vec(&b);
+            r.mag()
+        };
+
+        let plain = matrix.solve(&b);
+        let (refined, refined_residual) = matrix.solve_refined(&b, 10);
+        assert!(refined_residual <= residual(&plain));
+        assert!(refined.dist(&expected) <= plain.dist(&expected));
+
+        let (unrefined, _) = matrix.solve_refined(&b, 0);
+        assert_eq!(unrefined, plain);
+    }
+
+    #[test]
+    fn symmetric_eigen() {
+        let matrix = Matrix::new(vec![
+            vec![4., 1., 2., 0.],
+            vec![1., 3., 0., 1.],
+            vec![2., 0., 5., 1.],
+            vec![0., 1., 1., 2.],
+        ]);
+        let (values, vectors) = matrix.symmetric_eigen();
+        let trace: f32 = values.vec().iter().sum();
+        assert!((trace - 14.).abs() < 1e-4);
+        assert!(values.vec().windows(2).all(|w| w[0] <= w[1]));
+        for k in 0..4 {
+            let v = vectors.col(k);
+            let av = matrix.dot_vec(&v);
+            let norm: f32 = v.vec().iter().map(|x| x * x).sum();
+            assert!((norm - 1.).abs() < 1e-5);
+            for i in 0..4 {
+                assert!((av.index(i) - values.index(k) * v.index(i)).abs() < 1e-4);
+            }
+        }
+    }
+
+    fn check_eigen_pairs(matrix: &Matrix, eps: f32) {
+        let (values, vectors) = matrix.eigen_vec(1e-10, 100);
+        let n = matrix.cols();
+        for k in 0..n {
+            let v = vectors.col(k);
+            let av = matrix.dot_vec(&v);
+            for i in 0..n {
+                assert!(
+                    (av.index(i) - values.index(k) * v.index(i)).abs() < eps,
+                    "eigenpair {} of {:?}",
+                    k,
+                    matrix
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn eigen_val_nonsymmetric() {
+        // the companion matrix of (x - 1)(x - 2)(x - 3)(x - 4)
+        let matrix = Matrix::new(vec![
+            vec![10., -35., 50., -24.],
+            vec![1., 0., 0., 0.],
+            vec![0., 1., 0., 0.],
+            vec![0., 0., 1., 0.],
+        ]);
+        let (re, im) = matrix.eigen_val(1e-10, 100);
+        for (k, expected) in [1., 2., 3., 4.].iter().enumerate() {
+            assert!((re.index(k) - expected).abs() < 1e-3);
+            assert_eq!(im.index(k), 0.);
+        }
+        check_eigen_pairs(&matrix, 1e-2);
+    }
+
+    #[test]
+    fn eigen_val_complex() {
+        // a rotation by 90 degrees around the z axis scaled by 2
+        let matrix = Matrix::new(vec![vec![0., -2., 0.], vec![2., 0., 0.], vec![0., 0., 5.]]);
+        let (re, im) = matrix.eigen_val(1e-10, 100);
+        assert_eq!(re.vec().len(), 3);
+        assert!(re.index(0).abs() < 1e-6 && re.index(1).abs() < 1e-6);
+        assert!((im.index(0) + 2.).abs() < 1e-6);
+        assert!((im.index(1) - 2.).abs() < 1e-6);
+        assert!((re.index(2) - 5.).abs() < 1e-6);
+    }
+
+    #[test]
+    fn eigen_vec_matches_symmetric() {
+        let matrix = Matrix::new(vec![
+            vec![4., 1., 2., 0., 1.],
+            vec![1., 3., 0., 1., 0.],
+            vec![2., 0., 5., 1., 2.],
+            vec![0., 1., 1., 2., 0.],
+            vec![1., 0., 2., 0., 6.],
+        ]);
+        let (values, _) = matrix.eigen_vec(1e-10, 100);
+        let (expected, _) = matrix.symmetric_eigen();
+        for k in 0..5 {
+            assert!((values.index(k) - expected.index(k)).abs() < 1e-4);
+        }
+        check_eigen_pairs(&matrix, 1e-4);
+    }
+
+    #[test]
+    fn eigen_vec_repeated() {
+        // the eigenvalue 2 has a two dimensional eigenspace
+        let matrix = Matrix::new(vec![vec![2., 0., 0.], vec![0., 2., 0.], vec![0., 0., 7.]]);
+        let (values, vectors) = matrix.eigen_vec(1e-10, 100);
+        assert_eq!(values, Vector::new(vec![2., 2., 7.]));
+        let dot: f32 = (0..3)
+            .map(|i| vectors.index(0, i) * vectors.index(1, i))
+            .sum();
+        assert!(dot.abs() < 1e-5);
+        check_eigen_pairs(&matrix, 1e-5);
+
+        // every vector is an eigenvector, the second one still has to be independent of the first
+        let matrix = Matrix::new(vec![vec![3., 0.], vec![0., 3.]]);
+        let (_, vectors) = matrix.eigen_vec(1e-10, 100);
+        assert!((vectors.col(1).dot_vec(&vectors.col(1)) - 1.).abs() < 1e-5);
+        assert!(vectors.col(0).dot_vec(&vectors.col(1)).abs() < 1e-5);
+    }
+
+    #[test]
+    #[should_panic(expected = "the matrix is defective, it has too few eigenvectors")]
+    fn eigen_vec_defective() {
+        // the eigenvalue 1 is repeated but has only the eigenvector (1, 0)
+        Matrix::new(vec![vec![1., 1.], vec![0., 1.]]).eigen_vec(1e-7, 100);
+    }
+
+    #[test]
+    fn eigen_random() {
+        let mut state = 12345u32;
+        let mut values = Vec::new();
+        for _ in 0..36 {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            values.push((state % 1000) as f32 / 100. - 5.);
+        }
+        let matrix = Matrix::new_flatt(values, 6, 6);
+        let (re, im) = matrix.eigen_val(1e-10, 100);
+        // the sum of the eigenvalues is the trace and complex eigenvalues come in conjugate pairs
+        let trace: f32 = (0..6).map(|i| matrix.index(i, i)).sum();
+        assert!((re.vec().iter().sum::<f32>() - trace).abs() < 1e-3);
+        assert!(im.vec().iter().sum::<f32>().abs() < 1e-3);
+    }
+
+    #[test]
+    #[should_panic(expected = "the matrix has complex eigenvalues")]
+    fn eigen_vec_complex() {
+        Matrix::new(vec![vec![0., -1.], vec![1., 0.]]).eigen_vec(1e-7, 100);
+    }
+
+    #[test]
+    #[should_panic(expected = "did not converge")]
+    fn eigen_val_max_iterations() {
+        let matrix = Matrix::new(vec![
+            vec![10., -35., 50., -24.],
+            vec![1., 0., 0., 0.],
+            vec![0., 1., 0., 0.],
+            vec![0., 0., 1., 0.],
+        ]);
+        matrix.eigen_val(1e-10, 0);
+    }
+
+    #[test]
+    fn det_large() {
+        // a triangular matrix with a swapped pair of cols, the determinant is minus the product of the diagonal
+        let n = 500;
+        let mut matrix = Matrix::new_zero(n, n);
+        for i in 0..n {
+            matrix.set_index(i, i, if i % 2 == 0 { 2. } else { 0.5 });
+            for j in (i + 1)..n {
+                matrix.set_index(i, j, ((i * 7 + j * 3) % 11) as f32 - 5.);
+            }
+        }
+        let mut swapped = Matrix::new_zero(n, n);
+        for i in 0..n {
+            let source = match i {
+                0 => 1,
+                1 => 0,
+                _ => i,
+            };
+            for j in 0..n {
+                swapped.set_index(i, j, matrix.index(source, j));
+            }
+        }
+        assert!((matrix.det() - 1.).abs() < 1e-3);
+        assert!((swapped.det() + 1.).abs() < 1e-3);
+    }
+
+    #[test]
+    fn det_singular() {
+        let matrix = Matrix::new(vec![
+            vec![1., 2., 3., 4., 5.],
+            vec![2., 4., 6., 8., 10.],
+            vec![0., 1., 0., 1., 0.],
+            vec![3., 1., 4., 1., 5.],
+            vec![9., 2., 6., 5., 3.],
+        ]);
+        assert_eq!(matrix.det(), 0.);
+    }
+
+    #[test]
+    fn iter_cols_rows() {
+        let mut matrix = Matrix::new(vec![vec![1., 2., 3.], vec![4., 5., 6.]]);
+        let cols: Vec<Vector> = matrix.iter_cols().collect();
+        assert_eq!(cols, vec![matrix.col(0), matrix.col(1)]);
+        let rows: Vec<Vector> = matrix.iter_rows().rev().collect();
+        assert_eq!(rows, vec![matrix.row(2), matrix.row(1), matrix.row(0)]);
+        assert_eq!(matrix.iter_rows().len(), 3);
+
+        matrix.transpose();
+        assert_eq!(matrix.iter_cols().len(), 3);
+        for (i, col) in matrix.iter_cols().enumerate() {
+            assert_eq!(col, matrix.col(i));
+            assert_eq!(col, Vector::new(vec![(i + 1) as f32, (i + 4) as f32]));
+        }
+        for (j, row) in matrix.iter_rows().enumerate() {
+            assert_eq!(row, matrix.row(j));
+        }
+    }
+
+    #[test]
+    fn iter_elems() {
+        let mut matrix = Matrix::new(vec![vec![1., 2., 3.], vec![4., 5., 6.]]);
+        assert_eq!(
+            matrix.iter_elems().collect::<Vec<f32>>(),
+            matrix.matrix_flatt()
+        );
+        matrix.transpose();
+        assert_eq!(matrix.iter_elems().len(), 6);
+        assert_eq!(
+            matrix.iter_elems().collect::<Vec<f32>>(),
+            vec![1., 4., 2., 5., 3., 6.]
+        );
+        assert_eq!(matrix.iter_elems().next_back(), Some(6.));
+        let max = matrix.iter_elems().fold(f32::MIN, f32::max);
+        assert_eq!(max, 6.);
+    }
+
+    #[test]
+    fn submatrix() {
+        let mut matrix = Matrix::new(vec![
+            vec![1., 2., 3., 4.],
+            vec![5., 6., 7., 8.],
+            vec![9., 10., 11., 12.],
+        ]);
+        assert_eq!(
+            matrix.submatrix(1..3, 1..=2),
+            Matrix::new(vec![vec![6., 7.], vec![10., 11.]])
+        );
+        assert_eq!(matrix.submatrix(.., ..), matrix);
+        assert_eq!(matrix.submatrix(1..1, ..).cols(), 0);
+
+        matrix.transpose();
+        let sub = matrix.submatrix(2.., 1..);
+        assert_eq!((sub.cols(), sub.rows()), (2, 2));
+        assert_eq!(sub.index(0, 0), 7.);
+        assert_eq!(sub.index(1, 1), 12.);
+    }
+
+    #[test]
+    fn view() {
+        let matrix = Matrix::new(vec![
+            vec![1., 2., 3., 4.],
+            vec![5., 6., 7., 8.],
+            vec![9., 10., 11., 12.],
+        ]);
+        let view = matrix.view(1.., 1..);
+        assert_eq!((view.cols(), view.rows()), (2, 3));
+        assert_eq!(view.index(1, 2), 12.);
+
+        let inner = view.view(..1, 1..);
+        assert_eq!(inner.to_matrix(), Matrix::new(vec![vec![7., 8.]]));
+        assert_eq!(Matrix::from(&inner), matrix.submatrix(1..2, 2..4));
+    }
+
+    #[test]
+    #[should_panic]
+    fn submatrix_out_of_bounds() {
+        Matrix::new_zero(3, 3).submatrix(1..4, ..);
+    }
+
+    #[test]
+    #[should_panic]
+    fn view_index_out_of_bounds() {
+        let matrix = Matrix::new_zero(3, 3);
+        matrix.view(1.., ..).index(2, 0);
+    }
+
+    #[test]
+    fn swap_rows_cols() {
+        let mut matrix = Matrix::new(vec![vec![1., 2., 3.], vec![4., 5., 6.]]);
+        matrix.swap_rows(1, 1);
+        assert_eq!(matrix.matrix_flatt(), vec![1., 2., 3., 4., 5., 6.]);
+        matrix.swap_rows(0, 1);
+        matrix.swap_cols(1, 0);
+        assert_eq!(matrix.matrix_flatt(), vec![5., 4., 6., 2., 1., 3.]);
+
+        matrix.transpose();
+        matrix.swap_rows(0, 1);
+        assert_eq!(matrix.row(0), Vector::new(vec![2., 1., 3.]));
+        matrix.swap_cols(0, 2);
+        assert_eq!(matrix.col(0), Vector::new(vec![3., 6.]));
+    }
+
+    #[test]
+    fn permute_rows() {
+        let mut matrix = Matrix::new(vec![vec![1., 2., 3.], vec![4., 5., 6.]]);
+        matrix.permute_rows(&[1, 2, 0]);
+        assert_eq!(matrix.matrix_flatt(), vec![2., 3., 1., 5., 6., 4.]);
+
+        matrix.transpose();
+        matrix.permute_rows(&[1, 0]);
+        assert_eq!(matrix.col(0), Vector::new(vec![5., 2.]));
+        assert_eq!(matrix.row(0), Vector::new(vec![5., 6., 4.]));
+    }
+
+    #[test]
+    #[should_panic(expected = "[0, 0, 1] is not a permutation")]
+    fn permute_rows_panic() {
+        Matrix::new_zero(2, 3).permute_rows(&[0, 0, 1]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn swap_rows_out_of_bounds() {
+        Matrix::new_zero(2, 3).swap_rows(0, 3);
+    }
+
+    #[test]
+    fn kron() {
+        let a = Matrix::new(vec![vec![1., 2., 3.], vec![4., 5., 6.]]);
+        let b = Matrix::new(vec![vec![1., -1.], vec![2., 0.], vec![0., 3.]]);
+        let product = a.kron(&b);
+        assert_eq!((product.cols(), product.rows()), (6, 6));
+        for i in 0..2 {
+            for j in 0..3 {
+                for k in 0..3 {
+                    for l in 0..2 {
+                        assert_eq!(
+                            product.index(i * 3 + k, j * 2 + l),
+                            a.index(i, j) * b.index(k, l)
+                        );
+                    }
+                }
+            }
+        }
+
+        // the mixed product property (A x B)(C x D) = AC x BD
+        let c = Matrix::new(vec![vec![1., 0.], vec![2., 1.], vec![0., 1.]]);
+        let d = Matrix::new(vec![vec![2., 1., 0.], vec![1., 0., 1.]]);
+        assert_eq!(
+            a.kron(&b).dot_mat(&c.kron(&d)),
+            a.dot_mat(&c).kron(&b.dot_mat(&d))
+        );
+    }
+
+    #[test]
+    fn kron_transposed() {
+        let mut a = Matrix::new(vec![vec![1., 2.], vec![3., 4.]]);
+        let b = Matrix::new(vec![vec![0., 1.], vec![1., 0.]]);
+        let expected = Matrix::new(vec![vec![1., 3.], vec![2., 4.]]).kron(&b);
+        a.transpose();
+        assert_eq!(a.kron(&b), expected);
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn neg() {
+        let mut matrix = Matrix::new(vec![vec![1., -2.], vec![0., 4.]]);
+        let negated = -&matrix;
+        assert_eq!(negated, Matrix::new(vec![vec![-1., 2.], vec![-0., -4.]]));
+        // the original is untouched even though the buffer was shared
+        assert_eq!(matrix.index(0, 1), -2.);
+        assert_eq!(-negated, matrix);
+
+        matrix.transpose();
+        let negated = -matrix.clone();
+        assert!(negated.is_transpose());
+        assert_eq!(negated.index(1, 0), 2.);
+    }
+
+    #[test]
+    fn scalar_operators() {
+        let matrix = Matrix::new(vec![vec![1., 2.], vec![3., 4.]]);
+        assert_eq!(
+            matrix.clone() * 2.,
+            Matrix::new(vec![vec![2., 4.], vec![6., 8.]])
+        );
+        assert_eq!(2. * matrix.clone(), matrix.clone() * 2.);
+        assert_eq!(
+            matrix.clone() + 1.,
+            Matrix::new(vec![vec![2., 3.], vec![4., 5.]])
+        );
+        assert_eq!(
+            matrix.clone() - 1.,
+            Matrix::new(vec![vec![0., 1.], vec![2., 3.]])
+        );
+        assert_eq!(
+            matrix.clone() / 2.,
+            Matrix::new(vec![vec![0.5, 1.], vec![1.5, 2.]])
+        );
+        assert_eq!(
+            (matrix.clone() * 3. - 1.) / 2.,
+            Matrix::new(vec![vec![1., 2.5], vec![4., 5.5]])
+        );
+    }
+
+    #[test]
+    fn mul_vector() {
+        let matrix = Matrix::new(vec![vec![1., 2., 3.], vec![4., 5., 6.]]);
+        let vector = Vector::new(vec![1., 0., -1.]);
+        assert_eq!(&matrix * &vector, Vector::new(vec![-2., -2.]));
+        assert_eq!(matrix.clone() * vector.clone(), matrix.dot_vec(&vector));
+        // the result composes with the other operators
+        assert_eq!(&matrix * &vector * 2. + 1., Vector::new(vec![-3., -3.]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn mul_vector_wrong_len() {
+        let matrix = Matrix::new(vec![vec![1., 2., 3.], vec![4., 5., 6.]]);
+        let _ = &matrix * &Vector::new(vec![1., 2.]);
+    }
 }
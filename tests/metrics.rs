@@ -0,0 +1,63 @@
+#[cfg(test)]
+mod tests {
+    use math::linear_algebra::{Matrix, Vector};
+    use math::metrics::*;
+
+    #[test]
+    fn errors() {
+        let pred = Vector::new(vec![2., 4., 1., 0.]);
+        let target = Vector::new(vec![1., 4., 3., 4.]);
+        assert_eq!(mse(&pred, &target), 21. / 4.);
+        assert_eq!(mae(&pred, &target), 7. / 4.);
+        assert_eq!(rmse(&pred, &target), (21_f32 / 4.).sqrt());
+    }
+
+    #[test]
+    #[should_panic]
+    fn mse_wrong_len() {
+        mse(&Vector::new(vec![1., 2.]), &Vector::new(vec![1.]));
+    }
+
+    #[test]
+    fn cross_entropy_clamp() {
+        let pred = Vector::new(vec![0., 1.]);
+        let target = Vector::new(vec![1., 0.]);
+        assert!(cross_entropy(&pred, &target).is_finite());
+    }
+
+    #[test]
+    fn accuracy_batch() {
+        let pred = Matrix::new(vec![vec![0.1, 0.8, 0.1], vec![0.6, 0.3, 0.1]]);
+        let target = Matrix::new(vec![vec![0., 1., 0.], vec![0., 0., 1.]]);
+        assert_eq!(accuracy_mat(&pred, &target), 0.5);
+    }
+
+    #[test]
+    fn confusion() {
+        let pred = Vector::new(vec![2., 0., 2., 2., 0., 1.]);
+        let target = Vector::new(vec![0., 0., 2., 2., 0., 2.]);
+        let matrix = confusion_matrix(&pred, &target, 3);
+        assert_eq!(
+            matrix.matrix_flatt(),
+            vec![2., 0., 1., 0., 0., 0., 0., 1., 2.]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "a label has to be a non-negative integer, got -1.7")]
+    fn accuracy_negative_label() {
+        accuracy(&Vector::new(vec![0., 1.]), &Vector::new(vec![-1.7, 1.]));
+    }
+
+    #[test]
+    #[should_panic(expected = "a label has to be a non-negative integer, got NaN")]
+    fn confusion_nan_label() {
+        confusion_matrix(&Vector::new(vec![f32::NAN]), &Vector::new(vec![0.]), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "label out of bounds max label 1, got pred = 2, target = 0")]
+    fn confusion_out_of_bounds() {
+        confusion_matrix(&Vector::new(vec![2.]), &Vector::new(vec![0.]), 2);
+    }
+}
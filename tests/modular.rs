@@ -0,0 +1,67 @@
+#[cfg(test)]
+mod tests {
+    use math::linear_algebra::modular::ModMatrix;
+    use math::linear_algebra::Matrix;
+    use math::number::ModInt;
+
+    const P: u64 = 1_000_000_007;
+
+    #[test]
+    fn det_matches_integer_det() {
+        let values = vec![
+            vec![2, 0, 1, 3],
+            vec![1, 4, 2, 0],
+            vec![0, 3, 5, 1],
+            vec![2, 1, 0, 6],
+        ];
+        let float = Matrix::new(
+            values
+                .iter()
+                .map(|v| v.iter().map(|&x| x as f32).collect())
+                .collect(),
+        );
+        let det = float.det().round() as i64;
+        let matrix = ModMatrix::<P>::new(values);
+        assert_eq!(matrix.det(), ModInt::from_i64(det));
+        assert_eq!(matrix.transpose().det(), matrix.det());
+    }
+
+    #[test]
+    fn inverse() {
+        let matrix = ModMatrix::<P>::new(vec![vec![0, 5, 7], vec![3, 0, 1], vec![4, 9, 2]]);
+        let inverse = matrix.inverse().unwrap();
+        assert_eq!(matrix.dot_mat(&inverse), ModMatrix::identity(3));
+        assert_eq!(inverse.dot_mat(&matrix), ModMatrix::identity(3));
+        assert_eq!(matrix.det() * inverse.det(), ModInt::new(1));
+    }
+
+    #[test]
+    fn singular_mod_p() {
+        // the determinant is 7, so the matrix is only singular modulo 7
+        let matrix = ModMatrix::<7>::new(vec![vec![4, 1], vec![1, 2]]);
+        assert_eq!(matrix.det().value(), 0);
+        assert_eq!(matrix.inverse(), None);
+        let matrix = ModMatrix::<11>::new(vec![vec![4, 1], vec![1, 2]]);
+        assert_eq!(matrix.det().value(), 7);
+        assert!(matrix.inverse().is_some());
+    }
+
+    #[test]
+    fn fibonacci() {
+        // the powers of [[1, 1], [1, 0]] contain the fibonacci numbers
+        let step = ModMatrix::<P>::new(vec![vec![1, 1], vec![1, 0]]);
+        let mut power = ModMatrix::identity(2);
+        for _ in 0..90 {
+            power = power.dot_mat(&step);
+        }
+        assert_eq!(power.index(0, 1).value(), 2880067194370816120 % P);
+        let v = power.dot_vec(&[ModInt::new(1), ModInt::new(0)]);
+        assert_eq!(v[1], power.index(1, 0));
+    }
+
+    #[test]
+    #[should_panic(expected = "square matrix")]
+    fn det_not_square() {
+        ModMatrix::<7>::new(vec![vec![1, 2, 3]]).det();
+    }
+}
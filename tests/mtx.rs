@@ -0,0 +1,88 @@
+#[cfg(test)]
+mod tests {
+    use math::linear_algebra::sparse::SparseMatrix;
+    use math::linear_algebra::Matrix;
+    use std::io;
+
+    #[test]
+    fn round_trip() {
+        let mut matrix = Matrix::new(vec![vec![1., 0., -2.5], vec![0., 4., 1e-4]]);
+        matrix.transpose();
+        let mut out = Vec::new();
+        matrix.to_mtx_writer(&mut out).unwrap();
+        let read = Matrix::from_mtx_reader(out.as_slice()).unwrap();
+        assert_eq!(read.matrix_flatt(), matrix.matrix_flatt());
+        assert_eq!(read.cols(), 3);
+        // an array file can be read as a sparse matrix too
+        let sparse = SparseMatrix::from_mtx_reader(out.as_slice()).unwrap();
+        assert_eq!(sparse.to_dense(), read);
+
+        let sparse = SparseMatrix::from_dense(&matrix);
+        let mut out = Vec::new();
+        sparse.to_mtx_writer(&mut out).unwrap();
+        assert_eq!(
+            SparseMatrix::from_mtx_reader(out.as_slice()).unwrap(),
+            sparse
+        );
+        assert_eq!(Matrix::from_mtx_reader(out.as_slice()).unwrap(), read);
+    }
+
+    #[test]
+    fn symmetry() {
+        let data = "%%MatrixMarket matrix coordinate pattern general\n%\n2 3 2\n1 3\n2 1\n";
+        assert_eq!(
+            Matrix::from_mtx_reader(data.as_bytes()).unwrap(),
+            Matrix::new(vec![vec![0., 0., 1.], vec![1., 0., 0.]])
+        );
+
+        let data = "%%MatrixMarket matrix array integer skew-symmetric\n3 3\n1\n2\n\n3\n";
+        assert_eq!(
+            Matrix::from_mtx_reader(data.as_bytes()).unwrap(),
+            Matrix::new(vec![
+                vec![0., -1., -2.],
+                vec![1., 0., -3.],
+                vec![2., 3., 0.]
+            ])
+        );
+
+        let data = "%%MatrixMarket matrix array real symmetric\n2 2\n1\n2\n3\n";
+        assert_eq!(
+            Matrix::from_mtx_reader(data.as_bytes()).unwrap(),
+            Matrix::new(vec![vec![1., 2.], vec![2., 3.]])
+        );
+    }
+
+    #[test]
+    fn errors() {
+        let err = |data: &str| Matrix::from_mtx_reader(data.as_bytes()).unwrap_err();
+
+        assert_eq!(
+            err("%%MatrixMarket matrix coordinate complex general\n1 1 1\n1 1 1 0\n").to_string(),
+            "unsupported field complex"
+        );
+        assert_eq!(
+            err("%%MatrixMarket matrix coordinate real general\n2 2 1\n3 1 1\n").to_string(),
+            "\"3\" in line 3 is not an index between 1 and 2"
+        );
+        assert_eq!(
+            err("%%MatrixMarket matrix array real symmetric\n2 3\n").to_string(),
+            "a symmetric matrix has to be square, got 2x3"
+        );
+        assert_eq!(
+            err("%%MatrixMarket matrix coordinate real general\n2 2 2\n1 1 1\n").kind(),
+            io::ErrorKind::InvalidData
+        );
+        assert!(Matrix::from_mtx_reader("1 2\n".as_bytes()).is_err());
+    }
+
+    #[test]
+    fn files() {
+        let path = std::env::temp_dir().join("math_mtx_test.mtx");
+        let sparse = SparseMatrix::from_triplets(3, 2, &[(0, 1, 2.), (2, 0, -1.)]);
+        sparse.write_mtx(&path).unwrap();
+        assert_eq!(SparseMatrix::read_mtx(&path).unwrap(), sparse);
+        sparse.to_dense().write_mtx(&path).unwrap();
+        assert_eq!(Matrix::read_mtx(&path).unwrap(), sparse.to_dense());
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,90 @@
+#[cfg(test)]
+mod tests {
+    use math::linear_algebra::{Matrix, Vector};
+    use math::nn::*;
+    use math::random::Random;
+
+    #[test]
+    fn new() {
+        let dense = Dense::new(3, 2, Activation::Sigmoid, &mut Random::new());
+        assert_eq!(dense.inputs(), 3);
+        assert_eq!(dense.outputs(), 2);
+        assert_eq!(dense.bias(), &Vector::new_zero(2));
+        let limit = (6. / 5_f32).sqrt();
+        assert!(dense
+            .weights()
+            .matrix_flatt()
+            .iter()
+            .all(|w| w.abs() <= limit));
+
+        // layers which share one rng get different weights
+        let mut rng = Random::new();
+        let first = Dense::new(3, 2, Activation::Sigmoid, &mut rng);
+        let second = Dense::new(3, 2, Activation::Sigmoid, &mut rng);
+        assert_ne!(first.weights(), second.weights());
+    }
+
+    #[test]
+    fn forward_batch() {
+        let mut dense = Dense::new(3, 2, Activation::Tanh, &mut Random::new());
+        let input = Matrix::new(vec![vec![1., 2., 3.], vec![-1., 0., 0.5]]);
+        let batch = dense.predict_batch(&input);
+        assert_eq!(batch.col(0), dense.forward(&input.col(0)));
+        assert_eq!(batch.col(1), dense.forward(&input.col(1)));
+    }
+
+    #[test]
+    fn backward_numerical() {
+        let mut dense = Dense::new(3, 2, Activation::Sigmoid, &mut Random::new());
+        let input = Vector::new(vec![0.5, -1., 2.]);
+        let loss = |dense: &mut Dense| -> f32 { dense.forward(&input).vec().iter().sum() };
+
+        loss(&mut dense);
+        let input_grad = dense.backward(&Vector::new(vec![1., 1.]));
+        let weights_grad = dense.weights_grad().clone();
+
+        let eps = 1e-2;
+        let weights = dense.weights().clone();
+        for i in 0..2 {
+            for j in 0..3 {
+                let mut plus = weights.clone();
+                plus.set_index(i, j, weights.index(i, j) + eps);
+                dense.set_weights(plus);
+                let l_plus = loss(&mut dense);
+
+                let mut minus = weights.clone();
+                minus.set_index(i, j, weights.index(i, j) - eps);
+                dense.set_weights(minus);
+                let l_minus = loss(&mut dense);
+
+                let numerical = (l_plus - l_minus) / (2. * eps);
+                assert!((numerical - weights_grad.index(i, j)).abs() < 1e-3);
+            }
+        }
+        assert_eq!(input_grad.len(), 3);
+    }
+
+    #[test]
+    fn train() {
+        // learn y = 2 * x - 1
+        let mut dense = Dense::new(1, 1, Activation::Identity, &mut Random::new());
+        for _ in 0..200 {
+            for &x in [-1., 0., 0.5, 1.].iter() {
+                let y = dense.forward(&Vector::new(vec![x]));
+                let mut grad = y.clone();
+                grad.sub_vec(&Vector::new(vec![2. * x - 1.]));
+                dense.backward(&grad);
+                dense.update(0.1);
+            }
+        }
+        assert!((dense.weights().index(0, 0) - 2.).abs() < 1e-3);
+        assert!((dense.bias().index(0) + 1.).abs() < 1e-3);
+    }
+
+    #[test]
+    #[should_panic(expected = "forward has to be called before backward")]
+    fn backward_panic() {
+        let mut dense = Dense::new(2, 2, Activation::Relu, &mut Random::new());
+        dense.backward(&Vector::new(vec![1., 1.]));
+    }
+}
@@ -0,0 +1,68 @@
+#[cfg(test)]
+mod tests {
+    use math::linear_algebra::Matrix;
+    use math::noise::*;
+
+    // the mean absolute difference of neighbouring values
+    fn roughness(matrix: &Matrix) -> f32 {
+        let mut sum = 0.;
+        for i in 0..matrix.cols() {
+            for j in 1..matrix.rows() {
+                sum += (matrix.index(i, j) - matrix.index(i, j - 1)).abs();
+            }
+        }
+        sum / (matrix.cols() * (matrix.rows() - 1)) as f32
+    }
+
+    // the mean absolute second difference, which grows with the high frequencies
+    fn curvature(matrix: &Matrix) -> f32 {
+        let mut sum = 0.;
+        for i in 0..matrix.cols() {
+            for j in 1..matrix.rows() - 1 {
+                let (a, b, c) = (
+                    matrix.index(i, j - 1),
+                    matrix.index(i, j),
+                    matrix.index(i, j + 1),
+                );
+                sum += (a - 2. * b + c).abs();
+            }
+        }
+        sum / (matrix.cols() * (matrix.rows() - 2)) as f32
+    }
+
+    #[test]
+    fn perlin() {
+        let noise = perlin2d(40, 50, 0.07, 1, 3);
+        assert_eq!((noise.cols(), noise.rows()), (40, 50));
+        let vals = noise.matrix_flatt();
+        assert!(vals.iter().all(|x| x.abs() <= 1.));
+        assert!(vals.iter().any(|&x| x > 0.2) && vals.iter().any(|&x| x < -0.2));
+
+        // coherent: neighbours are close
+        assert!(roughness(&noise) < 0.1);
+        // more octaves add detail
+        assert!(
+            curvature(&perlin2d(40, 50, 0.02, 4, 3))
+                > 2. * curvature(&perlin2d(40, 50, 0.02, 1, 3))
+        );
+
+        assert_eq!(noise, perlin2d(40, 50, 0.07, 1, 3));
+        assert_ne!(noise, perlin2d(40, 50, 0.07, 1, 4));
+    }
+
+    #[test]
+    fn simplex() {
+        let noise = simplex2d(40, 50, 0.07, 1, 3);
+        let vals = noise.matrix_flatt();
+        assert!(vals.iter().all(|x| x.abs() <= 1.));
+        assert!(vals.iter().any(|&x| x > 0.3) && vals.iter().any(|&x| x < -0.3));
+        assert!(roughness(&noise) < 0.15);
+        assert_eq!(noise, simplex2d(40, 50, 0.07, 1, 3));
+    }
+
+    #[test]
+    #[should_panic(expected = "the noise needs at least one octave")]
+    fn no_octaves() {
+        perlin2d(2, 2, 0.1, 0, 0);
+    }
+}
@@ -0,0 +1,73 @@
+#[cfg(test)]
+mod tests {
+    use math::linear_algebra::norm::NormKind;
+    use math::linear_algebra::Matrix;
+
+    #[test]
+    fn norms() {
+        let matrix = Matrix::new(vec![vec![1., 2., 3.], vec![-4., 5., -6.]]);
+        assert_eq!(matrix.norm(NormKind::L1), 9.);
+        assert_eq!(matrix.norm(NormKind::LInf), 15.);
+        assert!((matrix.norm(NormKind::Frobenius) - 91f32.sqrt()).abs() < 1e-5);
+    }
+
+    #[test]
+    fn norms_transposed() {
+        let mut matrix = Matrix::new(vec![vec![1., 2., 3.], vec![-4., 5., -6.]]);
+        let spectral = matrix.norm(NormKind::Spectral);
+        let (l1, linf) = (matrix.norm(NormKind::L1), matrix.norm(NormKind::LInf));
+        matrix.transpose();
+        assert_eq!(matrix.norm(NormKind::L1), linf);
+        assert_eq!(matrix.norm(NormKind::LInf), l1);
+        assert!((matrix.norm(NormKind::Spectral) - spectral).abs() < 1e-5);
+    }
+
+    #[test]
+    fn spectral() {
+        let diagonal = Matrix::new(vec![vec![3., 0., 0.], vec![0., -7., 0.], vec![0., 0., 2.]]);
+        assert!((diagonal.norm(NormKind::Spectral) - 7.).abs() < 1e-5);
+
+        // a rank one matrix u v^T has the spectral norm |u| |v|
+        let rank_one = Matrix::new(vec![vec![1., 2.], vec![2., 4.], vec![3., 6.]]);
+        let expected = 14f32.sqrt() * 5f32.sqrt();
+        assert!((rank_one.norm(NormKind::Spectral) - expected).abs() < 1e-5);
+        assert!(rank_one.norm(NormKind::Spectral) <= rank_one.norm(NormKind::Frobenius) + 1e-5);
+    }
+
+    #[test]
+    fn empty() {
+        let matrix = Matrix::new_zero(0, 0);
+        assert_eq!(matrix.norm(NormKind::Frobenius), 0.);
+        assert_eq!(matrix.norm(NormKind::L1), 0.);
+        assert_eq!(matrix.norm(NormKind::Spectral), 0.);
+    }
+
+    #[test]
+    fn cond() {
+        let diagonal = Matrix::new(vec![vec![1., 0., 0.], vec![0., -1., 0.], vec![0., 0., 1.]]);
+        assert!((diagonal.cond() - 1.).abs() < 1e-6);
+
+        let matrix = Matrix::new(vec![vec![1., 2.], vec![3., 4.]]);
+        // the condition number is |A| |A^-1| in the spectral norm
+        let expected = matrix.norm(NormKind::Spectral) * matrix.inverse().norm(NormKind::Spectral);
+        assert!((matrix.cond() - expected).abs() < 1e-3);
+
+        let hilbert = Matrix::new(
+            (0..4)
+                .map(|i| (0..4).map(|j| 1. / (i + j + 1) as f32).collect())
+                .collect(),
+        );
+        assert!(hilbert.cond() > 1e4);
+
+        assert_eq!(Matrix::new_zero(2, 2).cond(), f32::INFINITY);
+        // the smallest singular value is not exactly 0 because of rounding
+        let singular = Matrix::new(vec![vec![1., 2., 3.], vec![4., 5., 6.], vec![7., 8., 9.]]);
+        assert_eq!(singular.cond(), f32::INFINITY);
+    }
+
+    #[test]
+    #[should_panic(expected = "the matrix has to have at least one component")]
+    fn cond_empty() {
+        Matrix::new_zero(0, 0).cond();
+    }
+}
@@ -0,0 +1,126 @@
+#[cfg(test)]
+mod tests {
+    use math::linear_algebra::{Matrix, Vector};
+    use std::io;
+
+    // a version 1.0 npy file with the header padded to 64 bytes
+    fn npy(header: &str, data: &[u8]) -> Vec<u8> {
+        let mut bytes = b"\x93NUMPY\x01\x00".to_vec();
+        let header = format!("{:<53}\n", header);
+        bytes.extend_from_slice(&(header.len() as u16).to_le_bytes());
+        bytes.extend_from_slice(header.as_bytes());
+        bytes.extend_from_slice(data);
+        bytes
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut matrix = Matrix::new(vec![vec![1., 2., 3.], vec![4., 5., 6.]]);
+        matrix.transpose();
+        let bytes = matrix.to_npy();
+        assert_eq!(&bytes[..8], b"\x93NUMPY\x01\x00");
+        // the values start at a multiple of 64 bytes
+        assert_eq!((bytes.len() - 6 * 4) % 64, 0);
+        assert_eq!(bytes[bytes.len() - 6 * 4 - 1], b'\n');
+
+        let read = Matrix::from_npy(&bytes).unwrap();
+        assert_eq!(read.matrix_flatt(), matrix.matrix_flatt());
+        assert_eq!(read.cols(), 3);
+
+        let vector = Vector::new(vec![0.5, -1., 2.]);
+        let bytes = vector.to_npy();
+        assert!(std::str::from_utf8(&bytes[10..bytes.len() - 12])
+            .unwrap()
+            .contains("'shape': (3,)"));
+        assert_eq!(Vector::from_npy(&bytes).unwrap(), vector);
+    }
+
+    #[test]
+    fn dtypes() {
+        let data: Vec<u8> = [1., -2.5f64].iter().flat_map(|x| x.to_be_bytes()).collect();
+        let bytes = npy(
+            "{'descr': '>f8', 'fortran_order': False, 'shape': (2,), }",
+            &data,
+        );
+        assert_eq!(
+            Vector::from_npy(&bytes).unwrap(),
+            Vector::new(vec![1., -2.5])
+        );
+
+        let bytes = npy(
+            "{'descr': '|i1', 'fortran_order': False, 'shape': (1, 2), }",
+            &[255, 7],
+        );
+        assert_eq!(
+            Matrix::from_npy(&bytes).unwrap(),
+            Matrix::new(vec![vec![-1., 7.]])
+        );
+
+        let data: Vec<u8> = [1u32, 3, 2, 4]
+            .iter()
+            .flat_map(|x| x.to_le_bytes())
+            .collect();
+        let bytes = npy(
+            "{'descr': '<u4', 'fortran_order': True, 'shape': (2, 2), }",
+            &data,
+        );
+        assert_eq!(
+            Matrix::from_npy(&bytes).unwrap(),
+            Matrix::new(vec![vec![1., 2.], vec![3., 4.]])
+        );
+    }
+
+    #[test]
+    fn errors() {
+        let bytes = npy(
+            "{'descr': '<f4', 'fortran_order': False, 'shape': (2,), }",
+            &[0; 8],
+        );
+        let err = Matrix::from_npy(&bytes).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(err.to_string(), "the array has 1 dimensions, expected 2");
+
+        let bytes = npy(
+            "{'descr': '<c8', 'fortran_order': False, 'shape': (1,), }",
+            &[0; 8],
+        );
+        assert_eq!(
+            Vector::from_npy(&bytes).unwrap_err().to_string(),
+            "unsupported dtype <c8"
+        );
+
+        let bytes = npy(
+            "{'descr': '<f4', 'fortran_order': False, 'shape': (3,), }",
+            &[0; 8],
+        );
+        assert!(Vector::from_npy(&bytes).is_err());
+        assert!(Vector::from_npy(b"not a npy file").is_err());
+
+        // the number of values overflows usize
+        let bytes = npy(
+            "{'descr': '<f4', 'fortran_order': False, 'shape': (99999999999, 99999999999), }",
+            &[0; 8],
+        );
+        let err = Matrix::from_npy(&bytes).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(
+            err.to_string(),
+            "the shape [99999999999, 99999999999] is too large"
+        );
+        // the number of bytes overflows usize
+        let header = format!(
+            "{{'descr': '<f8', 'fortran_order': False, 'shape': ({},), }}",
+            usize::MAX / 4
+        );
+        assert!(Vector::from_npy(&npy(&header, &[0; 8])).is_err());
+    }
+
+    #[test]
+    fn files() {
+        let path = std::env::temp_dir().join("math_npy_test.npy");
+        let matrix = Matrix::new(vec![vec![1.5, 2.], vec![3., -4.]]);
+        matrix.write_npy(&path).unwrap();
+        assert_eq!(Matrix::read_npy(&path).unwrap(), matrix);
+        std::fs::remove_file(&path).unwrap();
+    }
+}
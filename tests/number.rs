@@ -0,0 +1,87 @@
+#[cfg(test)]
+mod tests {
+    use math::number::{ModInt, Rational};
+
+    type M13 = ModInt<13>;
+    // the largest prime below 2^64
+    type Big = ModInt<18446744073709551557>;
+
+    #[test]
+    fn arithmetic() {
+        assert_eq!(M13::new(20).value(), 7);
+        assert_eq!(M13::new(3) - M13::new(5), M13::new(11));
+        assert_eq!(-M13::new(0), M13::new(0));
+        assert_eq!(M13::new(2).pow(12), M13::new(1));
+        assert_eq!(M13::new(6) / M13::new(4), M13::new(8));
+        let mut x = M13::new(4);
+        x += M13::new(10);
+        x *= M13::new(3);
+        x -= M13::new(1);
+        x /= M13::new(2);
+        assert_eq!(x, M13::new(1));
+        assert_eq!(format!("{}", M13::from_i64(-27)), "12");
+    }
+
+    #[test]
+    fn inverse_all() {
+        for a in 1..13 {
+            assert_eq!(M13::new(a) * M13::new(a).inv(), M13::new(1));
+        }
+    }
+
+    #[test]
+    fn large_modulus() {
+        let a = Big::new(u64::MAX);
+        assert_eq!(a.value(), 58);
+        let b = -Big::new(1);
+        assert_eq!((b + b).value(), 18446744073709551555);
+        assert_eq!(b * b, Big::new(1));
+        assert_eq!(a * a.inv(), Big::new(1));
+    }
+
+    #[test]
+    #[should_panic(expected = "0 has no inverse modulo 13")]
+    fn zero_inverse() {
+        M13::new(26).inv();
+    }
+
+    #[test]
+    fn rational_arithmetic() {
+        let a = Rational::new(3, 4);
+        let b = Rational::new(-5, 6);
+        assert_eq!(a + b, Rational::new(-1, 12));
+        assert_eq!(a - b, Rational::new(19, 12));
+        assert_eq!(a * b, Rational::new(-5, 8));
+        assert_eq!(a / b, Rational::new(-9, 10));
+        assert_eq!(b.recip(), Rational::new(-6, 5));
+        assert_eq!(b.abs(), Rational::new(5, 6));
+        assert!(b < a);
+        assert_eq!((a / b).denom(), 10);
+        assert_eq!((a / b).numer(), -9);
+        assert_eq!(format!("{}", Rational::new(-8, 4)), "-2");
+        assert_eq!(Rational::new(1, 8).to_f32(), 0.125);
+    }
+
+    #[test]
+    fn rational_exact_sum() {
+        // 0.1 can't be represented as a float but 1/10 is exact
+        let tenth = Rational::new(1, 10);
+        let mut sum = Rational::default();
+        for _ in 0..10 {
+            sum += tenth;
+        }
+        assert_eq!(sum, Rational::from(1));
+    }
+
+    #[test]
+    #[should_panic(expected = "overflowed")]
+    fn rational_overflow() {
+        let _ = Rational::from(i64::MAX) * Rational::from(2);
+    }
+
+    #[test]
+    #[should_panic(expected = "the denominator can't be 0")]
+    fn rational_zero_denominator() {
+        Rational::new(1, 0);
+    }
+}
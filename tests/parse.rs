@@ -0,0 +1,63 @@
+#[cfg(test)]
+mod tests {
+    use math::linear_algebra::parse::ParseMatrixError;
+    use math::linear_algebra::{Matrix, Vector};
+
+    #[test]
+    fn matrix() {
+        let expected = Matrix::new(vec![vec![1., 2., 3.], vec![4., 5., 6.]]);
+        for s in [
+            "1 2 3; 4 5 6",
+            " [1, 2, 3; 4, 5, 6] ",
+            "[1 2 3\n 4 5 6]",
+            "1,2,3;\n;4 5\t6;",
+        ] {
+            assert_eq!(s.parse::<Matrix>(), Ok(expected.clone()), "{:?}", s);
+        }
+        assert_eq!(
+            "-1e-3 inf".parse::<Matrix>(),
+            Ok(Matrix::new(vec![vec![-1e-3, f32::INFINITY]]))
+        );
+    }
+
+    #[test]
+    fn vector() {
+        let expected = Vector::new(vec![1., 2., 3.]);
+        assert_eq!("1 2 3".parse::<Vector>(), Ok(expected.clone()));
+        assert_eq!("[1; 2; 3]".parse::<Vector>(), Ok(expected));
+        assert_eq!("[7]".parse::<Vector>(), Ok(Vector::new(vec![7.])));
+        assert_eq!(
+            "1 2; 3 4".parse::<Vector>(),
+            Err(ParseMatrixError::NotVector)
+        );
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            "1 2; 3".parse::<Matrix>(),
+            Err(ParseMatrixError::LineLen {
+                line: 2,
+                expected: 2,
+                got: 1
+            })
+        );
+        assert_eq!(
+            "[1 2".parse::<Matrix>().unwrap_err().to_string(),
+            "the closing ] is missing"
+        );
+        assert_eq!("[ ; ]".parse::<Matrix>(), Err(ParseMatrixError::Empty));
+        assert_eq!("".parse::<Vector>(), Err(ParseMatrixError::Empty));
+        assert_eq!(
+            "1 [2]".parse::<Matrix>(),
+            Err(ParseMatrixError::InvalidNumber {
+                value: "[2]".to_string(),
+                pos: 2
+            })
+        );
+        assert_eq!(
+            "1 2; 3 4x".parse::<Matrix>().unwrap_err().to_string(),
+            "\"4x\" at position 7 is not a number"
+        );
+    }
+}
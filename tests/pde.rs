@@ -0,0 +1,121 @@
+#[cfg(test)]
+mod tests {
+    use math::linear_algebra::{solve_tridiagonal, Matrix, Vector};
+    use math::pde::{HeatEquation, Scheme};
+    use std::f32::consts::PI;
+
+    // sin(pi x) sin(pi y) on the unit square, which decays with exp(-2 pi^2 alpha t)
+    fn sine_mode(n: usize) -> Matrix {
+        let dx = 1. / (n - 1) as f32;
+        let mut grid = Matrix::new_zero(n, n);
+        for i in 0..n {
+            for j in 0..n {
+                let val = (PI * i as f32 * dx).sin() * (PI * j as f32 * dx).sin();
+                // the edges are exactly 0
+                grid.set_index(
+                    i,
+                    j,
+                    if i == 0 || j == 0 || i == n - 1 || j == n - 1 {
+                        0.
+                    } else {
+                        val
+                    },
+                );
+            }
+        }
+        grid
+    }
+
+    #[test]
+    fn heat_decay() {
+        let n = 21;
+        let dx = 1. / (n - 1) as f32;
+        let (alpha, t) = (0.1, 0.5);
+        let expected = (-2. * PI * PI * alpha * t).exp();
+
+        for &(scheme, dt) in [(Scheme::Explicit, 0.0005), (Scheme::Implicit, 0.005)].iter() {
+            let heat = HeatEquation::new(alpha, dx, dt, scheme);
+            let steps = (t / dt).round() as usize;
+            let snapshots = heat.run(&sine_mode(n), steps, steps);
+            assert_eq!(snapshots.len(), 2);
+            let ratio = snapshots[1].index(10, 10) / snapshots[0].index(10, 10);
+            assert!(
+                (ratio - expected).abs() < 0.03 * expected,
+                "{:?} {} {}",
+                scheme,
+                ratio,
+                expected
+            );
+            // the edges stay fixed
+            assert_eq!(snapshots[1].col(0), snapshots[0].col(0));
+        }
+    }
+
+    #[test]
+    fn implicit_large_step() {
+        // far beyond the explicit limit, but the implicit scheme stays bounded
+        let heat = HeatEquation::new(1., 1., 10., Scheme::Implicit);
+        let mut grid = Matrix::new_zero(6, 7);
+        grid.set_index(3, 3, 100.);
+        let snapshots = heat.run(&grid, 20, 1);
+        for snapshot in snapshots.iter() {
+            assert!(snapshot.matrix_flatt().iter().all(|x| x.abs() <= 100.));
+        }
+        assert!(snapshots[20].index(3, 3).abs() < 1.);
+
+        // a hot edge heats the inside towards the steady state
+        let mut grid = Matrix::new_zero(5, 5);
+        for j in 0..5 {
+            grid.set_index(0, j, 1.);
+        }
+        let last = heat.run(&grid, 200, 200).pop().unwrap();
+        // the solution of the discrete Laplace equation
+        assert!((last.index(1, 2) - 0.5268).abs() < 1e-3);
+        assert!((last.index(2, 2) - 0.25).abs() < 1e-3);
+        assert!((last.index(3, 1) - 0.0714).abs() < 1e-3);
+        assert!(last.index(1, 2) > last.index(2, 2) && last.index(2, 2) > last.index(3, 2));
+    }
+
+    #[test]
+    #[should_panic(expected = "the explicit scheme is unstable")]
+    fn explicit_unstable() {
+        HeatEquation::new(1., 1., 0.3, Scheme::Explicit);
+    }
+
+    #[test]
+    fn tridiagonal() {
+        let lower = Vector::new(vec![-1., 2., 0.5]);
+        let diag = Vector::new(vec![4., 5., 6., 3.]);
+        let upper = Vector::new(vec![1., -2., 1.]);
+        let x = Vector::new(vec![1., -2., 3., 0.5]);
+        let mut dense = Matrix::new_zero(4, 4);
+        for i in 0..4 {
+            dense.set_index(i, i, diag.index(i));
+            if i > 0 {
+                dense.set_index(i, i - 1, lower.index(i - 1));
+                dense.set_index(i - 1, i, upper.index(i - 1));
+            }
+        }
+        let rhs = dense.dot_vec(&x);
+        let solved = solve_tridiagonal(&lower, &diag, &upper, &rhs);
+        for i in 0..4 {
+            assert!((solved.index(i) - x.index(i)).abs() < 1e-5);
+        }
+        assert_eq!(
+            solve_tridiagonal(
+                &Vector::new(vec![]),
+                &Vector::new(vec![2.]),
+                &Vector::new(vec![]),
+                &Vector::new(vec![4.])
+            ),
+            Vector::new(vec![2.])
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "the lower and upper diagonal have to have the len 2")]
+    fn tridiagonal_shape() {
+        let v = Vector::new(vec![1., 1., 1.]);
+        solve_tridiagonal(&v, &v, &v, &v);
+    }
+}
@@ -0,0 +1,136 @@
+#[cfg(test)]
+mod tests {
+    use math::linear_algebra::Vector;
+    use math::polynomial::{polyfit, polyval, Polynomial};
+
+    #[test]
+    fn new() {
+        let p = Polynomial::new(vec![1., 0., 2., 0., 0.]);
+        assert_eq!(p.coeffs(), &[1., 0., 2.]);
+        assert_eq!(p.degree(), 2);
+        assert!(Polynomial::new(vec![0., 0.]).is_zero());
+        assert_eq!(Polynomial::new(vec![0.]), Polynomial::zero());
+        assert_eq!(Polynomial::zero().degree(), 0);
+        assert_eq!(Polynomial::monomial(3., 2).coeffs(), &[0., 0., 3.]);
+    }
+
+    #[test]
+    fn eval() {
+        let p = Polynomial::new(vec![0.5, -1., 0., 2.]);
+        for &x in [-2., 0., 0.5, 3.].iter() {
+            assert_eq!(p.eval(x), 2. * x * x * x - x + 0.5);
+        }
+        assert_eq!(Polynomial::zero().eval(5.), 0.);
+    }
+
+    #[test]
+    fn ops() {
+        let a = Polynomial::new(vec![1., 2., 3.]);
+        let b = Polynomial::new(vec![-1., 1.]);
+        assert_eq!(&a + &b, Polynomial::new(vec![0., 3., 3.]));
+        assert_eq!(&a - &a, Polynomial::zero());
+        assert_eq!(a.clone() - b.clone(), Polynomial::new(vec![2., 1., 3.]));
+        assert_eq!(&a * &b, Polynomial::new(vec![-1., -1., -1., 3.]));
+        assert_eq!(&a * &Polynomial::zero(), Polynomial::zero());
+        assert_eq!(-b.clone() * 2., Polynomial::new(vec![2., -2.]));
+    }
+
+    #[test]
+    fn div_rem() {
+        // x^3 - 2x^2 - 4 = (x - 3)(x^2 + x + 3) + 5
+        let p = Polynomial::new(vec![-4., 0., -2., 1.]);
+        let d = Polynomial::new(vec![-3., 1.]);
+        let (q, r) = p.div_rem(&d);
+        assert_eq!(q, Polynomial::new(vec![3., 1., 1.]));
+        assert_eq!(r, Polynomial::constant(5.));
+        assert_eq!(&(&q * &d) + &r, p);
+        assert_eq!(&p / &d, q);
+        assert_eq!(&p % &d, r);
+
+        // a divisor with a bigger degree leaves everything in the remainder
+        let (q, r) = d.div_rem(&p);
+        assert_eq!((q, r), (Polynomial::zero(), d.clone()));
+
+        // a non monic divisor
+        let (q, r) = Polynomial::new(vec![1., 0., 4.]).div_rem(&Polynomial::new(vec![0., 2.]));
+        assert_eq!(
+            (q, r),
+            (Polynomial::new(vec![0., 2.]), Polynomial::constant(1.))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "division by the zero polynomial")]
+    fn div_zero() {
+        Polynomial::new(vec![1., 2.]).div_rem(&Polynomial::zero());
+    }
+
+    #[test]
+    fn calculus() {
+        let p = Polynomial::new(vec![5., 0., 3., 4.]);
+        assert_eq!(p.derivative(), Polynomial::new(vec![0., 6., 12.]));
+        assert_eq!(Polynomial::constant(2.).derivative(), Polynomial::zero());
+        assert_eq!(
+            p.antiderivative(),
+            Polynomial::new(vec![0., 5., 0., 1., 1.])
+        );
+        assert_eq!(Polynomial::zero().antiderivative(), Polynomial::zero());
+        // the integral of 3x^2 from 0 to 2 is 8
+        assert_eq!(Polynomial::monomial(3., 2).integrate(0., 2.), 8.);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            Polynomial::new(vec![0.5, -1., 0., 2.]).to_string(),
+            "2x^3 - x + 0.5"
+        );
+        assert_eq!(Polynomial::new(vec![0., -1.]).to_string(), "-x");
+        assert_eq!(Polynomial::new(vec![-3.]).to_string(), "-3");
+        assert_eq!(Polynomial::zero().to_string(), "0");
+    }
+
+    #[test]
+    fn polyfit_exact() {
+        // samples of a quadratic are fitted exactly
+        let p = Polynomial::new(vec![1., -2., 0.5]);
+        let x = Vector::new(vec![-2., -1., 0., 0.5, 1., 2.]);
+        let fit = polyfit(&x, &polyval(&p, &x), 2);
+        for (a, b) in fit.coeffs().iter().zip(p.coeffs().iter()) {
+            assert!((a - b).abs() < 1e-5);
+        }
+        // a higher degree finds 0 for the extra coefficient
+        let fit = polyfit(&x, &p.eval_vec(&x), 3);
+        assert!(fit.coeffs().get(3).map_or(true, |c| c.abs() < 1e-4));
+    }
+
+    #[test]
+    fn polyfit_least_squares() {
+        // the best constant is the mean and the residual of the best line is orthogonal to x
+        let x = Vector::new(vec![0., 1., 2., 3., 4.]);
+        let y = Vector::new(vec![1., 3., 2., 5., 4.]);
+        let constant = polyfit(&x, &y, 0);
+        assert!((constant.coeffs()[0] - 3.).abs() < 1e-5);
+
+        let line = polyfit(&x, &y, 1);
+        let residual: Vec<f32> = polyval(&line, &x)
+            .vec()
+            .iter()
+            .zip(y.vec().iter())
+            .map(|(a, b)| a - b)
+            .collect();
+        assert!(residual.iter().sum::<f32>().abs() < 1e-4);
+        let dot: f32 = residual
+            .iter()
+            .zip(x.vec().iter())
+            .map(|(r, x)| r * x)
+            .sum();
+        assert!(dot.abs() < 1e-4);
+    }
+
+    #[test]
+    #[should_panic(expected = "a polynomial of degree 2 needs at least 3 samples, got 2")]
+    fn polyfit_too_few_samples() {
+        polyfit(&Vector::new(vec![0., 1.]), &Vector::new(vec![0., 1.]), 2);
+    }
+}
@@ -0,0 +1,119 @@
+#[cfg(test)]
+mod tests {
+    use math::linear_algebra::{Matrix, Vector};
+    use math::preprocessing::*;
+
+    #[test]
+    fn standard_scaler() {
+        let train = Matrix::new(vec![vec![2., 0., 5.], vec![4., 0., 5.], vec![6., 3., 5.]]);
+        let mut scaler = StandardScaler::new();
+        let scaled = scaler.fit_transform(&train);
+        assert_eq!(scaler.mean(), Some(&Vector::new(vec![4., 1., 5.])));
+        assert_eq!(scaled.row(2).vec(), vec![0., 0., 0.]);
+        assert!(scaled.row(0).vec().iter().sum::<f32>().abs() < 1e-6);
+        let restored = scaler.inverse_transform(&scaled).matrix_flatt();
+        for (x, y) in restored.iter().zip(train.matrix_flatt().iter()) {
+            assert!((x - y).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn min_max_scaler() {
+        let train = Matrix::new(vec![vec![-1., 2.], vec![1., 2.]]);
+        let mut scaler = MinMaxScaler::new();
+        scaler.fit(&train);
+        let test = Matrix::new(vec![vec![0., 2.], vec![3., 4.]]);
+        assert_eq!(
+            scaler.transform(&test),
+            Matrix::new(vec![vec![0.5, 0.], vec![2., 2.]])
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "the scaler has to be fitted before it can transform")]
+    fn not_fitted() {
+        let scaler = StandardScaler::new();
+        scaler.transform(&Matrix::new(vec![vec![1.]]));
+    }
+
+    #[test]
+    #[should_panic(expected = "wrong number of features expected 2, got 3")]
+    fn wrong_features() {
+        let mut scaler = MinMaxScaler::new();
+        scaler.fit(&Matrix::new(vec![vec![1., 2.]]));
+        scaler.transform(&Matrix::new(vec![vec![1., 2., 3.]]));
+    }
+
+    #[test]
+    fn normalizer_matches_scalers() {
+        let train = Matrix::new(vec![vec![2., 0., 5.], vec![4., 0., 5.], vec![6., 3., 5.]]);
+        let test = Matrix::new(vec![vec![1., 2., 3.]]);
+
+        let mut normalizer = Normalizer::new(Normalization::ZScore);
+        let mut scaler = StandardScaler::new();
+        normalizer.fit(&train);
+        scaler.fit(&train);
+        assert_eq!(normalizer.method(), Normalization::ZScore);
+        assert_eq!(normalizer.center(), scaler.mean());
+        assert_eq!(normalizer.transform(&test), scaler.transform(&test));
+
+        let mut normalizer = Normalizer::new(Normalization::MinMax);
+        let mut scaler = MinMaxScaler::new();
+        normalizer.fit(&train);
+        scaler.fit(&train);
+        assert_eq!(normalizer.transform(&test), scaler.transform(&test));
+        assert_eq!(
+            normalizer.inverse_transform(&test),
+            scaler.inverse_transform(&test)
+        );
+    }
+
+    #[test]
+    fn normalizer_robust() {
+        // the features [1, 2, 3, 4] and [0, 0, 0, 10], the second has an iqr of 2.5
+        let train = Matrix::new(vec![
+            vec![1., 0.],
+            vec![2., 0.],
+            vec![3., 0.],
+            vec![4., 10.],
+        ]);
+        let mut normalizer = Normalizer::new(Normalization::Robust);
+        let scaled = normalizer.fit_transform(&train);
+        assert_eq!(normalizer.center(), Some(&Vector::new(vec![2.5, 0.])));
+        assert_eq!(normalizer.scale(), Some(&Vector::new(vec![1.5, 2.5])));
+        assert_eq!(scaled.row(1).vec(), vec![0., 0., 0., 4.]);
+        assert_eq!(normalizer.inverse_transform(&scaled), train);
+
+        // a constant feature is only shifted
+        let constant = Matrix::new(vec![vec![7.], vec![7.]]);
+        normalizer.fit(&constant);
+        assert_eq!(normalizer.scale(), Some(&Vector::new(vec![1.])));
+        assert_eq!(
+            normalizer.transform(&constant),
+            Matrix::new(vec![vec![0.], vec![0.]])
+        );
+    }
+
+    #[test]
+    fn normalizer_robust_nan() {
+        // the NaN is ignored, so the center and scale are the ones of [1, 2, 3, 4, 100]
+        let train = Matrix::new(vec![
+            vec![1.],
+            vec![f32::NAN],
+            vec![2.],
+            vec![3.],
+            vec![4.],
+            vec![100.],
+        ]);
+        let mut normalizer = Normalizer::new(Normalization::Robust);
+        normalizer.fit(&train);
+        assert_eq!(normalizer.center(), Some(&Vector::new(vec![3.])));
+        assert_eq!(normalizer.scale(), Some(&Vector::new(vec![2.])));
+    }
+
+    #[test]
+    #[should_panic(expected = "the normalizer has to be fitted before it can transform")]
+    fn normalizer_not_fitted() {
+        Normalizer::new(Normalization::Robust).inverse_transform(&Matrix::new(vec![vec![1.]]));
+    }
+}
@@ -47,4 +47,63 @@ mod tests {
         assert_eq!(rand.f64(), 0.44477898328394805);
         assert_eq!(rand.f64(), 0.9650074960886351);
     }
+
+    #[test]
+    #[should_panic(expected = "max has to be greater than 0")]
+    fn usize_zero() {
+        Random::new().usize(0);
+    }
+
+    #[test]
+    fn halton() {
+        let mut halton = Halton::new(2);
+        assert_eq!(halton.next_vec().vec(), vec![0.5, 0.33333334]);
+        assert_eq!(halton.next_vec().vec(), vec![0.25, 0.6666667]);
+        assert_eq!(halton.next_vec().vec(), vec![0.75, 0.11111111]);
+        assert_eq!(halton.next_vec().vec(), vec![0.125, 0.44444445]);
+    }
+
+    #[test]
+    fn sobol() {
+        let mut sobol = Sobol::new(3);
+        assert_eq!(sobol.next_vec().vec(), vec![0., 0., 0.]);
+        assert_eq!(sobol.next_vec().vec(), vec![0.5, 0.5, 0.5]);
+        assert_eq!(sobol.next_vec().vec(), vec![0.75, 0.25, 0.25]);
+        assert_eq!(sobol.next_vec().vec(), vec![0.25, 0.75, 0.75]);
+        assert_eq!(sobol.next_vec().vec(), vec![0.375, 0.375, 0.625]);
+    }
+
+    #[test]
+    fn sobol_integration() {
+        // the integral of x * y over the unit square is 0.25
+        let mut sobol = Sobol::new(2);
+        let points = sobol.matrix(1024);
+        let mut sum = 0.;
+        for i in 0..points.cols() {
+            let point = points.col(i);
+            sum += point.index(0) * point.index(1);
+        }
+        assert!((sum / 1024. - 0.25).abs() < 0.001);
+    }
+
+    #[test]
+    fn reservoir_uniform() {
+        let mut rand = Random::new();
+        let mut counts = [0; 10];
+        for _ in 0..5000 {
+            let sample = reservoir_sample((0..10).map(|x| x as f32), 2, &mut rand);
+            assert_eq!(sample.len(), 2);
+            assert_ne!(sample.index(0), sample.index(1));
+            for i in 0..2 {
+                counts[sample.index(i) as usize] += 1;
+            }
+        }
+        // each value is drawn with a probability of 2 / 10
+        for count in counts.iter() {
+            assert!((850..1150).contains(count), "{:?}", counts);
+        }
+
+        assert_eq!(reservoir_sample(vec![], 2, &mut rand).len(), 0);
+        assert_eq!(reservoir_sample(vec![1., 2.], 0, &mut rand).len(), 0);
+    }
 }
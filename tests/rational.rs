@@ -0,0 +1,57 @@
+#[cfg(test)]
+mod tests {
+    use math::linear_algebra::rational::RationalMatrix;
+    use math::number::Rational;
+
+    fn hilbert(n: usize) -> RationalMatrix {
+        let mut matrix = RationalMatrix::new_zero(n, n);
+        for i in 0..n {
+            for j in 0..n {
+                matrix.set_index(i, j, Rational::new(1, (i + j + 1) as i64));
+            }
+        }
+        matrix
+    }
+
+    #[test]
+    fn hilbert_inverse() {
+        // the hilbert matrix is badly conditioned, but its inverse has integer values
+        let matrix = hilbert(5);
+        let inverse = matrix.inverse().unwrap();
+        assert_eq!(matrix.dot_mat(&inverse), RationalMatrix::identity(5));
+        assert_eq!(inverse.index(0, 0), Rational::from(25));
+        assert_eq!(inverse.index(3, 3), Rational::from(179200));
+        assert_eq!(inverse.index(4, 3), Rational::from(-88200));
+        assert_eq!(matrix.det(), Rational::new(1, 266716800000));
+    }
+
+    #[test]
+    fn solve() {
+        let matrix = RationalMatrix::from_ints(vec![vec![1, 2, 3], vec![0, 1, 4], vec![5, 6, 0]]);
+        let b = vec![Rational::from(1), Rational::new(1, 2), Rational::from(0)];
+        let x = matrix.solve(&b).unwrap();
+        assert_eq!(matrix.dot_vec(&x), b);
+        assert_eq!(matrix.det(), Rational::from(1));
+        assert_eq!(matrix.transpose().det(), matrix.det());
+    }
+
+    #[test]
+    fn singular() {
+        let matrix = RationalMatrix::from_ints(vec![vec![1, 2], vec![2, 4]]);
+        assert!(matrix.det().is_zero());
+        assert_eq!(matrix.inverse(), None);
+        assert_eq!(matrix.solve(&[Rational::from(1), Rational::from(2)]), None);
+    }
+
+    #[test]
+    fn to_matrix() {
+        let matrix = RationalMatrix::new(vec![vec![Rational::new(1, 2), Rational::new(-3, 4)]]);
+        assert_eq!(matrix.to_matrix().matrix_flatt(), vec![0.5, -0.75]);
+    }
+
+    #[test]
+    #[should_panic(expected = "square matrix")]
+    fn inverse_not_square() {
+        RationalMatrix::from_ints(vec![vec![1, 2]]).inverse();
+    }
+}
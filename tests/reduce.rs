@@ -0,0 +1,49 @@
+#[cfg(test)]
+mod tests {
+    use math::linear_algebra::shape::Axis;
+    use math::linear_algebra::{Matrix, Vector};
+
+    #[test]
+    fn log_sum_exp() {
+        let vector = Vector::new(vec![1., 2., 3.]);
+        let naive = vector.vec().iter().map(|x| x.exp()).sum::<f32>().ln();
+        assert!((vector.log_sum_exp() - naive).abs() < 1e-6);
+
+        assert_eq!(Vector::new(vec![]).log_sum_exp(), f32::NEG_INFINITY);
+        assert_eq!(Vector::new(vec![f32::NEG_INFINITY, 0.]).log_sum_exp(), 0.);
+        assert_eq!(
+            Vector::new(vec![f32::INFINITY, 0.]).log_sum_exp(),
+            f32::INFINITY
+        );
+        assert!(Vector::new(vec![1e30, -1e30]).log_sum_exp().is_finite());
+    }
+
+    #[test]
+    fn log_softmax_axis() {
+        let matrix = Matrix::new(vec![vec![1., 2., 3.], vec![200., 100., 0.]]);
+
+        let cols = matrix.log_softmax(Axis::Col);
+        for i in 0..matrix.cols() {
+            let sum: f32 = (0..matrix.rows()).map(|j| cols.index(i, j).exp()).sum();
+            assert!((sum - 1.).abs() < 1e-6);
+            assert!(cols.col(i).is_finite());
+        }
+
+        let rows = matrix.log_softmax(Axis::Row);
+        for j in 0..matrix.rows() {
+            let sum: f32 = (0..matrix.cols()).map(|i| rows.index(i, j).exp()).sum();
+            assert!((sum - 1.).abs() < 1e-6);
+        }
+        assert_eq!(rows.index(1, 0), 0.);
+    }
+
+    #[test]
+    fn log_softmax_transposed() {
+        let mut matrix = Matrix::new(vec![vec![1., 2., 3.], vec![4., 5., 6.]]);
+        let expected = matrix.log_softmax(Axis::Row);
+        matrix.transpose();
+        let mut result = matrix.log_softmax(Axis::Col);
+        result.transpose();
+        assert_eq!(result, expected);
+    }
+}
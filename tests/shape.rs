@@ -0,0 +1,118 @@
+#[cfg(test)]
+mod tests {
+    use math::linear_algebra::shape::{Axis, Shape, StorageOrder};
+    use math::linear_algebra::{Matrix, Vector};
+
+    #[test]
+    fn broadcast() {
+        let shape = Shape::new(2, 3);
+        assert_eq!(shape.broadcast(&shape), Some(shape));
+        assert_eq!(shape.broadcast(&Shape::new(1, 1)), Some(shape));
+        assert_eq!(Shape::new(1, 3).broadcast(&Shape::new(2, 1)), Some(shape));
+        assert_eq!(shape.broadcast(&Shape::new(3, 2)), None);
+        assert_eq!(format!("{}", shape), "2x3");
+    }
+
+    #[test]
+    fn vec_axis() {
+        let matrix = Matrix::new(vec![vec![1., 2., 3.], vec![4., 5., 6.]]);
+
+        let mut col = matrix.clone();
+        col.add_vec_axis(Axis::Col, &Vector::new(vec![10., 20., 30.]));
+        assert_eq!(
+            col,
+            Matrix::new(vec![vec![11., 22., 33.], vec![14., 25., 36.]])
+        );
+
+        let mut row = matrix.clone();
+        row.mul_vec_axis(Axis::Row, &Vector::new(vec![2., -1.]));
+        assert_eq!(
+            row,
+            Matrix::new(vec![vec![2., 4., 6.], vec![-4., -5., -6.]])
+        );
+    }
+
+    #[test]
+    fn vec_axis_transposed() {
+        let mut matrix = Matrix::new(vec![vec![1., 2., 3.], vec![4., 5., 6.]]);
+        matrix.transpose();
+        matrix.sub_vec_axis(Axis::Col, &Vector::new(vec![1., 4.]));
+        assert_eq!(matrix.matrix_flatt(), vec![0., 0., 1., 1., 2., 2.]);
+
+        matrix.div_vec_axis(Axis::Row, &Vector::new(vec![1., 1., 2.]));
+        assert_eq!(matrix.matrix_flatt(), vec![0., 0., 1., 1., 1., 1.]);
+    }
+
+    #[test]
+    #[should_panic(expected = "can't broadcast shape 3x1 to 2x3")]
+    fn vec_axis_panic() {
+        let mut matrix = Matrix::new(vec![vec![1., 2., 3.], vec![4., 5., 6.]]);
+        matrix.add_vec_axis(Axis::Row, &Vector::new(vec![1., 2., 3.]));
+    }
+
+    #[test]
+    fn reshape() {
+        let mut matrix = Matrix::new(vec![vec![1., 2., 3.], vec![4., 5., 6.]]);
+        assert_eq!(matrix.reshape(1, 6).flatten(), matrix.flatten());
+        assert_eq!(matrix.reshape(6, 1).shape(), Shape::new(6, 1));
+
+        matrix.transpose();
+        assert_eq!(
+            matrix.reshape(2, 3),
+            Matrix::new(vec![vec![1., 4., 2.], vec![5., 3., 6.]])
+        );
+        assert_eq!(matrix.flatten(), Vector::new(vec![1., 4., 2., 5., 3., 6.]));
+    }
+
+    #[test]
+    #[should_panic(expected = "can't reshape a 2x3 matrix to 4x2")]
+    fn reshape_panic() {
+        Matrix::new_zero(2, 3).reshape(4, 2);
+    }
+
+    #[test]
+    fn resize() {
+        let mut matrix = Matrix::new(vec![vec![1., 2.], vec![3., 4.]]);
+        matrix.resize(2, 3, -1.);
+        assert_eq!(
+            matrix,
+            Matrix::new(vec![vec![1., 2., -1.], vec![3., 4., -1.]])
+        );
+
+        matrix.transpose();
+        matrix.resize(2, 2, 0.);
+        assert_eq!(matrix.index(0, 1), 3.);
+        assert_eq!(matrix.index(1, 1), 4.);
+
+        matrix.resize(0, 0, 0.);
+        assert_eq!(matrix.shape(), Shape::new(0, 0));
+    }
+
+    #[test]
+    fn storage_order() {
+        let matrix = Matrix::new(vec![vec![1., 2., 3.], vec![4., 5., 6.]]);
+        let row_major = vec![1., 4., 2., 5., 3., 6.];
+        assert_eq!(matrix.to_flatt_order(StorageOrder::RowMajor), row_major);
+        assert_eq!(
+            Matrix::from_flatt_order(row_major, 2, 3, StorageOrder::RowMajor),
+            matrix
+        );
+        assert_eq!(
+            Matrix::from_flatt_order(matrix.matrix_flatt(), 2, 3, StorageOrder::ColMajor),
+            matrix
+        );
+
+        let mut transposed = matrix.clone();
+        transposed.transpose();
+        assert_eq!(
+            transposed.to_flatt_order(StorageOrder::RowMajor),
+            matrix.matrix_flatt()
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn storage_order_wrong_len() {
+        Matrix::from_flatt_order(vec![1., 2., 3.], 2, 2, StorageOrder::RowMajor);
+    }
+}
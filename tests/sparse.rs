@@ -0,0 +1,163 @@
+#[cfg(test)]
+mod tests {
+    use math::linear_algebra::sparse::{Ordering, SparseMatrix};
+    use math::linear_algebra::{Matrix, Vector};
+
+    // the 5-point laplacian (negated) on a n x n grid
+    fn laplacian(n: usize) -> SparseMatrix {
+        let mut triplets = Vec::new();
+        for a in 0..n {
+            for b in 0..n {
+                let k = a * n + b;
+                triplets.push((k, k, 4.));
+                if a > 0 {
+                    triplets.push((k, k - n, -1.));
+                }
+                if a + 1 < n {
+                    triplets.push((k, k + n, -1.));
+                }
+                if b > 0 {
+                    triplets.push((k, k - 1, -1.));
+                }
+                if b + 1 < n {
+                    triplets.push((k, k + 1, -1.));
+                }
+            }
+        }
+        SparseMatrix::from_triplets(n * n, n * n, &triplets)
+    }
+
+    fn assert_close(a: &Vector, b: &Vector, eps: f32) {
+        assert_eq!(a.len(), b.len());
+        for i in 0..a.len() {
+            assert!(
+                (a.index(i) - b.index(i)).abs() < eps,
+                "{} != {} at {}",
+                a.index(i),
+                b.index(i),
+                i
+            );
+        }
+    }
+
+    #[test]
+    fn dense_round_trip() {
+        let dense = Matrix::new(vec![
+            vec![1., 0., 2.],
+            vec![0., 0., 0.],
+            vec![0., 3., 0.],
+            vec![4., 0., 5.],
+        ]);
+        let sparse = SparseMatrix::from_dense(&dense);
+        assert_eq!(sparse.cols(), 4);
+        assert_eq!(sparse.rows(), 3);
+        assert_eq!(sparse.nnz(), 5);
+        assert_eq!(sparse.index(3, 2), 5.);
+        assert_eq!(sparse.index(1, 1), 0.);
+        assert_eq!(sparse.to_dense(), dense);
+
+        let transposed = sparse.transpose();
+        assert_eq!((transposed.cols(), transposed.rows()), (3, 4));
+        for i in 0..4 {
+            for j in 0..3 {
+                assert_eq!(transposed.index(j, i), dense.index(i, j));
+            }
+        }
+
+        let v = Vector::new(vec![1., 2., 3.]);
+        assert_eq!(sparse.dot_vec(&v), dense.dot_vec(&v));
+    }
+
+    #[test]
+    fn cholesky_laplacian() {
+        let matrix = laplacian(8);
+        let b = Vector::new((0..64).map(|i| (i % 7) as f32 - 3.).collect());
+        let expected = matrix.to_dense().solve(&b);
+
+        let natural = matrix.cholesky(Ordering::Natural);
+        let amd = matrix.cholesky(Ordering::MinimumDegree);
+        assert_close(&natural.solve(&b), &expected, 1e-3);
+        assert_close(&amd.solve(&b), &expected, 1e-3);
+        assert_close(&matrix.dot_vec(&amd.solve(&b)), &b, 1e-3);
+        assert!(amd.nnz() < natural.nnz());
+    }
+
+    #[test]
+    fn lu_nonsymmetric() {
+        let dense = Matrix::new(vec![
+            vec![0., 2., 0., 1., 0.],
+            vec![3., 0., 0., 0., 1.],
+            vec![0., 1., 4., 0., 0.],
+            vec![1., 0., 0., 0., 2.],
+            vec![0., 0., 1., 5., 0.],
+        ]);
+        let sparse = SparseMatrix::from_dense(&dense);
+        let b = Vector::new(vec![1., 2., 3., 4., 5.]);
+        let expected = dense.solve(&b);
+        for &ordering in [Ordering::Natural, Ordering::MinimumDegree].iter() {
+            let x = sparse.lu(ordering).solve(&b);
+            assert_close(&x, &expected, 1e-4);
+            assert_close(&sparse.dot_vec(&x), &b, 1e-4);
+        }
+        assert_close(&sparse.solve(&b), &expected, 1e-4);
+    }
+
+    #[test]
+    fn lu_laplacian() {
+        let matrix = laplacian(6);
+        let b = Vector::new(vec![1.; 36]);
+        let x = matrix.lu(Ordering::MinimumDegree).solve(&b);
+        assert_close(&matrix.dot_vec(&x), &b, 1e-4);
+    }
+
+    #[test]
+    fn arrowhead_fill() {
+        // the first index is connected to all others, eliminating it first fills the whole matrix
+        let n = 20;
+        let mut triplets = vec![(0, 0, n as f32)];
+        for i in 1..n {
+            triplets.push((i, i, 2.));
+            triplets.push((0, i, 1.));
+            triplets.push((i, 0, 1.));
+        }
+        let matrix = SparseMatrix::from_triplets(n, n, &triplets);
+        let natural = matrix.cholesky(Ordering::Natural);
+        let amd = matrix.cholesky(Ordering::MinimumDegree);
+        assert_eq!(natural.nnz(), n * (n + 1) / 2);
+        assert_eq!(amd.nnz(), 2 * n - 1);
+        // the center is only eliminated when a single neighbour is left
+        assert!(amd.perm()[..n - 2].iter().all(|&i| i != 0));
+
+        let b = Vector::new(vec![1.; n]);
+        assert_close(&natural.solve(&b), &amd.solve(&b), 1e-5);
+        assert_close(&matrix.dot_vec(&amd.solve(&b)), &b, 1e-4);
+    }
+
+    #[test]
+    fn duplicate_triplets() {
+        let sparse = SparseMatrix::from_triplets(2, 2, &[(0, 0, 1.), (1, 1, 2.), (0, 0, 2.)]);
+        assert_eq!(sparse.nnz(), 2);
+        assert_eq!(sparse.index(0, 0), 3.);
+    }
+
+    #[test]
+    #[should_panic(expected = "the matrix is singular")]
+    fn lu_singular() {
+        let sparse = SparseMatrix::from_triplets(3, 3, &[(0, 0, 1.), (1, 1, 1.), (2, 1, 1.)]);
+        sparse.lu(Ordering::Natural);
+    }
+
+    #[test]
+    #[should_panic(expected = "the matrix is not positive definite")]
+    fn cholesky_not_positive_definite() {
+        let sparse =
+            SparseMatrix::from_triplets(2, 2, &[(0, 0, 1.), (0, 1, 2.), (1, 0, 2.), (1, 1, 1.)]);
+        sparse.cholesky(Ordering::Natural);
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn triplet_out_of_bounds() {
+        SparseMatrix::from_triplets(2, 2, &[(2, 0, 1.)]);
+    }
+}
@@ -0,0 +1,199 @@
+#[cfg(test)]
+mod tests {
+    use math::linear_algebra::shape::Axis;
+    use math::linear_algebra::{Matrix, Vector};
+    use math::statistics::{Interpolation, RunningStats};
+
+    #[test]
+    fn empty() {
+        let stats = RunningStats::new();
+        assert_eq!(stats.count(), 0);
+        assert_eq!(stats.mean(), None);
+        assert_eq!(stats.variance(), None);
+        assert_eq!(stats.min(), None);
+        assert_eq!(stats.max(), None);
+        assert_eq!(stats, RunningStats::default());
+
+        let one: RunningStats = vec![3.].into_iter().collect();
+        assert_eq!(one.variance(), Some(0.));
+        assert_eq!(one.sample_variance(), None);
+    }
+
+    #[test]
+    fn large_offset() {
+        // the naive sum of squares loses all precision here
+        let mut stats = RunningStats::new();
+        stats.push_vector(&Vector::new(vec![1e6 + 4., 1e6 + 7., 1e6 + 13., 1e6 + 16.]));
+        assert_eq!(stats.mean(), Some(1e6 + 10.));
+        assert_eq!(stats.variance(), Some(22.5));
+        assert_eq!(stats.sample_variance(), Some(30.));
+    }
+
+    #[test]
+    fn merge() {
+        let values: Vec<f32> = (0..100).map(|x| ((x * 37) % 23) as f32 - 5.).collect();
+        let all: RunningStats = values.iter().copied().collect();
+
+        let mut merged = RunningStats::new();
+        for chunk in values.chunks(7) {
+            let part: RunningStats = chunk.iter().copied().collect();
+            merged.merge(&part);
+        }
+        merged.merge(&RunningStats::new());
+
+        assert_eq!(merged.count(), all.count());
+        assert!((merged.mean().unwrap() - all.mean().unwrap()).abs() < 1e-5);
+        assert!((merged.variance().unwrap() - all.variance().unwrap()).abs() < 1e-4);
+        assert_eq!(merged.min(), Some(-5.));
+        assert_eq!(merged.max(), Some(17.));
+    }
+
+    #[test]
+    fn descriptive() {
+        let vector = Vector::new(vec![1e6 + 4., 1e6 + 7., 1e6 + 13., 1e6 + 16.]);
+        assert_eq!(vector.mean(), Some(1e6 + 10.));
+        assert_eq!(vector.variance(), Some(22.5));
+        assert_eq!(vector.sample_variance(), Some(30.));
+        assert_eq!(vector.sample_std(), Some(30_f32.sqrt()));
+        assert_eq!(vector.min(), Some(1e6 + 4.));
+        assert_eq!(vector.max(), Some(1e6 + 16.));
+        assert_eq!(vector.range(), Some(12.));
+        // the same as the running statistics
+        let stats: RunningStats = vector.iter().collect();
+        assert_eq!(vector.variance(), stats.variance());
+        assert_eq!(vector.std(), stats.std());
+    }
+
+    #[test]
+    fn shape() {
+        // symmetric values have no skewness
+        let symmetric = Vector::new(vec![1., 2., 3., 4., 5.]);
+        assert_eq!(symmetric.skewness(), Some(0.));
+        // m2 = 2, m4 = 6.8
+        assert!((symmetric.kurtosis().unwrap() + 1.3).abs() < 1e-6);
+
+        // m2 = 27 / 16, m3 = 81 / 32 with a long tail on the right
+        let skewed = Vector::new(vec![0., 0., 0., 3.]);
+        assert!((skewed.skewness().unwrap() - 2. / 3_f32.sqrt()).abs() < 1e-6);
+        assert!(Vector::new(vec![-3., 0., 0., 0.]).skewness().unwrap() < 0.);
+
+        assert!(Vector::new(vec![2., 2.]).skewness().unwrap().is_nan());
+    }
+
+    #[test]
+    fn descriptive_empty() {
+        let empty = Vector::new(vec![]);
+        assert_eq!(empty.mean(), None);
+        assert_eq!(empty.variance(), None);
+        assert_eq!(empty.std(), None);
+        assert_eq!(empty.skewness(), None);
+        assert_eq!(empty.kurtosis(), None);
+        assert_eq!(empty.min(), None);
+        assert_eq!(empty.range(), None);
+        assert_eq!(Vector::new(vec![3.]).sample_variance(), None);
+        assert_eq!(Vector::new(vec![f32::NAN, 1.]).max(), Some(1.));
+    }
+
+    #[test]
+    fn quantile() {
+        let vector = Vector::new(vec![5., 1., f32::NAN, 4., 2., 3.]);
+        assert_eq!(vector.median(), Some(3.));
+        assert_eq!(vector.quantile(0., Interpolation::Linear), Some(1.));
+        assert_eq!(vector.quantile(1., Interpolation::Linear), Some(5.));
+        // the position 0.3 * 4 = 1.2 lies between 2 and 3
+        assert_eq!(vector.quantile(0.3, Interpolation::Linear), Some(2.2));
+        assert_eq!(vector.quantile(0.3, Interpolation::Lower), Some(2.));
+        assert_eq!(vector.quantile(0.3, Interpolation::Higher), Some(3.));
+        assert_eq!(vector.quantile(0.3, Interpolation::Nearest), Some(2.));
+        assert_eq!(vector.quantile(0.3, Interpolation::Midpoint), Some(2.5));
+        assert_eq!(vector.percentile(30., Interpolation::Linear), Some(2.2));
+        assert_eq!(vector.percentile(90., Interpolation::Nearest), Some(5.));
+
+        // the positions 0.5 and 1.5 are ties which go to the even position
+        let three = Vector::new(vec![1., 2., 3.]);
+        assert_eq!(three.quantile(0.25, Interpolation::Nearest), Some(1.));
+        assert_eq!(three.quantile(0.75, Interpolation::Nearest), Some(3.));
+
+        assert_eq!(Vector::new(vec![]).median(), None);
+        assert_eq!(Vector::new(vec![f32::NAN]).median(), None);
+        assert_eq!(
+            Vector::new(vec![7.]).quantile(0.8, Interpolation::Linear),
+            Some(7.)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "the quantile has to be between 0 and 1, got 1.5")]
+    fn quantile_out_of_range() {
+        Vector::new(vec![1., 2.]).quantile(1.5, Interpolation::Linear);
+    }
+
+    #[test]
+    #[should_panic(expected = "the percentile has to be between 0 and 100, got -1")]
+    fn percentile_out_of_range() {
+        Vector::new(vec![1., 2.]).percentile(-1., Interpolation::Linear);
+    }
+
+    #[test]
+    fn mode() {
+        assert_eq!(Vector::new(vec![2., 1., 2., 1., 3.]).mode(0.), Some(1.));
+        assert_eq!(Vector::new(vec![4.]).mode(0.), Some(4.));
+        assert_eq!(Vector::new(vec![]).mode(1.), None);
+
+        // the clusters around 0 and 5, the one around 5 is larger
+        let noisy = Vector::new(vec![0.1, 5.02, -0.1, 4.98, 5., 5.03, 0.]);
+        assert!((noisy.mode(0.1).unwrap() - 5.0075).abs() < 1e-5);
+        // a big tolerance puts everything in one group
+        assert_eq!(noisy.mode(10.), noisy.mean());
+    }
+
+    #[test]
+    fn covariance() {
+        // 4 observations of 3 variables, each row is one observation
+        let mut data = Matrix::new(vec![
+            vec![1., 2., 5.],
+            vec![2., 1., 5.],
+            vec![3., 4., 5.],
+            vec![4., 3., 5.],
+        ]);
+        data.transpose();
+        let cov = data.covariance(Axis::Col);
+        assert_eq!((cov.cols(), cov.rows()), (3, 3));
+        let expected = [[5. / 3., 1., 0.], [1., 5. / 3., 0.], [0., 0., 0.]];
+        for (i, line) in expected.iter().enumerate() {
+            for (j, val) in line.iter().enumerate() {
+                assert!((cov.index(i, j) - val).abs() < 1e-6);
+            }
+        }
+        // the diagonal is the sample variance
+        assert_eq!(cov.index(1, 1), data.col(1).sample_variance().unwrap());
+        assert_eq!(data.transposed().covariance(Axis::Row), cov);
+
+        // a large offset doesn't change the covariance
+        let shifted = Matrix::new(vec![vec![1e5 + 1., 1e5 + 3.], vec![2., 4.]]);
+        assert_eq!(shifted.covariance(Axis::Col).index(0, 1), 2.);
+    }
+
+    #[test]
+    fn correlation() {
+        let data = Matrix::new(vec![
+            vec![1., 2., 3., 4.],
+            vec![2., 1., 4., 3.],
+            vec![5., 5., 5., 5.],
+        ]);
+        let corr = data.correlation(Axis::Col);
+        assert_eq!(corr.index(0, 0), 1.);
+        assert_eq!(corr.index(1, 1), 1.);
+        assert!((corr.index(0, 1) - 0.6).abs() < 1e-6);
+        assert_eq!(corr.index(1, 0), corr.index(0, 1));
+        // a constant variable has no correlation
+        assert!(corr.index(2, 2).is_nan());
+        assert!(corr.index(0, 2).is_nan());
+    }
+
+    #[test]
+    #[should_panic(expected = "the covariance needs at least 2 observations, got 1")]
+    fn covariance_one_observation() {
+        Matrix::new(vec![vec![1.], vec![2.]]).covariance(Axis::Col);
+    }
+}
@@ -0,0 +1,114 @@
+#[cfg(test)]
+mod tests {
+    use math::linear_algebra::Matrix;
+
+    #[test]
+    fn triu_tril() {
+        let matrix = Matrix::new(vec![
+            vec![1., 2., 3., 4.],
+            vec![5., 6., 7., 8.],
+            vec![9., 10., 11., 12.],
+        ]);
+        assert_eq!(
+            matrix.triu(0),
+            Matrix::new(vec![
+                vec![1., 2., 3., 4.],
+                vec![0., 6., 7., 8.],
+                vec![0., 0., 11., 12.]
+            ])
+        );
+        assert_eq!(
+            matrix.tril(1),
+            Matrix::new(vec![
+                vec![1., 2., 0., 0.],
+                vec![5., 6., 7., 0.],
+                vec![9., 10., 11., 12.]
+            ])
+        );
+        // the upper and the strictly lower triangle add up to the matrix
+        assert_eq!(matrix.triu(0) + matrix.tril(-1), matrix);
+        assert_eq!(matrix.triu(4), Matrix::new_zero(3, 4));
+        assert_eq!(matrix.tril(-3), Matrix::new_zero(3, 4));
+        assert_eq!(matrix.triu(-2), matrix);
+    }
+
+    #[test]
+    fn triu_transposed() {
+        let mut matrix = Matrix::new(vec![vec![1., 2.], vec![3., 4.]]);
+        matrix.transpose();
+        let upper = matrix.triu(0);
+        assert_eq!(upper.index(0, 1), 3.);
+        assert_eq!(upper.index(1, 0), 0.);
+    }
+
+    #[test]
+    fn symmetric() {
+        let matrix = Matrix::new(vec![vec![1., 2., 3.], vec![2., 4., 5.], vec![3., 5., 6.]]);
+        assert!(matrix.is_symmetric(0.));
+        let mut not_symmetric = matrix.clone();
+        not_symmetric.set_index(0, 2, 3.5);
+        assert!(!not_symmetric.is_symmetric(0.1));
+        assert!(not_symmetric.is_symmetric(0.5));
+        assert!(!Matrix::new_zero(2, 3).is_symmetric(1.));
+    }
+
+    #[test]
+    fn diagonal() {
+        let mut matrix = Matrix::new(vec![vec![1., 0.], vec![0., 2.]]);
+        assert!(matrix.is_diagonal(0.));
+        matrix.set_index(1, 0, 1e-3);
+        assert!(!matrix.is_diagonal(0.));
+        assert!(matrix.is_diagonal(1e-2));
+        assert!(Matrix::new_zero(3, 2).is_diagonal(0.));
+    }
+
+    #[test]
+    fn orthogonal() {
+        let permutation = Matrix::new(vec![vec![0., 1., 0.], vec![0., 0., 1.], vec![1., 0., 0.]]);
+        assert!(permutation.is_orthogonal(0.));
+
+        let (_, vectors) = Matrix::new(vec![vec![4., 1., 2.], vec![1., 3., 0.], vec![2., 0., 5.]])
+            .symmetric_eigen();
+        assert!(vectors.is_orthogonal(1e-5));
+
+        let scaled = Matrix::new(vec![vec![2., 0.], vec![0., 2.]]);
+        assert!(!scaled.is_orthogonal(1e-3));
+        assert!(!Matrix::new(vec![vec![1., 0., 0.], vec![0., 1., 0.]]).is_orthogonal(0.));
+    }
+
+    #[test]
+    fn upper_triangular() {
+        let matrix = Matrix::new(vec![vec![1., 2., 3.], vec![4., 5., 6.], vec![7., 8., 9.]]);
+        assert!(!matrix.is_upper_triangular());
+        assert!(matrix.triu(0).is_upper_triangular());
+        assert!(matrix.triu(2).is_upper_triangular());
+
+        let mut lower = matrix.tril(0);
+        assert!(!lower.is_upper_triangular());
+        lower.transpose();
+        assert!(lower.is_upper_triangular());
+    }
+
+    #[test]
+    fn positive_definite() {
+        let laplacian = Matrix::new(vec![
+            vec![2., -1., 0.],
+            vec![-1., 2., -1.],
+            vec![0., -1., 2.],
+        ]);
+        assert!(laplacian.is_positive_definite());
+
+        // a gram matrix of independent columns is positive definite
+        let a = Matrix::new(vec![vec![1., 2., 0.], vec![0., 1., 3.], vec![1., 0., 1.]]);
+        let mut at = a.clone();
+        at.transpose();
+        assert!(at.dot_mat(&a).is_positive_definite());
+
+        let semi_definite = Matrix::new(vec![vec![1., 1.], vec![1., 1.]]);
+        assert!(!semi_definite.is_positive_definite());
+        let negative = Matrix::new(vec![vec![-1., 0.], vec![0., -1.]]);
+        assert!(!negative.is_positive_definite());
+        let not_symmetric = Matrix::new(vec![vec![2., 1.], vec![0., 2.]]);
+        assert!(!not_symmetric.is_positive_definite());
+    }
+}
@@ -0,0 +1,62 @@
+#[cfg(test)]
+mod tests {
+    use math::linear_algebra::{Matrix, Vector};
+    use math::testing::{is_close, matrix_diff, vector_diff};
+    use math::{assert_matrix_eq, assert_vector_eq};
+
+    #[test]
+    fn close() {
+        assert!(is_close(1., 1.05, 0.1, 0.));
+        assert!(!is_close(1., 1.05, 0.01, 0.));
+        assert!(is_close(1000., 1001., 0., 0.01));
+        assert!(is_close(f32::INFINITY, f32::INFINITY, 0., 0.));
+        assert!(!is_close(f32::NAN, f32::NAN, 1., 1.));
+    }
+
+    #[test]
+    fn diff() {
+        let a = Matrix::new(vec![vec![1., 2.], vec![3., 4.]]);
+        let b = Matrix::new(vec![vec![1., 2.5], vec![3., 4.]]);
+        let diff = matrix_diff(&a, &b, 0.1, 0.).unwrap();
+        assert!(diff.starts_with("1 of 4 elements differ"));
+        assert!(diff.contains("(0, 1): 2 != 2.5"));
+
+        let c = Matrix::new(vec![vec![1., 2., 3.]]);
+        assert_eq!(
+            matrix_diff(&a, &c, 0., 0.).unwrap(),
+            "wrong matrix shape 2x2 != 1x3"
+        );
+
+        let v = Vector::new((0..20).map(|x| x as f32).collect());
+        let w = Vector::new(vec![-1.; 20]);
+        let diff = vector_diff(&v, &w, 0., 0.).unwrap();
+        assert!(diff.contains("[9]: 9 != -1"));
+        assert!(!diff.contains("[10]"));
+        assert!(diff.ends_with("... and 10 more"));
+    }
+
+    #[test]
+    fn macros() {
+        let a = Matrix::new(vec![vec![1., 2.], vec![3., 4.]]);
+        let mut t = a.clone();
+        t.transpose();
+        assert_matrix_eq!(a, a.clone());
+        assert_matrix_eq!(a, t, abs = 1.);
+        assert_vector_eq!(Vector::new(vec![100.]), Vector::new(vec![101.]), rel = 0.01);
+    }
+
+    #[test]
+    #[should_panic(expected = "(1, 0): 3 != 2")]
+    fn matrix_panic() {
+        let a = Matrix::new(vec![vec![1., 2.], vec![3., 4.]]);
+        let mut t = a.clone();
+        t.transpose();
+        assert_matrix_eq!(a, t);
+    }
+
+    #[test]
+    #[should_panic(expected = "wrong vector length 1 != 2")]
+    fn vector_panic() {
+        assert_vector_eq!(Vector::new(vec![1.]), Vector::new(vec![1., 1.]));
+    }
+}
@@ -0,0 +1,153 @@
+#[cfg(test)]
+mod tests {
+    use math::linear_algebra::Vector;
+    use math::timeseries::*;
+
+    #[test]
+    fn acf_constant() {
+        let series = Vector::new(vec![2., 2., 2.]);
+        assert_eq!(acf(&series, 2), Vector::new(vec![1., 0., 0.]));
+    }
+
+    #[test]
+    fn pacf_ar1() {
+        // x_t = 0.8 * x_{t-1} + noise, the pacf cuts off after lag 1
+        let mut rand = math::random::Random::new();
+        let mut vec = vec![0.];
+        for t in 1..2000 {
+            vec.push(0.8 * vec[t - 1] + rand.f32() - 0.5);
+        }
+        let pacf = pacf(&Vector::new(vec), 3);
+        assert!((pacf.index(1) - 0.8).abs() < 0.05);
+        assert!(pacf.index(2).abs() < 0.1);
+        assert!(pacf.index(3).abs() < 0.1);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "the max lag has to be smaller then the len of the series max_lag = 3, len = 3"
+    )]
+    fn acf_panic() {
+        acf(&Vector::new(vec![1., 2., 3.]), 3);
+    }
+
+    #[test]
+    fn difference_zero() {
+        let series = Vector::new(vec![1., 2., 4.]);
+        assert_eq!(difference(&series, 0), series);
+    }
+
+    #[test]
+    fn detrend_keeps_residual() {
+        let series = Vector::new(vec![1., 2., 1., 2.]);
+        let detrended = detrend(&series);
+        assert!(detrended.vec().iter().sum::<f32>().abs() < 1e-6);
+        assert!((detrended.index(0) - detrended.index(2) - 0.4).abs() < 1e-6);
+    }
+
+    #[test]
+    fn holt_winters_trend_and_season() {
+        // linear trend plus a season of period 4
+        let season = [2., -1., 0., -1.];
+        let series: Vec<f32> = (0..40).map(|t| 0.5 * t as f32 + season[t % 4]).collect();
+        let smoothing = ExponentialSmoothing::triple(&Vector::new(series), 0.3, 0.1, 0.1, 4);
+        let forecast = smoothing.forecast(4);
+        for h in 0..4 {
+            let expected = 0.5 * (40 + h) as f32 + season[h % 4];
+            assert!((forecast.index(h) - expected).abs() < 0.2);
+        }
+    }
+
+    #[test]
+    fn ar2() {
+        // x_t = 0.5 + 0.6 * x_(t-1) - 0.2 * x_(t-2) + noise
+        let mut rand = math::random::Random::new();
+        let mut vec = vec![0., 0.];
+        for t in 2..3000 {
+            vec.push(0.5 + 0.6 * vec[t - 1] - 0.2 * vec[t - 2] + (rand.f32() - 0.5) * 0.1);
+        }
+        let ar = AR::fit(&Vector::new(vec), 2);
+        assert_eq!(ar.order(), 2);
+        assert!((ar.intercept() - 0.5).abs() < 0.02);
+        assert!((ar.coefficients().index(0) - 0.6).abs() < 0.02);
+        assert!((ar.coefficients().index(1) + 0.2).abs() < 0.02);
+
+        // the forecast converges to the mean c / (1 - phi_1 - phi_2)
+        let forecast = ar.forecast(50);
+        assert!((forecast.index(49) - 0.5 / 0.6).abs() < 0.02);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "triple exponential smoothing needs at least 2 periods, period = 3, len = 5"
+    )]
+    fn triple_panic() {
+        ExponentialSmoothing::triple(&Vector::new(vec![1., 2., 3., 4., 5.]), 0.5, 0.5, 0.5, 3);
+    }
+
+    #[test]
+    fn moving_average_edges() {
+        let series = Vector::new(vec![2., 4., 6., 8., 10.]);
+        assert_eq!(
+            moving_average(&series, 3, Edge::Valid),
+            Vector::new(vec![4., 6., 8.])
+        );
+        assert_eq!(
+            moving_average(&series, 3, Edge::Shrink),
+            Vector::new(vec![2., 3., 4., 6., 8.])
+        );
+        let nan = moving_average(&series, 3, Edge::Nan).vec();
+        assert!(nan[0].is_nan() && nan[1].is_nan());
+        assert_eq!(&nan[2..], &[4., 6., 8.]);
+
+        // a window of 1 keeps the series
+        assert_eq!(moving_average(&series, 1, Edge::Valid), series);
+        // a window longer then the series
+        assert_eq!(moving_average(&series, 7, Edge::Valid).len(), 0);
+        assert_eq!(
+            moving_average(&series, 7, Edge::Shrink),
+            Vector::new(vec![2., 3., 4., 5., 6.])
+        );
+        assert_eq!(moving_average(&series, 7, Edge::Nan).len(), 5);
+    }
+
+    #[test]
+    fn moving_std_window() {
+        let series = Vector::new(vec![1e6 + 1., 1e6 + 3., 1e6 + 3., 1e6 + 3.]);
+        assert_eq!(
+            moving_std(&series, 2, Edge::Shrink),
+            Vector::new(vec![0., 1., 0., 0.])
+        );
+        let window = Vector::new(vec![1e6 + 1., 1e6 + 3., 1e6 + 3.]);
+        assert_eq!(
+            moving_std(&series, 3, Edge::Valid).index(0),
+            window.std().unwrap()
+        );
+    }
+
+    #[test]
+    fn exponential_moving_average_alpha() {
+        let series = Vector::new(vec![0., 8., 8., 8.]);
+        assert_eq!(
+            exponential_moving_average(&series, 0.5),
+            Vector::new(vec![0., 4., 6., 7.])
+        );
+        assert_eq!(exponential_moving_average(&series, 1.), series);
+        assert_eq!(
+            exponential_moving_average(&Vector::new(vec![]), 0.3).len(),
+            0
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "the window has to be at least 1")]
+    fn moving_average_zero_window() {
+        moving_average(&Vector::new(vec![1., 2.]), 0, Edge::Valid);
+    }
+
+    #[test]
+    #[should_panic(expected = "alpha has to be in (0, 1], got 0")]
+    fn exponential_moving_average_zero_alpha() {
+        exponential_moving_average(&Vector::new(vec![1., 2.]), 0.);
+    }
+}
@@ -154,6 +154,20 @@ mod tests {
         );
     }
 
+    #[test]
+    fn from_bytes_vec() {
+        let vector = Vector::new(vec![2., -1.5, 6.]);
+        assert_eq!(Vector::from_bytes(&vector.bytes()), vector);
+        assert_eq!(Vector::from_bytes(&Vector::new(vec![]).bytes()).len(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "the len of the data 7 is not a multiple of 4")]
+    fn from_bytes_vec_wrong_len() {
+        let bytes = Vector::new(vec![2., 1.]).bytes();
+        let _ = Vector::from_bytes(&bytes[..7]);
+    }
+
     #[test]
     fn vec() {
         let vector = Vector::new(vec![2., 1., 6.]);
@@ -300,4 +314,48 @@ mod tests {
         let vector2 = Vector::new(vec![3., 1., 3., 1.]);
         vector1.mul_vec(&vector2);
     }
+
+    #[test]
+    fn apply() {
+        let mut vector = Vector::new(vec![1., 2., 3.]);
+        let scale = 3.;
+        vector.apply(|x| x * scale);
+        assert_eq!(vector, Vector::new(vec![3., 6., 9.]));
+
+        let func: Box<dyn Fn(f32) -> f32> = Box::new(|x| x / scale);
+        vector.apply_dyn(func.as_ref());
+        assert_eq!(vector, Vector::new(vec![1., 2., 3.]));
+    }
+
+    #[test]
+    fn iter_collect() {
+        let a = Vector::new(vec![1., 2., 3.]);
+        let b = Vector::new(vec![4., 5., 6.]);
+        let sum: Vector = a.iter().zip(b.iter()).map(|(x, y)| x + y).collect();
+        assert_eq!(sum, a.clone() + b.clone());
+        assert_eq!(a.iter().len(), 3);
+        assert_eq!(
+            a.iter().rev().collect::<Vector>(),
+            Vector::new(vec![3., 2., 1.])
+        );
+        let empty: Vector = std::iter::empty().collect();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn neg() {
+        let vector = Vector::new(vec![1., -2., 0.5]);
+        assert_eq!(-&vector, Vector::new(vec![-1., 2., -0.5]));
+        assert_eq!(-(-vector.clone()), vector);
+    }
+
+    #[test]
+    fn scalar_operators() {
+        let vector = Vector::new(vec![1., 2., 4.]);
+        assert_eq!(vector.clone() * 2., Vector::new(vec![2., 4., 8.]));
+        assert_eq!(0.5 * vector.clone(), Vector::new(vec![0.5, 1., 2.]));
+        assert_eq!(vector.clone() + 1., Vector::new(vec![2., 3., 5.]));
+        assert_eq!(vector.clone() - 1., Vector::new(vec![0., 1., 3.]));
+        assert_eq!(vector / 4., Vector::new(vec![0.25, 0.5, 1.]));
+    }
 }
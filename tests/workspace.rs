@@ -0,0 +1,61 @@
+#[cfg(test)]
+mod tests {
+    use math::linear_algebra::workspace::Workspace;
+    use math::linear_algebra::{Matrix, Vector};
+
+    #[test]
+    fn take_reuses_buffers() {
+        let mut ws = Workspace::new();
+        let mut buf = ws.take(8);
+        buf.extend_from_slice(&[1., 2.]);
+        let ptr = buf.as_ptr();
+        ws.give(buf);
+        assert_eq!(ws.len(), 1);
+
+        let buf = ws.take(4);
+        assert_eq!(buf.as_ptr(), ptr);
+        assert!(buf.is_empty());
+        assert!(ws.is_empty());
+    }
+
+    #[test]
+    fn take_smallest_fitting() {
+        let mut ws = Workspace::new();
+        ws.give(Vec::with_capacity(100));
+        ws.give(Vec::with_capacity(10));
+        ws.give(Vec::with_capacity(2));
+        assert!(ws.take(5).capacity() < 100);
+        assert_eq!(ws.len(), 2);
+        assert!(ws.take(1000).capacity() >= 1000);
+    }
+
+    #[test]
+    fn take_zeroed() {
+        let mut ws = Workspace::new();
+        ws.give(vec![1., 2., 3.]);
+        assert_eq!(ws.take_zeroed(2), vec![0., 0.]);
+    }
+
+    #[test]
+    fn with_matches_allocating() {
+        let mut ws = Workspace::new();
+        let mut matrix1 = Matrix::new(vec![vec![1., 2., 3.], vec![4., 5., 6.]]);
+        let matrix2 = Matrix::new(vec![vec![1., 0.], vec![2., 1.], vec![0., 3.]]);
+        let vector = Vector::new(vec![1., -1., 2.]);
+
+        for _ in 0..2 {
+            assert_eq!(matrix1.matrix_flatt_with(&mut ws), matrix1.matrix_flatt());
+            assert_eq!(matrix1.col_with(1, &mut ws), matrix1.col(1));
+            assert_eq!(matrix1.row_with(0, &mut ws), matrix1.row(0));
+            matrix1.transpose();
+        }
+
+        let product = matrix1.dot_mat_with(&matrix2, &mut ws);
+        assert_eq!(product, matrix1.dot_mat(&matrix2));
+        ws.recycle_matrix(product);
+        assert_eq!(
+            matrix1.dot_vec_with(&vector, &mut ws),
+            matrix1.dot_vec(&vector)
+        );
+    }
+}